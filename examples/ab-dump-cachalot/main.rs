@@ -96,7 +96,7 @@ fn load_dir(dir: &str) -> (AbsoluteVulnerability, Vec<Board>) {
             std::fs::File::open(&path).expect("open shard"),
         ))
         .expect("parse shard");
-        vul = Some(dump.vulnerability);
+        vul = Some(dump.header.vulnerability);
         boards.extend(dump.boards);
     }
     (vul.expect("at least one shard"), boards)
@@ -92,8 +92,8 @@ fn report(
     writeln!(
         out,
         "ON {} vs OFF {} ({} boards, vul {vul}): {} fired ({:.2}%)",
-        on.our_label,
-        off.our_label,
+        on.header.our_label,
+        off.header.our_label,
         n,
         d,
         100.0 * d as f64 / n.max(1) as f64,
@@ -144,7 +144,7 @@ fn main() -> anyhow::Result<()> {
     let on = common::load_dump(&args.on);
     let off = common::load_dump(&args.off);
     assert_eq!(on.boards.len(), off.boards.len(), "dumps must be aligned");
-    let vul = args.vulnerability.unwrap_or(on.vulnerability);
+    let vul = args.vulnerability.unwrap_or(on.header.vulnerability);
 
     // Pair our (table_a) contract with the feature on vs off; the deals must match.
     let mut deals = Vec::with_capacity(on.boards.len());
@@ -186,7 +186,7 @@ fn main() {
     let on = common::load_dump(&args.on);
     let off = common::load_dump(&args.off);
     assert_eq!(on.boards.len(), off.boards.len(), "dumps must be aligned");
-    let vul = args.vulnerability.unwrap_or(on.vulnerability);
+    let vul = args.vulnerability.unwrap_or(on.header.vulnerability);
     let n = on.boards.len();
 
     // Leader-view partnerships (knobs are read at book-construction time).  The OFF
@@ -342,8 +342,8 @@ fn main() {
         };
         println!(
             "{bracket} ON {} vs OFF {} ({n} boards, vul {vul}): {fired} fired ({:.2}%)",
-            on.our_label,
-            off.our_label,
+            on.header.our_label,
+            off.header.our_label,
             100.0 * fired as f64 / n.max(1) as f64,
         );
         // Read the perfect-defense line as the arbiter: plain SD relaxes the
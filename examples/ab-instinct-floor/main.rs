@@ -221,15 +221,7 @@ fn main() {
                 &deal,
                 &mut tally,
             );
-            (
-                Board {
-                    deal,
-                    dealer,
-                    table_a,
-                    table_b,
-                },
-                tally,
-            )
+            (Board::new(deal, dealer, table_a, table_b), tally)
         })
         .collect();
 
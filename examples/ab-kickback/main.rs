@@ -422,25 +422,26 @@ fn rescore(args: &Args, path: &str) {
         ValueEnum::from_str(label, true)
             .unwrap_or_else(|e| panic!("dump label {label:?} is not an arm: {e}"))
     };
-    let feature = parse(&dump.our_label);
-    let baseline = parse(&dump.their_label);
-    let vul = dump.vulnerability;
+    let feature = parse(&dump.header.our_label);
+    let baseline = parse(&dump.header.their_label);
+    let vul = dump.header.vulnerability;
     let count = dump
+        .header
         .gen_args
         .iter()
         .position(|a| a == "--count" || a == "-c")
-        .and_then(|i| dump.gen_args.get(i + 1))
+        .and_then(|i| dump.header.gen_args.get(i + 1))
         .and_then(|v| v.parse::<usize>().ok());
     println!(
         "=== rescore: {} vs {}, {} divergent boards of {} bid, vulnerability {}, gen seed {:?} ===",
-        dump.our_label,
-        dump.their_label,
+        dump.header.our_label,
+        dump.header.their_label,
         dump.boards.len(),
         count.map_or_else(|| "?".to_owned(), |c| c.to_string()),
         vul,
-        dump.seed,
+        dump.header.seed,
     );
-    println!("gen args: {:?}", dump.gen_args);
+    println!("gen args: {:?}", dump.header.gen_args);
 
     let contracts: Vec<_> = dump
         .boards
@@ -678,11 +679,13 @@ fn main() {
         .collect();
     let boards: Vec<Board> = deals
         .par_iter()
-        .map(|&(dealer, deal)| Board {
-            deal,
-            dealer,
-            table_a: bid_out(&args, &feature, &baseline, true, dealer, &deal),
-            table_b: bid_out(&args, &feature, &baseline, false, dealer, &deal),
+        .map(|&(dealer, deal)| {
+            Board::new(
+                deal,
+                dealer,
+                bid_out(&args, &feature, &baseline, true, dealer, &deal),
+                bid_out(&args, &feature, &baseline, false, dealer, &deal),
+            )
         })
         .collect();
 
@@ -702,21 +705,25 @@ fn main() {
     if let Some(dir) = &args.dump {
         std::fs::create_dir_all(dir).expect("create dump directory");
         let shard = common::Dump {
-            our_label: args.feature.label().to_owned(),
-            their_label: args.baseline.label().to_owned(),
-            vulnerability: args.vulnerability,
-            seed: Some(args.seed),
-            gen_args: std::env::args().skip(1).collect(),
+            header: pons::record::Header {
+                seed: Some(args.seed),
+                gen_args: std::env::args().skip(1).collect(),
+                ..pons::record::Header::new(
+                    args.feature.label(),
+                    args.baseline.label(),
+                    args.vulnerability,
+                )
+            },
             boards: divergent
                 .iter()
                 .map(|&index| {
                     let board = &boards[index];
-                    Board {
-                        deal: board.deal,
-                        dealer: board.dealer,
-                        table_a: board.table_a.clone(),
-                        table_b: board.table_b.clone(),
-                    }
+                    Board::new(
+                        board.deal,
+                        board.dealer,
+                        board.table_a.clone(),
+                        board.table_b.clone(),
+                    )
                 })
                 .collect(),
         };
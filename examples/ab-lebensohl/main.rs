@@ -266,6 +266,7 @@ fn next_call_ns(
         &context,
         &calls,
         partnership,
+        partnership,
         &mut rng,
         pd_layouts,
     );
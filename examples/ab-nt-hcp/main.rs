@@ -116,11 +116,13 @@ fn main() {
         .collect();
     let boards: Vec<Board> = deals
         .par_iter()
-        .map(|&(dealer, deal)| Board {
-            deal,
-            dealer,
-            table_a: bid_out(&partnerships, &args, true, dealer, &deal),
-            table_b: bid_out(&partnerships, &args, false, dealer, &deal),
+        .map(|&(dealer, deal)| {
+            Board::new(
+                deal,
+                dealer,
+                bid_out(&partnerships, &args, true, dealer, &deal),
+                bid_out(&partnerships, &args, false, dealer, &deal),
+            )
         })
         .collect();
 
@@ -208,18 +208,20 @@ fn main() {
         .collect();
     let boards: Vec<Board> = deals
         .par_iter()
-        .map(|&(dealer, deal)| Board {
-            deal,
-            dealer,
-            table_a: bid_out(&feature, &baseline, true, dealer, args.vulnerability, &deal),
-            table_b: bid_out(
-                &feature,
-                &baseline,
-                false,
+        .map(|&(dealer, deal)| {
+            Board::new(
+                deal,
                 dealer,
-                args.vulnerability,
-                &deal,
-            ),
+                bid_out(&feature, &baseline, true, dealer, args.vulnerability, &deal),
+                bid_out(
+                    &feature,
+                    &baseline,
+                    false,
+                    dealer,
+                    args.vulnerability,
+                    &deal,
+                ),
+            )
         })
         .collect();
 
@@ -6,12 +6,15 @@
 //! ```sh
 //! cargo run --example bba-card -- --system american >cards/American.bbsa
 //! cargo run --example bba-card -- --system dutch    >cards/Dutch.bbsa
+//! cargo run --example bba-card -- --system sayc     >cards/SAYC.bbsa
+//! cargo run --example bba-card -- --system acol     >cards/Acol.bbsa
+//! cargo run --example bba-card -- --system precision >cards/Precision.bbsa
 //! ```
 //!
 //! Needs neither EPBot nor the `bba` feature — a card is a pure function of the
 //! thread-local knob state.
 
-use pons::bidding::card::{Card, american_card, dutch_card};
+use pons::bidding::card::{Card, acol_card, american_card, dutch_card, precision_card, sayc_card};
 
 fn main() {
     let mut system = "american".to_owned();
@@ -21,7 +24,7 @@ fn main() {
             "--system" => system = args.next().unwrap_or_default(),
             other => {
                 eprintln!("bba-card: unexpected argument `{other}`");
-                eprintln!("usage: bba-card [--system american|dutch]");
+                eprintln!("usage: bba-card [--system american|dutch|sayc|acol|precision]");
                 std::process::exit(2);
             }
         }
@@ -38,9 +41,12 @@ fn card_for(system: &str) -> Card {
     match system {
         "american" => american_card(&pons::bidding::agreements::Agreements::default()),
         "dutch" => dutch_card(&pons::bidding::agreements::Agreements::default()),
+        "sayc" => sayc_card(&pons::bidding::agreements::Agreements::default()),
+        "acol" => acol_card(&pons::bidding::agreements::Agreements::default()),
+        "precision" => precision_card(&pons::bidding::agreements::Agreements::default()),
         other => {
             eprintln!("bba-card: no card generator for system `{other}`");
-            eprintln!("           known systems: american, dutch");
+            eprintln!("           known systems: american, dutch, sayc, acol, precision");
             std::process::exit(2);
         }
     }
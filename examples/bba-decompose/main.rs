@@ -311,13 +311,17 @@ fn load_arms(inputs: &[String]) -> anyhow::Result<Vec<Arm>> {
     for file in files {
         let dump: Dump =
             serde_json::from_reader(std::io::BufReader::new(std::fs::File::open(&file)?))?;
-        if let Some(arm) = arms.iter_mut().find(|arm| arm.vul == dump.vulnerability) {
+        if let Some(arm) = arms
+            .iter_mut()
+            .find(|arm| arm.vul == dump.header.vulnerability)
+        {
             anyhow::ensure!(
-                arm.our_label == dump.our_label && arm.their_label == dump.their_label,
+                arm.our_label == dump.header.our_label
+                    && arm.their_label == dump.header.their_label,
                 "dump {file:?} has mismatched labels — won't merge different references",
             );
             arm.origin
-                .extend((0..dump.boards.len()).map(|i| (dump.seed, i)));
+                .extend((0..dump.boards.len()).map(|i| (dump.header.seed, i)));
             arm.boards.extend(dump.boards);
         } else {
             arms.push(arm_from_dump(dump));
@@ -329,12 +333,12 @@ fn load_arms(inputs: &[String]) -> anyhow::Result<Vec<Arm>> {
 fn arm_from_dump(dump: Dump) -> Arm {
     let board_count = dump.boards.len();
     Arm {
-        vul: dump.vulnerability,
+        vul: dump.header.vulnerability,
         boards: dump.boards,
-        origin: (0..board_count).map(|i| (dump.seed, i)).collect(),
-        gen_args: dump.gen_args,
-        our_label: dump.our_label,
-        their_label: dump.their_label,
+        origin: (0..board_count).map(|i| (dump.header.seed, i)).collect(),
+        gen_args: dump.header.gen_args,
+        our_label: dump.header.our_label,
+        their_label: dump.header.their_label,
     }
 }
 
@@ -824,20 +828,19 @@ mod tests {
             .enumerate()
             .map(|(index, deal)| {
                 let dealer = Seat::ALL[index % Seat::ALL.len()];
-                common::Board {
-                    table_a: common::bid_out(&partnership, &partnership, true, dealer, vul, &deal),
-                    table_b: common::bid_out(&partnership, &partnership, false, dealer, vul, &deal),
+                common::Board::new(
                     deal,
                     dealer,
-                }
+                    common::bid_out(&partnership, &partnership, true, dealer, vul, &deal),
+                    common::bid_out(&partnership, &partnership, false, dealer, vul, &deal),
+                )
             })
             .collect::<Vec<_>>();
         arm_from_dump(Dump {
-            our_label: format!("fixture {floor:?}"),
-            their_label: "fixture reference".into(),
-            vulnerability: vul,
-            seed: Some(0xD3C0_5EED),
-            gen_args: Vec::new(),
+            header: pons::record::Header {
+                seed: Some(0xD3C0_5EED),
+                ..pons::record::Header::new(format!("fixture {floor:?}"), "fixture reference", vul)
+            },
             boards,
         })
     }
@@ -892,11 +895,10 @@ mod tests {
     #[test]
     fn json_emits_generic_call_and_compatibility_alias() {
         let arm = arm_from_dump(Dump {
-            our_label: "ours".into(),
-            their_label: "BEN".into(),
-            vulnerability: AbsoluteVulnerability::NONE,
-            seed: Some(7),
-            gen_args: Vec::new(),
+            header: pons::record::Header {
+                seed: Some(7),
+                ..pons::record::Header::new("ours", "BEN", AbsoluteVulnerability::NONE)
+            },
             boards: Vec::new(),
         });
         let row = sample_row();
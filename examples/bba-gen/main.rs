@@ -61,7 +61,8 @@ struct Args {
     count: usize,
 
     /// Write the bid boards as JSON here; default is stdout (pipe into
-    /// `bba-score`, or save to re-score many ways without re-bidding)
+    /// `bba-score`, or save to re-score many ways without re-bidding).  A
+    /// `.jsonl` path writes one board per line (see [`pons::record`])
     #[arg(short, long)]
     output: Option<String>,
 
@@ -235,6 +236,19 @@ struct Args {
     #[arg(long = "disclose-conv", value_parser = parse_override, value_name = "NAME=0|1")]
     disclose_conv: Vec<(CString, c_int)>,
 
+    /// Set any of *our* knobs by its registry key, e.g.
+    /// `--knob rebid.fourth_suit_forcing=false`; repeatable, applied after
+    /// every `--ns-*` flag so it wins over them.
+    ///
+    /// Reaches the knobs no `--ns-*` flag was ever written for.  See
+    /// `--list-knobs` for the keys and the values each one takes.
+    #[arg(long = "knob", value_name = "KEY=VALUE")]
+    knob: Vec<String>,
+
+    /// Print every knob key with its default and accepted values, then exit.
+    #[arg(long)]
+    list_knobs: bool,
+
     /// Only keep deals with a balanced 15-17 HCP hand somewhere (a 1NT-opener
     /// candidate), to raise the yield of 1NT boards.  Cheap shape gate, no
     /// bidding; `--count` then means *kept* boards.
@@ -878,6 +892,12 @@ struct Args {
     #[arg(long, default_value_t = false)]
     no_ns_limit_raise_acceptance: bool,
 
+    /// Play Reverse Drury opposite a third- or fourth-seat major: a passed
+    /// responder's `2♣` is the limit raise.  Off by default — on-switch for the
+    /// A/B (see `response.drury`).
+    #[arg(long, default_value_t = false)]
+    ns_drury: bool,
+
     /// Disable opener's answer to partner's cue-raise (`1M (ovc) cue -`)
     /// (shipped default-on; see `competition.cue_raise_answer`).
     #[arg(long, default_value_t = false)]
@@ -2342,6 +2362,7 @@ fn arm_knobs(args: &Args) -> anyhow::Result<Agreements> {
     agreements.response.two_over_one_major_discount = args.ns_two_over_one_major_discount;
     agreements.response.major_game_tries = !args.no_ns_major_game_tries;
     agreements.response.limit_raise_acceptance = !args.no_ns_limit_raise_acceptance;
+    agreements.response.drury = args.ns_drury;
     agreements.rebid.new_minor_forcing = args.ns_new_minor_forcing;
     agreements.rebid.balanced_1nt_rebid = !args.no_ns_balanced_1nt_rebid;
     agreements.rebid.major_rebid_tails = !args.no_ns_major_rebid_tails;
@@ -2361,12 +2382,41 @@ fn arm_knobs(args: &Args) -> anyhow::Result<Agreements> {
     agreements.notrump.texas_game_floor = args.ns_texas_game_floor;
     agreements.notrump.sixcard_invite_floor = args.ns_sixcard_invite_floor;
     agreements.notrump.sixcard_accept_floor = args.ns_sixcard_accept_floor;
+    for spec in &args.knob {
+        let (key, value) = spec
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("--knob must be KEY=VALUE, got {spec:?}"))?;
+        agreements.set(key.trim(), value)?;
+    }
     Ok(agreements)
 }
 
+/// `--list-knobs`: one line per registry row, grouped by area
+fn list_knobs() {
+    use pons::bidding::agreements::KNOBS;
+
+    let mut area = "";
+    for knob in KNOBS {
+        if knob.area != area {
+            area = knob.area;
+            println!("[{area}]");
+        }
+        println!(
+            "  {} = {}  ({})",
+            knob.key,
+            knob.default_value(),
+            knob.accepts()
+        );
+    }
+}
+
 #[allow(clippy::too_many_lines)]
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
+    if args.list_knobs {
+        list_knobs();
+        return Ok(());
+    }
     let path = std::env::var("BBA_LIB").unwrap_or_else(|_| DEFAULT_LIB.into());
     // A full `.bbsa` card expands to convention overrides applied before the
     // explicit `--*-conv` singles, so singles override the card.
@@ -2765,33 +2815,31 @@ fn main() -> anyhow::Result<()> {
             }
             _ => bid_out(ours, opponent, false, dealer, args.vulnerability, &deal),
         };
-        boards.push(Board {
-            deal,
-            dealer,
-            table_a,
-            table_b,
-        });
+        boards.push(Board::new(deal, dealer, table_a, table_b));
     }
 
     let dump = Dump {
-        our_label,
-        their_label,
-        vulnerability: args.vulnerability,
-        seed: Some(seed),
-        gen_args: std::env::args().skip(1).collect(),
+        header: pons::record::Header {
+            seed: Some(seed),
+            gen_args: std::env::args().skip(1).collect(),
+            ..pons::record::Header::new(our_label, their_label, args.vulnerability)
+        },
         boards,
     };
     match args.output.as_deref() {
+        Some(path) if path.ends_with(".jsonl") => {
+            dump.write_jsonl(std::io::BufWriter::new(std::fs::File::create(path)?))?;
+        }
         Some(path) => {
-            serde_json::to_writer(std::io::BufWriter::new(std::fs::File::create(path)?), &dump)?
+            serde_json::to_writer(std::io::BufWriter::new(std::fs::File::create(path)?), &dump)?;
         }
         None => serde_json::to_writer(std::io::stdout().lock(), &dump)?,
     }
     eprintln!(
         "bba-gen: {} (us) vs {} (them), vulnerability {} — wrote {} boards ({scanned} scanned){}",
-        dump.our_label,
-        dump.their_label,
-        dump.vulnerability,
+        dump.header.our_label,
+        dump.header.their_label,
+        dump.header.vulnerability,
         dump.boards.len(),
         match args.output.as_deref() {
             Some(path) => format!(" to {path}"),
@@ -2847,4 +2895,27 @@ mod tests {
             );
         }
     }
+
+    /// `--knob` lands on the field its key names, after the `--ns-*` flag for
+    /// that field, and a bad spec fails the run rather than arming the default.
+    #[test]
+    fn knob_flags_win_over_ns_flags() {
+        let armed = arm_knobs(&Args::parse_from([
+            "bba-gen",
+            "--no-ns-fourth-suit-forcing",
+            "--knob",
+            "rebid.fourth_suit_forcing=true",
+            "--knob",
+            "decision.reading.rkcb_variant=kickback",
+        ]))
+        .unwrap();
+        assert!(armed.rebid.fourth_suit_forcing);
+        assert_eq!(
+            armed.decision.reading.rkcb_variant,
+            pons::bidding::instinct::RkcbVariant::Kickback
+        );
+        for spec in ["rebid.fourth_suit_forcing", "rebid.fourth_suit=true"] {
+            assert!(arm_knobs(&Args::parse_from(["bba-gen", "--knob", spec])).is_err());
+        }
+    }
 }
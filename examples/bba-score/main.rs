@@ -33,7 +33,7 @@ use common::{Dump, mean_with_ci, score_boards, seat_to_act};
 /// A/B duplicate match)
 #[derive(Parser)]
 struct Args {
-    /// Board dump(s) from `bba-gen` (default: stdin).  Several shard files merge
+    /// Board dump(s) from `bba-gen` (default: stdin), JSON or `.jsonl`.  Several shard files merge
     /// into one match — their boards concatenate; the labels and vulnerability
     /// must agree (they do when the shards share `bba-gen` flags).
     inputs: Vec<String>,
@@ -123,29 +123,26 @@ fn main() -> anyhow::Result<()> {
     // processes).  The labels and vulnerability must agree across shards — they do
     // when the shards were generated with the same flags, and a mismatch would
     // silently average two different experiments, so we reject it.
-    let mut dump: Dump = if args.inputs.is_empty() {
-        serde_json::from_reader(std::io::stdin().lock())?
+    let mut dump = if args.inputs.is_empty() {
+        Dump::read(std::io::stdin().lock())?
     } else {
-        serde_json::from_reader(std::io::BufReader::new(std::fs::File::open(
-            &args.inputs[0],
-        )?))?
+        Dump::load(&args.inputs[0])?
     };
     for path in args.inputs.iter().skip(1) {
-        let shard: Dump =
-            serde_json::from_reader(std::io::BufReader::new(std::fs::File::open(path)?))?;
+        let shard = Dump::load(path)?;
         anyhow::ensure!(
-            shard.our_label == dump.our_label
-                && shard.their_label == dump.their_label
-                && shard.vulnerability == dump.vulnerability,
+            shard.header.our_label == dump.header.our_label
+                && shard.header.their_label == dump.header.their_label
+                && shard.header.vulnerability == dump.header.vulnerability,
             "shard {path:?} has mismatched labels/vulnerability — won't merge different experiments",
         );
         dump.boards.extend(shard.boards);
     }
     let boards = &dump.boards;
     let count = boards.len();
-    // The boards were bid at `dump.vulnerability`; `--vulnerability` re-prices the
+    // The boards were bid at `dump.header.vulnerability`; `--vulnerability` re-prices the
     // same contracts at a different one (a what-if — it does not re-bid).
-    let vul = args.vulnerability.unwrap_or(dump.vulnerability);
+    let vul = args.vulnerability.unwrap_or(dump.header.vulnerability);
     let pd = match args.score.as_str() {
         "plain" => false,
         "pd" => true,
@@ -177,7 +174,7 @@ fn main() -> anyhow::Result<()> {
     let (mean, half_width) = mean_with_ci(&scored.board_imps);
     println!(
         "=== {} (us) vs {} (them): {count} boards, vulnerability {} ===",
-        dump.our_label, dump.their_label, vul,
+        dump.header.our_label, dump.header.their_label, vul,
     );
     println!(
         "Divergent boards: {} of {count} ({:.0}%)",
@@ -192,10 +189,10 @@ fn main() -> anyhow::Result<()> {
         mean - half_width,
         mean + half_width,
     );
-    if vul != dump.vulnerability {
+    if vul != dump.header.vulnerability {
         println!(
             "(re-priced at vulnerability {vul}; the boards were bid at {} and are not re-bid)",
-            dump.vulnerability,
+            dump.header.vulnerability,
         );
     }
 
@@ -489,21 +489,21 @@ fn main() -> anyhow::Result<()> {
         .map(|index| {
             let deal = full_deal(&mut rng);
             let dealer = Seat::ALL[index % 4];
-            Board {
-                table_a: bid_out(ours, &ben, true, dealer, args.vulnerability, &deal),
-                table_b: bid_out(ours, &ben, false, dealer, args.vulnerability, &deal),
+            Board::new(
                 deal,
                 dealer,
-            }
+                bid_out(ours, &ben, true, dealer, args.vulnerability, &deal),
+                bid_out(ours, &ben, false, dealer, args.vulnerability, &deal),
+            )
         })
         .collect();
 
     let dump = Dump {
-        our_label: our_label.into(),
-        their_label,
-        vulnerability: args.vulnerability,
-        seed: Some(seed),
-        gen_args: std::env::args().skip(1).collect(),
+        header: pons::record::Header {
+            seed: Some(seed),
+            gen_args: std::env::args().skip(1).collect(),
+            ..pons::record::Header::new(our_label, their_label, args.vulnerability)
+        },
         boards,
     };
     match args.output.as_deref() {
@@ -514,9 +514,9 @@ fn main() -> anyhow::Result<()> {
     }
     eprintln!(
         "ben-gen: {} (us) vs {} (them), vulnerability {} — wrote {} boards{}",
-        dump.our_label,
-        dump.their_label,
-        dump.vulnerability,
+        dump.header.our_label,
+        dump.header.their_label,
+        dump.header.vulnerability,
         dump.boards.len(),
         match args.output.as_deref() {
             Some(path) => format!(" to {path}"),
@@ -15,7 +15,7 @@ use contract_bridge::eval::hcp as holding_hcp;
 use contract_bridge::{AbsoluteVulnerability, Contract, FullDeal, Hand, Rank, Seat, Strain, Suit};
 use ddss::{NonEmptyStrainFlags, Solver, TrickCountTable};
 use pons::bidding::agreements::Agreements;
-use pons::bidding::card::{Card, american_card, dutch_card};
+use pons::bidding::card::{Card, acol_card, american_card, dutch_card, precision_card, sayc_card};
 use pons::bidding::context::relative;
 use pons::bidding::features::ConventionCard;
 use pons::bidding::{Bidder, Partnership};
@@ -254,39 +254,9 @@ pub fn bid_uncontested(
     auction
 }
 
-/// One board: the deal, the dealer, and both tables' auctions.  The interchange
-/// unit between `bba-gen` (writes it) and `bba-score` (reads it).
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Board {
-    pub deal: FullDeal,
-    pub dealer: Seat,
-    /// Our pair North/South
-    pub table_a: Auction,
-    /// Our pair East/West
-    pub table_b: Auction,
-}
-
-/// A serialized match: the bidder labels, the vulnerability the boards were bid
-/// at, and every board.  `bba-gen` writes it; `bba-score` reads and scores it.
-/// The serde derive is feature-gated so non-`serde` harnesses still compile this
-/// shared module (the struct is then just dead code).
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Dump {
-    pub our_label: String,
-    pub their_label: String,
-    pub vulnerability: AbsoluteVulnerability,
-    /// The deal seed this shard was generated from, so the exact board stream
-    /// is reproducible forever.  `None` in dumps written before the anchor
-    /// campaign (serde-defaulted for backwards compatibility).
-    #[cfg_attr(feature = "serde", serde(default))]
-    pub seed: Option<u64>,
-    /// The generating command line (`argv[1..]`), so a scorer can rebuild the
-    /// exact book configuration instead of guessing knob state.  Empty in
-    /// older dumps.
-    #[cfg_attr(feature = "serde", serde(default))]
-    pub gen_args: Vec<String>,
-    pub boards: Vec<Board>,
-}
+pub use pons::record::Board;
+#[cfg(feature = "serde")]
+pub use pons::record::Dump;
 
 /// Load a dump from `path`.  A plain file is read directly; a **directory**
 /// globs its `shard-*.json` (sorted, so paired arms concatenate in the same
@@ -297,11 +267,7 @@ pub struct Dump {
 #[cfg(feature = "serde")]
 pub fn load_dump(path: &str) -> Dump {
     fn read(path: &std::path::Path) -> Dump {
-        serde_json::from_reader(std::io::BufReader::new(
-            std::fs::File::open(path)
-                .unwrap_or_else(|e| panic!("open dump {}: {e}", path.display())),
-        ))
-        .unwrap_or_else(|e| panic!("parse dump {}: {e}", path.display()))
+        Dump::load(path).unwrap_or_else(|e| panic!("load dump {}: {e}", path.display()))
     }
     let root = std::path::Path::new(path);
     if !root.is_dir() {
@@ -705,8 +671,16 @@ pub fn seat_floor(name: &str, agreements: &Agreements) -> anyhow::Result<Partner
         "american-floor" => pons::american_floor(agreements).bind(),
         "american-v6" => pons::bidding::american::american_v6(agreements).bind(),
         "dutch-v6" => pons::bidding::dutch::dutch_v6(agreements).bind(),
+        // Standard American Yellow Card: the club-standard opponent.
+        "sayc" => pons::sayc(agreements).bind(),
+        "sayc-instinct" => pons::sayc_instinct(agreements).bind(),
+        // Acol: the weak-notrump UK opponent.
+        "acol" => pons::acol(agreements).bind(),
+        "acol-instinct" => pons::acol_instinct(agreements).bind(),
+        "precision" => pons::precision(agreements).bind(),
+        "precision-instinct" => pons::precision_instinct(agreements).bind(),
         other => anyhow::bail!(
-            "floor must be american|american-book|american-instinct|american-floor|american-v6|dutch|dutch-instinct|dutch-v6, got {other:?}"
+            "floor must be american|american-book|american-instinct|american-floor|american-v6|dutch|dutch-instinct|dutch-v6|sayc|sayc-instinct|acol|acol-instinct|precision|precision-instinct, got {other:?}"
         ),
     })
 }
@@ -720,8 +694,11 @@ pub fn floor_card(name: &str, agreements: &Agreements) -> anyhow::Result<Card> {
     Ok(match name.split('-').next().unwrap_or_default() {
         "american" => american_card(agreements),
         "dutch" => dutch_card(agreements),
+        "sayc" => sayc_card(agreements),
+        "acol" => acol_card(agreements),
+        "precision" => precision_card(agreements),
         other => anyhow::bail!(
-            "no card generator for system `{other}` (known: american, dutch).  \
+            "no card generator for system `{other}` (known: american, dutch, sayc, acol, precision).  \
              Write one in `src/bidding/card.rs` rather than declaring another \
              system's card."
         ),
@@ -740,7 +717,7 @@ pub fn floor_card(name: &str, agreements: &Agreements) -> anyhow::Result<Card> {
 /// of a `--declare-opponents` A/B measure the net swap rather than the
 /// declaration — which is exactly what happened for one commit after the
 /// 2026-08-08 v5 ship, at 3.5× `scripts/ab-declared-opponents.sh`'s own CI.  So
-/// Both shipped names use the honest-reading v6 floor on both paths.
+/// Every net-floored name uses the honest-reading v6 floor on both paths.
 ///
 /// Our own half rides `agreements`, captured by the caller once every `--ns-*`
 /// cell is armed — the same "set every `--ns-*` first" rule that applies to
@@ -752,7 +729,7 @@ pub fn floor_card(name: &str, agreements: &Agreements) -> anyhow::Result<Card> {
 /// neither Multi-Landy nor Landy reads as `Natural` — so a `--their-ns` arm on
 /// one of those axes declares an approximation, not the row it armed.
 ///
-/// Only the two net-floored systems accept a declared opponent.  The other
+/// Only the net-floored systems accept a declared opponent.  The other
 /// floors are refused rather than silently ignored: an arm that quietly kept a
 /// symmetric config would be incomparable to its sibling with nothing in the
 /// output saying so.
@@ -766,9 +743,14 @@ pub fn seat_floor_vs(
             pons::american_with_card(agreements, &ConventionCard::from_card(theirs)).bind()
         }
         "dutch" => pons::dutch_with_card(agreements, &ConventionCard::from_card(theirs)).bind(),
+        "sayc" => pons::sayc_with_card(agreements, &ConventionCard::from_card(theirs)).bind(),
+        "acol" => pons::acol_with_card(agreements, &ConventionCard::from_card(theirs)).bind(),
+        "precision" => {
+            pons::precision_with_card(agreements, &ConventionCard::from_card(theirs)).bind()
+        }
         other => anyhow::bail!(
             "--declare-opponents needs a net floor to declare them to: \
-             floor must be american|dutch, got {other:?}"
+             floor must be american|dutch|sayc|acol|precision, got {other:?}"
         ),
     })
 }
@@ -815,7 +797,9 @@ pub fn deviant_floor(
     // Only the net-floored names take a config; the instinct and book-only
     // floors have no net to declare anything to.
     match name {
-        "american" | "dutch" => seat_floor_vs(name, theirs, &agreements),
+        "american" | "dutch" | "sayc" | "acol" | "precision" => {
+            seat_floor_vs(name, theirs, &agreements)
+        }
         _ => seat_floor(name, &agreements),
     }
 }
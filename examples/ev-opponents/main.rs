@@ -0,0 +1,142 @@
+//! How much does self-play mis-rank our calls against another field?
+//!
+//! Bids seeded boards with `american` North/South against `dutch` East/West.
+//! At each North/South decision whose shortlist holds two or more calls,
+//! prices the shortlist twice with `ev_all` over the same layouts: once under
+//! self-play (`american` at all four seats) and once with the opponents
+//! modelled as they are (`dutch`, reading us in our books).  Reports Kendall's
+//! τ between the two rankings, how often the best call changes, and the mean
+//! EV shift of the self-play favourite.
+//!
+//! ```text
+//! cargo run --release --example ev-opponents -- --count 200
+//! ```
+
+use clap::Parser;
+use contract_bridge::auction::{Auction, Call};
+use contract_bridge::deck::full_deal;
+use contract_bridge::{AbsoluteVulnerability, Hand, Seat};
+use pons::bidding::agreements::Agreements;
+use pons::bidding::context::relative;
+use pons::bidding::{Bidder, Table, ev_all, rank_agreement};
+use pons::{american, dutch};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+#[derive(Parser)]
+#[command(about = "Compare self-play and asymmetric call-EV rankings")]
+struct Args {
+    /// Boards to bid
+    #[arg(short, long, default_value = "200")]
+    count: usize,
+
+    /// Layouts per priced decision
+    #[arg(short, long, default_value = "24")]
+    layouts: usize,
+
+    /// Calls shortlisted per decision
+    #[arg(short, default_value = "3")]
+    k: usize,
+
+    /// Vulnerability: none, ns, ew, both
+    #[arg(short, long, default_value = "none")]
+    vulnerability: AbsoluteVulnerability,
+
+    /// Deal seed
+    #[arg(long, default_value = "0")]
+    seed: u64,
+}
+
+/// The top `k` legal calls by logit, best first
+fn shortlist(
+    bidder: &impl Bidder,
+    hand: Hand,
+    vul: AbsoluteVulnerability,
+    seat: Seat,
+    prior: &Auction,
+    k: usize,
+) -> Vec<Call> {
+    let Some(logits) = bidder.classify(hand, relative(vul, seat), prior) else {
+        return Vec::new();
+    };
+    let mut calls: Vec<(Call, f32)> = logits
+        .iter()
+        .filter(|&(call, &logit)| logit.is_finite() && prior.can_push(call).is_ok())
+        .map(|(call, &logit)| (call, logit))
+        .collect();
+    calls.sort_by(|a, b| b.1.total_cmp(&a.1));
+    calls.into_iter().take(k).map(|(call, _)| call).collect()
+}
+
+fn main() {
+    let args = Args::parse();
+    let agreements = Agreements::default();
+    let ours = american(&agreements).bind();
+    let theirs = dutch(&agreements).bind().with_opponents(&ours);
+    let reading_them = ours.clone().with_opponents(&theirs);
+    let vul = args.vulnerability;
+
+    let (mut decisions, mut taus, mut flips, mut shift) = (0usize, Vec::new(), 0usize, 0.0f64);
+    for board in 0..args.count {
+        let seed = args.seed.wrapping_add(board as u64);
+        let deal = full_deal(&mut StdRng::seed_from_u64(seed));
+        let table = Table::new(&reading_them, &theirs, Seat::ALL[board % 4], vul);
+        let auction = table.bid_out(&deal);
+
+        for len in 0..auction.len() {
+            let seat = table.seat_to_act(len);
+            if !matches!(seat, Seat::North | Seat::South) {
+                continue;
+            }
+            let mut prior = Auction::new();
+            prior
+                .try_extend(auction[..len].iter().copied())
+                .expect("a bid-out auction is legal");
+            let calls = shortlist(&reading_them, deal[seat], vul, seat, &prior, args.k);
+            if calls.len() < 2 {
+                continue;
+            }
+
+            let price = |ours_model: &pons::Partnership, theirs_model: &pons::Partnership| {
+                let context = ours_model.prefixed_context(relative(vul, seat), &prior);
+                ev_all(
+                    deal[seat],
+                    seat,
+                    vul,
+                    &context,
+                    &calls,
+                    ours_model,
+                    theirs_model,
+                    &mut StdRng::seed_from_u64(seed),
+                    args.layouts,
+                )
+            };
+            let symmetric = price(&ours, &ours);
+            let asymmetric = price(&reading_them, &theirs);
+            let Some(tau) = rank_agreement(&symmetric, &asymmetric) else {
+                continue;
+            };
+
+            let best = |evs: &[f32]| {
+                (0..evs.len())
+                    .filter(|&i| evs[i].is_finite())
+                    .max_by(|&i, &j| evs[i].total_cmp(&evs[j]))
+            };
+            let favourite = best(&symmetric).expect("a ranked decision priced a call");
+            decisions += 1;
+            taus.push(f64::from(tau));
+            flips += usize::from(best(&asymmetric) != Some(favourite));
+            shift += f64::from((asymmetric[favourite] - symmetric[favourite]).abs());
+        }
+    }
+
+    if decisions == 0 {
+        println!("no decision had two priced calls");
+        return;
+    }
+    let n = decisions as f64;
+    println!("decisions:            {decisions}");
+    println!("mean Kendall's tau:   {:.3}", taus.iter().sum::<f64>() / n);
+    println!("best call changed:    {:.1}%", 100.0 * flips as f64 / n);
+    println!("favourite's EV shift: {:.1} points", shift / n);
+}
@@ -4,13 +4,29 @@
 //! Two formats, chosen by extension: `.pdd` is the compact binary format
 //! ([`pons::pdd`]); anything else is GIB text, one
 //! `<West-first PBN>:<20 hex DD digits>` line per deal ([`pons::gib`]).
-//! Readers sniff the magic, so every subcommand accepts either. Double-dummy
-//! solving is the expensive step; the file caches it, so a database produced
-//! once is reused for free. With this tool every machine can independently
-//! produce a shard — `generate` is deterministic in its `--seed`, so shards
-//! from distinct seeds just concatenate into a bigger database
-//! (`cat shard-*.txt > all.txt`, or `convert shard-* --out all.pdd`), no
-//! online coordination needed.
+//! Readers sniff the magic, so every subcommand accepts either.  `convert`
+//! also speaks PBN ([`pons::pbn`]) both ways, by the `.pbn` extension: out,
+//! each deal becomes a numbered board with its table as
+//! `[OptimumResultTable]` and its exact par as `[OptimumScore]` and
+//! `[ParContract]`, so other software can open it; in, every game must carry
+//! that table.
+//!
+//! A version-2 `.pdd` also carries board metadata — dealer, vulnerability,
+//! board number, par, an auction.  `read` prints it, and `convert` keeps it:
+//! `.pdd` output is written with the smallest schema holding every input's
+//! columns (or the ones `--columns` names), PBN maps it to its own tags — par
+//! recomputed from the table at the board's dealer and vulnerability — and GIB
+//! text drops it.  `convert` streams one input at a time, so a row missing a
+//! column the output keeps gets its board's defaults: the position in the
+//! output as its number, that board's rotation, and the par of its table.  An
+//! auction has no default.
+//!
+//! Double-dummy solving is the expensive step; the file caches it, so a
+//! database produced once is reused for free. With this tool every machine
+//! can independently produce a shard — `generate` is deterministic in its
+//! `--seed`, so shards from distinct seeds just concatenate into a bigger
+//! database (`cat shard-*.txt > all.txt`, or `convert shard-* --out
+//! all.pdd`), no online coordination needed.
 //!
 //! Determinism also makes a shard *resumable*: the deals on disk are an exact
 //! prefix of the seed's stream, so `--append` replays the RNG past them — free,
@@ -23,15 +39,17 @@
 //! gib read shard-1.pdd | head   # human-readable deal + DD grid
 //! gib read shard-1.pdd --last 3       # tail of a sealed shard, by seek
 //! gib read shard-1.pdd --skip 500 --count 10   # any window, likewise
+//! gib read shard-1.pdd --count 1 --par   # plus par at every dealer and vul
 //! gib convert shard-1.pdd --out shard-1.txt   # binary <-> text
+//! gib convert shard-1.pdd --out shard-1.pbn   # hand records for other tools
 //! ```
 
 use clap::{Parser, Subcommand};
 use contract_bridge::deck::full_deal;
-use contract_bridge::{FullDeal, Seat, Strain};
+use contract_bridge::{AbsoluteVulnerability, FullDeal, Seat, Strain};
 use core::num::NonZero;
-use ddss::{NonEmptyStrainFlags, Solver, TrickCountTable};
-use pons::{gib, pdd};
+use ddss::{NonEmptyStrainFlags, Par, Solver, TrickCountTable};
+use pons::{gib, pbn, pdd, stats};
 use rand::SeedableRng;
 use rand::rngs::StdRng;
 use std::io::{BufWriter, Write};
@@ -62,6 +80,10 @@ enum Cmd {
         /// Print the final N deals — `--skip` counted from the end.
         #[arg(long, conflicts_with_all = ["skip", "count"])]
         last: Option<u64>,
+        /// Also print each deal's exact par at every vulnerability, by dealer
+        /// where the dealer changes it.
+        #[arg(long)]
+        par: bool,
     },
     /// Deal random boards, solve them, and write GIB lines.
     Generate {
@@ -92,12 +114,41 @@ enum Cmd {
     Convert {
         /// Input files in either format (concatenated in order)
         inputs: Vec<String>,
-        /// Output file: `.pdd` -> binary, anything else -> GIB text
+        /// Output file: `.pdd` -> binary, `.pbn` -> PBN, anything else -> GIB text
         #[arg(long)]
         out: String,
+        /// `.pdd` output columns, comma-separated (default: every column an
+        /// input has; `none` writes version 1)
+        #[arg(long, value_delimiter = ',')]
+        columns: Option<Vec<Column>>,
     },
 }
 
+/// A `.pdd` column `convert --columns` can keep
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum Column {
+    /// No columns: a version-1 file
+    None,
+    Dealer,
+    Vul,
+    Board,
+    Par,
+    Auction,
+}
+
+impl Column {
+    const fn columns(self) -> pdd::Columns {
+        match self {
+            Self::None => pdd::Columns::empty(),
+            Self::Dealer => pdd::Columns::DEALER,
+            Self::Vul => pdd::Columns::VULNERABILITY,
+            Self::Board => pdd::Columns::BOARD,
+            Self::Par => pdd::Columns::PAR,
+            Self::Auction => pdd::Columns::AUCTION,
+        }
+    }
+}
+
 /// Strains in GIB tail order, with display labels for `read`.
 const STRAINS: [(&str, Strain); 5] = [
     ("NT", Strain::Notrump),
@@ -114,7 +165,8 @@ fn main() -> std::io::Result<()> {
             skip,
             count,
             last,
-        } => read(&file, skip, count, last),
+            par,
+        } => read(&file, skip, count, last, par),
         Cmd::Generate {
             count,
             seed,
@@ -123,7 +175,11 @@ fn main() -> std::io::Result<()> {
             threads,
         } => generate(count, seed, out.as_deref(), append, threads),
         Cmd::Verify { file } => verify(&file),
-        Cmd::Convert { inputs, out } => convert(&inputs, &out),
+        Cmd::Convert {
+            inputs,
+            out,
+            columns,
+        } => convert(&inputs, &out, columns.as_deref()),
     }
 }
 
@@ -132,14 +188,14 @@ fn is_pdd(path: &str) -> bool {
     path.ends_with(".pdd")
 }
 
-/// Whether `path` opens with the binary format's magic — the same test
+/// Whether `path` opens with either version's binary magic — the same test
 /// [`pdd::from_bytes`] makes, so a window seeks exactly when the bytes support
 /// it, whatever the extension says. A file too short to hold the magic is not
 /// binary; it is an empty or truncated text file, which `load` reads as text.
 fn is_binary(path: &str) -> std::io::Result<bool> {
     let mut magic = [0; pdd::MAGIC.len()];
     match std::io::Read::read_exact(&mut std::fs::File::open(path)?, &mut magic) {
-        Ok(()) => Ok(magic == pdd::MAGIC),
+        Ok(()) => Ok(magic == pdd::MAGIC || magic == pdd::MAGIC_V2),
         Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => Ok(false),
         Err(e) => Err(e),
     }
@@ -151,19 +207,108 @@ fn is_binary(path: &str) -> std::io::Result<bool> {
 fn window(total: u64, skip: u64, count: Option<usize>, last: Option<u64>) -> (u64, u64) {
     let skip = last.map_or(skip, |n| total.saturating_sub(n)).min(total);
     let room = total - skip;
-    // Clamp before widening: an unbounded `count` would overflow `load_slice`'s
-    // `count * ROW_LEN` byte budget.
     (skip, count.map_or(room, |c| room.min(c as u64)))
 }
 
-fn read(file: &str, skip: u64, count: Option<usize>, last: Option<u64>) -> std::io::Result<()> {
-    let (first, deals) = if is_binary(file)? {
-        let total = pdd::rows_in(std::fs::metadata(file)?.len());
-        let (skip, take) = window(total, skip, count, last);
+/// The PBN spelling of an absolute vulnerability.
+const fn vul_name(vul: AbsoluteVulnerability) -> &'static str {
+    match vul.bits() {
+        0 => "None",
+        1 => "NS",
+        2 => "EW",
+        _ => "All",
+    }
+}
+
+/// One line of a version-2 entry's metadata; empty when it carries none.
+fn metadata(entry: &pdd::Entry) -> String {
+    let mut fields = Vec::new();
+    if let Some(board) = entry.board {
+        fields.push(format!("board {board}"));
+    }
+    if let Some(dealer) = entry.dealer {
+        fields.push(format!("dealer {}", dealer.letter()));
+    }
+    if let Some(vul) = entry.vul {
+        fields.push(format!("vul {}", vul_name(vul)));
+    }
+    if let Some(par) = entry.par {
+        fields.push(match par.contract {
+            Some(c) => format!(
+                "par {:+} ({} by {}, {:+})",
+                par.score,
+                c.contract,
+                c.declarer.letter(),
+                c.overtricks
+            ),
+            None => format!("par {:+}", par.score),
+        });
+    }
+    if let Some(auction) = &entry.auction {
+        let calls: Vec<String> = auction.iter().map(ToString::to_string).collect();
+        fields.push(format!("auction {}", calls.join(" ")));
+    }
+    fields.join(", ")
+}
+
+/// A par as `+420 4♠ by N, 4♠ by S`, a sacrifice with its undertricks.
+fn par_line(par: &Par) -> String {
+    let contracts: Vec<String> = par
+        .contracts
+        .iter()
+        .map(|c| match c.overtricks {
+            0.. => format!("{} by {}", c.contract, c.declarer.letter()),
+            down => format!("{}{down} by {}", c.contract, c.declarer.letter()),
+        })
+        .collect();
+    if contracts.is_empty() {
+        format!("{:+} passed out", par.score)
+    } else {
+        format!("{:+} {}", par.score, contracts.join(", "))
+    }
+}
+
+/// The par at each vulnerability, split by dealer only where it differs.
+fn write_par(w: &mut impl Write, table: &TrickCountTable) -> std::io::Result<()> {
+    let pars = stats::par(*table);
+    for vul in [
+        AbsoluteVulnerability::NONE,
+        AbsoluteVulnerability::NS,
+        AbsoluteVulnerability::EW,
+        AbsoluteVulnerability::ALL,
+    ] {
+        let name = vul_name(vul);
+        if pars.depends_on_dealer(vul) {
+            for dealer in Seat::ALL {
+                let line = par_line(pars.get(dealer, vul));
+                writeln!(w, "  par {name:<4} dealer {}: {line}", dealer.letter())?;
+            }
+        } else {
+            writeln!(
+                w,
+                "  par {name:<4} {}",
+                par_line(pars.get(Seat::North, vul))
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn read(
+    file: &str,
+    skip: u64,
+    count: Option<usize>,
+    last: Option<u64>,
+    par: bool,
+) -> std::io::Result<()> {
+    let (first, entries) = if is_binary(file)? {
+        let reader = pdd::Reader::open(file)?;
+        let (skip, take) = window(reader.len(), skip, count, last);
         let take = usize::try_from(take).unwrap_or(usize::MAX);
-        (skip, pdd::load_slice(file, skip, take)?)
+        let window = reader.strided(skip, 1).entries().take(take);
+        (skip, window.collect::<std::io::Result<Vec<_>>>()?)
     } else {
-        let all = pdd::load(file)?;
+        let all = pdd::load_entries(file)?;
         let (skip, take) = window(all.len() as u64, skip, count, last);
         let cut = usize::try_from(skip).unwrap_or(usize::MAX);
         let take = usize::try_from(take).unwrap_or(usize::MAX);
@@ -173,13 +318,18 @@ fn read(file: &str, skip: u64, count: Option<usize>, last: Option<u64>) -> std::
     let mut w = BufWriter::new(stdout.lock());
     // Number deals by their position in the file, not in the window, so a tail
     // print says which deals these are.
-    for (i, (deal, table)) in deals.iter().enumerate() {
+    for (i, entry) in entries.iter().enumerate() {
+        let (deal, table) = (&entry.deal, &entry.tricks);
         writeln!(
             w,
             "# {}: {}",
             first + i as u64 + 1,
             deal.display(Seat::West)
         )?;
+        let metadata = metadata(entry);
+        if !metadata.is_empty() {
+            writeln!(w, "  {metadata}")?;
+        }
         writeln!(w, "        N   E   S   W")?;
         for (label, strain) in STRAINS {
             let row = table[strain];
@@ -192,6 +342,9 @@ fn read(file: &str, skip: u64, count: Option<usize>, last: Option<u64>) -> std::
                 row.get(Seat::West).get(),
             )?;
         }
+        if par {
+            write_par(&mut w, table)?;
+        }
     }
     w.flush()
 }
@@ -346,20 +499,192 @@ fn verify(file: &str) -> std::io::Result<()> {
     Ok(())
 }
 
-fn convert(inputs: &[String], out: &str) -> std::io::Result<()> {
-    let binary = is_pdd(out);
-    let mut w = BufWriter::new(std::fs::File::create(out)?);
-    if binary {
-        w.write_all(&pdd::MAGIC)?;
+/// Whether a path names a PBN hand-record file.
+fn is_pbn(path: &str) -> bool {
+    path.ends_with(".pbn")
+}
+
+/// Read entries from any supported file, PBN included.  A PBN game without
+/// an `[OptimumResultTable]` has nothing to convert, so it is an error rather
+/// than a silent drop; its board, dealer, vulnerability, and auction carry
+/// over.
+fn load_any(path: &str) -> std::io::Result<Vec<pdd::Entry>> {
+    if !is_pbn(path) {
+        return pdd::load_entries(path);
+    }
+    pbn::load(path)?
+        .into_iter()
+        .enumerate()
+        .map(|(i, game)| {
+            let tricks = game.tricks.ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("{path}: game {} has no OptimumResultTable", i + 1),
+                )
+            })?;
+            let mut entry = pdd::Entry::new(game.deal, tricks);
+            entry.board = game.board;
+            entry.dealer = Some(game.dealer);
+            entry.vul = Some(game.vul);
+            entry.auction = game.auction;
+            Ok(entry)
+        })
+        .collect()
+}
+
+/// A PBN game for the `number`-th converted entry: its own board number,
+/// dealer, and vulnerability where it has them, the rotation otherwise, and
+/// the par at that dealer and vulnerability.
+fn to_game(number: usize, entry: pdd::Entry) -> pbn::Game {
+    let board = entry
+        .board
+        .unwrap_or_else(|| u32::try_from(number).unwrap_or(u32::MAX));
+    let mut game = pbn::Game::numbered(board, entry.deal);
+    game.dealer = entry.dealer.unwrap_or(game.dealer);
+    game.vul = entry.vul.unwrap_or(game.vul);
+    game.auction = entry.auction;
+    game.tricks = Some(entry.tricks);
+    game.set_par(stats::par(entry.tricks).get(game.dealer, game.vul));
+    game
+}
+
+/// The entries of one input, streamed from a `.pdd` and loaded otherwise
+fn entries_of(
+    path: &str,
+) -> std::io::Result<Box<dyn Iterator<Item = std::io::Result<pdd::Entry>>>> {
+    if is_binary(path)? {
+        Ok(Box::new(pdd::Reader::open(path)?.entries()))
+    } else {
+        Ok(Box::new(load_any(path)?.into_iter().map(Ok)))
+    }
+}
+
+/// The columns one input carries: a `.pdd` header says, GIB text has none,
+/// and PBN has to be read.
+fn schema_of(path: &str) -> std::io::Result<pdd::Schema> {
+    if is_binary(path)? {
+        Ok(pdd::Reader::open(path)?.schema())
+    } else if is_pbn(path) {
+        Ok(pdd::Schema::of(&load_any(path)?))
+    } else {
+        Ok(pdd::Schema::V1)
+    }
+}
+
+/// The `.pdd` output schema: every input's columns, or the `columns` asked
+/// for, with room for the longest auction any input holds
+fn output_schema(inputs: &[String], columns: Option<&[Column]>) -> std::io::Result<pdd::Schema> {
+    let mut schema = pdd::Schema::V1;
+    for input in inputs {
+        let of = schema_of(input)?;
+        schema.columns |= of.columns;
+        schema.auction_calls = schema.auction_calls.max(of.auction_calls);
+    }
+    if let Some(columns) = columns {
+        schema.columns = columns
+            .iter()
+            .fold(pdd::Columns::empty(), |all, column| all | column.columns());
+    }
+    Ok(schema)
+}
+
+/// Give the `number`-th entry every `columns` it lacks: the number as its
+/// board, that board's rotation, and the par of its table at them
+fn fill(mut entry: pdd::Entry, number: usize, columns: pdd::Columns) -> pdd::Entry {
+    let board = *entry
+        .board
+        .get_or_insert_with(|| u32::try_from(number).unwrap_or(u32::MAX));
+    let dealer = *entry
+        .dealer
+        .get_or_insert_with(|| pons::scoring::board_dealer(board));
+    let vul = *entry
+        .vul
+        .get_or_insert_with(|| pons::scoring::board_vulnerability(board));
+    if columns.contains(pdd::Columns::PAR) && entry.par.is_none() {
+        entry.par = Some(stats::par(entry.tricks).get(dealer, vul).into());
     }
-    let mut total = 0usize;
+    entry
+}
+
+/// Where `convert` writes, by the output extension
+enum Sink<W: Write> {
+    /// Binary rows, every one filled out to the writer's schema
+    Pdd(pdd::Writer<W>),
+    /// PBN games after the export header
+    Pbn(W),
+    /// GIB text, and whether a row's metadata was dropped
+    Text(W, bool),
+}
+
+impl<W: Write> Sink<W> {
+    /// Start `out`: the `.pdd` header from a pass over the inputs' own
+    /// schemas, or the PBN export header
+    fn new(
+        mut w: W,
+        out: &str,
+        inputs: &[String],
+        columns: Option<&[Column]>,
+    ) -> std::io::Result<Self> {
+        if is_pdd(out) {
+            Ok(Self::Pdd(pdd::Writer::new(
+                w,
+                output_schema(inputs, columns)?,
+            )?))
+        } else if is_pbn(out) {
+            // The export header alone; each game follows it as `write` lays
+            // it out.
+            pbn::write(&mut w, &[])?;
+            Ok(Self::Pbn(w))
+        } else {
+            Ok(Self::Text(w, false))
+        }
+    }
+
+    /// Write the `number`-th entry
+    fn write(&mut self, number: usize, entry: pdd::Entry) -> std::io::Result<()> {
+        match self {
+            Self::Pdd(writer) => {
+                let columns = writer.schema().columns;
+                writer.write(&fill(entry, number, columns))
+            }
+            Self::Pbn(w) => writeln!(w, "{}", to_game(number, entry)),
+            Self::Text(w, dropped) => {
+                *dropped |= !entry.columns().is_empty();
+                write_deal(w, false, &entry.deal, &entry.tricks)
+            }
+        }
+    }
+
+    /// Flush the sink, warning if GIB text dropped metadata
+    fn finish(self) -> std::io::Result<()> {
+        match self {
+            Self::Pdd(writer) => writer.into_inner()?.flush(),
+            Self::Pbn(mut w) => w.flush(),
+            Self::Text(mut w, dropped) => {
+                if dropped {
+                    eprintln!("gib convert: GIB text has no metadata columns; dropped them");
+                }
+                w.flush()
+            }
+        }
+    }
+}
+
+/// Rewrite `inputs` into `out` one input at a time, so no input is held past
+/// its own rows
+fn convert(inputs: &[String], out: &str, columns: Option<&[Column]>) -> std::io::Result<()> {
+    let w = BufWriter::new(std::fs::File::create(out)?);
+    let mut sink = Sink::new(w, out, inputs, columns)?;
+    let mut written = 0;
     for input in inputs {
-        for (deal, table) in pdd::load(input)? {
-            write_deal(&mut w, binary, &deal, &table)?;
-            total += 1;
+        for entry in entries_of(input)? {
+            written += 1;
+            sink.write(written, entry?).map_err(|e| {
+                std::io::Error::new(e.kind(), format!("{input}: deal {written}: {e}"))
+            })?;
         }
     }
-    w.flush()?;
-    eprintln!("gib convert: wrote {total} deals to {out}");
+    sink.finish()?;
+    eprintln!("gib convert: wrote {written} deals to {out}");
     Ok(())
 }
@@ -0,0 +1,104 @@
+//! Round-robin league of the defenses to their 1NT.
+//!
+//! Every `NotrumpDefense` plays every other over the same seeded boards (see
+//! `pons::league`); each bracket prints the IMP payoff matrix, Bradley–Terry
+//! ratings on the Elo scale, the fictitious-play equilibrium of the matrix and
+//! the variants another one dominates.
+//!
+//! ```text
+//! cargo run --release --features rayon --example league -- --count 20000
+//! ```
+
+use clap::Parser;
+use contract_bridge::AbsoluteVulnerability;
+use pons::bidding::agreements::Agreements;
+use pons::bidding::american::NotrumpDefense;
+use pons::league::{League, Payoff, Variant, equilibrium};
+
+#[derive(Parser)]
+struct Args {
+    /// Boards per pairing
+    #[arg(short, long, default_value = "2000")]
+    count: usize,
+
+    /// Vulnerability: none, ns, ew, both
+    #[arg(short, long, default_value = "none")]
+    vulnerability: AbsoluteVulnerability,
+
+    /// Deal seed, shared by every pairing
+    #[arg(long, default_value = "0")]
+    seed: u64,
+
+    /// Fictitious-play iterations
+    #[arg(long, default_value = "100000")]
+    fp_iters: usize,
+}
+
+const DEFENSES: [NotrumpDefense; 6] = [
+    NotrumpDefense::AlwaysPass,
+    NotrumpDefense::Natural,
+    NotrumpDefense::DirectDont,
+    NotrumpDefense::Meckwell,
+    NotrumpDefense::Woolsey,
+    NotrumpDefense::DirectLandy,
+];
+
+fn report(name: &str, payoff: &Payoff, iterations: usize) {
+    println!("== {name} ==");
+    print!("{:>12}", "");
+    for label in &payoff.labels {
+        print!(" {label:>11}");
+    }
+    println!();
+    for (label, row) in payoff.labels.iter().zip(&payoff.cells) {
+        print!("{label:>12}");
+        for cell in row {
+            print!(" {:>+6.3}±{:<4.2}", cell.imps, cell.ci);
+        }
+        println!();
+    }
+
+    println!("ratings:");
+    for (label, rating) in payoff.labels.iter().zip(payoff.ratings()) {
+        println!("  {label:>12} {:>+7.1} ± {:.1}", rating.elo, rating.se);
+    }
+
+    let nash = equilibrium(&payoff.matrix(), iterations);
+    let mixture: Vec<String> = payoff
+        .labels
+        .iter()
+        .zip(&nash.row)
+        .filter(|(_, p)| **p >= 0.01)
+        .map(|(label, p)| format!("{label} {p:.2}"))
+        .collect();
+    println!(
+        "equilibrium: {} (value {:+.3}, gap {:.3})",
+        mixture.join(" · "),
+        nash.value,
+        nash.gap
+    );
+    for (loser, winner) in payoff.dominated() {
+        println!(
+            "dominated: {} by {}",
+            payoff.labels[loser], payoff.labels[winner]
+        );
+    }
+    println!();
+}
+
+fn main() {
+    let args = Args::parse();
+    let variants = DEFENSES
+        .into_iter()
+        .map(|defense| {
+            let mut agreements = Agreements::default();
+            agreements.decision.reading.notrump_defense = defense;
+            Variant::new(format!("{defense:?}"), agreements)
+        })
+        .collect();
+    let standings = League::new(variants, args.seed, args.count)
+        .vulnerability(args.vulnerability)
+        .play();
+    report("plain DD", &standings.dd, args.fp_iters);
+    report("perfect defense", &standings.pd, args.fp_iters);
+}
@@ -0,0 +1,128 @@
+//! Which call lost the board?
+//!
+//! Prices every decision one side made in an auction against the book's
+//! shortlist there (see `pons::bidding::postmortem`) and flags the costliest.
+//! The deal comes from `--deal` as PBN or from `--seed`; the auction from
+//! `--auction` or, by default, `american` bidding it out at all four seats.
+//!
+//! ```text
+//! cargo run --release --example post-mortem -- --seed 7 --pair south
+//! cargo run --release --example post-mortem -- --deal 'N:...' --auction '1NT P 3NT P P P' --fast
+//! ```
+
+use clap::Parser;
+use contract_bridge::auction::{Auction, Call};
+use contract_bridge::deck::full_deal;
+use contract_bridge::{AbsoluteVulnerability, FullDeal, Seat};
+use pons::american;
+use pons::bidding::agreements::Agreements;
+use pons::bidding::postmortem::{Position, fast, post_mortem, rollout};
+use pons::bidding::{Decision, Table};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+#[derive(Parser)]
+#[command(about = "Price each of one side's calls in an auction and flag the costliest")]
+struct Args {
+    /// The deal as PBN; dealt from `--seed` when absent
+    #[arg(long)]
+    deal: Option<FullDeal>,
+
+    /// Deal seed, when no `--deal` is given
+    #[arg(long, default_value = "0")]
+    seed: u64,
+
+    /// The dealer
+    #[arg(long, default_value = "N")]
+    dealer: Seat,
+
+    /// Vulnerability: none, ns, ew, both
+    #[arg(short, long, default_value = "none")]
+    vulnerability: AbsoluteVulnerability,
+
+    /// The auction, e.g. "1NT P 3NT P P P"; bid out by `american` when absent
+    #[arg(long)]
+    auction: Option<Auction>,
+
+    /// Either seat of the side to analyse
+    #[arg(long, default_value = "N")]
+    pair: Seat,
+
+    /// Alternatives priced beside each call made
+    #[arg(short, default_value = "3")]
+    k: usize,
+
+    /// Layouts per decision
+    #[arg(short, long, default_value = "32")]
+    layouts: usize,
+
+    /// Price with the trick evaluator instead of double-dummy rollouts
+    #[arg(long)]
+    fast: bool,
+}
+
+/// Either stock pricer
+type Pricer<'a> = Box<dyn FnMut(&Position<'_>, &[Call]) -> Vec<f32> + 'a>;
+
+fn show(call: Call, ev: f32) -> String {
+    if ev.is_finite() {
+        format!("{call} {ev:+.0}")
+    } else {
+        format!("{call} ?")
+    }
+}
+
+fn row(decision: &Decision, costliest: bool) {
+    let best = decision
+        .best
+        .map_or_else(|| "-".to_owned(), |(call, ev)| show(call, ev));
+    println!(
+        "{:>3} {} {:<12} {:<12} {:>6.0}{}",
+        decision.index,
+        decision.seat.letter(),
+        show(decision.chosen, decision.chosen_ev),
+        best,
+        decision.regret,
+        if costliest { "  <- costliest" } else { "" },
+    );
+}
+
+fn main() {
+    let args = Args::parse();
+    let ours = american(&Agreements::default()).bind();
+    let deal = args
+        .deal
+        .unwrap_or_else(|| full_deal(&mut StdRng::seed_from_u64(args.seed)));
+    let auction = args.auction.unwrap_or_else(|| {
+        Table::new(&ours, &ours, args.dealer, args.vulnerability).bid_out(&deal)
+    });
+
+    println!("{deal}");
+    println!("dealer {}, {}", args.dealer.letter(), args.vulnerability);
+    println!("auction: {auction}");
+    println!();
+
+    let pricer: Pricer<'_> = if args.fast {
+        Box::new(fast(&ours, args.layouts, args.seed))
+    } else {
+        Box::new(rollout(&ours, &ours, args.layouts, args.seed))
+    };
+    let report = post_mortem(
+        &deal,
+        args.dealer,
+        args.vulnerability,
+        &auction,
+        args.pair,
+        &ours,
+        args.k,
+        pricer,
+    );
+
+    println!("  # S {:<12} {:<12} {:>6}", "made", "best other", "regret");
+    let costliest = report.costliest().map(|decision| decision.index);
+    for decision in &report.decisions {
+        row(decision, Some(decision.index) == costliest);
+    }
+    println!();
+    println!("total regret: {:.0} points", report.total_regret());
+}
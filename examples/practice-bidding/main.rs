@@ -1,10 +1,10 @@
 //! Interactive bidding-practice tool: bid one seat on random deals, get feedback.
 //!
 //! A human bids one seat; pons bots bid the other seats (or just partner in
-//! `--bots 1` mode).  After the auction ends the tool reveals all four hands,
-//! shows the auction grid, and judges the final contract by reshuffling the
-//! unseen hands — no actual-layout double-dummy verdict, which would be pure
-//! hindsight.
+//! `--bots 1` mode), the board run by a `pons::session::Session`.  After the
+//! auction ends the tool reveals all four hands, shows the auction grid, and
+//! judges the final contract by reshuffling the unseen hands — no
+//! actual-layout double-dummy verdict, which would be pure hindsight.
 //!
 //! ```text
 //! cargo run --example practice-bidding -- --seat south --count 5
@@ -18,13 +18,14 @@ use std::fmt::Write as _;
 use std::io::{self, BufRead, Write as _};
 
 use clap::Parser;
-use contract_bridge::auction::{Auction, Call};
+use contract_bridge::auction::Call;
 use contract_bridge::deck::{fill_deals, full_deal};
 use contract_bridge::eval::{self, HandEvaluator as _, SimpleEvaluator};
 use contract_bridge::{AbsoluteVulnerability, Builder, Contract, FullDeal, Seat, Strain, Suit};
 use ddss::{NonEmptyStrainFlags, Solver, StrainFlags, TrickCountTable};
-use pons::bidding::{System, Table};
-use pons::scoring::{final_contract, ns_score_contract};
+use pons::bidding::{Bidder, System, Table, Trie};
+use pons::scoring::ns_score_contract;
+use pons::session::{Phase, Session};
 use pons::{american, american_instinct};
 
 // ---------------------------------------------------------------------------
@@ -116,11 +117,6 @@ fn build_system(floor: Floor) -> System {
 // Helpers copied / adapted from instinct-floor
 // ---------------------------------------------------------------------------
 
-/// The seat acting after `len` calls from `dealer`
-const fn seat_to_act(dealer: Seat, len: usize) -> Seat {
-    Seat::ALL[(dealer as usize + len) % 4]
-}
-
 /// Signed human-side score: positive means good for the human's side
 fn human_side_score(
     contract: Contract,
@@ -324,123 +320,130 @@ fn main() {
             human_hand,
         );
 
-        // Build the table fresh per board so dealer/vul are correct
-        let ns = build_system(args.floor);
-        let ew = build_system(args.floor);
-        let table = Table::of_systems(&ns, &ew, dealer, args.vulnerability);
+        // Build the table fresh per board so dealer/vul are correct.  In
+        // `--bots 1` mode the opponents' side sits silent: an empty book
+        // passes throughout.
+        let ns = build_system(args.floor).bind();
+        let ew = build_system(args.floor).bind();
+        let silent = Trie::new();
+        let human_ns = matches!(args.seat, Seat::North | Seat::South);
+        let north_south: &dyn Bidder = if args.bots == 1 && !human_ns {
+            &silent
+        } else {
+            &ns
+        };
+        let east_west: &dyn Bidder = if args.bots == 1 && human_ns {
+            &silent
+        } else {
+            &ew
+        };
+        let table = Table::new(north_south, east_west, dealer, args.vulnerability);
 
-        let mut auction = Auction::new();
+        let mut session = Session::new(table, deal, args.seat.into());
+        let mut shown = 0;
         let mut quit_session = false;
 
-        while !auction.has_ended() {
-            let seat = seat_to_act(dealer, auction.len());
-
-            if seat == args.seat {
-                // --- Human's turn ---
-                // Snapshot the bot's opinion BEFORE pushing the human's call.
-                // (classify reads auction.len() to determine which seat acts,
-                //  so it must run before we extend the auction.)
-                let bot_logits = table.classify(human_hand, &auction);
-
-                // Build the bot's ranked top-3 legal calls (finite logit only)
-                let top3: Vec<(Call, f32)> = if let Some(logits) = bot_logits.as_ref() {
-                    let softmax = logits.softmax();
-                    let mut scored: Vec<(Call, f32)> = logits
-                        .iter()
-                        .filter(|&(_, &logit)| logit.is_finite())
-                        .filter(|(call, _)| auction.can_push(*call).is_ok())
-                        .map(|(call, &logit)| (call, logit))
-                        .collect();
-                    scored.sort_by(|a, b| b.1.partial_cmp(&a.1).expect("logits never NaN"));
-                    scored
-                        .into_iter()
-                        .take(3)
-                        .map(|(call, _)| {
-                            let prob = softmax.as_ref().map_or(0.0, |sm| *sm.get(call));
-                            (call, prob)
-                        })
-                        .collect()
-                } else {
-                    vec![]
-                };
+        loop {
+            // Print the bot calls made since the human last called
+            for (index, call) in session.auction().iter().enumerate().skip(shown) {
+                let seat = session.table().seat_to_act(index);
+                println!("  {}: {call}", seat.letter());
+            }
+            shown = session.auction().len();
+            if session.phase() != Phase::Auction {
+                break;
+            }
 
-                let bot_top1 = top3.first().map(|(c, _)| *c);
+            // --- Human's turn ---
+            // Snapshot the bot's opinion BEFORE the human's call goes in.
+            // (classify reads auction.len() to determine which seat acts,
+            //  so it must run before we extend the auction.)
+            let auction = session.auction();
+            let bot_logits = session.table().classify(human_hand, auction);
+
+            // Build the bot's ranked top-3 legal calls (finite logit only)
+            let top3: Vec<(Call, f32)> = if let Some(logits) = bot_logits.as_ref() {
+                let softmax = logits.softmax();
+                let mut scored: Vec<(Call, f32)> = logits
+                    .iter()
+                    .filter(|&(_, &logit)| logit.is_finite())
+                    .filter(|(call, _)| auction.can_push(*call).is_ok())
+                    .map(|(call, &logit)| (call, logit))
+                    .collect();
+                scored.sort_by(|a, b| b.1.partial_cmp(&a.1).expect("logits never NaN"));
+                scored
+                    .into_iter()
+                    .take(3)
+                    .map(|(call, _)| {
+                        let prob = softmax.as_ref().map_or(0.0, |sm| *sm.get(call));
+                        (call, prob)
+                    })
+                    .collect()
+            } else {
+                vec![]
+            };
 
-                // Prompt and read the human's call, looping on bad input
-                let human_call = loop {
-                    print!("Your call (e.g. 1H, P, X) [{seat}]: ");
-                    let _ = io::stdout().flush();
+            let bot_top1 = top3.first().map(|(c, _)| *c);
 
-                    let Some(Ok(line)) = lines.next() else {
-                        // EOF → quit the session
-                        quit_session = true;
-                        break Call::Pass; // value unused; we break 'board below
-                    };
+            // Prompt and read the human's call, looping on bad input
+            let human_call = loop {
+                print!("Your call (e.g. 1H, P, X) [{}]: ", args.seat);
+                let _ = io::stdout().flush();
 
-                    let trimmed = line.trim();
-                    if trimmed.eq_ignore_ascii_case("q") || trimmed.eq_ignore_ascii_case("quit") {
-                        quit_session = true;
-                        break Call::Pass; // value unused
-                    }
-
-                    match trimmed.parse::<Call>() {
-                        Err(e) => println!("  Parse error: {e}. Try again."),
-                        Ok(call) => {
-                            if let Err(e) = auction.can_push(call) {
-                                println!("  Illegal call: {e}. Try again.");
-                            } else {
-                                break call;
-                            }
-                        }
-                    }
+                let Some(Ok(line)) = lines.next() else {
+                    // EOF → quit the session
+                    quit_session = true;
+                    break Call::Pass; // value unused; we break 'board below
                 };
 
-                if quit_session {
-                    break;
+                let trimmed = line.trim();
+                if trimmed.eq_ignore_ascii_case("q") || trimmed.eq_ignore_ascii_case("quit") {
+                    quit_session = true;
+                    break Call::Pass; // value unused
                 }
 
-                auction.push(human_call);
-                human_calls_total += 1;
-
-                // Print the bot's opinion
-                if top3.is_empty() {
-                    // Off-book position
-                    println!("  [Book has no opinion; bot would pass]");
-                    if human_call == Call::Pass {
-                        human_agree += 1;
-                    }
-                } else {
-                    let agreed = bot_top1 == Some(human_call);
-                    if agreed {
-                        human_agree += 1;
-                    }
-                    println!("  Bot's opinion:");
-                    for (i, (call, prob)) in top3.iter().enumerate() {
-                        let marker = if *call == human_call && i == 0 {
-                            " ✓"
-                        } else if *call == human_call {
-                            " <"
-                        } else {
-                            ""
-                        };
-                        println!("    {}: {call} ({:.0}%){marker}", i + 1, 100.0 * prob);
-                    }
-                    if !agreed && let Some(top) = bot_top1 {
-                        println!("  Bot's top pick was {top}; you chose {human_call}.");
-                    }
+                match trimmed.parse::<Call>() {
+                    Err(e) => println!("  Parse error: {e}. Try again."),
+                    Ok(call) => match session.bid(call) {
+                        Err(e) => println!("  Illegal call: {e}. Try again."),
+                        Ok(()) => break call,
+                    },
+                }
+            };
+
+            if quit_session {
+                break;
+            }
+
+            shown += 1;
+            human_calls_total += 1;
+
+            // Print the bot's opinion
+            if top3.is_empty() {
+                // Off-book position
+                println!("  [Book has no opinion; bot would pass]");
+                if human_call == Call::Pass {
+                    human_agree += 1;
                 }
             } else {
-                // --- Bot or silent seat ---
-                let is_partner = seat == args.seat.partner();
-                let call = if args.bots == 3 || is_partner {
-                    // Active bot: use the system
-                    table.next_call(deal[seat], &auction)
-                } else {
-                    // Silent opponent: always pass
-                    Call::Pass
-                };
-                auction.push(call);
-                println!("  {}: {call}", seat.letter());
+                let agreed = bot_top1 == Some(human_call);
+                if agreed {
+                    human_agree += 1;
+                }
+                println!("  Bot's opinion:");
+                for (i, (call, prob)) in top3.iter().enumerate() {
+                    let marker = if *call == human_call && i == 0 {
+                        " ✓"
+                    } else if *call == human_call {
+                        " <"
+                    } else {
+                        ""
+                    };
+                    println!("    {}: {call} ({:.0}%){marker}", i + 1, 100.0 * prob);
+                }
+                if !agreed && let Some(top) = bot_top1 {
+                    println!("  Bot's top pick was {top}; you chose {human_call}.");
+                }
             }
         }
 
@@ -456,10 +459,9 @@ fn main() {
         // -----------------------------------------------------------------------
         print_board_all(&deal, dealer, args.vulnerability, board_index);
         println!("Auction:");
-        let calls: Vec<Call> = auction.iter().copied().collect();
-        print_auction(&calls, dealer);
+        print_auction(session.auction(), dealer);
 
-        let result = final_contract(&auction, dealer);
+        let result = session.contract();
         match result {
             Some((contract, declarer)) => {
                 println!("Contract: {contract} by {}", declarer.letter());
@@ -103,7 +103,7 @@ struct Position {
 /// [`common::Board`]), so BBA holds the other pair in each.
 fn positions(path: &str, boards: usize) -> Vec<Position> {
     let dump = load_dump(path);
-    let vul = dump.vulnerability;
+    let vul = dump.header.vulnerability;
     let mut out = Vec::new();
     for board in dump.boards.iter().take(boards) {
         for (auction, ours_is_ns) in [(&board.table_a, true), (&board.table_b, false)] {
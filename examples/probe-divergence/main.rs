@@ -320,7 +320,7 @@ fn main() -> anyhow::Result<()> {
     let on = common::load_dump(&args.on);
     let off = common::load_dump(&args.off);
     assert_eq!(on.boards.len(), off.boards.len(), "arms must be aligned");
-    let vul = args.vulnerability.unwrap_or(on.vulnerability);
+    let vul = args.vulnerability.unwrap_or(on.header.vulnerability);
 
     let mut deals = Vec::with_capacity(on.boards.len());
     let contracts: Vec<(Reached, Reached)> = on
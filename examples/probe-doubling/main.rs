@@ -162,7 +162,7 @@ fn ratio(numerator: u64, denominator: u64) -> f64 {
 /// Sample, solve and fold one arm directory
 fn fold(path: &str, args: &Args, tally: &mut Tally) {
     let dump = load_dump(path);
-    let vul = dump.vulnerability;
+    let vul = dump.header.vulnerability;
     let stride = dump.boards.len().div_ceil(args.limit.max(1)).max(1);
     let kept: Vec<usize> = (0..dump.boards.len()).step_by(stride).collect();
     let deals: Vec<FullDeal> = kept.iter().map(|&index| dump.boards[index].deal).collect();
@@ -0,0 +1,94 @@
+//! Tune knobs by sequential A/B testing.
+//!
+//! Walks each `--knob key=v1,v2,…` (or `key=lo..hi` for an integer knob) by
+//! coordinate descent from the shipped agreements, playing every step as a
+//! duplicate match stopped by an SPRT (see `pons::tune`), and prints the
+//! ledger of every trial as a Markdown table for docs/bidding-options.md.
+//! Tuple values such as a weak-two band are separated with `;`.
+//!
+//! ```text
+//! cargo run --release --features rayon --example tune -- \
+//!     --knob competition.free_bid_floor=5..9 --delta 0.02
+//! cargo run --release --features rayon --example tune -- \
+//!     --knob 'opening.weak_two_hcp=none;5,10;6,10' --scoring pd
+//! ```
+
+use clap::Parser;
+use contract_bridge::AbsoluteVulnerability;
+use pons::bidding::agreements::Agreements;
+use pons::tune::{Axis, Scoring, Sprt, Tuner};
+
+#[derive(Parser)]
+struct Args {
+    /// A knob and its values: `key=lo..hi` or `key=v1,v2,…` (`;` between
+    /// tuple values); repeat for coordinate descent over several
+    #[arg(long, required = true)]
+    knob: Vec<String>,
+
+    /// The gain worth detecting, in IMPs per board
+    #[arg(long, default_value = "0.05")]
+    delta: f64,
+
+    /// Wald's α and β
+    #[arg(long, default_value = "0.05")]
+    error: f64,
+
+    /// Boards between looks
+    #[arg(long, default_value = "1000")]
+    batch: usize,
+
+    /// Boards after which a trial keeps the incumbent
+    #[arg(long, default_value = "100000")]
+    max_boards: usize,
+
+    /// Bracket: plain or pd
+    #[arg(long, default_value = "plain")]
+    scoring: String,
+
+    /// Vulnerability: none, ns, ew, both
+    #[arg(short, long, default_value = "none")]
+    vulnerability: AbsoluteVulnerability,
+
+    /// Deal seed
+    #[arg(long, default_value = "0")]
+    seed: u64,
+}
+
+fn axis(spec: &str) -> anyhow::Result<Axis> {
+    let (key, values) = spec
+        .split_once('=')
+        .ok_or_else(|| anyhow::anyhow!("`{spec}` is not `key=values`"))?;
+    if let Some((lo, hi)) = values.split_once("..") {
+        return Ok(Axis::range(key, lo.parse()?..=hi.parse()?)?);
+    }
+    let separator = if values.contains(';') { ';' } else { ',' };
+    Ok(Axis::new(key, values.split(separator))?)
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let axes = args
+        .knob
+        .iter()
+        .map(|spec| axis(spec))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let scoring = match args.scoring.as_str() {
+        "plain" => Scoring::Plain,
+        "pd" => Scoring::PerfectDefense,
+        other => anyhow::bail!("unknown scoring `{other}`"),
+    };
+    let sprt = Sprt {
+        delta: args.delta,
+        alpha: args.error,
+        beta: args.error,
+        batch: args.batch,
+        max_boards: args.max_boards,
+    };
+    let tuning = Tuner::new(Agreements::default(), axes, args.seed)
+        .sprt(sprt)
+        .scoring(scoring)
+        .vulnerability(args.vulnerability)
+        .run()?;
+    print!("{tuning}");
+    Ok(())
+}
@@ -1,3 +1,5 @@
+/// Acol, the British natural system with a weak notrump
+pub mod acol;
 /// What the partnership has agreed to play — the value a book is built from
 pub mod agreements;
 /// The basic 2/1 game-forcing system
@@ -22,7 +24,6 @@ pub(in crate::bidding) mod decoder;
 /// The Dutch system — a natural 2/1 built around a wide, non-forcing 1♣
 pub mod dutch;
 /// Call-EV evaluator: a candidate call's cardplay-grounded worth by rollout
-#[cfg(feature = "dd")]
 pub mod ev;
 /// Learned trick evaluator: hidden-seat ranges → double-dummy trick mean/spread
 pub mod evaluator;
@@ -42,11 +43,20 @@ pub mod map;
 pub mod neural;
 /// Deterministic safety shell over the distilled neural floor
 pub mod neural_floor;
+/// Post-mortem auction analysis: each of our calls priced against the shortlist
+pub mod postmortem;
+/// Precision — a strong artificial 1♣ and limited 11–15 openings
+pub mod precision;
 /// Declarative book layer: entry rows compiled into the existing [`Trie`]
 pub(in crate::bidding) mod rows;
 pub mod rules;
 /// Constrained layout sampling: deals consistent with an auction's inferences
 pub mod sampler;
+/// Standard American Yellow Card — five-card majors, a 2/1 forcing one round
+pub mod sayc;
+/// Live search bidder: a partnership's shortlist reranked by rollout EV
+#[cfg(feature = "dd")]
+pub mod search;
 pub mod table;
 /// Structural tag reading of a call — the shared corpus/feature vocabulary
 pub mod tags;
@@ -55,6 +65,10 @@ pub mod trie;
 /// Behavioral verification of authored constraints (AI-bidder M4.2)
 pub mod verify;
 
+pub use acol::{
+    acol, acol_book, acol_book_default, acol_default, acol_instinct, acol_instinct_default,
+    acol_with_card, acol_with_config,
+};
 pub use american::{
     american, american_book, american_book_default, american_default, american_floor,
     american_floor_default, american_instinct, american_instinct_default, american_with_card,
@@ -72,6 +86,7 @@ pub use dutch::{
 };
 #[cfg(feature = "dd")]
 pub use ev::ev_all;
+pub use ev::{ev_fast, rank_agreement};
 pub use features::{
     CALLS_EVAL_V3, Config, FEATURES_LEN_EVAL, FEATURES_LEN_EVAL_V3, FEATURES_LEN_V3,
     FEATURES_LEN_V4, FEATURES_VERSION_EVAL, FEATURES_VERSION_V3, FEATURES_VERSION_V4,
@@ -83,8 +98,19 @@ pub use inference::{
 };
 pub use instinct::instinct;
 pub use map::Map;
+pub use postmortem::{Decision, PostMortem, post_mortem};
+pub use precision::{
+    precision, precision_book, precision_book_default, precision_default, precision_instinct,
+    precision_instinct_default, precision_with_card, precision_with_config,
+};
 pub use rules::{Alert, Rules};
 pub use sampler::{sample_defender_remnants, sample_layouts};
+pub use sayc::{
+    sayc, sayc_book, sayc_book_default, sayc_default, sayc_instinct, sayc_instinct_default,
+    sayc_with_card, sayc_with_config,
+};
+#[cfg(feature = "dd")]
+pub use search::SearchBidder;
 pub use table::Table;
 pub use trie::{Trie, classifier};
 pub use verify::{Report, accepts, compare};
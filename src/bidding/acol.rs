@@ -0,0 +1,191 @@
+//! Acol — the British natural system with a weak notrump
+//!
+//! Acol shares `american()`'s competitive and defensive books and most of its
+//! minor-opening structure.  Where a UK club pair plays differently, the
+//! divergent nodes are recompiled on top:
+//!
+//! * the **1NT opening is 12–14** balanced, and a balanced 15–19 opens a suit
+//!   (`openings`);
+//! * majors are **four-card**: the suit chosen is the longest, a 4-4 the
+//!   higher except clubs with spades, a 4-4-4-1 the suit below the singleton;
+//! * the twos are **strong** (`2♦`/`2♥`/`2♠` eight playing tricks, an
+//!   artificial game-forcing `2♣`) or, under
+//!   [`OpeningKnobs::benjamin`][crate::bidding::agreements::OpeningKnobs::benjamin],
+//!   **Benjamin** (`2♣` a strong one-suiter, `2♦` the game force, `2♥`/`2♠`
+//!   weak);
+//! * responses to 1NT are Stayman and Jacoby transfers on weak-notrump bands,
+//!   and over a **double** of 1NT responder wriggles: a five-card suit at the
+//!   two level, or an SOS redouble with two four-card suits (`notrump`);
+//! * the major responses are limit raises, a non-forcing `1NT`, natural
+//!   notrump jumps and SAYC's two-over-one, forcing one round, and a balanced
+//!   hand too strong for the weak notrump rebids one on 15–17 (`responses`).
+//!
+//! Minor-opening responses, competition, slam machinery and defense are
+//! american's, carried verbatim.
+//!
+//! The floor's regime input has no Acol axis and no weak-notrump slot (see
+//! [`ConventionCard::one_notrump_range`]), so the compact v6 floor is told the
+//! 2/1 regime with a balanced 1NT.  It learns the range through the reading,
+//! which narrows an Acol 1NT to 12–14, and its 2/1 game force is off.
+
+pub(in crate::bidding) mod notrump;
+mod openings;
+mod responses;
+
+use super::System;
+use super::agreements::Agreements;
+use super::american::{EUROPEAN, NotrumpRange, NotrumpShape};
+use super::common::{with_floor, with_floor_v6, with_instinct_floor};
+use super::features::{CompactConfig, Config, ConventionCard};
+use super::rows::compile_into;
+
+/// Build the Acol system as one side's [`System`]
+///
+/// Bind it with [`System::bind`] and seat it with
+/// [`Table::of_systems`][super::Table::of_systems], exactly like `american()`.
+///
+/// `agreements` is read through [`acol_agreements`], which pins the knobs
+/// Acol fixes: the weak balanced 1NT, four-card majors, and the floor's 2/1
+/// game force.
+///
+/// ```
+/// use pons::acol_default;
+/// use pons::bidding::Bidder;
+/// use contract_bridge::auction::{Call, RelativeVulnerability};
+/// use contract_bridge::{Bid, Strain};
+///
+/// let partnership = acol_default().bind();
+/// let hand = "AQ32.K53.QJ4.J92".parse().unwrap(); // 13 HCP, balanced
+/// let logits = partnership
+///     .classify(hand, RelativeVulnerability::NONE, &[])
+///     .expect("an opening decision");
+/// let best = (&logits.0)
+///     .into_iter()
+///     .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+///     .map(|(call, _)| call)
+///     .unwrap();
+/// assert_eq!(best, Call::Bid(Bid::new(1, Strain::Notrump)));
+/// ```
+#[must_use]
+pub fn acol(agreements: &Agreements) -> System {
+    let agreements = acol_agreements(agreements);
+    with_floor_v6(
+        book(&agreements),
+        CompactConfig::symmetric(&ConventionCard::capture(&agreements, false)),
+        &agreements,
+    )
+}
+
+/// [`acol`] on the shipped agreements — see
+/// [`american_default`][super::american::american_default]
+#[must_use]
+pub fn acol_default() -> System {
+    acol(&Agreements::default())
+}
+
+/// [`acol`] against a **declared** opponent — see
+/// [`dutch_with_config`][super::dutch::dutch_with_config], whose caveat about
+/// a card the book does not play applies here too
+#[must_use]
+pub fn acol_with_config(agreements: &Agreements, config: Config) -> System {
+    let agreements = acol_agreements(agreements);
+    with_floor(book(&agreements), config, &agreements)
+}
+
+/// [`acol`] against a declared opponent, on the shipped v6 floor
+#[must_use]
+pub fn acol_with_card(agreements: &Agreements, theirs: &ConventionCard) -> System {
+    let agreements = acol_agreements(agreements);
+    with_floor_v6(
+        book(&agreements),
+        CompactConfig::new(&ConventionCard::capture(&agreements, false), theirs),
+        &agreements,
+    )
+}
+
+/// Acol with the deterministic **instinct** floor
+///
+/// Exactly [`acol`] but for the floor — the fully-disclosable reference, as
+/// [`american_instinct`][crate::american_instinct] is for `american()`.
+#[must_use]
+pub fn acol_instinct(agreements: &Agreements) -> System {
+    let agreements = acol_agreements(agreements);
+    with_instinct_floor(book(&agreements), &agreements)
+}
+
+/// [`acol_instinct`] on the shipped agreements — see
+/// [`american_default`][super::american::american_default]
+#[must_use]
+pub fn acol_instinct_default() -> System {
+    acol_instinct(&Agreements::default())
+}
+
+/// Build the Acol system as the authored books alone, with no floor
+///
+/// Takes a full [`american_book`][super::american::american_book] system under
+/// [`acol_agreements`], compiles SAYC's `sayc-two-over-one` package onto its
+/// constructive trie and then three ungated Acol ones — `acol-openings`,
+/// `acol-one-notrump` and `acol-suit-responses` — and one, `acol-wriggle`,
+/// onto its competitive trie.
+#[must_use]
+pub fn acol_book(agreements: &Agreements) -> System {
+    book(&acol_agreements(agreements))
+}
+
+/// [`acol_book`] on the shipped agreements — see
+/// [`american_default`][super::american::american_default]
+#[must_use]
+pub fn acol_book_default() -> System {
+    acol_book(&Agreements::default())
+}
+
+/// `agreements` with the knobs Acol fixes pinned
+///
+/// The 1NT opening is a [`NotrumpShape::Balanced`] [`NotrumpRange::Weak`],
+/// majors are four-card, and the floor does not treat a 2/1 as game forcing.
+/// The reading walk is told what the 1NT responses are not: no `1NT - 3M`
+/// splinter, no garbage Stayman, and `1NT - 2NT` a natural invitation (the
+/// [`EUROPEAN`] reading, as for [`sayc`][super::sayc]).  The major responses
+/// are SAYC's, so Reverse Drury is off.  Every other knob passes through, so
+/// the american adjuncts Acol inherits still answer to them.
+#[must_use]
+pub fn acol_agreements(agreements: &Agreements) -> Agreements {
+    let mut agreements = *agreements;
+    agreements.opening.notrump_shape = NotrumpShape::Balanced;
+    agreements.opening.one_notrump_offshape = false;
+    agreements.decision.two_over_one_force = false;
+    agreements.response.drury = false;
+    let reading = &mut agreements.decision.reading;
+    reading.one_notrump_range = NotrumpRange::Weak;
+    reading.four_card_majors = true;
+    reading.notrump_minors = EUROPEAN;
+    reading.nt_splinter = false;
+    reading.garbage_stayman = false;
+    agreements
+}
+
+/// [`acol_book`] on already-pinned agreements
+fn book(agreements: &Agreements) -> System {
+    let mut system = super::american::book(agreements);
+    // Compile after American, and Acol's own after SAYC's two-over-one: each
+    // layer replaces the exact nodes it inherits.
+    compile_into(
+        &mut system.constructive.0,
+        agreements,
+        &[super::sayc::responses::package()],
+    );
+    compile_into(
+        &mut system.constructive.0,
+        agreements,
+        &[
+            openings::package(),
+            notrump::package(),
+            responses::package(),
+        ],
+    );
+    compile_into(&mut system.competitive.0, agreements, &[notrump::wriggle()]);
+    system
+}
+
+#[cfg(test)]
+mod tests;
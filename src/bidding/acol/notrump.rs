@@ -0,0 +1,338 @@
+//! Responses to the weak 1NT, and the wriggles when it is doubled
+//!
+//! Opposite 12–14 every band sits three points above american's (and two
+//! opposite Precision's 13–15, which reuses this [`package`] — see
+//! [`NotrumpRange::lift`]):
+//!
+//! * `2♣!` is **Stayman** on 11+ with a four-card major, and `2♦!`/`2♥!` are
+//!   **Jacoby transfers** at any strength;
+//! * `2NT` invites on a balanced 11–12, `3NT` is 13–18, and `4NT` a
+//!   quantitative 19–20;
+//! * opener accepts every invitation on 14, the top of the range.
+//!
+//! Stayman's answers and the transfer completions are american's, carried
+//! verbatim; this module authors responder's rebid over each and opener's
+//! reply to the invitations.
+//!
+//! When they **double** the weak notrump, responder with up to 9 runs
+//! ([`wriggle`]): a five-card suit is bid at the two level, and two four-card
+//! suits **redouble** — an SOS that asks opener to bid `2♣`, after which the
+//! pair bids four-card suits up the line until one is passed.
+
+use crate::bidding::Alert;
+use crate::bidding::Rules;
+use crate::bidding::american::NotrumpRange;
+use crate::bidding::common::call;
+use crate::bidding::constraint::{Cons, Constraint, balanced, described, hcp, len};
+use crate::bidding::context::Context;
+use crate::bidding::rows::{Entry, Package, Pattern, rows_of};
+use contract_bridge::auction::Call;
+use contract_bridge::{Hand, Strain, Suit};
+
+/// Stayman over the weak notrump — 11+ with a four-card major
+const STAYMAN: Alert = Alert("acol:stayman");
+/// A Jacoby transfer over the weak notrump — five-plus of the next suit up
+const TRANSFER: Alert = Alert("acol:transfer");
+/// The SOS redouble of their double of 1NT — two four-card suits, no five
+const SOS: Alert = Alert("acol:sos-redouble");
+/// Opener's forced `2♣` after the SOS redouble
+const SOS_RELAY: Alert = Alert("acol:sos-relay");
+
+/// Responder's strength bands opposite one notrump range
+///
+/// Each is a strong-notrump band lifted by [`NotrumpRange::lift`], so the
+/// combined total it aims at is the same whatever opener's range.
+#[derive(Clone, Copy)]
+struct Bands {
+    /// The invitation's floor — 8 opposite 15–17
+    invite: u8,
+    /// The game-forcing floor — 10 opposite 15–17
+    game: u8,
+    /// The quantitative `4NT`'s floor — 16 opposite 15–17
+    quantitative: u8,
+    /// The direct `6NT`'s floor — 18 opposite 15–17
+    slam: u8,
+    /// Opener's maximum, which accepts every invitation
+    maximum: u8,
+}
+
+impl Bands {
+    const fn of(range: NotrumpRange) -> Self {
+        let lift = range.lift();
+        Self {
+            invite: 8 + lift,
+            game: 10 + lift,
+            quantitative: 16 + lift,
+            slam: 18 + lift,
+            maximum: range.hcp().1,
+        }
+    }
+}
+
+/// Neither major held four long
+fn no_major() -> Cons<impl Constraint + Clone> {
+    len(Suit::Hearts, ..4) & len(Suit::Spades, ..4)
+}
+
+/// Responder's first call over our 1NT
+fn one_notrump_responses(bands: Bands) -> Rules {
+    Rules::new()
+        .rule(call(2, Strain::Diamonds), 200, len(Suit::Hearts, 5..))
+        .alert(TRANSFER)
+        // With five of each major, transfer to spades.
+        .rule(call(2, Strain::Hearts), 205, len(Suit::Spades, 5..))
+        .alert(TRANSFER)
+        .rule(
+            call(2, Strain::Clubs),
+            150,
+            (len(Suit::Hearts, 4..) | len(Suit::Spades, 4..)) & hcp(bands.invite..),
+        )
+        .alert(STAYMAN)
+        .rule(call(6, Strain::Notrump), 125, hcp(bands.slam..))
+        .rule(
+            call(4, Strain::Notrump),
+            120,
+            hcp(bands.quantitative..bands.slam),
+        )
+        .rule(
+            call(2, Strain::Notrump),
+            110,
+            balanced() & hcp(bands.invite..bands.game) & no_major(),
+        )
+        .rule(
+            call(3, Strain::Notrump),
+            100,
+            hcp(bands.game..bands.quantitative),
+        )
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// Responder's rebid after opener answers Stayman
+///
+/// `shown` is the major opener showed, or `None` after the `2♦` denial.  With
+/// four of it responder raises — to game on 13+, inviting on 11–12 — and
+/// otherwise bids notrump on the same bands.
+fn stayman_rebid(shown: Option<Suit>, bands: Bands) -> Rules {
+    let rules = Rules::new()
+        .rule(call(3, Strain::Notrump), 100, hcp(bands.game..))
+        .rule(call(2, Strain::Notrump), 90, hcp(..bands.game))
+        .rule(Call::Pass, 0, hcp(0..));
+    match shown {
+        Some(major) => rules
+            .rule(
+                call(4, Strain::from(major)),
+                150,
+                len(major, 4..) & hcp(bands.game..),
+            )
+            .rule(
+                call(3, Strain::from(major)),
+                140,
+                len(major, 4..) & hcp(..bands.game),
+            ),
+        None => rules,
+    }
+}
+
+/// Responder's rebid after the transfer to `major` completes
+///
+/// A weak hand passes.  Six of the major raises — to game on 13+, to three on
+/// 11–12 — and five bids notrump on the same bands, for opener to choose.
+fn transfer_rebid(major: Suit, bands: Bands) -> Rules {
+    let trump = Strain::from(major);
+    let invite = bands.invite..bands.game;
+    Rules::new()
+        .rule(call(4, trump), 150, len(major, 6..) & hcp(bands.game..))
+        .rule(
+            call(3, Strain::Notrump),
+            140,
+            hcp(bands.game..bands.quantitative),
+        )
+        .rule(call(3, trump), 130, len(major, 6..) & hcp(invite.clone()))
+        .rule(call(2, Strain::Notrump), 120, hcp(invite))
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// Opener's reply to responder's invitation or choice of games
+///
+/// `major` is responder's known five-card major, if any.  Opener plays it
+/// with three, accepts on a maximum, and otherwise stops as low as the
+/// auction allows.
+fn after_notrump_invite(major: Option<Suit>, game: bool, bands: Bands) -> Rules {
+    let rules = Rules::new().rule(Call::Pass, 0, hcp(0..));
+    match (major, game) {
+        (Some(major), true) => rules.rule(call(4, Strain::from(major)), 100, len(major, 3..)),
+        (Some(major), false) => rules
+            .rule(
+                call(4, Strain::from(major)),
+                110,
+                len(major, 3..) & hcp(bands.maximum..),
+            )
+            .rule(call(3, Strain::Notrump), 100, hcp(bands.maximum..))
+            .rule(call(3, Strain::from(major)), 90, len(major, 3..)),
+        (None, _) => rules.rule(call(3, Strain::Notrump), 100, hcp(bands.maximum..)),
+    }
+}
+
+/// Opener's reply to an invitational raise of `major`: game on a maximum
+fn after_major_invite(major: Suit, bands: Bands) -> Rules {
+    Rules::new()
+        .rule(call(4, Strain::from(major)), 100, hcp(bands.maximum..))
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// Opener's reply to the quantitative `4NT`: slam on a maximum
+fn after_quantitative(bands: Bands) -> Rules {
+    Rules::new()
+        .rule(call(6, Strain::Notrump), 100, hcp(bands.maximum..))
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// The weak-notrump response table and the continuations it opens
+///
+/// Banded off the agreements' [`one_notrump_range`], so Precision's 13–15
+/// compiles the same structure two points lower.
+///
+/// [`one_notrump_range`]: field@crate::bidding::ReadingProfile::one_notrump_range
+pub(in crate::bidding) fn package() -> Package {
+    Package {
+        name: "acol-one-notrump",
+        gate: |_| true,
+        entries: |agreements| {
+            let bands = Bands::of(agreements.decision.reading.one_notrump_range);
+            let mut entries: Vec<Entry> =
+                rows_of(Pattern::node("P* 1NT -"), one_notrump_responses(bands));
+            entries.extend(rows_of(
+                Pattern::node("P* 1NT - 2NT -"),
+                after_notrump_invite(None, false, bands),
+            ));
+            entries.extend(rows_of(
+                Pattern::node("P* 1NT - 4NT -"),
+                after_quantitative(bands),
+            ));
+            for (answer, shown) in [
+                ("2♦", None),
+                ("2♥", Some(Suit::Hearts)),
+                ("2♠", Some(Suit::Spades)),
+            ] {
+                let prefix = format!("P* 1NT - 2♣ - {answer} -");
+                entries.extend(rows_of(Pattern::node(&prefix), stayman_rebid(shown, bands)));
+                entries.extend(rows_of(
+                    Pattern::node(&format!("{prefix} 2NT -")),
+                    after_notrump_invite(None, false, bands),
+                ));
+                if let Some(major) = shown {
+                    entries.extend(rows_of(
+                        Pattern::node(&format!("{prefix} {} -", call(3, Strain::from(major)))),
+                        after_major_invite(major, bands),
+                    ));
+                }
+            }
+            for (transfer, major) in [("2♦", Suit::Hearts), ("2♥", Suit::Spades)] {
+                let prefix = format!("P* 1NT - {transfer} - {} -", call(2, Strain::from(major)));
+                entries.extend(rows_of(
+                    Pattern::node(&prefix),
+                    transfer_rebid(major, bands),
+                ));
+                entries.extend(rows_of(
+                    Pattern::node(&format!("{prefix} 2NT -")),
+                    after_notrump_invite(Some(major), false, bands),
+                ));
+                entries.extend(rows_of(
+                    Pattern::node(&format!("{prefix} 3NT -")),
+                    after_notrump_invite(Some(major), true, bands),
+                ));
+                entries.extend(rows_of(
+                    Pattern::node(&format!("{prefix} {} -", call(3, Strain::from(major)))),
+                    after_major_invite(major, bands),
+                ));
+            }
+            entries
+        },
+    }
+}
+
+/// Two four-card suits and no five-card one — the SOS redouble's shape
+fn two_fours() -> Cons<impl Constraint + Clone> {
+    described(
+        "two four-card suits, no five",
+        |hand: Hand, _: &Context<'_>| {
+            let lengths = Suit::ASC.map(|suit| hand[suit].len());
+            lengths.iter().all(|&n| n < 5) && lengths.iter().filter(|&&n| n == 4).count() >= 2
+        },
+    )
+}
+
+/// Responder's call after their double of our 1NT
+///
+/// Up to 9 runs: a five-card suit at the two level (a six-card one more
+/// surely), or the SOS redouble with two four-card suits.  A strong hand, or a
+/// weak one with nowhere to go, passes.
+fn doubled_responses() -> Rules {
+    let mut rules = Rules::new()
+        .rule(Call::Redouble, 100, two_fours() & hcp(..=9))
+        .alert(SOS);
+    for suit in Suit::ASC {
+        rules = rules
+            .rule(call(2, Strain::from(suit)), 110, len(suit, 5..) & hcp(..=9))
+            .rule(call(2, Strain::from(suit)), 115, len(suit, 6..) & hcp(..=9));
+    }
+    rules.rule(Call::Pass, 0, hcp(0..))
+}
+
+/// Opener's forced `2♣` after the SOS redouble
+fn sos_relay() -> Rules {
+    Rules::new()
+        .rule(call(2, Strain::Clubs), 100, hcp(0..))
+        .alert(SOS_RELAY)
+}
+
+/// The suits above `suit`, cheapest first
+fn above(suit: Suit) -> impl Iterator<Item = Suit> {
+    Suit::ASC.into_iter().filter(move |&other| other > suit)
+}
+
+/// Pass with four of `suit`, else bid the next suit up
+///
+/// Responder's answer to the relay and each later step of the up-the-line
+/// search: `suit` is the one just bid.  `fit` is the length that stops the
+/// search — four for responder, who holds two four-card suits; three for
+/// opener, who holds at least two of every suit.
+fn up_the_line(suit: Suit, fit: usize) -> Rules {
+    let mut rules = Rules::new().rule(Call::Pass, 100, len(suit, fit..));
+    let mut weight = 90;
+    for next in above(suit) {
+        rules = rules.rule(call(2, Strain::from(next)), weight, len(next, fit..));
+        weight -= 10;
+    }
+    match above(suit).next() {
+        Some(next) => rules.rule(call(2, Strain::from(next)), 10, hcp(0..)),
+        None => rules.rule(Call::Pass, 0, hcp(0..)),
+    }
+}
+
+/// Our 1NT doubled: responder's wriggle and the SOS search it opens
+pub(super) fn wriggle() -> Package {
+    Package {
+        name: "acol-wriggle",
+        gate: |_| true,
+        entries: |_| {
+            let mut entries: Vec<Entry> = rows_of(Pattern::node("P* 1NT (X)"), doubled_responses());
+            entries.extend(rows_of(Pattern::node("P* 1NT (X) XX -"), sos_relay()));
+            let relay = "P* 1NT (X) XX - 2♣ -";
+            entries.extend(rows_of(Pattern::node(relay), up_the_line(Suit::Clubs, 4)));
+            // Responder shows the lower of two four-card suits; opener passes
+            // with three, else bids on, and responder passes or corrects.
+            for first in [Suit::Diamonds, Suit::Hearts] {
+                let shown = format!("{relay} {} -", call(2, Strain::from(first)));
+                entries.extend(rows_of(Pattern::node(&shown), up_the_line(first, 3)));
+                for second in above(first) {
+                    entries.extend(rows_of(
+                        Pattern::node(&format!("{shown} {} -", call(2, Strain::from(second)))),
+                        up_the_line(second, 4),
+                    ));
+                }
+            }
+            entries
+        },
+    }
+}
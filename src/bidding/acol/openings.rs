@@ -0,0 +1,168 @@
+//! The Acol opening table — four-card suits, a weak notrump, and the twos
+//!
+//! A balanced 12–14 opens 1NT and a balanced 20–22 opens 2NT; every other
+//! hand up to the twos opens one of the suit [`acol_suit`] names.  The twos
+//! follow [`OpeningKnobs::benjamin`][crate::bidding::agreements::OpeningKnobs::benjamin]:
+//!
+//! | Opening | Strong twos (default) | Benjamin |
+//! | --- | --- | --- |
+//! | `2♣` | game force, 23+ | strong one-suiter, 19–22 |
+//! | `2♦` | strong, six-plus diamonds, 19–22 | game force, 23+ |
+//! | `2♥`/`2♠` | strong, six-plus of the major, 19–22 | weak, six cards |
+//!
+//! Three-level preempts are american's seven-card ones.
+
+use crate::bidding::Alert;
+use crate::bidding::Rules;
+use crate::bidding::agreements::Agreements;
+use crate::bidding::common::call;
+use crate::bidding::constraint::{
+    Cons, Constraint, balanced, described, hcp, len, nth_seat, points,
+};
+use crate::bidding::context::Context;
+use crate::bidding::rows::{Package, Pattern, rows_of};
+use contract_bridge::auction::Call;
+use contract_bridge::{Hand, Strain, Suit};
+
+/// The Acol `2♣` — game forcing, 23+
+const ACOL_2C: Alert = Alert("acol:two-clubs");
+/// Benjamin `2♣` — any strong one-suiter, eight playing tricks
+const BENJAMIN_2C: Alert = Alert("acol:benjamin-2c");
+/// Benjamin `2♦` — the game force that Acol otherwise opens `2♣`
+const BENJAMIN_2D: Alert = Alert("acol:benjamin-2d");
+
+/// The suit an Acol one-level opening names
+///
+/// The longest suit; between two five-card or longer suits the higher.  With
+/// two four-card suits the higher, except spades with clubs, which opens
+/// `1♣`.  A 4-4-4-1 opens the suit below the singleton — `1♦` with a black
+/// singleton spade or a red singleton heart, `1♣` with a singleton diamond,
+/// and `1♥` (the middle of the three) with a singleton club.
+pub(super) fn acol_suit(hand: Hand) -> Suit {
+    let lengths = Suit::ASC.map(|suit| (suit, hand[suit].len()));
+    let longest = lengths.iter().map(|&(_, n)| n).max().unwrap_or(0);
+    let fours: Vec<Suit> = lengths
+        .iter()
+        .filter(|&&(_, n)| n == 4)
+        .map(|&(suit, _)| suit)
+        .collect();
+    if longest == 4 && fours.len() == 3 {
+        return match lengths
+            .iter()
+            .find(|&&(_, n)| n <= 1)
+            .map(|&(suit, _)| suit)
+        {
+            Some(Suit::Clubs) => Suit::Hearts,
+            Some(Suit::Diamonds) => Suit::Clubs,
+            _ => Suit::Diamonds,
+        };
+    }
+    if longest == 4 && fours == [Suit::Clubs, Suit::Spades] {
+        return Suit::Clubs;
+    }
+    // `Suit::ASC` runs upward, so the last suit of the longest length is the
+    // highest of them.
+    lengths
+        .iter()
+        .rev()
+        .find(|&&(_, n)| n == longest)
+        .map_or(Suit::Clubs, |&(suit, _)| suit)
+}
+
+/// Whether `suit` is the one [`acol_suit`] opens
+fn opens(suit: Suit) -> Cons<impl Constraint + Clone> {
+    described(
+        match suit {
+            Suit::Clubs => "Acol opens 1♣",
+            Suit::Diamonds => "Acol opens 1♦",
+            Suit::Hearts => "Acol opens 1♥",
+            Suit::Spades => "Acol opens 1♠",
+        },
+        move |hand: Hand, _: &Context<'_>| acol_suit(hand) == suit,
+    )
+}
+
+/// A strong two-level one-suiter's values: about eight tricks
+fn eight_tricks() -> Cons<impl Constraint + Clone> {
+    points(19..=22) & hcp(15..)
+}
+
+/// The game force: 23+, balanced or not
+fn game_force() -> Cons<impl Constraint + Clone> {
+    points(23..) | hcp(23..)
+}
+
+/// The opening table, shared by every seat
+///
+/// The 1NT (200) outranks the one-level suits (150) so a balanced 12–14 never
+/// opens a suit; the twos and `2NT` sit above both.  A third- or fourth-seat
+/// suit opens a point lighter, as in american.
+fn openings(agreements: &Agreements) -> Rules {
+    let mut rules = Rules::new()
+        .rule(call(1, Strain::Notrump), 200, balanced() & hcp(12..=14))
+        .rule(call(2, Strain::Notrump), 220, balanced() & hcp(20..=22));
+    if agreements.opening.benjamin {
+        rules = rules
+            .rule(call(2, Strain::Diamonds), 300, game_force())
+            .alert(BENJAMIN_2D);
+        let any_six = len(Suit::Clubs, 6..)
+            | len(Suit::Diamonds, 6..)
+            | len(Suit::Hearts, 6..)
+            | len(Suit::Spades, 6..);
+        rules = rules
+            .rule(call(2, Strain::Clubs), 280, any_six & eight_tricks())
+            .alert(BENJAMIN_2C);
+        for major in [Suit::Hearts, Suit::Spades] {
+            rules = rules.rule(
+                call(2, Strain::from(major)),
+                120,
+                len(major, 6..=6) & points(5..=10) & !nth_seat(4),
+            );
+        }
+    } else {
+        rules = rules
+            .rule(call(2, Strain::Clubs), 300, game_force())
+            .alert(ACOL_2C);
+        for suit in [Suit::Diamonds, Suit::Hearts, Suit::Spades] {
+            rules = rules.rule(
+                call(2, Strain::from(suit)),
+                280,
+                len(suit, 6..) & eight_tricks(),
+            );
+        }
+    }
+    for suit in Suit::ASC {
+        let strain = Strain::from(suit);
+        rules = rules
+            .rule(
+                call(1, strain),
+                150,
+                opens(suit) & len(suit, 4..) & points(12..=22) & hcp(10..),
+            )
+            .rule(
+                call(1, strain),
+                140,
+                opens(suit)
+                    & len(suit, 4..)
+                    & points(11..=22)
+                    & hcp(8..)
+                    & (nth_seat(3) | nth_seat(4)),
+            )
+            // Three-level preempts: a seven-card suit, not in fourth seat.
+            .rule(
+                call(3, strain),
+                90,
+                len(suit, 7..) & points(..12) & !nth_seat(4),
+            );
+    }
+    rules.rule(Call::Pass, 0, points(..12))
+}
+
+/// The Acol opening table at the empty auction
+pub(super) fn package() -> Package {
+    Package {
+        name: "acol-openings",
+        gate: |_| true,
+        entries: |agreements| rows_of(Pattern::node("P*"), openings(agreements)),
+    }
+}
@@ -0,0 +1,216 @@
+//! Acol responses to a major, and opener's rebids after a one-level response
+//!
+//! The two-over-one is SAYC's — a new suit at the two level on 10+, forcing
+//! one round, with opener's rebid graded — and is compiled in whole from
+//! [`sayc`][crate::bidding::sayc]'s package.  On top of it:
+//!
+//! * **Responder's first call** over `1♥`/`1♠` ([`major_responses`]) — no
+//!   Jacoby `2NT`: `2NT` is a natural 11–12 and `3NT` a natural 13–15, both
+//!   balanced without four-card support, and a four-card raise to game shows
+//!   13–15.
+//! * **Opener's rebid after `1M - 1NT`** ([`rebid_after_notrump`]) — the
+//!   response is non-forcing, and a balanced 15–16 passes it.
+//! * **Opener's rebid after a one-level new suit** ([`rebid_after_new_suit`])
+//!   — a balanced hand too strong for the weak notrump rebids `1NT` on 15–17
+//!   and jumps to `2NT` on 18–19, and a raise promises four.
+//! * **Responder's second call after that `1NT` rebid**
+//!   ([`after_notrump_rebid`]) — game on 9, an invitation on 7–8.
+//!
+//! The minor-opening responses are american's: an Acol `1♣`/`1♦` is four
+//! cards or longer, which american's better-minor tables already allow.
+
+use crate::bidding::Rules;
+use crate::bidding::agreements::Agreements;
+use crate::bidding::american::responses::JACOBY_2NT;
+use crate::bidding::common::call;
+use crate::bidding::constraint::{balanced, hcp, len, points, support};
+use crate::bidding::rows::{Entry, Package, Pattern, expand, rows_of};
+use contract_bridge::auction::Call;
+use contract_bridge::{Strain, Suit};
+
+/// Responses to our `1♥`/`1♠` opening
+///
+/// SAYC's table — american's with the game-forcing 2/1 replaced by the
+/// one-round force — less Jacoby `2NT`, plus Acol's natural notrump jumps and
+/// the 13–15 raise to game.  The jumps outrank the 2/1 (110 and below), so a
+/// balanced hand without a fit names its strength at once.
+fn major_responses(major: Suit, agreements: &Agreements) -> Rules {
+    let no_fit = balanced() & !support(4..);
+    crate::bidding::sayc::responses::major_responses(major, agreements)
+        .gated_out(&[JACOBY_2NT])
+        .rule(
+            call(4, Strain::from(major)),
+            165,
+            support(4..) & points(13..=15),
+        )
+        .rule(call(3, Strain::Notrump), 125, no_fit.clone() & hcp(13..=15))
+        .rule(call(2, Strain::Notrump), 120, no_fit & hcp(11..=12))
+}
+
+/// Opener's answer to the natural `1M - 2NT`: game on 14
+fn after_two_notrump(major: Suit) -> Rules {
+    let trump = Strain::from(major);
+    Rules::new()
+        .rule(call(4, trump), 110, len(major, 6..) & points(14..))
+        .rule(call(3, Strain::Notrump), 100, hcp(14..))
+        .rule(call(3, trump), 90, len(major, 6..))
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// Opener's answer to the natural `1M - 3NT`: the major with six
+fn after_three_notrump(major: Suit) -> Rules {
+    Rules::new()
+        .rule(call(4, Strain::from(major)), 100, len(major, 6..))
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// The suits other than `a` and `b`, cheapest first
+fn other_suits(a: Suit, b: Suit) -> impl Iterator<Item = Suit> {
+    Suit::ASC
+        .into_iter()
+        .filter(move |&suit| suit != a && suit != b)
+}
+
+/// Whether `a` ranks above `b`
+fn above(a: Suit, b: Suit) -> bool {
+    Strain::from(a) > Strain::from(b)
+}
+
+/// Opener's rebid after `1M - 1NT`, which Acol plays non-forcing
+///
+/// A balanced 15–16 passes and a 17–18 invites; an unbalanced minimum rebids
+/// a six-card major or names a lower four-card suit.  A new suit above the
+/// major is a reverse and shows 16+.
+fn rebid_after_notrump(major: Suit) -> Rules {
+    let trump = Strain::from(major);
+    let mut rules = Rules::new()
+        .rule(call(3, Strain::Notrump), 135, balanced() & hcp(19..))
+        .rule(call(2, Strain::Notrump), 130, balanced() & hcp(17..=18))
+        .rule(call(3, trump), 120, len(major, 6..) & points(16..=18))
+        .rule(call(2, trump), 100, len(major, 6..))
+        .rule(Call::Pass, 0, hcp(0..));
+    for other in Suit::ASC.into_iter().filter(|&suit| suit != major) {
+        rules = if above(other, major) {
+            rules.rule(
+                call(2, Strain::from(other)),
+                110,
+                len(other, 4..) & points(16..),
+            )
+        } else {
+            rules.rule(call(2, Strain::from(other)), 95, len(other, 4..))
+        };
+    }
+    rules
+}
+
+/// Opener's rebid after a one-level new-suit response
+///
+/// Forcing, so no [`Pass`][Call::Pass] rule.  A four-card raise is graded by
+/// strength, a balanced hand rebids notrump on 15–17 or jumps on 18–19, and a
+/// new suit is bid at the one level on four cards, at the two level below the
+/// opening on four, and above it — a reverse — on 16+.  The two-level rebid
+/// of the opening suit shows five and is the catch-all.
+fn rebid_after_new_suit(opening: Suit, response: Suit) -> Rules {
+    let own = Strain::from(opening);
+    let raise = Strain::from(response);
+    let mut rules = Rules::new()
+        .rule(call(4, raise), 170, len(response, 4..) & points(19..))
+        .rule(call(3, raise), 165, len(response, 4..) & points(16..=18))
+        .rule(call(2, raise), 160, len(response, 4..) & points(..=15))
+        .rule(call(2, Strain::Notrump), 150, balanced() & hcp(18..=19))
+        .rule(call(1, Strain::Notrump), 140, balanced() & hcp(15..=17))
+        .rule(call(3, own), 120, len(opening, 6..) & points(16..))
+        .rule(call(2, own), 100, len(opening, 5..))
+        .rule(call(2, own), 10, hcp(0..));
+    let mut one_level = 130;
+    for other in other_suits(opening, response) {
+        let strain = Strain::from(other);
+        rules = if above(other, response) {
+            let rule = rules.rule(call(1, strain), one_level, len(other, 4..));
+            one_level -= 5;
+            rule
+        } else if above(other, opening) {
+            rules.rule(call(2, strain), 115, len(other, 4..) & points(16..))
+        } else {
+            rules.rule(call(2, strain), 110, len(other, 4..) & points(..=18))
+        };
+    }
+    rules
+}
+
+/// Responder's second call after opener's 15–17 `1NT` rebid
+///
+/// `response` is responder's own suit.  Game on 9+ — in a six-card major, else
+/// 3NT — an invitation on 7–8, and a weak hand with a long suit rebids it.
+fn after_notrump_rebid(response: Suit) -> Rules {
+    let own = Strain::from(response);
+    let mut rules = Rules::new()
+        .rule(call(3, Strain::Notrump), 100, hcp(9..))
+        .rule(call(2, Strain::Notrump), 90, hcp(7..=8))
+        .rule(call(2, own), 80, len(response, 6..) & hcp(..=6))
+        .rule(Call::Pass, 0, hcp(0..));
+    if matches!(response, Suit::Hearts | Suit::Spades) {
+        rules = rules.rule(call(4, own), 110, len(response, 6..) & hcp(9..));
+    }
+    rules
+}
+
+/// Opener's answer to responder's `2NT` invitation over the `1NT` rebid
+fn accept_after_notrump_rebid() -> Rules {
+    Rules::new()
+        .rule(call(3, Strain::Notrump), 100, hcp(16..))
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// Acol's major responses and the one-level rebid structure
+pub(super) fn package() -> Package {
+    Package {
+        name: "acol-suit-responses",
+        gate: |_| true,
+        entries: |agreements| {
+            let mut entries: Vec<Entry> = expand(
+                "P* 1M -",
+                |_| true,
+                |b| major_responses(b.suit('M'), agreements),
+            );
+            entries.extend(expand(
+                "P* 1M - 1NT -",
+                |_| true,
+                |b| rebid_after_notrump(b.suit('M')),
+            ));
+            entries.extend(expand(
+                "P* 1M - 2NT -",
+                |_| true,
+                |b| after_two_notrump(b.suit('M')),
+            ));
+            entries.extend(expand(
+                "P* 1M - 3NT -",
+                |_| true,
+                |b| after_three_notrump(b.suit('M')),
+            ));
+            for opening in Suit::ASC {
+                for response in Suit::ASC.into_iter().filter(|&suit| above(suit, opening)) {
+                    let prefix = format!(
+                        "P* {} - {} -",
+                        call(1, Strain::from(opening)),
+                        call(1, Strain::from(response)),
+                    );
+                    entries.extend(rows_of(
+                        Pattern::node(&prefix),
+                        rebid_after_new_suit(opening, response),
+                    ));
+                    let notrump = format!("{prefix} 1NT -");
+                    entries.extend(rows_of(
+                        Pattern::node(&notrump),
+                        after_notrump_rebid(response),
+                    ));
+                    entries.extend(rows_of(
+                        Pattern::node(&format!("{notrump} 2NT -")),
+                        accept_after_notrump_rebid(),
+                    ));
+                }
+            }
+            entries
+        },
+    }
+}
@@ -0,0 +1,181 @@
+use super::{acol, acol_agreements};
+use crate::bidding::Bidder;
+use crate::bidding::agreements::Agreements;
+use crate::bidding::american::NotrumpRange;
+use crate::bidding::context::Context;
+use crate::bidding::inference::{Inferences, Range};
+use contract_bridge::auction::{Call, RelativeVulnerability};
+use contract_bridge::{Bid, Strain, Suit};
+
+const P: Call = Call::Pass;
+const X: Call = Call::Double;
+const XX: Call = Call::Redouble;
+
+fn bid(level: u8, strain: Strain) -> Call {
+    Call::Bid(Bid::new(level, strain))
+}
+
+/// The Acol call after `auction` on `agreements`
+fn calls_with(agreements: &Agreements, auction: &[Call], hand: &str) -> Call {
+    let partnership = acol(agreements).bind();
+    let hand = hand.parse().unwrap();
+    let logits = partnership
+        .classify(hand, RelativeVulnerability::NONE, auction)
+        .expect("a decision");
+    (&logits.0)
+        .into_iter()
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+        .map(|(call, _)| call)
+        .unwrap()
+}
+
+/// The Acol call after `auction` on the shipped agreements
+fn calls(auction: &[Call], hand: &str) -> Call {
+    calls_with(&Agreements::default(), auction, hand)
+}
+
+/// Every Acol override package preserves the declarative row invariants.
+#[test]
+fn row_package_invariants() {
+    crate::bidding::rows::assert_package_invariants(
+        &acol_agreements(&Agreements::default()),
+        &[
+            super::openings::package(),
+            super::notrump::package(),
+            super::notrump::wriggle(),
+            super::responses::package(),
+        ],
+    );
+    let mut benjamin = Agreements::default();
+    benjamin.opening.benjamin = true;
+    crate::bidding::rows::assert_package_invariants(
+        &acol_agreements(&benjamin),
+        &[super::openings::package()],
+    );
+}
+
+/// A balanced 12–14 opens 1NT; a balanced 15 opens a suit.
+#[test]
+fn the_notrump_is_weak() {
+    assert_eq!(calls(&[], "AQ32.K53.QJ4.J92"), bid(1, Strain::Notrump));
+    assert_eq!(calls(&[], "AQ32.K53.QJ4.K92"), bid(1, Strain::Spades));
+    assert_eq!(calls(&[], "AQ32.K53.KJ4.A92"), bid(1, Strain::Spades));
+}
+
+/// Four-card majors, a 4-4 in the higher except spades with clubs, and the
+/// 4-4-4-1 suit below the singleton.
+#[test]
+fn four_card_suits() {
+    assert_eq!(calls(&[], "A2.KQ32.AJ4.KJ92"), bid(1, Strain::Hearts));
+    assert_eq!(calls(&[], "AQ32.KQ32.A4.J92"), bid(1, Strain::Spades));
+    assert_eq!(calls(&[], "AQ32.K2.A54.KJ92"), bid(1, Strain::Clubs));
+    assert_eq!(calls(&[], "AQ32.KJ32.A5432."), bid(1, Strain::Diamonds));
+    assert_eq!(calls(&[], "AQ32.KJ32.AJ32.2"), bid(1, Strain::Hearts));
+    assert_eq!(
+        super::openings::acol_suit("2.KJ32.AJ32.AQ32".parse().unwrap()),
+        Suit::Diamonds
+    );
+    assert_eq!(
+        super::openings::acol_suit("AQ32.KJ32.2.AJ32".parse().unwrap()),
+        Suit::Clubs
+    );
+}
+
+/// Strong twos and the Acol 2♣ by default; Benjamin twos on the knob.
+#[test]
+fn twos() {
+    let strong_hearts = "A2.AKQJ932.KQ4.2";
+    let game_force = "AK2.AKQJ3.AQ4.K2";
+    assert_eq!(calls(&[], strong_hearts), bid(2, Strain::Hearts));
+    assert_eq!(calls(&[], game_force), bid(2, Strain::Clubs));
+
+    let mut benjamin = Agreements::default();
+    benjamin.opening.benjamin = true;
+    assert_eq!(
+        calls_with(&benjamin, &[], strong_hearts),
+        bid(2, Strain::Clubs)
+    );
+    assert_eq!(
+        calls_with(&benjamin, &[], game_force),
+        bid(2, Strain::Diamonds)
+    );
+    assert_eq!(
+        calls_with(&benjamin, &[], "32.KQJ932.J42.32"),
+        bid(2, Strain::Hearts)
+    );
+}
+
+/// Responses to the weak notrump sit three points higher.
+#[test]
+fn notrump_responses() {
+    let one_notrump = [bid(1, Strain::Notrump), P];
+    assert_eq!(calls(&one_notrump, "K32.Q32.KJ32.J32"), P);
+    assert_eq!(
+        calls(&one_notrump, "K32.Q32.KJ32.Q32"),
+        bid(2, Strain::Notrump)
+    );
+    assert_eq!(
+        calls(&one_notrump, "K32.Q32.KJ32.A32"),
+        bid(3, Strain::Notrump)
+    );
+    assert_eq!(
+        calls(&one_notrump, "KJ32.Q32.KJ3.A32"),
+        bid(2, Strain::Clubs)
+    );
+    let invite = [bid(1, Strain::Notrump), P, bid(2, Strain::Notrump), P];
+    assert_eq!(calls(&invite, "AQ32.K53.QJ4.J92"), P);
+    assert_eq!(calls(&invite, "AQ32.K53.QJ4.Q92"), bid(3, Strain::Notrump));
+}
+
+/// Over a double of 1NT responder runs to a five-card suit or redoubles SOS.
+#[test]
+fn wriggles() {
+    let doubled = [bid(1, Strain::Notrump), X];
+    assert_eq!(calls(&doubled, "32.J8643.Q32.932"), bid(2, Strain::Hearts));
+    assert_eq!(calls(&doubled, "J432.2.Q432.9432"), XX);
+    assert_eq!(calls(&doubled, "KQ2.K32.QJ32.K32"), P);
+
+    let sos = [bid(1, Strain::Notrump), X, XX, P];
+    assert_eq!(calls(&sos, "AQ3.K53.QJ4.J932"), bid(2, Strain::Clubs));
+    let relay = [bid(1, Strain::Notrump), X, XX, P, bid(2, Strain::Clubs), P];
+    assert_eq!(calls(&relay, "J432.2.Q432.9432"), P);
+    assert_eq!(calls(&relay, "J432.Q432.9432.2"), bid(2, Strain::Diamonds));
+    let shown = [
+        bid(1, Strain::Notrump),
+        X,
+        XX,
+        P,
+        bid(2, Strain::Clubs),
+        P,
+        bid(2, Strain::Diamonds),
+        P,
+    ];
+    assert_eq!(calls(&shown, "AQ3.K53.QJ4.J932"), P);
+    assert_eq!(calls(&shown, "AQ32.K532.J4.J93"), bid(2, Strain::Hearts));
+}
+
+/// A balanced hand too strong for the weak notrump rebids one on 15–17.
+#[test]
+fn notrump_rebid_is_strong() {
+    let one_club = [bid(1, Strain::Clubs), P, bid(1, Strain::Hearts), P];
+    assert_eq!(
+        calls(&one_club, "A32.K3.KQ32.KJ32"),
+        bid(1, Strain::Notrump)
+    );
+}
+
+/// The reading takes an Acol 1NT as 12–14, whoever's agreements read it.
+#[test]
+fn the_reading_knows_the_weak_notrump() {
+    let agreements = acol_agreements(&Agreements::default());
+    let auction = [bid(1, Strain::Notrump)];
+    let context =
+        Context::new(RelativeVulnerability::NONE, &auction).with_profile(agreements.decision);
+    let inferences = Inferences::read(&context);
+    // The opener sits on the right of the player to act.
+    assert_eq!(inferences.rho().strength.hcp, Range::new(12, 14));
+    assert_eq!(
+        agreements.decision.reading.one_notrump_range,
+        NotrumpRange::Weak
+    );
+}
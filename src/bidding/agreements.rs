@@ -36,6 +36,9 @@ use super::american::{
 };
 use super::context::DecisionProfile;
 
+mod registry;
+pub use registry::{KNOBS, Kind, Knob, KnobError, knob};
+
 /// The competitive book's build-time knobs
 ///
 /// Each field is one build-time setting; *derived* readings
@@ -43,6 +46,8 @@ use super::context::DecisionProfile;
 /// that owns them rather than becoming fields, so the "one knob, one home"
 /// invariant stays explicit.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct CompetitionKnobs {
     // --- competition/cue_raise.rs
     /// Answer partner's cue-raise of their major overcall
@@ -978,6 +983,8 @@ impl Default for CompetitionKnobs {
 /// [`convention_points`][field@crate::bidding::inference::ReadingProfile::convention_points],
 /// where the sampler's readers can also reach it.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct DefenseKnobs {
     // --- defense.rs
     /// Prefer the longest suit when advancing partner's takeout double
@@ -1948,6 +1955,8 @@ impl Default for DefenseKnobs {
 /// the module that owns them rather than becoming fields.  The three settings
 /// also read at classify time live only in `DecisionProfile`.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct NotrumpKnobs {
     // --- notrump.rs
     // --- notrump/size_ask.rs
@@ -2275,6 +2284,8 @@ impl Default for NotrumpKnobs {
 /// read at classify time as well and so lives only in `DecisionProfile`,
 /// deliberately absent here.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct OpeningKnobs {
     // --- openings/one_notrump.rs
     /// Open our strong `1NT` at all — **default on**
@@ -2373,6 +2384,14 @@ pub struct OpeningKnobs {
     /// 10⁴, so a random-deal A/B cannot resolve it.  The knob exists to ablate
     /// it in the enriched probe (`examples/probe-weak-two-major --mode tie`).
     pub weak_two_longest_first: bool,
+    // --- acol/openings.rs
+    /// Play Benjamin twos in [`acol`][crate::acol()] rather than strong twos
+    ///
+    /// **Default off** — the traditional Acol strong `2♦`/`2♥`/`2♠` with the
+    /// Acol `2♣`.  On, `2♣` is any strong one-suiter, `2♦` the game force and
+    /// `2♥`/`2♠` weak.  Only the Acol opening table reads it; `american()`
+    /// always plays its strong `2♣` and weak twos.
+    pub benjamin: bool,
 }
 
 impl Default for OpeningKnobs {
@@ -2387,6 +2406,7 @@ impl Default for OpeningKnobs {
             weak_two_wild: false,
             weak_two_major_priority: true,
             weak_two_longest_first: true,
+            benjamin: false,
         }
     }
 }
@@ -2399,6 +2419,8 @@ impl Default for OpeningKnobs {
 /// control-bid classifier reads the same discipline the response rule authors —
 /// and so lives only in `DecisionProfile`, deliberately absent here.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct ResponseKnobs {
     // --- responses/two_over_one.rs
     /// Author the fit leg of the major 2/1 game force
@@ -2521,6 +2543,17 @@ pub struct ResponseKnobs {
     ///
     /// Opener accepts, asks for keycards, or declines.
     pub limit_raise_acceptance: bool,
+    // --- raises/drury.rs
+    /// Play Reverse Drury when responder is a passed hand
+    ///
+    /// **Default off** (book byte-identical); A/B pending.
+    /// `--ns-drury` in `bba-gen`.
+    ///
+    /// On: opposite a third- or fourth-seat `1M`, responder's `2♣` is the
+    /// limit raise (three-plus trumps, 10–12 support points), so a light
+    /// opener can stop in `2M`; `2♦` shows a full opening.  Off: the passed
+    /// hand's limit raise jumps to `3M`, as an unpassed one's does.
+    pub drury: bool,
 }
 
 impl Default for ResponseKnobs {
@@ -2535,6 +2568,7 @@ impl Default for ResponseKnobs {
             major_choice_of_games: true,
             major_game_tries: true,
             limit_raise_acceptance: true,
+            drury: false,
         }
     }
 }
@@ -2547,6 +2581,8 @@ impl Default for ResponseKnobs {
 /// `opener_major_jump_rebid` and `xyz` — and so live only in `DecisionProfile`,
 /// deliberately absent here.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct RebidKnobs {
     // --- rebids.rs
     /// Rebid `1NT` rather than a natural `2m` on a balanced 12-14
@@ -2684,6 +2720,8 @@ impl Default for RebidKnobs {
 /// all three trade against the floor rather than against another agreement: off
 /// means the node falls through, not that a different rule fires.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct GameForceKnobs {
     // --- game_force/backstop.rs
     /// Re-register the retired wildcard game backstop over uncovered nodes
@@ -2769,6 +2807,8 @@ impl Default for GameForceKnobs {
 /// read inside [`instinct`][crate::bidding::instinct()]'s table builder, so
 /// they are baked into the ladder that comes back.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct InstinctKnobs {
     /// Author the competitive long-suit rebid
     pub competitive_rebid: bool,
@@ -2807,6 +2847,8 @@ impl Default for InstinctKnobs {
 /// rule for dual-read settings is one home — the classify profile — with the
 /// book reading it from there.
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct TheirDisclosures {
     /// Their `2♣` overcall of our `1NT` shows **both majors** (the
     /// Landy / Multi-Landy family)
@@ -2862,6 +2904,8 @@ pub struct TheirDisclosures {
 /// ([`TheirDisclosures`]) ride `decision.their`: both the book and the
 /// reading walk consult them.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct Agreements {
     /// The classify-time settings, pinned into the partnership at `System::bind`
     pub decision: DecisionProfile,
@@ -2900,5 +2944,60 @@ impl Default for Agreements {
     }
 }
 
+/// Agreements as a file: an A/B arm or a user's system, stored instead of
+/// spelled out in flags
+///
+/// Every area and every field is optional on the way in — what the text omits
+/// keeps its [`Default`] — so a file need only list where it departs from the
+/// shipped system.  An unknown field is an error rather than ignored: a
+/// misspelt knob would otherwise measure the default while the file claims
+/// the arm.  Enums are written in snake case (`"woolsey"`) and the 1NT minor
+/// scheme by its alert slug (`"puppet"` / `"european"`) — the same spellings
+/// [`Agreements::set`] takes.
+#[cfg(feature = "serde")]
+impl Agreements {
+    /// Read agreements from JSON
+    ///
+    /// ```
+    /// use pons::bidding::agreements::Agreements;
+    ///
+    /// let a = Agreements::from_json(r#"{ "decision": { "reading": { "xyz": false } } }"#)?;
+    /// assert!(!a.decision.reading.xyz);
+    /// assert_eq!(a.competition, Agreements::default().competition);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the text is not JSON, names a field the tree does not have, or gives a
+    /// value of the wrong type or an unknown variant.
+    pub fn from_json(text: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(text)
+    }
+
+    /// Write every field as pretty-printed JSON
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("agreements are plain data")
+    }
+
+    /// Read agreements from TOML, one `[area]` table per field of [`Agreements`]
+    ///
+    /// # Errors
+    ///
+    /// As [`from_json`][Self::from_json], for TOML.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(text: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(text)
+    }
+
+    /// Write every field as TOML
+    #[cfg(feature = "toml")]
+    #[must_use]
+    pub fn to_toml(&self) -> String {
+        toml::to_string_pretty(self).expect("agreements are plain data")
+    }
+}
+
 #[cfg(test)]
 mod tests;
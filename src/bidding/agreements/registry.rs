@@ -0,0 +1,681 @@
+//! The knob registry: every leaf of [`Agreements`] by name
+//!
+//! Tools used to re-invent the knob list — the web crate kept a hand-synced
+//! `SETTINGS` table, and every example grew its own clap flags for the same
+//! fields.  [`KNOBS`] enumerates the tree once, with each knob's key, area,
+//! [`Kind`], default, variants and doc summary, and [`Agreements::get`] /
+//! [`Agreements::set`] read and write any of them by key, as text.
+//!
+//! A key is the field's dotted path from [`Agreements`]
+//! (`decision.reading.xyz`), which is also where the field nests in a JSON or
+//! TOML agreements file.  Values are written the way a flag takes them:
+//! `true`/`false`, a number, a snake_case variant name (the file spelling
+//! too), a comma-separated tuple (`9,18`), and `none` for an absent optional.
+//!
+//! The table cannot drift from the structs.  Each row's accessors *are* the
+//! field, so a renamed or retyped knob stops compiling, and
+//! `every_field_is_a_knob` scans the struct sources for a field with no row or
+//! a `doc` that no longer matches the field's summary paragraph.
+
+use super::Agreements;
+use crate::bidding::american::{
+    Competitive4333, DoubleShape, DoubleStyle, EUROPEAN, FreeBidStyle, LebensohlStyle,
+    MultiStopperAsk, NegativeDoubleShape, NotrumpDefense, NotrumpRange, NotrumpShape, PUPPET,
+    SizeAskEight, TakeoutSupport, TwoOverOneGate, WeakTwoEval,
+};
+use crate::bidding::constraint::{FifthsCompanion, PointScale};
+use crate::bidding::inference::ReadingScope;
+use crate::bidding::instinct::{LatchStyle, RkcbVariant, Unusual2nt};
+use crate::bidding::rules::Alert;
+use thiserror::Error;
+
+/// How a knob's value is written
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// `true` or `false`
+    Toggle,
+    /// One of the knob's [`variants`][field@Knob::variants]; a variant that
+    /// carries numbers writes them after a colon (`nltc_band:7,9`)
+    Choice,
+    /// A whole number, or a fixed tuple of values written comma-separated
+    Integer,
+}
+
+/// One leaf of the [`Agreements`] tree
+#[derive(Clone, Copy)]
+pub struct Knob {
+    /// The dotted path from [`Agreements`], e.g. `rebid.fourth_suit_forcing`
+    pub key: &'static str,
+    /// The struct the field lives in: the key without its last segment
+    pub area: &'static str,
+    /// How the value is written
+    pub kind: Kind,
+    /// Whether `none` is a value too
+    pub optional: bool,
+    /// The values of a [`Kind::Choice`], in declaration order; empty otherwise
+    pub variants: &'static [&'static str],
+    /// The summary paragraph of the field's doc comment
+    pub doc: &'static str,
+    get: fn(&Agreements) -> String,
+    set: fn(&mut Agreements, &str) -> Option<()>,
+}
+
+impl core::fmt::Debug for Knob {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Knob")
+            .field("key", &self.key)
+            .field("kind", &self.kind)
+            .field("optional", &self.optional)
+            .field("variants", &self.variants)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Knob {
+    /// The value at [`Agreements::default`]
+    #[must_use]
+    pub fn default_value(&self) -> String {
+        (self.get)(&Agreements::default())
+    }
+
+    /// The value `agreements` holds
+    #[must_use]
+    pub fn get(&self, agreements: &Agreements) -> String {
+        (self.get)(agreements)
+    }
+
+    /// Overwrite the value `agreements` holds
+    ///
+    /// # Errors
+    ///
+    /// [`KnobError::InvalidValue`] if `value` is not of this knob's kind;
+    /// `agreements` is then unchanged.
+    pub fn set(&self, agreements: &mut Agreements, value: &str) -> Result<(), KnobError> {
+        (self.set)(agreements, value.trim()).ok_or_else(|| KnobError::InvalidValue {
+            key: self.key,
+            value: value.to_owned(),
+            expected: self.accepts(),
+        })
+    }
+
+    /// What [`set`][Self::set] accepts, in words
+    #[must_use]
+    pub fn accepts(&self) -> String {
+        let kind = match self.kind {
+            Kind::Toggle => "`true` or `false`".to_owned(),
+            Kind::Choice => format!("one of `{}`", self.variants.join("`, `")),
+            Kind::Integer => format!("values shaped like `{}`", self.default_value()),
+        };
+        if self.optional {
+            format!("{kind}, or `none`")
+        } else {
+            kind
+        }
+    }
+}
+
+/// A rejected [`Agreements::get`] or [`Agreements::set`]
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum KnobError {
+    /// No knob has this key
+    #[error("no knob `{0}`")]
+    UnknownKey(String),
+    /// The value does not parse as the knob's [`Kind`]
+    #[error("`{value}` is not a value of `{key}` (expected {expected})")]
+    InvalidValue {
+        /// The knob
+        key: &'static str,
+        /// The rejected text
+        value: String,
+        /// What the knob accepts
+        expected: String,
+    },
+}
+
+/// The knob with this key
+#[must_use]
+pub fn knob(key: &str) -> Option<&'static Knob> {
+    KNOBS.iter().find(|knob| knob.key == key)
+}
+
+impl Agreements {
+    /// One knob's value by key, written as [`Agreements::set`] reads it
+    ///
+    /// # Errors
+    ///
+    /// [`KnobError::UnknownKey`] if no knob has this key.
+    pub fn get(&self, key: &str) -> Result<String, KnobError> {
+        knob(key)
+            .map(|knob| knob.get(self))
+            .ok_or_else(|| KnobError::UnknownKey(key.to_owned()))
+    }
+
+    /// Set one knob by key
+    ///
+    /// ```
+    /// use pons::bidding::agreements::Agreements;
+    ///
+    /// let mut a = Agreements::default();
+    /// a.set("decision.reading.notrump_defense", "woolsey")?;
+    /// a.set("decision.reading.convention_points", "8,19")?;
+    /// assert_eq!(a.get("decision.reading.notrump_defense")?, "woolsey");
+    /// assert!(a.set("decision.reading.notrump_defense", "astro").is_err());
+    /// # Ok::<(), pons::bidding::agreements::KnobError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`KnobError::UnknownKey`] or [`KnobError::InvalidValue`]; either way
+    /// nothing is written.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), KnobError> {
+        knob(key)
+            .ok_or_else(|| KnobError::UnknownKey(key.to_owned()))?
+            .set(self, value)
+    }
+}
+
+/// A knob's value type, written and read as text
+trait Value: Copy {
+    const KIND: Kind;
+    const OPTIONAL: bool = false;
+    const VARIANTS: &'static [&'static str] = &[];
+    fn write(self) -> String;
+    fn read(text: &str) -> Option<Self>;
+}
+
+impl Value for bool {
+    const KIND: Kind = Kind::Toggle;
+
+    fn write(self) -> String {
+        self.to_string()
+    }
+
+    fn read(text: &str) -> Option<Self> {
+        text.parse().ok()
+    }
+}
+
+macro_rules! number {
+    ($($ty:ty),+) => {$(
+        impl Value for $ty {
+            const KIND: Kind = Kind::Integer;
+
+            fn write(self) -> String {
+                self.to_string()
+            }
+
+            fn read(text: &str) -> Option<Self> {
+                text.parse().ok()
+            }
+        }
+    )+};
+}
+
+number!(u8, i16, usize, f64);
+
+impl<T: Value> Value for Option<T> {
+    const KIND: Kind = T::KIND;
+    const OPTIONAL: bool = true;
+    const VARIANTS: &'static [&'static str] = T::VARIANTS;
+
+    fn write(self) -> String {
+        self.map_or_else(|| "none".to_owned(), T::write)
+    }
+
+    fn read(text: &str) -> Option<Self> {
+        if text == "none" {
+            Some(None)
+        } else {
+            T::read(text).map(Some)
+        }
+    }
+}
+
+macro_rules! tuple {
+    ($($ty:ident $index:tt),+) => {
+        impl<$($ty: Value),+> Value for ($($ty,)+) {
+            const KIND: Kind = Kind::Integer;
+
+            fn write(self) -> String {
+                [$(self.$index.write()),+].join(",")
+            }
+
+            fn read(text: &str) -> Option<Self> {
+                let mut parts = text.split(',').map(str::trim);
+                let value = ($($ty::read(parts.next()?)?,)+);
+                parts.next().is_none().then_some(value)
+            }
+        }
+    };
+}
+
+tuple!(A 0, B 1);
+tuple!(A 0, B 1, C 2);
+tuple!(A 0, B 1, C 2, D 3);
+
+/// Each variant's snake_case name — the spelling serde uses for the same enum
+macro_rules! choice {
+    ($($ty:ident { $($variant:ident => $name:literal),+ $(,)? })+) => {$(
+        impl Value for $ty {
+            const KIND: Kind = Kind::Choice;
+            const VARIANTS: &'static [&'static str] = &[$($name),+];
+
+            fn write(self) -> String {
+                match self {
+                    $($ty::$variant => $name,)+
+                }
+                .to_owned()
+            }
+
+            fn read(text: &str) -> Option<Self> {
+                match text {
+                    $($name => Some($ty::$variant),)+
+                    _ => None,
+                }
+            }
+        }
+    )+};
+}
+
+choice! {
+    Competitive4333 { Allow => "allow", Suppress => "suppress", SuppressWithStopper => "suppress_with_stopper" }
+    DoubleShape { Balanced => "balanced", SemiBalanced => "semi_balanced", Any => "any" }
+    DoubleStyle { Takeout => "takeout", Penalty => "penalty", PenaltyLight => "penalty_light", Optional => "optional" }
+    FifthsCompanion { Hcp => "hcp", Bumrap => "bumrap" }
+    FreeBidStyle { Forcing => "forcing", Negative => "negative", Transfer => "transfer" }
+    LatchStyle { Penalty => "penalty", Optional => "optional" }
+    LebensohlStyle { Off => "off", Plain => "plain", Transfer => "transfer" }
+    MultiStopperAsk { Off => "off", FitSearch => "fit_search", OpenerPlaces => "opener_places" }
+    NegativeDoubleShape { BothMajors => "both_majors", Modern => "modern", Cachalot => "cachalot", Sputnik => "sputnik" }
+    NotrumpDefense {
+        Natural => "natural",
+        DirectDont => "direct_dont",
+        Meckwell => "meckwell",
+        Woolsey => "woolsey",
+        DirectLandy => "direct_landy",
+        AlwaysPass => "always_pass",
+        Off => "off",
+    }
+    NotrumpRange { Strong => "strong", Weak => "weak", Medium => "medium" }
+    NotrumpShape { Balanced => "balanced", Wide => "wide", Wide6322 => "wide6322" }
+    PointScale { PointCount => "point_count", Hcp => "hcp", RuleOfN => "rule_of_n", RuleOfNFloored => "rule_of_n_floored" }
+    ReadingScope { None => "none", Alerted => "alerted", All => "all" }
+    RkcbVariant { Plain => "plain", Redwood => "redwood", Kickback => "kickback" }
+    SizeAskEight { Shipped => "shipped", Invite => "invite", Pass => "pass" }
+    TakeoutSupport { Off => "off", Lenient => "lenient", Strict => "strict" }
+    TwoOverOneGate { Points13 => "points13", Points12 => "points12", Hcp13 => "hcp13", Hcp12 => "hcp12", Hcp14 => "hcp14" }
+    Unusual2nt { FourFour => "four_four", FiveFiveAdd => "five_five_add", Direct => "direct" }
+}
+
+/// The weak-two gauge carries its thresholds after the variant:
+/// `cccc_band:lo,hi`, `cccc_floor:x`, `nltc_band:lo,hi`, `nltc_ceil:x`
+impl Value for WeakTwoEval {
+    const KIND: Kind = Kind::Choice;
+    const VARIANTS: &'static [&'static str] =
+        &["cccc_band", "cccc_floor", "nltc_band", "nltc_ceil"];
+
+    fn write(self) -> String {
+        match self {
+            Self::CcccBand(lo, hi) => format!("cccc_band:{lo},{hi}"),
+            Self::CcccFloor(floor) => format!("cccc_floor:{floor}"),
+            Self::NltcBand(lo, hi) => format!("nltc_band:{lo},{hi}"),
+            Self::NltcCeil(ceiling) => format!("nltc_ceil:{ceiling}"),
+        }
+    }
+
+    fn read(text: &str) -> Option<Self> {
+        let (name, thresholds) = text.split_once(':')?;
+        match name.trim() {
+            "cccc_band" => Value::read(thresholds).map(|(lo, hi)| Self::CcccBand(lo, hi)),
+            "cccc_floor" => Value::read(thresholds.trim()).map(Self::CcccFloor),
+            "nltc_band" => Value::read(thresholds).map(|(lo, hi)| Self::NltcBand(lo, hi)),
+            "nltc_ceil" => Value::read(thresholds.trim()).map(Self::NltcCeil),
+            _ => None,
+        }
+    }
+}
+
+/// The one `Alert`-valued knob is the 1NT minor scheme, so only its two
+/// schemes are values
+impl Value for Alert {
+    const KIND: Kind = Kind::Choice;
+    const VARIANTS: &'static [&'static str] = &[PUPPET.0, EUROPEAN.0];
+
+    fn write(self) -> String {
+        self.0.to_owned()
+    }
+
+    fn read(text: &str) -> Option<Self> {
+        [PUPPET, EUROPEAN]
+            .into_iter()
+            .find(|scheme| scheme.0 == text)
+    }
+}
+
+const fn kind<T: Value>(_: fn(&Agreements) -> T) -> Kind {
+    T::KIND
+}
+
+const fn optional<T: Value>(_: fn(&Agreements) -> T) -> bool {
+    T::OPTIONAL
+}
+
+const fn variants<T: Value>(_: fn(&Agreements) -> T) -> &'static [&'static str] {
+    T::VARIANTS
+}
+
+/// One [`KNOBS`] row over the field `area.field`
+macro_rules! knob {
+    ($($area:ident).+ => $field:ident, $doc:literal) => {
+        Knob {
+            key: concat!($(stringify!($area), ".",)+ stringify!($field)),
+            area: knob!(@area $($area).+),
+            kind: kind(|a| a.$($area).+.$field),
+            optional: optional(|a| a.$($area).+.$field),
+            variants: variants(|a| a.$($area).+.$field),
+            doc: $doc,
+            get: |a| Value::write(a.$($area).+.$field),
+            set: |a, text| {
+                a.$($area).+.$field = Value::read(text)?;
+                Some(())
+            },
+        }
+    };
+    (@area $head:ident $(. $rest:ident)*) => {
+        concat!(stringify!($head) $(, ".", stringify!($rest))*)
+    };
+}
+
+/// Every knob of [`Agreements`], area by area in field order
+///
+/// `#[rustfmt::skip]` keeps one row per line, so a knob is one grep away.
+#[rustfmt::skip]
+pub static KNOBS: &[Knob] = &[
+    // ---- decision ----
+    knob!(decision => eval_auction, "Serve the v3 calls-tail evaluator (**default on**, shipped 2026-07-27)"),
+    knob!(decision => eval_shape, "Serve the v4 shape-reading evaluator (**default off**, pending its A/B)"),
+    knob!(decision => blind_inference, "Blank every inference block the nets see — the reading program's *negative control*"),
+    knob!(decision => two_over_one_force, "The floor's two-over-one game force (**on by default**)"),
+    knob!(decision => fuzzy_fifths, "Evaluate Fifths, rather than raw HCP, in the `fifths` gauge"),
+    knob!(decision => fifths_companion, "The honor count averaged with Fifths in the `fifths` gauge"),
+    knob!(decision => stayman_net_force, "Price responder's Stayman-rebid invite/force seams with the evaluator net instead of the point tests (**off by default — measured a loss**, kept for re-measurement)"),
+    knob!(decision => transfer_gf_majors, "The GF-majors transfer structure's master flag"),
+    knob!(decision => transfer_gf_hearts, "Whether the structure is mirrored onto the heart transfer — the **raw** setting, meaningless on its own"),
+
+    // ---- decision.reading ----
+    knob!(decision.reading => nt_invite, "Quantify a natural notrump raise of our own `1NT` opening"),
+    knob!(decision.reading => rubens_transfer, "Record what a one-level Rubens transfer means"),
+    knob!(decision.reading => scope, "How much of the authored book the projection pass decodes"),
+    knob!(decision.reading => fallback_projection, "Decode calls authored by *guarded fallbacks*, not just exact-node classifiers"),
+    knob!(decision.reading => envelope_union, "Store a reading as an *envelope union* rather than one bounding hull"),
+    knob!(decision.reading => blind_opponents, "Blank what the *opponents* have shown (**default off**, measurement only)"),
+    knob!(decision.reading => gauge_membership, "Give the strength gauges membership teeth (**default off**, chop E of docs/dnf-migration.md)"),
+    knob!(decision.reading => sum_closure, "C1: narrow each box's suit lengths to what `Σ len = 13` implies"),
+    knob!(decision.reading => upgrade_closure, "C2: close `hcp` against `points` through the shape upgrade"),
+    knob!(decision.reading => strength_ceilings, "Read a made call's strength **ceilings**, not just its floors"),
+    knob!(decision.reading => control_bid, "Read a high (four-plus level) new suit as a control bid, not to-play"),
+    knob!(decision.reading => cue, "Read a bid of a suit only the *opponents* have naturally shown as a cue"),
+    knob!(decision.reading => length_soundness, "Relax two over-tight natural length floors to sound ones"),
+    knob!(decision.reading => pass, "Read a pass off its own table's Pass gate"),
+    knob!(decision.reading => bid_exclusion, "A **bid** also excludes the sibling gates its bidder declined"),
+    knob!(decision.reading => probed, "Fold the partnership's behaviorally probed boxes into the projection overlay"),
+    knob!(decision.reading => probed_vacuous, "The probed overlay, scoped to where the symbolic reading has none"),
+    knob!(decision.reading => announced, "Fold a second, *agreement* overlay off each rule's `announce_union` — what a call announces, beside what it projects"),
+    knob!(decision.reading => table_alerts, "Decode the *opponents'* alerted calls too"),
+    knob!(decision.reading => rule_accept, "Accept a sampled layout by *replaying the rule* that authored each bid"),
+    knob!(decision.reading => point_scale, "The point scale every strength gauge counts on — [`point_count`][crate::bidding::constraint::point_count] and the slack its projections owe raw HCP"),
+    knob!(decision.reading => support_points, "Gauge the fit-known shortness scale on `support_points`"),
+    knob!(decision.reading => strength_dial, "The deviation panel's antisymmetric strength adjustment (**default 0**, measurement only)"),
+    knob!(decision.reading => rubens_advances, "Advance partner's simple overcall with Rubens transfers and the cue-raise"),
+    knob!(decision.reading => penalty_latch, "The \"once penalty, always penalty\" latch"),
+    knob!(decision.reading => nt_overcall_systems_on, "Run **systems-on** advances after our natural `1NT` overcall"),
+    knob!(decision.reading => nt_overcall_gladiator, "Run **Gladiator** advances after our `1NT` overcall of their *major*"),
+    knob!(decision.reading => nt_splinter, "Author responder's `1NT - 3M` splinter"),
+    knob!(decision.reading => opener_extras_ladder, "Author opener's strength-showing rebid ladder after a one-level response"),
+    knob!(decision.reading => xyz, "Author XYZ — the two-way checkback after three one-level bids"),
+    knob!(decision.reading => notrump_minors, "Which minor scheme our `1NT` plays — the alert its `2♠`/`2NT`/`3♣` calls carry"),
+    knob!(decision.reading => opener_major_jump_rebid, "Author opener's `3M` jump rebid of a six-card major with extras"),
+    knob!(decision.reading => garbage_stayman, "Author garbage (drop-dead) Stayman"),
+    knob!(decision.reading => crawling_stayman, "Author Crawling Stayman — `1NT - 2♣ - 2♦ - 2♥` as pass-or-correct"),
+    knob!(decision.reading => convention_points, "`(min, max)` inclusive `points` band on our conventional 1NT defense"),
+    knob!(decision.reading => woolsey_double_floor, "`points` floor on the Woolsey takeout `X` (4-card major + longer minor)"),
+    knob!(decision.reading => natural_double_floor, "HCP floor on the natural defense's penalty double of their `1NT`"),
+    knob!(decision.reading => longer_major_response, "Name the longer major when responding to our minor"),
+    knob!(decision.reading => landy, "Overlay the Landy `2♣`/`2NT` two-suiters on the defense"),
+    knob!(decision.reading => their_landy_reading, "Read the opponents' disclosed Landy `2♣` over our `1NT` as what it is"),
+    knob!(decision.reading => their_multi_reading, "Read the opponents' disclosed Multi `2♦` over our `1NT`"),
+    knob!(decision.reading => their_multi_advance_reading, "Read the opponents' Multi **advance** as a pass-or-correct ladder"),
+    knob!(decision.reading => their_multi_double_reading, "Read our values double of their Multi at its authored floor"),
+    knob!(decision.reading => completion_alerts, "Alert every forced completion, transfer completion and conventional answer — the uniform completion-alert doctrine"),
+    knob!(decision.reading => notrump_defense, "Which mutually-exclusive defense we play over their `1NT` opening"),
+    knob!(decision.reading => natural_overcall_points, "`(min, max)` inclusive `points` band on the natural two-level suit overcall of their `1NT`"),
+    knob!(decision.reading => two_notrump_wide, "Open the strong `2NT` on the wide-minor shape"),
+    knob!(decision.reading => one_notrump_range, "How strong the `1NT` opening is"),
+    knob!(decision.reading => four_card_majors, "Read a one-of-a-major opening as four-plus, not five-plus"),
+    knob!(decision.reading => strong_club, "Read the openings as Precision's: a strong, artificial `1♣`"),
+    knob!(decision.reading => dutch_twos, "Read the two-level openings as Dutch's: Multi `2♦`, Muiderberg and the unusual `2NT`"),
+    knob!(decision.reading => floor_rkcb, "The floor asks and answers RKCB 1430 (M6.4)"),
+    knob!(decision.reading => rkcb_variant, "Where the keycard ask lives — the relocation stance of the 1430 machinery"),
+
+    // ---- decision.their ----
+    knob!(decision.their => two_clubs_landy, "Their `2♣` overcall of our `1NT` shows **both majors** (the Landy / Multi-Landy family)"),
+    knob!(decision.their => two_diamonds_multi, "Their `2♦` overcall of our `1NT` is a **Multi** — a single six-card major, unknown which (Woolsey Multi-Landy; the 2/1 reference bids `hcp 9-18`, median 13, and its advancer answers `2♥` weak / `2♠` invitational pass-or-correct)"),
+
+    // ---- decision.instinct ----
+    knob!(decision.instinct => inference_aware, "Consult the auction interpretation for known fits"),
+    knob!(decision.instinct => one_nt_runout, "A weak responder runs from our doubled `1NT`"),
+    knob!(decision.instinct => runout_xx_min, "HCP floor at which responder redoubles a doubled `1NT` to play"),
+    knob!(decision.instinct => one_nt_runout_universal, "The runout is universal, not just responder's direct seat"),
+    knob!(decision.instinct => unusual_2nt, "What responder's `2NT` shows in the doubled-`1NT` runout"),
+    knob!(decision.instinct => penalize_escape_stack, "Double the opponents' escape from our doubled `1NT` on a trump stack"),
+    knob!(decision.instinct => penalize_escape_values, "Double their escape from our `1NT` XX on values"),
+    knob!(decision.instinct => uvu_encircle, "Encircle the opponents' escape from our `1NT (2NT) X`"),
+    knob!(decision.instinct => settle_floor, "The \"settle\" view of Pass — partner's takeout double is not 100% forcing"),
+    knob!(decision.instinct => latch_style, "What a *latched* later double means after our penalty double of their `1NT`"),
+    knob!(decision.instinct => penalty_no_pull, "Suppress the doubler's constructive pulls of its own penalty double"),
+    knob!(decision.instinct => advancer_xx_runout, "A weak advancer runs from their redoubled penalty double"),
+    knob!(decision.instinct => nt_responder_game_floor, "HCP floor at which a strong-`1NT` responder forces game off the floor"),
+    knob!(decision.instinct => suppress_nt_gf_over_double, "Suppress that game force at responder's first turn over a double of `1NT`"),
+    knob!(decision.instinct => correct_3nt_to_major, "Opener corrects a choice-of-games `3NT` to `4M` on a known eight-card fit"),
+    knob!(decision.instinct => gambling_3nt_over_double, "Responder's `3NT` over a double of our `1NT` is the gambling long-minor game"),
+    knob!(decision.instinct => gambling_3nt_top_honors, "Top-honor floor (count of A/K/Q) the gambling `3NT`'s long minor must hold"),
+    knob!(decision.instinct => gambling_3nt_require_ace, "The gambling `3NT` requires the ace of its own long minor"),
+    knob!(decision.instinct => preempt_4m_over_double, "Responder's `4M` over a double of our `1NT` is a preemptive long-major game"),
+    knob!(decision.instinct => preempt_4m_floor, "HCP floor for the preemptive `4M`"),
+    knob!(decision.instinct => preempt_4m_top_honors, "Top-honor floor (count of A/K/Q) the preemptive `4M`'s long major must hold"),
+    knob!(decision.instinct => preempt_4m_require_ace, "The preemptive `4M` requires the trump ace"),
+    knob!(decision.instinct => floor_slam_entry, "Combined-points floor at which the floor's RKCB ask (`4NT`) fires on a known five-plus-card fit"),
+    knob!(decision.instinct => fit_sum_game, "Combined-points floor for a major game on a known eight-plus fit, counting the trump length as points"),
+    knob!(decision.instinct => accountant_floor, "The *accountant* floor — price the game/slam boundary gates with the learned trick evaluator instead of point arithmetic"),
+    knob!(decision.instinct => net_collar, "Collar the accountant net instead of letting it replace the point arithmetic"),
+    knob!(decision.instinct => competitive_accountant, "Price the contested game-level decision — the **competitive accountant**"),
+    knob!(decision.instinct => fit_sum_support_read, "Edit 1 — read partner's fit-known strength off the `support_points` gauge"),
+    knob!(decision.instinct => nt_hcp_read, "Edit 2 — value the notrump milestones on raw HCP"),
+    knob!(decision.instinct => forcing_ceiling_read, "Edit 3 — a partner who denied the values has not forced us to game"),
+    knob!(decision.instinct => two_over_one_slam_strength, "A live 2/1 floors partner's shown strength for the slam-entry gate"),
+    knob!(decision.instinct => keycard_minors, "The floor's keycard ask reaches agreed **minors** as well as majors"),
+    knob!(decision.instinct => rein_advance_raise, "Rein in a minimum takeout doubler that over-raises partner's forced advance"),
+
+    // ---- competition ----
+    knob!(competition => cue_raise_answer, "Answer partner's cue-raise of their major overcall"),
+    knob!(competition => cue_minor_raise_answer, "Answer partner's cue-raise of their minor overcall"),
+    knob!(competition => delayed_cue, "Bid (not merely recognize) the delayed cue — 2NT relay, then their suit"),
+    knob!(competition => free_bids, "Author the free bids directly, rather than only as a negative-double outlet"),
+    knob!(competition => free_bid_floor, "Minimum points/HCP for the 1-level free bids"),
+    knob!(competition => free_1nt_floor, "Minimum HCP for the free `1NT`, decoupled from the suit floor"),
+    knob!(competition => free_bid_quality, "Require a quality suit for a free bid"),
+    knob!(competition => free_bid_style, "Whether a free bid is forcing, one-round forcing, or a transfer"),
+    knob!(competition => high_overcall_responses, "Author responder's structure over their jump / 3-level overcalls"),
+    knob!(competition => nt_high_overcall_responses, "Author responder's structure over their three-level overcall of our `1NT`"),
+    knob!(competition => nt_high_overcall_3nt_stopper, "Require a stopper for responder's direct `3NT` over their *three-level* overcall of our `1NT`"),
+    knob!(competition => nt_3c_transfers, "Play transfers over their `(3♣)` overcall of our `1NT`"),
+    knob!(competition => nt_high_overcall_x_major_at_four, "Opener answers the takeout double in the shown major at its **cheapest legal** level, even when that is the four level"),
+    knob!(competition => nt_high_overcall_x_leave_in, "Opener may **leave in** responder's takeout double of their three-level overcall"),
+    knob!(competition => nt_high_overcall_x_leave_in_three, "Extend the leave-in to three cards headed by two of the top three"),
+    knob!(competition => direct_3nt_stopper, "Require a stopper for the direct `3NT` over their overcall"),
+    knob!(competition => natural_floor, "`(hcp_floor, points_floor)` on responder's weak natural 2-level escape"),
+    knob!(competition => lebensohl_style, "Which Lebensohl package the competitive book carries"),
+    knob!(competition => defense_2c_landy_cues, "Add the game-forcing minor cues to the Landy counter (N1b)"),
+    knob!(competition => defense_2c_landy_transfer, "Re-rung the Landy counter's minors around a club transfer (N1c)"),
+    knob!(competition => defense_2c_landy_cue_floor, "Raise the Landy cues' floor from `points(8..)` to `points(10..)` (N1d)"),
+    knob!(competition => defense_2c_landy_low_minors, "Price the Landy counter's minor rungs one point lower (N1h)"),
+    knob!(competition => defense_2c_landy_hcp_rungs, "Grade the Landy counter's minor rungs on **`hcp`**, not `points` (N1i)"),
+    knob!(competition => defense_2c_landy_fit_answers, "Answer a Landy cue in notrump on doubleton support (N1e)"),
+    knob!(competition => defense_2c_landy_competition, "Author the Landy counter's interfered tails (N1f)"),
+    knob!(competition => defense_2c_landy_bba, "Play the BBA-ladder Landy counter (N1j) — the anchor-aligned table"),
+    knob!(competition => defense_2c_landy_weak_2d_cap, "Cap the BBA ladder's weak natural `2♦` at `hcp(..=6)` (N1j's 2♦ arm)"),
+    knob!(competition => negative_double_shape, "Which negative-double school the minor openings play"),
+    knob!(competition => cachalot_contested_x, "Author the Cachalot answers when the auction is contested"),
+    knob!(competition => weak_two_competition, "Author continuations when they contest our weak two"),
+    knob!(competition => strong_two_competition, "Author continuations when they contest our strong two"),
+    knob!(competition => competition_over_diamond_transfer, "Author continuations when they contest our `2NT` diamond transfer"),
+    knob!(competition => competition_over_transfer, "Author continuations when they contest our Jacoby transfer"),
+    knob!(competition => competition_over_minor_transfer, "Author continuations when they contest our `2♠` minor transfer"),
+    knob!(competition => competition_over_stayman, "Author continuations when they contest our Stayman"),
+    knob!(competition => jordan_truscott, "Jordan/Truscott `2NT` over their takeout double"),
+    knob!(competition => redouble_answer, "Author answers to partner's redouble"),
+    knob!(competition => splinter_doubled, "Rebase to systems-on when they double our splinter"),
+    knob!(competition => double_style, "Whether a double of their overcall is takeout, optional, or penalty"),
+    knob!(competition => penalty_double_leave_in, "Opener may leave in responder's penalty double"),
+    knob!(competition => double_override, "`(min_len, max_len, hcp_floor)` override on responder's penalty double"),
+    knob!(competition => two_diamond_double, "`(min_diamond_len, min_diamond_hcp, hcp_floor)` redefining responder's double of a `(2♦)` overcall of our 1NT as a **diamond penalty double**"),
+    knob!(competition => penalty_pass, "`(min_club_len, min_club_hcp, convert_over_major)` on the stolen-Stayman pass"),
+    knob!(competition => trap_pass, "Author the trap pass"),
+    knob!(competition => competitive_4333, "How a flat 4-3-3-3 cue-Staymans when our `1NT` is overcalled"),
+    knob!(competition => multi_stopper_ask, "Responder's `3♠` stopper ask after the Multi corrects to spades"),
+    knob!(competition => multi_weak_escape, "Minimum suit length that may escape their Multi with **no HCP floor**"),
+    knob!(competition => multi_balance, "Opener's balancing double when responder passes their declared Multi"),
+    knob!(competition => major_support_double, "Support doubles/redoubles for the majors"),
+    knob!(competition => uvu_over_majors, "Unusual-vs-unusual over their two-suiter showing both majors"),
+    knob!(competition => uvu_over_minors, "Unusual-vs-unusual over their both-majors Michaels of our **minor** opening (`1♣ (2♣)` / `1♦ (2♦)`)"),
+    knob!(competition => uvu, "Author unusual-vs-unusual at all"),
+    knob!(competition => uvu_x_floor, "HCP floor on the unusual-vs-unusual double"),
+    knob!(competition => uvu_cue_floor, "Points floor on the unusual-vs-unusual cue"),
+    knob!(competition => uvu_natural_floor, "Length floor on the natural escape over their two-suiter"),
+
+    // ---- defense ----
+    knob!(defense => longest_first_advance_enabled, "Prefer the longest suit when advancing partner's takeout double"),
+    knob!(defense => advance_pass_yield_major_enabled, "Let a weak penalty pass yield to a four-card unbid major"),
+    knob!(defense => natural_double_shape, "Shape gate for the natural penalty double of their `1NT`"),
+    knob!(defense => natural_double_weight, "Logit weight of the natural penalty double of their `1NT`"),
+    knob!(defense => takeout_support, "Support gate on the takeout double's 12+ tier"),
+    knob!(defense => suppress_flat_4333_takeout, "Suppress a takeout double on a weak flat 4-3-3-3"),
+    knob!(defense => suppress_5332_takeout, "Suppress a takeout double on a weak 5-3-3-2"),
+    knob!(defense => suppress_4432_vs_major, "Suppress a weak 4-4-3-2 takeout double over a major opening"),
+    knob!(defense => suppress_4432_vs_minor, "Suppress a weak 4-4-3-2 takeout double over a minor opening"),
+    knob!(defense => suppress_5card_major_takeout, "Suppress a takeout double with an unbid five-card major"),
+    knob!(defense => suppress_long_minor_takeout, "Suppress a takeout double with an unbid six-card minor"),
+    knob!(defense => defensive_seam_split, "Split the overcall/double seam by level, and author the doubler's rebids"),
+    knob!(defense => overcall_discipline, "Use disciplined strength bands for natural suit overcalls"),
+    knob!(defense => direct_weak_jump_overcall, "Use a weak single-jump overcall in an unbid major"),
+    knob!(defense => direct_minor_weak_jump_overcall, "Extend the weak single-jump treatment to `(1♣) 2♦`"),
+    knob!(defense => overcall_four_card, "Allow a good four-card natural overcall"),
+    knob!(defense => passed_hand_overcall, "Let a passed hand make the disciplined two-level overcall lighter"),
+    knob!(defense => two_level_minor_overcall_tight, "Demand extra strength for a two-level minor overcall"),
+    knob!(defense => two_level_overcall_quality, "Use suit quality in the two-level natural-overcall gate"),
+    knob!(defense => nt_overcall_no_major, "Bar an unbid five-card major from the natural `1NT` overcall"),
+    knob!(defense => nt_overcall_prefer_one_level_major, "Prefer a one-level major overcall to `1NT`"),
+    knob!(defense => nt_overcall_without_stopper, "Allow the natural `1NT` overcall without a stopper"),
+    knob!(defense => strong_double_hcp, "Optional HCP seam between natural overcalls and the strong double"),
+    knob!(defense => direct_dont_one_suiter_min, "Minimum length for direct DONT's one-suiter"),
+    knob!(defense => direct_dont_four_four, "Allow four-four two-suiters in direct DONT"),
+    knob!(defense => direct_dont_x_floor, "Raw points-floor cell for direct DONT's double"),
+    knob!(defense => weak_two_notrump_advances_enabled, "Author advances of our `2NT` overcall of their weak two"),
+    knob!(defense => advance_minor_jump_enabled, "Author invitational minor jumps after partner's takeout double"),
+    knob!(defense => notrump_balancing_enabled, "Extend the notrump defense to the balancing seat"),
+    knob!(defense => leaping_michaels_enabled, "Author Leaping Michaels over their weak two"),
+    knob!(defense => weak_two_pass_gate, "Author the weak-two pass as the complement of stronger actions"),
+    knob!(defense => weak_two_notrump_shape, "Require the `2NT` overcall to have the wide-notrump shape"),
+    knob!(defense => weak_two_jump_overcall, "Author jump overcalls over their weak two"),
+    knob!(defense => weak_two_overcall_discipline, "Use disciplined bands for suit overcalls of their weak two"),
+    knob!(defense => weak_two_cue, "Author the natural cue-bid over their weak two"),
+    knob!(defense => weak_two_notrump_points, "Inclusive HCP band for the `2NT` overcall of their weak two"),
+    knob!(defense => weak_two_overcall_points, "Points bands for two- and three-level overcalls of their weak two"),
+    knob!(defense => advance_rubens_enabled, "Author Rubens advances of partner's takeout double"),
+    knob!(defense => doubled_landy_escape, "Escape thresholds after their double of Landy `2♣`"),
+    knob!(defense => landy_use_hcp, "Gauge the Landy band in HCP rather than points"),
+    knob!(defense => direct_landy_four_four, "Raw four-four-shape cell for direct Landy's double"),
+    knob!(defense => direct_landy_double_floor, "Points floor for direct Landy's double"),
+    knob!(defense => direct_landy_penalty_pass, "Author the direct-Landy penalty pass"),
+    knob!(defense => unusual_notrump_range, "Optional strength band for the unusual `2NT`"),
+    knob!(defense => two_suiter_hcp_floor, "Optional HCP floor for defensive two-suiters"),
+    knob!(defense => advance_sohl_style, "Which sohl advance structure partner's takeout double uses"),
+    knob!(defense => meckwell_minor_major_44, "Allow four-four in Meckwell's minor-major calls"),
+    knob!(defense => meckwell_x_four_four, "Allow a four-four two-suiter in Meckwell's double"),
+    knob!(defense => meckwell_x_floor, "Raw points floor cell for Meckwell's double"),
+    knob!(defense => advance_2nt_continuation_enabled, "Author the continuation after advancer's invitational `2NT`"),
+    knob!(defense => stayman_defense_enabled, "Defend their Stayman convention"),
+    knob!(defense => stayman_defense_overcall, "Length and strength floor for the natural call over their Stayman"),
+    knob!(defense => transfer_defense_enabled, "Defend their major-suit transfers"),
+    knob!(defense => minor_transfer_defense_enabled, "Defend their minor-suit transfer"),
+    knob!(defense => diamond_transfer_defense_enabled, "Defend their diamond transfer"),
+    knob!(defense => rich_advance_double_enabled, "Author the rich advance of partner's takeout double"),
+    knob!(defense => advance_sit_hcp_gate, "Optional HCP gate on advancer's penalty pass"),
+    knob!(defense => responsive_takeout_enabled, "Author responsive doubles after partner's takeout double"),
+    knob!(defense => responsive_overcall_enabled, "Author responsive doubles after partner's natural overcall"),
+
+    // ---- notrump ----
+    knob!(notrump => size_ask_eight, "How a balanced eight with no four-card major handles the size ask"),
+    knob!(notrump => size_ask_accept_floor, "Opener's HCP floor for accepting the balanced-eight size ask"),
+    knob!(notrump => stayman_both_majors, "Show both four-card majors in response to Stayman"),
+    knob!(notrump => stayman_5card_max, "Show a five-card major when answering Stayman with a maximum"),
+    knob!(notrump => minor_min_to_3nt, "Route minimum game-forcing minor side suits directly to `3NT`"),
+    knob!(notrump => transfer_super_accept, "Author super-accepts of Jacoby transfers"),
+    knob!(notrump => transfer_longer_major, "Prefer the longer major when both majors can transfer"),
+    knob!(notrump => sixcard_invite_floor, "Raw strength floor for inviting with a six-card major"),
+    knob!(notrump => sixcard_accept_floor, "Raw strength floor for accepting a six-card-major invitation"),
+    knob!(notrump => transfer_slam_try, "Author the transfer slam-try structure"),
+    knob!(notrump => invitational_5card_majors, "Author the invitational five-card-major structure"),
+    knob!(notrump => texas_slam_drive, "Route strong Texas hands through the slam-drive continuations"),
+    knob!(notrump => texas_game_floor, "Raw strength floor for the Texas game transfer"),
+    knob!(notrump => nt_splinter_floor, "Responder's HCP floor for the `1NT` splinter"),
+    knob!(notrump => stayman_cue_continuation, "Author the Stayman cue-bid continuation"),
+    knob!(notrump => stayman_minor_slam_try, "Author the Stayman minor-slam try"),
+    knob!(notrump => long_minor_force, "Author the source-of-tricks-eight long-minor force"),
+    knob!(notrump => diamond_splinter, "Author responder's diamond splinters after the `2NT` transfer"),
+
+    // ---- opening ----
+    knob!(opening => open_one_notrump, "Open our strong `1NT` at all — **default on**"),
+    knob!(opening => one_notrump_fifths, "Gauge the `1NT` range in Andrews' fifths rather than plain HCP"),
+    knob!(opening => notrump_shape, "Which balanced shapes the strong `1NT` opening admits"),
+    knob!(opening => one_notrump_offshape, "Admit the off-shape `1NT` (a singleton honour in 4441/5431)"),
+    knob!(opening => weak_two_hcp, "Optional raw-HCP band gauging the weak-two opening"),
+    knob!(opening => weak_two_eval, "Optional honour-location evaluator gauging the weak-two opening"),
+    knob!(opening => weak_two_wild, "Open wild weak twos (five- or six-card suit, `points(3..=12)`)"),
+    knob!(opening => weak_two_major_priority, "Prefer a good five-card major to the Ogust ask over a weak `2♦`"),
+    knob!(opening => weak_two_longest_first, "Answer partner's weak two with the longest suit first"),
+    knob!(opening => benjamin, "Play Benjamin twos in [`acol`][crate::acol()] rather than strong twos"),
+
+    // ---- response ----
+    knob!(response => two_over_one_fit, "Author the fit leg of the major 2/1 game force"),
+    knob!(response => two_over_one_gate, "The gauge for the no-fit leg of the major 2/1 game force"),
+    knob!(response => two_over_one_natural_lengths, "Name natural per-call suit lengths in the major 2/1 game force"),
+    knob!(response => two_over_one_major_discount, "Force game one HCP light on `1♠ - 2♥`"),
+    knob!(response => two_over_one_heart_light, "Force game on a flat twelve with five hearts on `1♠ - 2♥`"),
+    knob!(response => up_the_line, "Complete the natural minor tree up the line"),
+    knob!(response => major_choice_of_games, "Author `1M - 3NT` as a choice of games"),
+    knob!(response => major_game_tries, "Author the long-suit and general game tries after `1M - 2M`"),
+    knob!(response => limit_raise_acceptance, "Author opener's acceptance ladder after `1M - 3M`"),
+    knob!(response => drury, "Play Reverse Drury when responder is a passed hand"),
+
+    // ---- rebid ----
+    knob!(rebid => balanced_1nt_rebid, "Rebid `1NT` rather than a natural `2m` on a balanced 12-14"),
+    knob!(rebid => major_rebid_tails, "Author the full continuations after `1♥ - 1♠`"),
+    knob!(rebid => fourth_suit_forcing, "Author fourth-suit forcing in the `1♥ - 1♠` tail"),
+    knob!(rebid => nt_invite_hcp, "Gauge responder's notrump invitation in raw HCP"),
+    knob!(rebid => meckstroth_adjunct, "Author the complete Meckstroth adjunct"),
+    knob!(rebid => meckstroth_minor_jumps, "Author the adjunct's invitational `3m` jumps"),
+    knob!(rebid => forcing_nt_two_suiter, "Author opener's two-suiter rebids over the forcing `1NT`"),
+    knob!(rebid => xyz_invite_judgment, "Let opener judge the checkback invitation rather than falling to the floor"),
+    knob!(rebid => new_minor_forcing, "Author New Minor Forcing on the four `1m - 1M - 1NT` slots"),
+
+    // ---- game_force ----
+    knob!(game_force => game_backstop, "Re-register the retired wildcard game backstop over uncovered nodes"),
+    knob!(game_force => opener_third, "Author opener's third call after responder sets trump at `1M - 2r - R - 3M`"),
+    knob!(game_force => second_suit_agreement, "Author opener's third call after responder raises opener's second suit"),
+
+    // ---- instinct ----
+    knob!(instinct => competitive_rebid, "Author the competitive long-suit rebid"),
+    knob!(instinct => reopening_notrump, "Author opener's balanced-18-19 notrump actions in a contested auction"),
+    knob!(instinct => doubler_xx_runout, "Author the doubler's runout after their redoubled penalty double"),
+];
@@ -74,3 +74,151 @@ fn no_knob_lives_in_two_homes() {
         );
     }
 }
+
+/// Each field of `struct name` in `src` with the summary paragraph of its doc
+///
+/// The same scanner as [`fields`], keeping the `///` lines above each field up
+/// to the first blank one, joined as the registry stores them.
+fn summaries(src: &str, name: &str) -> Vec<(String, String)> {
+    let body = src
+        .split_once(&format!("struct {name} {{"))
+        .unwrap_or_else(|| panic!("{name} is declared"))
+        .1
+        .split_once("\n}")
+        .expect("the struct body is closed")
+        .0;
+    let mut doc: Vec<&str> = Vec::new();
+    let mut found = Vec::new();
+    for line in body.lines().map(str::trim) {
+        if let Some(text) = line.strip_prefix("///") {
+            doc.push(text.trim());
+        } else if let Some(field) = line.strip_prefix("pub ") {
+            let (ident, _) = field.split_once(':').expect("one field per line");
+            let summary: Vec<&str> = doc.iter().copied().take_while(|l| !l.is_empty()).collect();
+            found.push((ident.to_owned(), summary.join(" ")));
+            doc.clear();
+        }
+    }
+    found
+}
+
+/// The registry lists every leaf field, under its path, with its current doc
+///
+/// A knob added to a struct without a row here would be invisible to every
+/// tool that derives its options from [`KNOBS`][super::KNOBS]; a row whose
+/// `doc` went stale would describe the old behaviour in each of them.
+#[test]
+fn every_field_is_a_knob() {
+    let agreements = include_str!("../agreements.rs");
+    let mut expected = Vec::new();
+    for (src, name, area) in [
+        (include_str!("../context.rs"), "DecisionProfile", "decision"),
+        (
+            include_str!("../inference/knobs.rs"),
+            "ReadingProfile",
+            "decision.reading",
+        ),
+        (agreements, "TheirDisclosures", "decision.their"),
+        (
+            include_str!("../instinct.rs"),
+            "InstinctProfile",
+            "decision.instinct",
+        ),
+        (agreements, "CompetitionKnobs", "competition"),
+        (agreements, "DefenseKnobs", "defense"),
+        (agreements, "NotrumpKnobs", "notrump"),
+        (agreements, "OpeningKnobs", "opening"),
+        (agreements, "ResponseKnobs", "response"),
+        (agreements, "RebidKnobs", "rebid"),
+        (agreements, "GameForceKnobs", "game_force"),
+        (agreements, "InstinctKnobs", "instinct"),
+    ] {
+        for (field, doc) in summaries(src, name) {
+            // The profile's nested areas are listed on their own.
+            if area == "decision" && ["reading", "their", "instinct"].contains(&field.as_str()) {
+                continue;
+            }
+            expected.push((format!("{area}.{field}"), area, doc));
+        }
+    }
+
+    let listed: Vec<_> = super::KNOBS
+        .iter()
+        .map(|knob| (knob.key.to_owned(), knob.area, knob.doc.to_owned()))
+        .collect();
+    for row in &expected {
+        assert!(listed.contains(row), "no registry row for {row:?}");
+    }
+    assert_eq!(listed.len(), expected.len(), "a registry row has no field");
+}
+
+/// Every knob reads back what it wrote, and a toggle or choice actually moves
+#[test]
+fn every_knob_round_trips_through_text() {
+    use super::{Agreements, Kind};
+
+    let shipped = Agreements::default();
+    for knob in super::KNOBS {
+        let mut a = shipped;
+        let default = knob.default_value();
+        knob.set(&mut a, &default).expect("the default is a value");
+        assert_eq!(a, shipped, "`{}` rewrote its default differently", knob.key);
+
+        let others: Vec<String> = match knob.kind {
+            Kind::Toggle => vec![(default == "false").to_string()],
+            Kind::Choice if knob.key == "opening.weak_two_eval" => {
+                vec!["nltc_band:7,9.5".to_owned()]
+            }
+            Kind::Choice => knob.variants.iter().map(|v| (*v).to_owned()).collect(),
+            Kind::Integer => continue,
+        };
+        for value in others {
+            knob.set(&mut a, &value)
+                .expect("a listed value is accepted");
+            assert_eq!(knob.get(&a), value, "`{}` did not read back", knob.key);
+        }
+    }
+}
+
+#[test]
+fn set_and_get_by_key() {
+    use super::{Agreements, KnobError};
+    use crate::bidding::american::{EUROPEAN, WeakTwoEval};
+
+    let mut a = Agreements::default();
+    a.set("decision.reading.notrump_minors", "european")
+        .unwrap();
+    a.set("defense.weak_two_overcall_points", "5, 10,6,11")
+        .unwrap();
+    a.set("opening.weak_two_hcp", "6,10").unwrap();
+    a.set("opening.weak_two_eval", "nltc_ceil:8").unwrap();
+    assert_eq!(a.decision.reading.notrump_minors, EUROPEAN);
+    assert_eq!(a.defense.weak_two_overcall_points, (5, 10, 6, 11));
+    assert_eq!(a.opening.weak_two_hcp, Some((6, 10)));
+    assert_eq!(a.opening.weak_two_eval, Some(WeakTwoEval::NltcCeil(8.0)));
+    a.set("opening.weak_two_hcp", "none").unwrap();
+    assert_eq!(a.opening.weak_two_hcp, None);
+    assert_eq!(
+        a.get("defense.weak_two_overcall_points").unwrap(),
+        "5,10,6,11"
+    );
+
+    let before = a;
+    assert_eq!(
+        a.set("rebid.fourth_suit", "true"),
+        Err(KnobError::UnknownKey("rebid.fourth_suit".to_owned()))
+    );
+    for (key, value) in [
+        ("rebid.fourth_suit_forcing", "yes"),
+        ("decision.reading.notrump_defense", "astro"),
+        ("defense.weak_two_overcall_points", "5,10,6"),
+        ("decision.reading.strength_dial", "256"),
+        ("decision.reading.landy", "none"),
+    ] {
+        assert!(
+            matches!(a.set(key, value), Err(KnobError::InvalidValue { .. })),
+            "`{key} = {value}` was accepted"
+        );
+    }
+    assert_eq!(a, before, "a rejected value writes nothing");
+}
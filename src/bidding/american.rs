@@ -97,7 +97,7 @@ pub use defense::{
 };
 pub use notrump::{EUROPEAN, PUPPET, SizeAskEight, notrump_responses};
 pub(crate) use openings::notrump_shape;
-pub use openings::{NotrumpShape, WeakTwoEval, openings, openings_with};
+pub use openings::{NotrumpRange, NotrumpShape, WeakTwoEval, openings, openings_with};
 
 pub use responses::{TwoOverOneGate, major_responses, minor_responses};
 
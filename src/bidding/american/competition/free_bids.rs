@@ -13,6 +13,8 @@ use super::*;
 /// The meaning of responder's non-jump 2-level new suit over their overcall
 /// (`agreements.competition.free_bid_style`)
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FreeBidStyle {
     /// Forcing one round — the shipped default (the Fix 1 ruling: 1-level
     /// frees unconditionally forcing, 2-level forcing one round), answered by
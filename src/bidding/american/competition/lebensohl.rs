@@ -61,6 +61,8 @@ use super::*;
 /// that the perfect-defense measure credits — unlike the reverted hybrid, whose
 /// only gain was DD-blind right-siding. See `docs/ai-bidder/21gf-ledger.md`.)
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum LebensohlStyle {
     /// Responder falls to the instinct floor (no Lebensohl node)
     Off,
@@ -10,6 +10,8 @@ use super::*;
 /// (`agreements.competition.negative_double_shape`; the major-opening double — 4+ in the other
 /// major, 8+ — is common to all three)
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum NegativeDoubleShape {
     /// Both majors 4-4+ at 8+ regardless of the overcall — the shipped rule
     BothMajors,
@@ -30,6 +30,8 @@ use super::*;
 /// ranking is robust to the responder's-double reading. `Takeout`/`Penalty` stay
 /// selectable for A/B.
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum DoubleStyle {
     /// Classic takeout, `len(over, ..=3) & hcp(8..)` (former default; best plain-DD
     /// double only while penalty doubles were pulled — see [`DoubleStyle`]).
@@ -24,6 +24,8 @@ use super::*;
 /// doubled.  **Default [`Suppress`][Competitive4333::Suppress]**; the other modes
 /// stay for re-measurement (e.g. at vul both).
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Competitive4333 {
     /// Cue-Stayman unchanged on a flat 4333 — the old behaviour / A/B baseline.
     Allow,
@@ -36,6 +38,8 @@ pub enum Competitive4333 {
 
 /// Responder's stopper ask after a disclosed Multi has corrected to spades.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum MultiStopperAsk {
     /// Shipped default: no `3♠` ask.
     Off,
@@ -95,6 +95,8 @@ pub use advance_double::advance_double;
 pub use nt_defense::NotrumpDefense;
 pub use overcall::{DoubleShape, TakeoutSupport, defense_to_suit};
 pub use weak_two_defense::defense_to_weak_two;
+// The Woolsey shapes double as Dutch's Multi and Muiderberg openings.
+pub(crate) use nt_woolsey::{woolsey_muiderberg, woolsey_multi};
 
 /// At least 5-4 (or 4-5) in the two named suits — the Landy two-suiter shape
 pub(crate) fn five_four(a: Suit, b: Suit) -> Cons<impl Constraint + Clone> {
@@ -45,6 +45,8 @@ use super::*;
 /// `agreements.defense.direct_landy_four_four`, since the flat-4-4 flag has no
 /// meaning without the double it configures.
 #[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum NotrumpDefense {
     /// Natural one-suiter defense: penalty `X` + the four natural two-level overcalls
     /// + the owning `Pass` catch-all.  The **default**.
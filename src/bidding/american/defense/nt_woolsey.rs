@@ -20,7 +20,7 @@ pub(super) fn woolsey_enabled(agreements: &Agreements) -> bool {
 /// both minors at most four.  M6.2d simplified the shape and states it with `or`/`and`
 /// so it projects straight off the rule: the strictly-longer-major and no-6-6 guards
 /// are dropped, so a 6-5 or 6-6 major hand now qualifies as Multi.
-pub(super) fn woolsey_multi() -> Cons<impl Constraint + Clone> {
+pub(crate) fn woolsey_multi() -> Cons<impl Constraint + Clone> {
     or([Suit::Hearts, Suit::Spades], 6..) & and([Suit::Clubs, Suit::Diamonds], ..=4)
 }
 
@@ -29,7 +29,7 @@ pub(super) fn woolsey_multi() -> Cons<impl Constraint + Clone> {
 /// otherwise unchanged — `5..=5` keeps it disjoint from the 6+ Multi `2♦`, and the
 /// other-major ≤3 cap keeps it disjoint from the 2♣ both-majors (the Woolsey structure
 /// relies on disjoint shapes so its uniform 1.9 weights never tie).
-pub(super) fn woolsey_muiderberg(major: Suit) -> Cons<impl Constraint + Clone> {
+pub(crate) fn woolsey_muiderberg(major: Suit) -> Cons<impl Constraint + Clone> {
     let other = if major == Suit::Hearts {
         Suit::Spades
     } else {
@@ -22,6 +22,8 @@ const WEAK_JUMP_OVERCALL: Alert = Alert("weak-jump-overcall");
 /// floor is fixed; this only widens the *shape* gate).  Selected by
 /// `agreements.defense.natural_double_shape`.
 #[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum DoubleShape {
     /// 4333/4432/5332 only — the 15+ penalty double restricted to balanced hands
     /// (**the default**).  A flat hand has no escape for the opener to punish, so it
@@ -54,6 +56,8 @@ pub enum DoubleShape {
 /// 12+ with 3-suit support, else 17+).  Selected by
 /// `agreements.defense.takeout_support`.
 #[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum TakeoutSupport {
     /// No support requirement — the 12+ double gates on shortness in their suit
     /// alone (reproduces the historical pre-fix book).
@@ -128,12 +128,36 @@ pub const PUPPET: Alert = Alert("puppet");
 /// [`notrump_minors`][field@crate::bidding::inference::ReadingProfile::notrump_minors].
 pub const EUROPEAN: Alert = Alert("european");
 
+/// Serde for [`notrump_minors`][field@crate::bidding::inference::ReadingProfile::notrump_minors]:
+/// the scheme's alert slug, `"puppet"` or `"european"`
+///
+/// [`Alert`] holds a `&'static str`, so it cannot deserialize by itself; the
+/// scheme is one of two constants, and reading maps the slug back onto one.
+#[cfg(feature = "serde")]
+pub(crate) mod minor_scheme {
+    use super::{Alert, EUROPEAN, PUPPET};
+    use serde::de::Error as _;
+    use serde::{Deserialize as _, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(scheme: &Alert, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(scheme.0)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Alert, D::Error> {
+        let slug = String::deserialize(deserializer)?;
+        [PUPPET, EUROPEAN]
+            .into_iter()
+            .find(|scheme| scheme.0 == slug)
+            .ok_or_else(|| D::Error::unknown_variant(&slug, &[PUPPET.0, EUROPEAN.0]))
+    }
+}
+
 // Always-on artificial 1NT responses (present under either minor scheme).  These
 // are alerts, not gates: the gate drops only the *dormant* minor scheme, so these
 // survive (see `notrump_responses`).
 const STAYMAN: Alert = Alert("stayman");
 const JACOBY: Alert = Alert("jacoby-transfer");
-const BOTH_MAJORS: Alert = Alert("both-majors");
+pub(in crate::bidding) const BOTH_MAJORS: Alert = Alert("both-majors");
 const TEXAS: Alert = Alert("texas");
 const SMOLEN: Alert = Alert("smolen");
 const SPLINTER: Alert = Alert("splinter");
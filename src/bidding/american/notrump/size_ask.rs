@@ -16,6 +16,8 @@ use super::*;
 /// double dummy, which is level-dependently pessimistic on the low contracts in
 /// play (very on 1NT, slightly on 3NT).  See [`NotrumpKnobs::size_ask_eight`][crate::bidding::agreements::NotrumpKnobs::size_ask_eight].
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum SizeAskEight {
     /// Flat 4-3-3-3 passes, shapelier eights size-ask — the crate default.
     #[default]
@@ -5,7 +5,7 @@
 //!
 //! | Module | Agreement | Knob |
 //! | --- | --- | --- |
-//! | [`one_notrump`] | the strong `1NT` opening, shape policy, strength gauge, and off-shape treatment | [`OpeningKnobs::open_one_notrump`], [`OpeningKnobs::one_notrump_fifths`], [`OpeningKnobs::notrump_shape`], [`OpeningKnobs::one_notrump_offshape`] |
+//! | [`one_notrump`] | the `1NT` opening, its range, shape policy, strength gauge, and off-shape treatment | [`one_notrump_range`][field@crate::bidding::inference::ReadingProfile::one_notrump_range], [`OpeningKnobs::open_one_notrump`], [`OpeningKnobs::one_notrump_fifths`], [`OpeningKnobs::notrump_shape`], [`OpeningKnobs::one_notrump_offshape`] |
 //! | [`two_notrump`] | the strong `2NT` opening and wide-minor shape treatment | [`two_notrump_wide`][field@crate::bidding::inference::ReadingProfile::two_notrump_wide] |
 //! | [`weak_two`] | weak-two strength gauges and wild five-card treatment | [`OpeningKnobs::weak_two_hcp`], [`OpeningKnobs::weak_two_eval`], [`OpeningKnobs::weak_two_wild`] |
 
@@ -25,7 +25,7 @@ use one_notrump::with_one_notrump;
 use two_notrump::with_two_notrump;
 use weak_two::with_weak_twos;
 
-pub use one_notrump::NotrumpShape;
+pub use one_notrump::{NotrumpRange, NotrumpShape};
 pub use weak_two::WeakTwoEval;
 
 pub(crate) use one_notrump::notrump_shape;
@@ -79,7 +79,12 @@ pub fn openings_with(shape: NotrumpShape, agreements: &Agreements) -> Rules {
         // union adds nothing else — it's redundant-but-exact by default).
         .rule(Bid::new(2, Strain::Clubs), 300, points(22..) | hcp(22..))
         .alert(STRONG_2C);
-    rules = with_one_notrump(rules, shape, &agreements.opening);
+    rules = with_one_notrump(
+        rules,
+        shape,
+        agreements.decision.reading.one_notrump_range,
+        &agreements.opening,
+    );
     rules = with_two_notrump(rules, agreements);
     // One-level suit openings.  Every band carries an explicit `hcp` floor.
     // On the default PointCount scale the shape [`upgrade`] caps at 2, so
@@ -1,4 +1,4 @@
-//! The strong 1NT opening and its shape and strength agreements
+//! The 1NT opening and its range, shape and strength agreements
 
 use crate::bidding::Rules;
 use crate::bidding::agreements::OpeningKnobs;
@@ -17,6 +17,8 @@ use contract_bridge::{Bid, Hand, Strain, Suit};
 /// are untouched; this is the shape-only knob for the deferred redesign (see the
 /// `nt-shape-abc` and `nt-shape-contested` examples).
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum NotrumpShape {
     /// Balanced only — the classic baseline.
     Balanced,
@@ -28,6 +30,47 @@ pub enum NotrumpShape {
     Wide6322,
 }
 
+/// How strong our `1NT` opening is
+///
+/// **Default [`Strong`][NotrumpRange::Strong]**, the range every american-family
+/// book is built around.  [`Weak`][NotrumpRange::Weak] is the Acol notrump:
+/// the balanced 15–17 opens a suit and rebids notrump instead.
+/// [`Medium`][NotrumpRange::Medium] is Precision's, between its limited
+/// openings and the strong `1♣`.  Only the strength moves — the shape policy
+/// is [`NotrumpShape`]'s.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum NotrumpRange {
+    /// 15–17 HCP.
+    Strong,
+    /// 12–14 HCP.
+    Weak,
+    /// 13–15 HCP.
+    Medium,
+}
+
+impl NotrumpRange {
+    /// The inclusive raw-HCP band the opening promises
+    #[must_use]
+    pub const fn hcp(self) -> (u8, u8) {
+        match self {
+            Self::Strong => (15, 17),
+            Self::Weak => (12, 14),
+            Self::Medium => (13, 15),
+        }
+    }
+
+    /// How many points the opening sits below the strong notrump
+    ///
+    /// Responder's strength bands — invitation, game, slam — move up by this
+    /// much, so the combined total each one aims at stays put.
+    #[must_use]
+    pub const fn lift(self) -> u8 {
+        15 - self.hcp().0
+    }
+}
+
 /// Shapes eligible for a 1NT opening, per the [`NotrumpShape`] policy
 ///
 /// Authored as an exact union of length boxes ([`shapes`]).  The wide
@@ -81,20 +124,29 @@ fn one_notrump_offshape_gate() -> Cons<impl Constraint + Clone> {
         )
 }
 
-pub(super) fn with_one_notrump(rules: Rules, shape: NotrumpShape, knobs: &OpeningKnobs) -> Rules {
+pub(super) fn with_one_notrump(
+    rules: Rules,
+    shape: NotrumpShape,
+    range: NotrumpRange,
+    knobs: &OpeningKnobs,
+) -> Rules {
     let mut rules = rules;
     let one_notrump_offshape = knobs.one_notrump_offshape;
-    // Strong 1NT — gated so a diagnostic can suppress our own 1NT opening
-    // (`open_one_notrump`); the 15-17 balanced hands then open a minor.
+    let (lo, hi) = range.hcp();
+    // The fifths gauge is centre-matched to the HCP band: half a point either
+    // side of it (see the comment on the arm below).
+    let band = f64::from(lo) - 0.5..f64::from(hi) + 0.5;
+    // 1NT — gated so a diagnostic can suppress our own 1NT opening
+    // (`open_one_notrump`); the balanced hands in range then open a suit.
     if knobs.open_one_notrump {
-        // Strength gauged by plain HCP 15-17 by default; `one_notrump_fifths`
+        // Strength gauged by plain HCP by default; `one_notrump_fifths`
         // restores the legacy Andrews' fifths gauge.  Each arm reissues `.rule()`
         // so the differing constraint types unify to `Rules`.
         rules = if (knobs.one_notrump_fifths, one_notrump_offshape) == (true, true) {
             rules.rule(
                 Bid::new(1, Strain::Notrump),
                 200,
-                fifths(14.5..17.5) & (notrump_shape(shape) | one_notrump_offshape_gate()),
+                fifths(band) & (notrump_shape(shape) | one_notrump_offshape_gate()),
             )
         } else if knobs.one_notrump_fifths {
             // 14.5..17.5 (centre 16), not 15..18 (centre 16.5): fifths sums to 40
@@ -103,19 +155,19 @@ pub(super) fn with_one_notrump(rules: Rules, shape: NotrumpShape, knobs: &Openin
             rules.rule(
                 Bid::new(1, Strain::Notrump),
                 200,
-                fifths(14.5..17.5) & notrump_shape(shape),
+                fifths(band) & notrump_shape(shape),
             )
         } else if one_notrump_offshape {
             rules.rule(
                 Bid::new(1, Strain::Notrump),
                 200,
-                hcp(15..=17) & (notrump_shape(shape) | one_notrump_offshape_gate()),
+                hcp(lo..=hi) & (notrump_shape(shape) | one_notrump_offshape_gate()),
             )
         } else {
             rules.rule(
                 Bid::new(1, Strain::Notrump),
                 200,
-                hcp(15..=17) & notrump_shape(shape),
+                hcp(lo..=hi) & notrump_shape(shape),
             )
         };
     }
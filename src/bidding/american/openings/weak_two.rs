@@ -16,6 +16,8 @@ use contract_bridge::{Bid, Strain, Suit};
 /// (evaluator-as-discipline — a strict subset, so the opening's `points
 /// 5..=10` inference reading stays exactly sound).
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum WeakTwoEval {
     /// Kaplan–Rubens CCCC in `lo..hi` replaces `points(5..=10)`.
     CcccBand(f64, f64),
@@ -8,12 +8,13 @@
 //! | [`jacoby`] | opener's descriptive rebid after `1M - 2NT`, and responder's slam try | always on |
 //! | [`game_try`] | long-suit and general game tries after `1M - 2M` | [`ResponseKnobs::major_game_tries`] |
 //! | [`limit_raise`] | opener's acceptance ladder after `1M - 3M` | [`ResponseKnobs::limit_raise_acceptance`] |
+//! | [`drury`] | Reverse Drury `2♣` from a passed hand, and its continuations | [`ResponseKnobs::drury`] |
 //!
-//! Both knobbed agreements ship default-on, measured on a silenced-opponent
+//! The game tries and limit-raise acceptance ship default-on, measured on a silenced-opponent
 //! A/B (200k boards/vul, plain-DD + perfect-defense both winning):
 //! +0.042/+0.065 IMPs/board NV/vul for the game tries, and +0.002/+0.002 for
 //! limit-raise acceptance — the whole of that win being the keycard ask at
-//! +4.4/+5.2 IMPs/divergent.
+//! +4.4/+5.2 IMPs/divergent.  Drury ships off, A/B pending.
 
 use super::{call, slam};
 use crate::bidding::agreements::Agreements;
@@ -23,12 +24,14 @@ use crate::bidding::{Alert, Rules, Trie};
 use contract_bridge::auction::Call;
 use contract_bridge::{Bid, Strain, Suit};
 
+mod drury;
 mod game_try;
 mod jacoby;
 mod limit_raise;
 
 // The packages, re-exported so `american::tests::row_package_invariants` and
 // `register` below name them at one path.
+pub(super) use drury::drury_continuations;
 pub(super) use game_try::major_game_try_continuations;
 pub(super) use jacoby::jacoby_continuations;
 pub(super) use limit_raise::limit_raise_acceptance_continuations;
@@ -42,6 +45,7 @@ pub(super) fn register(book: &mut Trie, agreements: &Agreements) {
             jacoby_continuations(),
             major_game_try_continuations(),
             limit_raise_acceptance_continuations(),
+            drury_continuations(),
         ],
     );
 }
@@ -0,0 +1,137 @@
+//! Reverse Drury: `P - 1M - 2♣` from a passed hand
+//!
+//! Opposite a third- or fourth-seat opening the limit raise bids `2♣` instead
+//! of jumping to `3M`, so opener can stop in `2M` when light.  Gated by
+//! [`ResponseKnobs::drury`], default off (A/B pending).
+//!
+//! | Call | Meaning |
+//! | --- | --- |
+//! | `2♣` | three-plus trumps, 10+ support points |
+//! | opener `2M` | sign-off, a light opening |
+//! | opener `2♦` | a full opening; responder bids game on a maximum |
+//! | opener `4M` | game on 15+ |
+//! | opener `4NT` | RKCB on 19+ |
+
+use super::super::major_responses;
+use super::super::responses::{GAME_FORCE, JACOBY_2NT};
+use super::*;
+use crate::bidding::constraint::support;
+
+/// Drury — a passed hand's limit raise in partner's major
+const DRURY: Alert = Alert("drury");
+/// Opener's full-opening answer to Drury
+const FULL_OPENING: Alert = Alert("drury:full-opening");
+
+/// The leading passes that leave responder a passed hand: opener in third or
+/// fourth seat
+const PASSED_SEATS: [&str; 2] = ["- -", "- - -"];
+
+/// Responder's first call over `1M` as a passed hand
+///
+/// The unpassed table with its game forces gated out — every 2/1 and Jacoby
+/// 2NT — and Drury in their place: a passed hand cannot force to game, so the
+/// club 2/1 is free and Drury is the top raise, with no ceiling.
+fn passed_responses(major: Suit, agreements: &Agreements) -> Rules {
+    Rules::new()
+        .rule(
+            call(2, Strain::Clubs),
+            210,
+            support(3..) & support_points(major, 10..),
+        )
+        .alert(DRURY)
+        .chain(major_responses(major, agreements).gated_out(&[GAME_FORCE, JACOBY_2NT]))
+}
+
+/// Opener's answer to Drury after `P - 1M - 2♣ -`
+///
+/// Forcing, so no [`Pass`][Call::Pass] rule: `2M` is the catch-all sign-off.
+fn opener_after_drury(major: Suit) -> Rules {
+    let trump = Strain::from(major);
+    Rules::new()
+        .rule(
+            Bid::new(4, Strain::Notrump),
+            150,
+            support_points(major, 19..),
+        )
+        .alert(slam::RKCB)
+        .rule(Bid::new(4, trump), 120, support_points(major, 15..))
+        .rule(
+            Bid::new(2, Strain::Diamonds),
+            100,
+            support_points(major, 12..),
+        )
+        .alert(FULL_OPENING)
+        .rule(Bid::new(2, trump), 10, hcp(0..))
+}
+
+/// Responder opposite a full opening, after `P - 1M - 2♣ - 2♦ -`: game on a
+/// maximum, an invitation on 11, else `2M`
+fn responder_after_full_opening(major: Suit) -> Rules {
+    let trump = Strain::from(major);
+    Rules::new()
+        .rule(Bid::new(4, trump), 100, support_points(major, 12..))
+        .rule(Bid::new(3, trump), 50, support_points(major, 11..))
+        .rule(Bid::new(2, trump), 10, hcp(0..))
+}
+
+/// Opener's answer to the invitation `P - 1M - 2♣ - 2♦ - 3M -`: game on 14+
+fn opener_after_invite(major: Suit) -> Rules {
+    Rules::new()
+        .rule(
+            Bid::new(4, Strain::from(major)),
+            100,
+            support_points(major, 14..),
+        )
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// A pass once `2M` has been bid
+fn settle() -> Rules {
+    Rules::new().rule(Call::Pass, 0, hcp(0..))
+}
+
+/// Reverse Drury for the passed-hand responder, including its RKCB subtree
+pub(crate) fn drury_continuations() -> Package {
+    Package {
+        name: "drury-continuations",
+        gate: |a| a.response.drury,
+        entries: |agreements| {
+            let mut entries = Vec::new();
+            for seats in PASSED_SEATS {
+                for major in [Suit::Hearts, Suit::Spades] {
+                    let trump = Strain::from(major);
+                    let opening = format!("{seats} {} -", call(1, trump));
+                    let drury = format!("{opening} {} -", call(2, Strain::Clubs));
+                    let full = format!("{drury} {} -", call(2, Strain::Diamonds));
+                    let sign_off = call(2, trump);
+                    entries.extend(rows_of(
+                        Pattern::node(&opening),
+                        passed_responses(major, agreements),
+                    ));
+                    entries.extend(rows_of(Pattern::node(&drury), opener_after_drury(major)));
+                    entries.extend(rows_of(
+                        Pattern::node(&format!("{drury} {sign_off} -")),
+                        settle(),
+                    ));
+                    entries.extend(rows_of(
+                        Pattern::node(&full),
+                        responder_after_full_opening(major),
+                    ));
+                    entries.extend(rows_of(
+                        Pattern::node(&format!("{full} {sign_off} -")),
+                        settle(),
+                    ));
+                    entries.extend(rows_of(
+                        Pattern::node(&format!("{full} {} -", call(3, trump))),
+                        opener_after_invite(major),
+                    ));
+                    entries.extend(slam::rkcb_rows(&drury, major));
+                }
+            }
+            entries
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests;
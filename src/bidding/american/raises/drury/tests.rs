@@ -0,0 +1,127 @@
+use super::super::tests::{best, hand};
+use super::*;
+use crate::bidding::Bidder;
+
+/// A fresh trie with Drury authored
+fn drury_trie() -> Trie {
+    let mut agreements = Agreements::default();
+    agreements.response.drury = true;
+    let mut trie = Trie::new();
+    super::super::register(&mut trie, &agreements);
+    trie
+}
+
+/// `P - P - 1♥ -`: responder is a passed hand
+const THIRD_SEAT: &[Call] = &[
+    Call::Pass,
+    Call::Pass,
+    Call::Bid(Bid::new(1, Strain::Hearts)),
+    Call::Pass,
+];
+
+#[test]
+fn passed_limit_raise_bids_two_clubs() {
+    let trie = drury_trie();
+    // A63.K75.Q432.J83: three trumps, 10 support points.
+    assert_eq!(
+        best(&trie, THIRD_SEAT, "A63.K75.Q432.J83"),
+        Call::Bid(Bid::new(2, Strain::Clubs)),
+    );
+    // The same hand in fourth seat.
+    let fourth = [&[Call::Pass][..], THIRD_SEAT].concat();
+    assert_eq!(
+        best(&trie, &fourth, "A63.K75.Q432.J83"),
+        Call::Bid(Bid::new(2, Strain::Clubs)),
+    );
+}
+
+#[test]
+fn passed_responder_never_forces_game() {
+    let agreements = Agreements::default();
+    for major in [Suit::Hearts, Suit::Spades] {
+        for rule in passed_responses(major, &agreements).rules() {
+            assert!(
+                rule.alert()
+                    .is_none_or(|a| a != GAME_FORCE && a != JACOBY_2NT),
+                "{:?} over 1{major:?} forces game from a passed hand",
+                rule.call(),
+            );
+        }
+    }
+    let trie = drury_trie();
+    // A3.K74.KQ983.832: an unpassed hand's 2♦ game force.
+    assert_ne!(
+        best(&trie, THIRD_SEAT, "A3.K74.KQ983.832"),
+        Call::Bid(Bid::new(2, Strain::Diamonds)),
+    );
+    // A63.K752.AQ43.83: Jacoby 2NT unpassed, Drury's top raise here.
+    assert_eq!(
+        best(&trie, THIRD_SEAT, "A63.K752.AQ43.83"),
+        Call::Bid(Bid::new(2, Strain::Clubs)),
+    );
+}
+
+#[test]
+fn american_plays_drury_with_the_knob_on() {
+    let mut agreements = Agreements::default();
+    agreements.response.drury = true;
+    let partnership = crate::bidding::american(&agreements).bind();
+    let logits = partnership
+        .classify(
+            hand("A63.K75.Q432.J83"),
+            contract_bridge::auction::RelativeVulnerability::NONE,
+            THIRD_SEAT,
+        )
+        .expect("american bids over a third-seat 1♥");
+    let best = (&logits.0)
+        .into_iter()
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).expect("logits are never NaN"))
+        .map(|(call, _)| call);
+    assert_eq!(best, Some(Call::Bid(Bid::new(2, Strain::Clubs))));
+}
+
+#[test]
+fn opener_signs_off_light_and_shows_a_full_opening() {
+    let trie = drury_trie();
+    let drury = [
+        THIRD_SEAT,
+        &[Call::Bid(Bid::new(2, Strain::Clubs)), Call::Pass],
+    ]
+    .concat();
+    // K63.QJ975.K43.83: a light third-seat opening.
+    assert_eq!(
+        best(&trie, &drury, "K63.QJ975.K43.83"),
+        Call::Bid(Bid::new(2, Strain::Hearts)),
+    );
+    // A63.AK975.Q43.83: a full opening.
+    assert_eq!(
+        best(&trie, &drury, "A63.AK975.Q43.83"),
+        Call::Bid(Bid::new(2, Strain::Diamonds)),
+    );
+
+    let full = [
+        &drury[..],
+        &[Call::Bid(Bid::new(2, Strain::Diamonds)), Call::Pass],
+    ]
+    .concat();
+    // A63.K75.Q432.K83: a maximum Drury bids game.
+    assert_eq!(
+        best(&trie, &full, "A63.K75.Q432.K83"),
+        Call::Bid(Bid::new(4, Strain::Hearts)),
+    );
+}
+
+#[test]
+fn unpassed_responder_keeps_the_club_two_over_one() {
+    let trie = drury_trie();
+    // The Drury package writes only the passed-hand nodes.
+    assert!(
+        trie.classify(
+            hand("A63.K75.Q432.J83"),
+            contract_bridge::auction::RelativeVulnerability::NONE,
+            &THIRD_SEAT[2..],
+        )
+        .is_none(),
+        "a first-seat 1♥ has no Drury node"
+    );
+}
@@ -3,7 +3,7 @@ use crate::bidding::Bidder;
 use contract_bridge::Hand;
 use contract_bridge::auction::RelativeVulnerability;
 
-fn hand(s: &str) -> Hand {
+pub(super) fn hand(s: &str) -> Hand {
     s.parse().expect("valid test hand")
 }
 
@@ -70,3 +70,24 @@ fn limit_raise_acceptance_absent_when_off() {
         "limit-raise acceptance must be absent with the knob off"
     );
 }
+
+#[test]
+fn drury_absent_when_off() {
+    let agreements = crate::bidding::agreements::Agreements::default();
+    let mut trie = Trie::new();
+    register(&mut trie, &agreements);
+    assert!(
+        trie.classify(
+            hand("A63.K75.Q432.J83"),
+            RelativeVulnerability::NONE,
+            &[
+                Call::Pass,
+                Call::Pass,
+                Call::Bid(Bid::new(1, Strain::Hearts)),
+                Call::Pass,
+            ]
+        )
+        .is_none(),
+        "Drury must be absent with the knob off"
+    );
+}
@@ -105,7 +105,10 @@ fn rebid_one_heart_one_spade(agreements: &Agreements) -> Rules {
 ///
 /// Forcing on opener.  A five-card-major rebid is the guaranteed-legal
 /// fallback when nothing more descriptive fits — a basic simplification.
-fn rebid_after_forcing_notrump(major: Suit, agreements: &Agreements) -> Rules {
+pub(in crate::bidding) fn rebid_after_forcing_notrump(
+    major: Suit,
+    agreements: &Agreements,
+) -> Rules {
     let trump = Strain::from(major);
     let mut rules = Rules::new();
     // 2NT: the Meckstroth adjunct's artificial 18+ game force (any shape) when
@@ -36,7 +36,7 @@ pub(super) use inverted_minor::minor_keycard_continuations;
 pub use two_over_one::TwoOverOneGate;
 
 /// Jacoby 2NT — the game-forcing major raise with four-card support
-const JACOBY_2NT: Alert = Alert("jacoby-2nt");
+pub(in crate::bidding) const JACOBY_2NT: Alert = Alert("jacoby-2nt");
 /// Splinter — a double jump in a new suit showing a singleton or void
 const SPLINTER: Alert = Alert("splinter");
 /// Weak jump shift — a single jump showing a weak six-card suit
@@ -44,7 +44,7 @@ const WEAK_JUMP_SHIFT: Alert = Alert("weak-jump-shift");
 /// Inverted minor raise — forcing `2m`, preemptive `3m`
 const INVERTED_MINOR: Alert = Alert("inverted-minor");
 /// 2/1 game force — a new suit at the two level, game forcing
-const GAME_FORCE: Alert = Alert("game-force");
+pub(in crate::bidding) const GAME_FORCE: Alert = Alert("game-force");
 
 /// Responses to our `1♥`/`1♠` opening
 ///
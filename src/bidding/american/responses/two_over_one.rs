@@ -20,6 +20,8 @@ use contract_bridge::{Bid, Strain, Suit};
 /// point; both lost at PD (the thin-game doubling signature — the perfect
 /// defender doubles the balanced-12 3NT), so they stay opt-in.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum TwoOverOneGate {
     /// `points(13..)` on the global scale — the shipped default: under
     /// PointCount, shape (`upgrade`) lets a strong 11-12 force game
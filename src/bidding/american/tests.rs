@@ -84,6 +84,7 @@ fn row_package_invariants() {
             raises::jacoby_continuations(),
             raises::major_game_try_continuations(),
             raises::limit_raise_acceptance_continuations(),
+            raises::drury_continuations(),
             strong_two::package(),
             strong_two::minor_keycard_continuations(),
         ],
@@ -57,7 +57,7 @@ const _: () = {
 ///
 /// Callers must pass `index < CALL_VARIANTS`; the round-trip with
 /// [`encode_call`] is verified at compile time below.
-const fn decode_call(index: usize) -> Call {
+pub(crate) const fn decode_call(index: usize) -> Call {
     match index {
         0 => Call::Pass,
         1 => Call::Double,
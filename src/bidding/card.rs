@@ -44,11 +44,22 @@
 //! [`american_instinct`] generate the same card and a
 //! `--our-floor` swap is not disclosed.  BBA's own defaults approximate the
 //! distilled floor; no row exists for it.
+//!
+//! # Reading a card back
+//!
+//! [`Card`] parses from the same text it renders, and
+//! [`Agreements::from_card`] inverts the computed rows onto their knobs, so a
+//! partner's card file can configure our bots.  The constant rows are what we
+//! author; a card that sets one differently asks for a system pons does not
+//! have, and the inversion says so instead of quietly playing ours.
 
 use super::agreements::Agreements;
-use super::american::{EUROPEAN, LebensohlStyle, NotrumpDefense, NotrumpShape};
-use super::instinct::relocating;
+use super::american::notrump::PUPPET;
+use super::american::{EUROPEAN, LebensohlStyle, NotrumpDefense, NotrumpRange, NotrumpShape};
+use super::instinct::{RkcbVariant, relocating};
 use core::fmt;
+use core::str::FromStr;
+use thiserror::Error;
 
 /// A generated `.bbsa` convention card
 ///
@@ -378,7 +389,8 @@ pub fn american_card(a: &Agreements) -> Card {
 /// the only channel for the system's defining feature.  Declaring 2/1 would tell
 /// BBA the one thing about Dutch that is most false.  WJ's own conventions that
 /// we do not play are overridden back to the American values by construction —
-/// every row not listed below keeps its American value.
+/// every row not listed below keeps its American value.  Beyond the 1♦ row,
+/// the list is the two-level openings: the Multi on, and no weak twos.
 #[must_use]
 pub fn dutch_card(a: &Agreements) -> Card {
     let mut card = american_card(a);
@@ -388,6 +400,94 @@ pub fn dutch_card(a: &Agreements) -> Card {
     // 1♦ can be three cards, so this is the one row the two systems disagree on
     // that BBA's schema can express.
     card.set("1D opening with 5 cards", 1);
+    // The two-level openings (`dutch::openings`): the Multi and Muiderberg
+    // replace the weak twos.  The schema has no Muiderberg row, and its
+    // `Unusual 2NT` row is the overcall, which we already play.
+    card.set("Multi", 1);
+    card.set("Weak natural 2D", 0);
+    card.set("Weak natural 2M", 0);
+    card
+}
+
+/// The card for [`sayc`][crate::sayc()]
+///
+/// [`american_card`] on [`sayc_agreements`][crate::bidding::sayc::sayc_agreements],
+/// which pins the balanced 1NT, under the **SAYC** header, plus the rows the
+/// SAYC packages replace: Minor Suit Stayman and natural `3♣`/`3♦` take the
+/// 1NT minor scheme's place, and `1M - 1NT` is no longer forcing.
+#[must_use]
+pub fn sayc_card(a: &Agreements) -> Card {
+    let mut card = american_card(&crate::bidding::sayc::sayc_agreements(a));
+    card.system = 1;
+    card.set("1N-2S Minor Suit Stayman", 1);
+    for name in [
+        "1N-2S transfer to clubs",
+        "1N-2N transfer to diamonds",
+        "1N-3C transfer to diamonds",
+        "1N-3C Puppet Stayman",
+        "1N-3D majors",
+        "Forcing 1NT",
+    ] {
+        card.set(name, 0);
+    }
+    card.set("1N-3D natural", 1);
+    card
+}
+
+/// The card for [`acol`][crate::acol()]
+///
+/// [`american_card`] on [`acol_agreements`][crate::bidding::acol::acol_agreements],
+/// which pins the weak balanced 1NT and the European minor scheme, under the
+/// **Acol** header.  The rows the Acol packages replace are overridden: four-card
+/// majors (so a `1♦` may hold four), a natural `1M - 1NT`, no Jacoby `2NT`, no
+/// `1NT - 3♦` for the majors and no `1NT - 3M` splinter, and the twos follow
+/// [`benjamin`][crate::bidding::agreements::OpeningKnobs::benjamin].
+#[must_use]
+pub fn acol_card(a: &Agreements) -> Card {
+    let mut card = american_card(&crate::bidding::acol::acol_agreements(a));
+    card.system = 4;
+    card.set("1D opening with 4 cards", 1);
+    for name in [
+        "1N-2S transfer to clubs",
+        "1N-2N transfer to diamonds",
+        "1N-3C transfer to diamonds",
+        "1N-3D majors",
+        "1N-3M splinter",
+        "Forcing 1NT",
+        "Jacoby 2NT",
+        "Weak natural 2D",
+    ] {
+        card.set(name, 0);
+    }
+    card.set("Benjamin 2D", i32::from(a.opening.benjamin));
+    card.set("Weak natural 2M", i32::from(a.opening.benjamin));
+    card
+}
+
+/// The card for [`precision`][crate::precision()]
+///
+/// [`american_card`] on
+/// [`precision_agreements`][crate::bidding::precision::precision_agreements],
+/// which pins the balanced 13–15 1NT and the European minor scheme, under the
+/// **Precision** header.  The schema has no strong-club row; what it can say is
+/// that `2♦` is not a weak two and that the 1NT responses are Acol's: no
+/// transfers to the minors, no `1NT - 3♦` for the majors and no `1NT - 3M`
+/// splinter.  The major responses are american's, so Jacoby `2NT` and the
+/// forcing `1NT` stand.
+#[must_use]
+pub fn precision_card(a: &Agreements) -> Card {
+    let mut card = american_card(&crate::bidding::precision::precision_agreements(a));
+    card.system = 3;
+    for name in [
+        "1N-2S transfer to clubs",
+        "1N-2N transfer to diamonds",
+        "1N-3C transfer to diamonds",
+        "1N-3D majors",
+        "1N-3M splinter",
+        "Weak natural 2D",
+    ] {
+        card.set(name, 0);
+    }
     card
 }
 
@@ -444,6 +544,253 @@ impl Card {
     }
 }
 
+/// Why a `.bbsa` card could not be read, or could not be played
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum CardError {
+    /// A non-empty line that is not `Name = value` with an integer value
+    #[error("line {0}: expected `Name = value`")]
+    Syntax(usize),
+    /// The first line is not the `System type = N` header
+    #[error("missing the `System type = N` header line")]
+    MissingSystem,
+    /// A row name neither EPBot's schema nor pons knows
+    #[error("line {line}: `{name}` is not a row of the .bbsa schema")]
+    UnknownRow {
+        /// 1-based line number
+        line: usize,
+        /// The name as written
+        name: String,
+    },
+    /// The same row set twice
+    #[error("line {line}: `{name}` is set twice")]
+    DuplicateRow {
+        /// 1-based line number of the second occurrence
+        line: usize,
+        /// The repeated row
+        name: &'static str,
+    },
+    /// A `Not defined` or `Opponent type` row carrying a value
+    #[error("line {line}: filler row `{name}` is set to {value}")]
+    Filler {
+        /// 1-based line number
+        line: usize,
+        /// `Not defined` or `Opponent type`
+        name: &'static str,
+        /// The nonzero value
+        value: i32,
+    },
+    /// A base system other than 2/1 (`0`), SAYC (`1`), WJ (`2`), Precision
+    /// (`3`) or Acol (`4`), which pons has no book for
+    #[error("`System type = {0}` is not a system pons plays")]
+    System(i32),
+    /// Rows no setting of the knobs reproduces, with the values the card asked for
+    #[error("pons cannot honour {}", unsupported(.0))]
+    Unsupported(Vec<(&'static str, i32)>),
+}
+
+/// One `Name = value` line, with its 1-based line number
+fn row((line, text): (usize, &str)) -> Result<(usize, &str, i32), CardError> {
+    let (name, value) = text.rsplit_once(" = ").ok_or(CardError::Syntax(line))?;
+    let value = value.trim().parse().map_err(|_| CardError::Syntax(line))?;
+    Ok((line, name.trim(), value))
+}
+
+fn unsupported(rows: &[(&'static str, i32)]) -> String {
+    rows.iter()
+        .map(|(name, value)| format!("`{name} = {value}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl FromStr for Card {
+    type Err = CardError;
+
+    /// Parse the `.bbsa` file format that [`Display`][fmt::Display] writes
+    ///
+    /// Rows keep the file's order; a row the file omits is simply absent, as it
+    /// is from a card BBA exported (which has no pons-only rows).  The filler
+    /// must be `0` — it is inert in EPBot, so a value there means the file is
+    /// not what it looks like.  Blank lines are skipped.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty());
+
+        let system = match lines.next().map(row).transpose()? {
+            Some((_, "System type", value)) => value,
+            _ => return Err(CardError::MissingSystem),
+        };
+        let mut rows: Vec<(&'static str, i32)> = Vec::new();
+        for entry in lines {
+            let (line, name, value) = row(entry)?;
+            if let Some(filler) = ["Not defined", "Opponent type"]
+                .into_iter()
+                .find(|f| *f == name)
+            {
+                if value != 0 {
+                    return Err(CardError::Filler {
+                        line,
+                        name: filler,
+                        value,
+                    });
+                }
+                continue;
+            }
+            let name = SCHEMA
+                .iter()
+                .chain(PONS_SCHEMA)
+                .find(|known| **known == name)
+                .ok_or_else(|| CardError::UnknownRow {
+                    line,
+                    name: name.to_owned(),
+                })?;
+            if rows.iter().any(|(seen, _)| seen == name) {
+                return Err(CardError::DuplicateRow { line, name });
+            }
+            rows.push((name, value));
+        }
+        Ok(Self { system, rows })
+    }
+}
+
+impl Agreements {
+    /// The agreements a card describes — the inverse of [`american_card`]
+    ///
+    /// Starts from the shipped defaults and sets every knob a computed row
+    /// reads, then regenerates the card from the result and compares: any row
+    /// that still differs is one pons cannot honour — a constant row set away
+    /// from what we author, or a combination of computed rows no knob setting
+    /// produces — and all of them are reported together.  A row the card omits
+    /// leaves its knob at the default.  `System type = 2` reads as
+    /// [`dutch_card`], which differs only in its 1♦ row, and `System type = 1`
+    /// as [`sayc_card`], whose balanced 1NT reads back as
+    /// [`NotrumpShape::Balanced`].  `System type = 4` reads as [`acol_card`],
+    /// whose `1NT opening range 12-14` row reads back as
+    /// [`NotrumpRange::Weak`], and `System type = 3` as [`precision_card`],
+    /// whose `13-15` row reads back as [`NotrumpRange::Medium`] and whose header
+    /// alone turns the strong-club reading on.
+    ///
+    /// Where the rows cannot tell two settings apart (plain `Wide` from
+    /// `Balanced` once the off-shape treatment is on), the default is kept.
+    ///
+    /// # Errors
+    ///
+    /// [`CardError::System`] for a base system pons has no book for, and
+    /// [`CardError::Unsupported`] listing the rows pons would play otherwise.
+    ///
+    /// ```
+    /// use pons::bidding::agreements::Agreements;
+    /// use pons::bidding::card::american_card;
+    ///
+    /// let mut agreements = Agreements::default();
+    /// agreements.decision.reading.xyz = false;
+    /// let card = american_card(&agreements).to_string().parse().unwrap();
+    /// assert_eq!(Agreements::from_card(&card), Ok(agreements));
+    /// ```
+    pub fn from_card(card: &Card) -> Result<Self, CardError> {
+        let generate = match card.system {
+            0 => american_card,
+            1 => sayc_card,
+            2 => dutch_card,
+            3 => precision_card,
+            4 => acol_card,
+            system => return Err(CardError::System(system)),
+        };
+        let mut a = Self::default();
+        let shipped = generate(&a);
+        let on = |name: &str| card.row(name).or_else(|| shipped.row(name)) != Some(0);
+
+        a.decision.reading.nt_splinter = on("1N-3M splinter");
+        a.decision.reading.one_notrump_range = if on("1NT opening range 12-14") {
+            NotrumpRange::Weak
+        } else if on("1NT opening range 13-15") {
+            NotrumpRange::Medium
+        } else {
+            NotrumpRange::Strong
+        };
+        // No row names the strong club, Dutch's two-level openings or Acol's
+        // four-card majors; the base system is their only channel.
+        a.decision.reading.strong_club = card.system == 3;
+        a.decision.reading.dutch_twos = card.system == 2;
+        a.decision.reading.four_card_majors = card.system == 4;
+        a.opening.benjamin = on("Benjamin 2D");
+        a.decision.reading.notrump_minors = if on("1N-3C transfer to diamonds") {
+            EUROPEAN
+        } else {
+            PUPPET
+        };
+        a.decision.reading.garbage_stayman = on("Garbage Stayman");
+        a.decision.reading.xyz = on("Two Way New Minor Forcing");
+        a.rebid.new_minor_forcing = on("Checkback");
+        a.response.drury = on("Reverse drury");
+        a.rebid.fourth_suit_forcing = on("Fourth suit");
+        a.notrump.transfer_super_accept = on("Super acceptance after NT");
+        a.competition.jordan_truscott = on("Jordan Truscott 2NT");
+        a.competition.major_support_double = on("Support double redouble");
+        a.defense.leaping_michaels_enabled = on("Leaping Michaels");
+        a.defense.responsive_takeout_enabled = on("Responsive double");
+
+        a.opening.one_notrump_offshape = on("1NT opening shape 4441");
+        a.opening.notrump_shape = if on("1NT opening shape 6 minor") {
+            NotrumpShape::Wide6322
+        } else if !on("1NT opening shape 5422") {
+            NotrumpShape::Balanced
+        } else {
+            NotrumpShape::Wide
+        };
+
+        // `Multi-Landy` is the Woolsey system; `Landy` is the balancing knob
+        // unless the direct-seat Landy system already lights it.
+        let reading = &mut a.decision.reading;
+        if on("Multi-Landy") {
+            reading.notrump_defense = NotrumpDefense::Woolsey;
+        } else if reading.notrump_defense == NotrumpDefense::Woolsey {
+            reading.notrump_defense = NotrumpDefense::Natural;
+        }
+        if on("Landy") {
+            reading.landy |= reading.notrump_defense != NotrumpDefense::DirectLandy;
+        } else {
+            reading.landy = false;
+            if reading.notrump_defense == NotrumpDefense::DirectLandy {
+                reading.notrump_defense = NotrumpDefense::Natural;
+            }
+        }
+
+        a.competition.lebensohl_style = if !on("Lebensohl after 1NT") {
+            LebensohlStyle::Off
+        } else if on("Rubensohl after double") {
+            LebensohlStyle::Transfer
+        } else {
+            LebensohlStyle::Plain
+        };
+
+        // The relocation needs the floor's keycard ask as well as the variant.
+        if on("Kickback 1430") != relocating(&a.decision) {
+            if on("Kickback 1430") {
+                a.decision.reading.rkcb_variant = RkcbVariant::Kickback;
+                a.decision.reading.floor_rkcb = true;
+            } else {
+                a.decision.reading.rkcb_variant = RkcbVariant::Plain;
+            }
+        }
+
+        let honoured = generate(&a);
+        let rejected: Vec<_> = card
+            .rows
+            .iter()
+            .copied()
+            .filter(|(name, value)| honoured.row(name) != Some(*value))
+            .collect();
+        if rejected.is_empty() {
+            Ok(a)
+        } else {
+            Err(CardError::Unsupported(rejected))
+        }
+    }
+}
+
 /// One American row's value, off the live knobs
 ///
 /// Exhaustive over [`SCHEMA`]: a row added there without a value here panics
@@ -473,6 +820,9 @@ fn american_row(name: &str, a: &Agreements) -> i32 {
         ),
         "1NT opening shape 6 minor" => i32::from(a.opening.notrump_shape == NotrumpShape::Wide6322),
         "Checkback" => i32::from(a.rebid.new_minor_forcing),
+        // Only the reverse form (`2M` signs off, `2♦` is the full opening);
+        // plain `Drury` stays 0 below.
+        "Reverse drury" => i32::from(a.response.drury),
         // `NotrumpKnobs::transfer_super_accept` is **off by default**, so we do not jump
         // super-accept a Jacoby transfer with four-card support and a maximum.
         // The hand-written card declared `= 1` here; that was a claim to a
@@ -514,14 +864,17 @@ fn american_row(name: &str, a: &Agreements) -> i32 {
         // `1NT - 3♦` shows both majors (authored; the floor misreads it as natural).
         "1N-3D majors" => 1,
         "1N-3D minors" | "1N-3D natural" | "1N-3D splinter" => 0,
-        // Our 1NT is a natural 15-17.  This pair is one mutually-exclusive radio
+        // Our 1NT is natural.  This pair is one mutually-exclusive radio
         // group and it describes the **responses**, not the opening: `(0, 1)` is
         // "responses are conventional", which is what makes BBA read our `2♥` as
         // a transfer.  See `docs/ai-bidder/bba-card-audit.md`.
         "1NT opening natural" => 0,
         "1NT opening NT style" => 1,
-        "1NT opening range 15-17" => 1,
-        "1NT opening range 12-14" | "1NT opening range 13-15" | "1NT opening range 14-16" => 0,
+        // 15-17, Acol's 12-14 or Precision's 13-15; never 14-16.
+        "1NT opening range 15-17" => i32::from(a.decision.reading.one_notrump_range == NotrumpRange::Strong),
+        "1NT opening range 12-14" => i32::from(a.decision.reading.one_notrump_range == NotrumpRange::Weak),
+        "1NT opening range 13-15" => i32::from(a.decision.reading.one_notrump_range == NotrumpRange::Medium),
+        "1NT opening range 14-16" => 0,
         // The shape ladder's wide arms are 5422/6322 *minors*, never 4441; the
         // off-shape treatment is what admits a 4441 (with a singleton Q/J).
         "1NT opening shape 4441" => i32::from(a.opening.one_notrump_offshape),
@@ -682,7 +1035,6 @@ fn american_row(name: &str, a: &Agreements) -> i32 {
         | "Natural 3N entering style"
         | "Raptor 1NT"
         | "Reverse Bergen"
-        | "Reverse drury"
         | "Rubensohl after 1NT"
         | "Rubensohl after 1m"
         | "Snapdragon Double"
@@ -7,7 +7,8 @@ use crate::bidding::agreements::Agreements;
 /// the card, and this goes red.  `cards/*.bbsa` are snapshots for humans and
 /// for `--disclose FILE`; the generator is the source of truth.  Bless with
 /// `cargo run --example bba-card -- --system american >cards/American.bbsa`
-/// (and `--system dutch >cards/Dutch.bbsa`).
+/// (and `--system dutch >cards/Dutch.bbsa`, `--system sayc >cards/SAYC.bbsa`,
+/// `--system acol >cards/Acol.bbsa`, `--system precision >cards/Precision.bbsa`).
 #[test]
 fn the_checked_in_cards_match_the_generator() {
     assert_eq!(
@@ -20,6 +21,21 @@ fn the_checked_in_cards_match_the_generator() {
         include_str!("../../../cards/Dutch.bbsa"),
         "cards/Dutch.bbsa is stale — re-bless it (see this test's doc)",
     );
+    assert_eq!(
+        sayc_card(&crate::bidding::agreements::Agreements::default()).to_string(),
+        include_str!("../../../cards/SAYC.bbsa"),
+        "cards/SAYC.bbsa is stale — re-bless it (see this test's doc)",
+    );
+    assert_eq!(
+        acol_card(&crate::bidding::agreements::Agreements::default()).to_string(),
+        include_str!("../../../cards/Acol.bbsa"),
+        "cards/Acol.bbsa is stale — re-bless it (see this test's doc)",
+    );
+    assert_eq!(
+        precision_card(&crate::bidding::agreements::Agreements::default()).to_string(),
+        include_str!("../../../cards/Precision.bbsa"),
+        "cards/Precision.bbsa is stale — re-bless it (see this test's doc)",
+    );
 }
 
 /// The card never claims a relocation the floor cannot make
@@ -168,17 +184,147 @@ fn a_knob_moves_its_row() {
 }
 
 #[test]
-fn dutch_differs_from_american_in_the_diamond_opening() {
+fn dutch_differs_from_american_in_the_diamond_and_two_level_openings() {
     let (american, dutch) = (
         american_card(&crate::bidding::agreements::Agreements::default()),
         dutch_card(&crate::bidding::agreements::Agreements::default()),
     );
     assert_eq!(dutch.system, 2, "Dutch declares the WJ base");
+    // The card reads back with the Dutch two-level openings.
+    let read = Agreements::from_card(&dutch).expect("Dutch reads back");
+    assert!(read.decision.reading.dutch_twos);
+    assert_eq!(dutch_card(&read), dutch);
     let moved: Vec<_> = SCHEMA
         .iter()
         .filter(|name| american.row(name) != dutch.row(name))
         .collect();
-    assert_eq!(moved, [&"1D opening with 5 cards"]);
+    assert_eq!(
+        moved,
+        [
+            &"1D opening with 5 cards",
+            &"Multi",
+            &"Weak natural 2D",
+            &"Weak natural 2M",
+        ]
+    );
+}
+
+#[test]
+fn sayc_differs_from_american_in_the_notrump_tier() {
+    let agreements = Agreements::default();
+    let (american, sayc) = (american_card(&agreements), sayc_card(&agreements));
+    assert_eq!(sayc.system, 1, "SAYC declares the SAYC base");
+    let moved: Vec<_> = SCHEMA
+        .iter()
+        .filter(|name| american.row(name) != sayc.row(name))
+        .collect();
+    assert_eq!(
+        moved,
+        [
+            &"1N-2S Minor Suit Stayman",
+            &"1N-2S transfer to clubs",
+            &"1N-2N transfer to diamonds",
+            &"1N-3C Puppet Stayman",
+            &"1N-3D majors",
+            &"1N-3D natural",
+            &"1NT opening shape 5422",
+            &"1NT opening shape 6 minor",
+            &"Forcing 1NT",
+        ]
+    );
+    // The card reads back as SAYC's pinned agreements.
+    let read = Agreements::from_card(&sayc).expect("SAYC reads back");
+    assert_eq!(
+        read.opening.notrump_shape,
+        crate::bidding::american::NotrumpShape::Balanced
+    );
+    assert_eq!(sayc_card(&read), sayc);
+}
+
+#[test]
+fn acol_declares_the_weak_notrump() {
+    let agreements = Agreements::default();
+    let acol = acol_card(&agreements);
+    assert_eq!(acol.system, 4, "Acol declares the Acol base");
+    assert_eq!(acol.row("1NT opening range 12-14"), Some(1));
+    assert_eq!(acol.row("1NT opening range 15-17"), Some(0));
+    assert_eq!(acol.row("Jacoby 2NT"), Some(0));
+    assert_eq!(acol.row("Benjamin 2D"), Some(0));
+    // The card reads back as Acol's pinned agreements, Benjamin included.
+    let read = Agreements::from_card(&acol).expect("Acol reads back");
+    assert_eq!(
+        read.decision.reading.one_notrump_range,
+        crate::bidding::american::NotrumpRange::Weak
+    );
+    assert!(read.decision.reading.four_card_majors);
+    assert_eq!(acol_card(&read), acol);
+    let mut benjamin = agreements;
+    benjamin.opening.benjamin = true;
+    let card = acol_card(&benjamin);
+    assert_eq!(card.row("Benjamin 2D"), Some(1));
+    assert!(
+        Agreements::from_card(&card)
+            .expect("reads back")
+            .opening
+            .benjamin
+    );
+}
+
+/// The Precision card declares system 3 and the 13–15 notrump, and reads back
+/// as Precision's pinned agreements.
+#[test]
+fn precision_declares_the_strong_club() {
+    let precision = precision_card(&Agreements::default());
+    assert_eq!(precision.system, 3, "Precision declares the Precision base");
+    assert_eq!(precision.row("1NT opening range 13-15"), Some(1));
+    assert_eq!(precision.row("1NT opening range 15-17"), Some(0));
+    assert_eq!(precision.row("Weak natural 2D"), Some(0));
+    assert_eq!(precision.row("Jacoby 2NT"), Some(1));
+    let read = Agreements::from_card(&precision).expect("Precision reads back");
+    assert_eq!(
+        read.decision.reading.one_notrump_range,
+        crate::bidding::american::NotrumpRange::Medium
+    );
+    assert!(read.decision.reading.strong_club);
+    assert_eq!(precision_card(&read), precision);
+}
+
+/// Every card's `Reverse drury` row is its own system's pinned knob, and
+/// plain `Drury` is never claimed.
+#[test]
+fn each_card_discloses_its_systems_drury() {
+    use crate::bidding::{acol, dutch, precision, sayc};
+
+    let mut on = Agreements::default();
+    on.response.drury = true;
+    let check = |name: &str, card: Card, pinned: Agreements| {
+        let drury = pinned.response.drury;
+        assert_eq!(
+            card.row("Reverse drury"),
+            Some(i32::from(drury)),
+            "{name} discloses its pinned knob"
+        );
+        assert_eq!(card.row("Drury"), Some(0), "{name} plays no plain Drury");
+        let read = Agreements::from_card(&card).expect("the card reads back");
+        assert_eq!(read.response.drury, drury, "{name} reads back");
+    };
+    check("american", american_card(&on), on);
+    check("dutch", dutch_card(&on), dutch::dutch_agreements(&on));
+    check("sayc", sayc_card(&on), sayc::sayc_agreements(&on));
+    check("acol", acol_card(&on), acol::acol_agreements(&on));
+    check(
+        "precision",
+        precision_card(&on),
+        precision::precision_agreements(&on),
+    );
+    // The systems that replace or limit the major responses pin it off.
+    for pin in [
+        sayc::sayc_agreements,
+        acol::acol_agreements,
+        precision::precision_agreements,
+    ] {
+        assert!(!pin(&on).response.drury);
+    }
 }
 
 /// [`foreign_card`] reproduces the schema half and zeroes the pons-only half
@@ -231,3 +377,117 @@ fn a_reading_knob_leaves_the_card_alone() {
     all.decision.reading.scope = crate::bidding::inference::ReadingScope::All;
     assert_eq!(american_card(&all).to_string(), shipped);
 }
+
+/// The checked-in cards parse back to the defaults they were generated from,
+/// with Dutch's reading of its two-level openings pinned
+#[test]
+fn the_checked_in_cards_read_back_to_the_defaults() {
+    let defaults = Agreements::default();
+    for (text, generate, expected) in [
+        (
+            include_str!("../../../cards/American.bbsa"),
+            american_card as fn(&Agreements) -> Card,
+            defaults,
+        ),
+        (
+            include_str!("../../../cards/Dutch.bbsa"),
+            dutch_card,
+            crate::bidding::dutch::dutch_agreements(&defaults),
+        ),
+    ] {
+        let card: Card = text.parse().expect("a generated card parses");
+        assert_eq!(card, generate(&defaults));
+        assert_eq!(card.to_string(), text);
+        assert_eq!(Agreements::from_card(&card), Ok(expected));
+    }
+}
+
+/// Every knob a computed row reads survives the trip through the card
+#[test]
+fn computed_rows_invert_onto_their_knobs() {
+    use crate::bidding::instinct::RkcbVariant;
+
+    let mut a = Agreements::default();
+    a.decision.reading.nt_splinter = false;
+    a.decision.reading.notrump_minors = EUROPEAN;
+    a.decision.reading.xyz = false;
+    a.decision.reading.notrump_defense = NotrumpDefense::Woolsey;
+    a.decision.reading.rkcb_variant = RkcbVariant::Kickback;
+    a.notrump.transfer_super_accept = true;
+    a.competition.lebensohl_style = LebensohlStyle::Plain;
+    a.opening.notrump_shape = NotrumpShape::Wide;
+
+    let card = american_card(&a)
+        .to_string()
+        .parse()
+        .expect("own output parses");
+    let read = Agreements::from_card(&card).expect("every row is computed");
+    assert_eq!(american_card(&read), american_card(&a));
+    assert_eq!(read.decision.reading.notrump_minors, EUROPEAN);
+    assert_eq!(
+        read.decision.reading.notrump_defense,
+        NotrumpDefense::Woolsey
+    );
+    assert_eq!(read.competition.lebensohl_style, LebensohlStyle::Plain);
+    assert_eq!(read.opening.notrump_shape, NotrumpShape::Wide);
+    assert!(read.notrump.transfer_super_accept);
+}
+
+#[test]
+fn rows_pons_cannot_play_are_reported_together() {
+    let mut card = american_card(&Agreements::default());
+    card.set("Gerber", 1);
+    card.set("1NT opening range 15-17", 0);
+    // A computed row no knob can reach: `New Minor Forcing` without `Checkback`.
+    card.set("Checkback", 0);
+    card.set("Two Way New Minor Forcing", 0);
+    card.set("New Minor Forcing", 1);
+    assert_eq!(
+        Agreements::from_card(&card),
+        Err(CardError::Unsupported(vec![
+            ("1NT opening range 15-17", 0),
+            ("Gerber", 1),
+            ("New Minor Forcing", 1),
+        ]))
+    );
+
+    card = american_card(&Agreements::default());
+    card.system = 5;
+    assert_eq!(Agreements::from_card(&card), Err(CardError::System(5)));
+}
+
+#[test]
+fn malformed_cards_are_rejected() {
+    let parse = |text: &str| text.parse::<Card>().expect_err("invalid card");
+    assert_eq!(parse("Gerber = 1\n"), CardError::MissingSystem);
+    assert_eq!(parse("System type = 0\nGerber 1\n"), CardError::Syntax(2));
+    assert_eq!(
+        parse("System type = 0\nGerbre = 1\n"),
+        CardError::UnknownRow {
+            line: 2,
+            name: "Gerbre".to_owned()
+        }
+    );
+    assert_eq!(
+        parse("System type = 0\nGerber = 1\n\nGerber = 0\n"),
+        CardError::DuplicateRow {
+            line: 4,
+            name: "Gerber"
+        }
+    );
+    assert_eq!(
+        parse("System type = 0\nNot defined = 1\n"),
+        CardError::Filler {
+            line: 2,
+            name: "Not defined",
+            value: 1
+        }
+    );
+
+    // A card BBA exported has no pons-only rows; they stay at our defaults.
+    let card: Card = "System type = 0\nGerber = 0\nNot defined = 0\nOpponent type = 0\n"
+        .parse()
+        .expect("valid card");
+    assert_eq!(card.rows, [("Gerber", 0)]);
+    assert_eq!(Agreements::from_card(&card), Ok(Agreements::default()));
+}
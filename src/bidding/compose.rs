@@ -48,6 +48,14 @@ impl<A: Bidder, B: Bidder> Bidder for Versus<A, B> {
             self.other.classify(hand, vul, auction)
         }
     }
+
+    fn authored_at(&self, vul: RelativeVulnerability, auction: &[Call]) -> bool {
+        if auction.len().is_multiple_of(2) {
+            self.dealer_side.authored_at(vul, auction)
+        } else {
+            self.other.authored_at(vul, auction)
+        }
+    }
 }
 
 /// A layered system falling through to a second one
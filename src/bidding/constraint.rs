@@ -1159,6 +1159,8 @@ pub fn announced(
 /// default — it edged HCP across every vulnerability in the
 /// `fifths-companion` A/B match.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FifthsCompanion {
     /// Milton Work 4-3-2-1 HCP
     Hcp,
@@ -1176,6 +1178,8 @@ pub enum FifthsCompanion {
 /// field on their pinned [`ReadingProfile`].
 #[doc(hidden)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum PointScale {
     /// Legacy raw HCP + [`upgrade`] (the deposed incumbent, kept opt-in)
     PointCount,
@@ -157,6 +157,8 @@ pub struct Context<'a> {
 /// so a built partnership is a pure value that any thread can classify through.  A
 /// A bare context with no attached system uses [`DecisionProfile::default`].
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct DecisionProfile {
     /// The settings that can change a full-auction reading
     ///
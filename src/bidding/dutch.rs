@@ -5,6 +5,8 @@
 //! is non-forcing, 2+♣, 11–23 HCP, and hosts every strong hand that lacks the
 //! strong-2♣ shape (the `1♣ - 1♦` relay sorts them out).  Otherwise it mirrors
 //! `american()`: five-card majors, a 15–17 1NT, 2/1 game-forcing continuations.
+//! The two-level openings are weak and Dutch: the Multi `2♦`, Muiderberg
+//! `2♥`/`2♠` and the unusual `2NT`.
 //!
 //! This is a **champion candidate**, built by copying `american()` and applying
 //! the Dutch diff one measurable phase at a time.  Until it measures stronger,
@@ -13,6 +15,7 @@
 
 mod openings;
 mod responses;
+mod two_level;
 
 use super::System;
 use super::agreements::Agreements;
@@ -28,6 +31,9 @@ use super::rows::compile_into;
 /// The contested books stand on the compact v6 floor retrained on the live
 /// authored reading. Its regime input identifies Dutch rather than 2/1.
 ///
+/// `agreements` is read through [`dutch_agreements`], which pins the reading
+/// of the two-level openings.
+///
 /// ```
 /// use pons::dutch_default;
 /// use pons::bidding::Bidder;
@@ -48,10 +54,11 @@ use super::rows::compile_into;
 /// ```
 #[must_use]
 pub fn dutch(agreements: &Agreements) -> System {
+    let agreements = dutch_agreements(agreements);
     with_floor_v6(
-        book(agreements),
-        CompactConfig::symmetric(&ConventionCard::capture(agreements, true)),
-        agreements,
+        book(&agreements),
+        CompactConfig::symmetric(&ConventionCard::capture(&agreements, true)),
+        &agreements,
     )
 }
 
@@ -75,16 +82,18 @@ pub fn dutch_default() -> System {
 /// told the opposition plays its own system — false at every seat.
 #[must_use]
 pub fn dutch_with_config(agreements: &Agreements, config: Config) -> System {
-    with_floor(book(agreements), config, agreements)
+    let agreements = dutch_agreements(agreements);
+    with_floor(book(&agreements), config, &agreements)
 }
 
 /// [`dutch`] against a declared opponent, on the shipped v6 floor.
 #[must_use]
 pub fn dutch_with_card(agreements: &Agreements, theirs: &ConventionCard) -> System {
+    let agreements = dutch_agreements(agreements);
     with_floor_v6(
-        book(agreements),
-        CompactConfig::new(&ConventionCard::capture(agreements, true), theirs),
-        agreements,
+        book(&agreements),
+        CompactConfig::new(&ConventionCard::capture(&agreements, true), theirs),
+        &agreements,
     )
 }
 
@@ -106,7 +115,8 @@ pub fn dutch_v6(agreements: &Agreements) -> System {
 /// The floor is the *only* difference; both share the same authored books.
 #[must_use]
 pub fn dutch_instinct(agreements: &Agreements) -> System {
-    with_instinct_floor(book(agreements), agreements)
+    let agreements = dutch_agreements(agreements);
+    with_instinct_floor(book(&agreements), &agreements)
 }
 
 /// [`dutch_instinct`] on the shipped agreements — see
@@ -118,12 +128,14 @@ pub fn dutch_instinct_default() -> System {
 
 /// Build the Dutch system as the authored books alone, with no floor
 ///
-/// Takes a full [`american_book`][super::american::american_book] system and compiles two ungated row packages
-/// onto its constructive trie. `dutch-openings` replaces the opening table;
+/// Takes a full [`american_book`][super::american::american_book] system under
+/// [`dutch_agreements`] and compiles three ungated row packages onto its
+/// constructive trie. `dutch-openings` replaces the opening table;
 /// `dutch-wide-one-club` carries the wide-1♣ responses, relay continuations,
-/// and both natural minor-response structures. Across their 17 exact patterns,
-/// eight replace inherited American classifiers and nine add Dutch-only nodes;
-/// every other American continuation is reused verbatim. The rare 18–20 `1NT`
+/// and both natural minor-response structures; `dutch-two-level` answers the
+/// Multi, Muiderberg and unusual-`2NT` openings. Across the first two's 17
+/// exact patterns, eight replace inherited American classifiers and nine add
+/// Dutch-only nodes; every other American continuation is reused verbatim. The rare 18–20 `1NT`
 /// / 21–23 `2♦!` continuations stay American's — projection discloses their
 /// strength; see `docs/dutch-system.md`.
 #[must_use]
@@ -138,16 +150,33 @@ pub fn dutch_book_default() -> System {
     dutch_book(&Agreements::default())
 }
 
+/// `agreements` with the knob Dutch fixes pinned
+///
+/// The reading takes the two-level openings as Dutch's
+/// ([`dutch_twos`][field@crate::bidding::ReadingProfile::dutch_twos]).  Every
+/// other knob passes through.
+#[must_use]
+pub fn dutch_agreements(agreements: &Agreements) -> Agreements {
+    let mut agreements = *agreements;
+    agreements.decision.reading.dutch_twos = true;
+    agreements
+}
+
 /// [`dutch_book`] on an explicit capture — see [`american::book`][super::american::book]
 pub(in crate::bidding) fn book(agreements: &Agreements) -> System {
-    let agreements = *agreements;
+    let agreements = dutch_agreements(agreements);
     let mut system = super::american::book(&agreements);
-    // Compile after American: these packages intentionally replace eight
-    // inherited exact nodes and add nine Dutch-only continuations.
+    // Compile after American: these packages intentionally replace the
+    // inherited opening table, weak-two and 2NT responses, and add the
+    // Dutch-only continuations.
     compile_into(
         &mut system.constructive.0,
         &agreements,
-        &[openings::package(), responses::package()],
+        &[
+            openings::package(),
+            responses::package(),
+            two_level::package(),
+        ],
     );
     system
 }
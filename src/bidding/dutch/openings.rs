@@ -1,27 +1,36 @@
-//! Dutch openings — the wide, non-forcing 1♣ table (Phase 1)
+//! Dutch openings — the wide, non-forcing 1♣ table (Phases 1 and 3)
 //!
-//! Diverges from american in the one-level suit partition and the strong 2♣;
-//! the 1NT/2NT/weak-two/preempt rows are held at american's defaults for now
-//! (Phase 3 replaces the 2-level rows with Multi/Muiderberg/UNT).  See
-//! `docs/dutch-system.md` for the full spec.
+//! Diverges from american in the one-level suit partition, the strong 2♣ and
+//! (Phase 3) the two-level openings: the Multi `2♦`, Muiderberg `2♥`/`2♠` and
+//! the unusual `2NT` replace the weak twos and the strong `2NT`.  The 1NT and
+//! preempt rows are held at american's defaults.  See `docs/dutch-system.md`
+//! for the full spec.
 
+use crate::bidding::american::defense::{woolsey_muiderberg, woolsey_multi};
 use crate::bidding::american::{NotrumpShape, notrump_shape};
-use crate::bidding::constraint::{balanced, fifths, hcp, len, nth_seat, or, points};
+use crate::bidding::constraint::{hcp, len, nth_seat, or, points};
 use crate::bidding::rows::{Package, Pattern, rows_of};
 use crate::bidding::{Alert, Rules};
 use contract_bridge::auction::Call;
 use contract_bridge::{Bid, Strain, Suit};
 
-/// The strong, artificial 2♣ opening — the only artificial Dutch opening
+/// The strong, artificial 2♣ opening
 const STRONG_2C: Alert = Alert("strong-2c");
+/// The Multi `2♦` — a weak six-card major, either one
+const MULTI: Alert = Alert("dutch-2:multi");
+/// Muiderberg `2♥`/`2♠` — a weak five-card major and a four-plus minor
+const MUIDERBERG: Alert = Alert("dutch-2:muiderberg");
+/// The unusual `2NT` — weak, five-five in the minors
+const UNUSUAL: Alert = Alert("dutch-2:unusual");
 
 /// The Dutch opening table, shared by every seat
 ///
 /// Wide, non-forcing 1♣ (2+♣, ≤4♦, 11–23); natural 1♦ (5+♦ or the singleton-club
 /// 4=4=4=1); five-card majors 10–20; the strong artificial 2♣ (21–23 with a
-/// five-card major or six-card minor, or any 24+).  Strong balanced 20–21 still
-/// opens 2NT here — the wide 1♣ only hosts 22–23 balanced until Phase 3 turns
-/// 2NT into UNT.
+/// five-card major or six-card minor, or any 24+).  The two-level openings are
+/// weak, 5–10 and not in fourth seat: the Multi `2♦`, Muiderberg `2♥`/`2♠` and
+/// the unusual `2NT`.  A strong balanced 20–21 has no `2NT` any more and opens
+/// the wide 1♣ (or 1♦, or a major, on its five-card suit).
 ///
 /// Sharp on shape, and sound: a one-level opening needs its raw-HCP band **and**
 /// `points(12..)`, which on the shipped rule-of-N+8 scale is the Rule of 20
@@ -47,12 +56,6 @@ pub(super) fn dutch_openings() -> Rules {
             200,
             hcp(15..=17) & notrump_shape(NotrumpShape::Wide6322),
         )
-        // Strong 2NT — balanced 20–21 (Phase 1 placeholder; Phase 3 → UNT).
-        .rule(
-            Bid::new(2, Strain::Notrump),
-            200,
-            fifths(20.0..22.0) & balanced(),
-        )
         // Five-card majors, 10–20 HCP and 12+ points; 1♠ ranks above 1♥ so 5-5
         // opens the higher.
         .rule(
@@ -100,14 +103,32 @@ pub(super) fn dutch_openings() -> Rules {
                 & len(Suit::Hearts, ..5)
                 & len(Suit::Spades, ..5),
         );
-    // Weak twos (Phase 1: american defaults; Phase 3 → Multi 2♦ / Muiderberg 2M).
-    for suit in [Suit::Diamonds, Suit::Hearts, Suit::Spades] {
-        rules = rules.rule(
-            Bid::new(2, Strain::from(suit)),
+    // The two-level openings (Phase 3), weak and not in fourth seat.  The Multi
+    // keeps the weak two's six cards, so a seven-card major still preempts.
+    let weak = || points(5..=10) & !nth_seat(4);
+    rules = rules
+        .rule(
+            Bid::new(2, Strain::Diamonds),
             100,
-            len(suit, 6..=6) & points(5..=10) & !nth_seat(4),
-        );
+            woolsey_multi() & len(Suit::Hearts, ..=6) & len(Suit::Spades, ..=6) & weak(),
+        )
+        .alert(MULTI);
+    for major in majors {
+        rules = rules
+            .rule(
+                Bid::new(2, Strain::from(major)),
+                100,
+                woolsey_muiderberg(major) & weak(),
+            )
+            .alert(MUIDERBERG);
     }
+    rules = rules
+        .rule(
+            Bid::new(2, Strain::Notrump),
+            100,
+            len(Suit::Clubs, 5..) & len(Suit::Diamonds, 5..) & weak(),
+        )
+        .alert(UNUSUAL);
     // Three-level preempts (seven-card suit, not in fourth seat).
     for suit in [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades] {
         rules = rules.rule(
@@ -21,12 +21,16 @@ fn bid(level: u8, strain: Strain) -> Call {
     Call::Bid(Bid::new(level, strain))
 }
 
-/// Both Dutch override packages preserve the declarative row invariants.
+/// Every Dutch override package preserves the declarative row invariants.
 #[test]
 fn row_package_invariants() {
     crate::bidding::rows::assert_package_invariants(
-        &crate::bidding::agreements::Agreements::default(),
-        &[super::openings::package(), super::responses::package()],
+        &super::dutch_agreements(&crate::bidding::agreements::Agreements::default()),
+        &[
+            super::openings::package(),
+            super::responses::package(),
+            super::two_level::package(),
+        ],
     );
 }
 
@@ -220,3 +224,136 @@ fn responder_continues_after_opener_rebid() {
     let inv_3nt = [c, P, c2, P, bid(3, Strain::Notrump), P];
     assert_eq!(responds(&inv_3nt, "Jxx.Qx.Qx.KQxxx"), P);
 }
+
+/// The weak two-level openings (Phase 3): Multi, Muiderberg and the unusual 2NT.
+#[test]
+fn two_level_openings() {
+    assert_eq!(opens("KQJ932.32.432.32"), bid(2, Strain::Diamonds));
+    assert_eq!(opens("32.KQJ32.Q432.32"), bid(2, Strain::Hearts));
+    assert_eq!(opens("2.3.KQ432.QJ432"), bid(2, Strain::Notrump));
+    // A strong balanced 20 has no 2NT any more: the wide 1♣ takes it.
+    assert_eq!(opens("AQ3.KQ3.KJ32.AJ2"), bid(1, Strain::Clubs));
+}
+
+/// The Multi is pass-or-correct below game, with a 2NT ask on game values.
+#[test]
+fn multi_responses() {
+    const P: Call = Call::Pass;
+    let multi = [bid(2, Strain::Diamonds), P];
+    assert_eq!(responds(&multi, "32.Q32.J432.J432"), bid(2, Strain::Hearts));
+    assert_eq!(responds(&multi, "A32.K32.A432.K32"), bid(4, Strain::Hearts));
+    assert_eq!(
+        responds(&multi, "AK2.K32.A432.K32"),
+        bid(2, Strain::Notrump)
+    );
+    let corrected = [bid(2, Strain::Diamonds), P, bid(2, Strain::Hearts), P];
+    assert_eq!(
+        responds(&corrected, "KQJ932.32.432.32"),
+        bid(2, Strain::Spades)
+    );
+    assert_eq!(responds(&corrected, "32.KQJ932.432.32"), P);
+    let asked = [bid(2, Strain::Diamonds), P, bid(2, Strain::Notrump), P];
+    assert_eq!(responds(&asked, "KQJ932.32.432.32"), bid(3, Strain::Spades));
+    assert_eq!(
+        responds(&asked, "KQJ932.A2.432.32"),
+        bid(3, Strain::Diamonds)
+    );
+}
+
+/// Muiderberg raises the known five-card major; the unusual 2NT picks a minor.
+#[test]
+fn muiderberg_and_unusual_responses() {
+    const P: Call = Call::Pass;
+    let hearts = [bid(2, Strain::Hearts), P];
+    // Four trumps and no invitation: the preemptive raise, which opener passes.
+    assert_eq!(
+        responds(&hearts, "32.Q432.K432.432"),
+        bid(3, Strain::Hearts)
+    );
+    let raised = [bid(2, Strain::Hearts), P, bid(3, Strain::Hearts), P];
+    assert_eq!(responds(&raised, "32.AKJ32.A432.32"), P);
+    assert_eq!(
+        responds(&hearts, "AK2.K32.A432.K32"),
+        bid(4, Strain::Hearts)
+    );
+    // An invitation asks for the minor, fit or no fit …
+    assert_eq!(
+        responds(&hearts, "A32.K32.A432.K32"),
+        bid(2, Strain::Notrump)
+    );
+    assert_eq!(
+        responds(&hearts, "AK32.2.A432.K432"),
+        bid(2, Strain::Notrump)
+    );
+    // … and the fit invites over the answer; opener accepts on a maximum.
+    let named = [
+        bid(2, Strain::Hearts),
+        P,
+        bid(2, Strain::Notrump),
+        P,
+        bid(3, Strain::Diamonds),
+        P,
+    ];
+    assert_eq!(responds(&named, "A32.K32.A432.K32"), bid(3, Strain::Hearts));
+    let invited = [&named[..], &[bid(3, Strain::Hearts), P]].concat();
+    assert_eq!(
+        responds(&invited, "32.AKJ32.A432.32"),
+        bid(4, Strain::Hearts)
+    );
+    assert_eq!(responds(&invited, "32.QJ932.K432.32"), P);
+    let unusual = [bid(2, Strain::Notrump), P];
+    assert_eq!(
+        responds(&unusual, "K432.A32.32.Q432"),
+        bid(3, Strain::Clubs)
+    );
+}
+
+/// Opener over the unusual 2NT passes or corrects — never the strong-2NT
+/// Stayman answers or transfer completions.
+#[test]
+fn unusual_opener_rebids() {
+    const P: Call = Call::Pass;
+    let over = |response: Call| [bid(2, Strain::Notrump), P, response, P];
+    let longer_diamonds = "2.3.KQ5432.QJ432";
+    assert_eq!(
+        responds(&over(bid(3, Strain::Clubs)), longer_diamonds),
+        bid(3, Strain::Diamonds)
+    );
+    let opener = "2.3.KQ432.QJ5432";
+    assert_eq!(responds(&over(bid(3, Strain::Clubs)), opener), P);
+    assert_eq!(
+        responds(&over(bid(3, Strain::Diamonds)), opener),
+        bid(4, Strain::Clubs)
+    );
+    assert_eq!(responds(&over(bid(3, Strain::Hearts)), opener), P);
+    assert_eq!(responds(&over(bid(3, Strain::Spades)), opener), P);
+    // Responder lets the correction stand.
+    let corrected = [
+        &over(bid(3, Strain::Clubs))[..],
+        &[bid(3, Strain::Diamonds), P],
+    ]
+    .concat();
+    assert_eq!(responds(&corrected, "K432.A32.32.Q432"), P);
+}
+
+/// The reading decodes the two-level openings as Dutch's, not as weak twos.
+#[test]
+fn the_reading_knows_the_two_level_openings() {
+    use crate::bidding::context::Context;
+    use crate::bidding::inference::{Inferences, Range};
+    use contract_bridge::Suit;
+
+    let agreements = super::dutch_agreements(&crate::bidding::agreements::Agreements::default());
+    let read = |auction: &[Call]| {
+        let context =
+            Context::new(RelativeVulnerability::NONE, auction).with_profile(agreements.decision);
+        *Inferences::read(&context).rho()
+    };
+    let multi = read(&[bid(2, Strain::Diamonds)]);
+    assert!(multi.length(Suit::Diamonds).max <= 4);
+    let muiderberg = read(&[bid(2, Strain::Spades)]);
+    assert_eq!(muiderberg.length(Suit::Spades), Range::new(5, 5));
+    let unusual = read(&[bid(2, Strain::Notrump)]);
+    assert!(unusual.length(Suit::Clubs).min >= 5);
+    assert!(unusual.strength.points.max <= 10);
+}
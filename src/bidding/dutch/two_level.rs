@@ -0,0 +1,280 @@
+//! Dutch responses to the weak two-level openings (Phase 3)
+//!
+//! The openings are in [`openings`][super::openings]; every one is weak, 5–10,
+//! so the response ladders share a 16+ game force.
+//!
+//! * **Multi `2♦`** ([`multi_responses`]) — responder does not know the major,
+//!   so every call below game is **pass-or-correct**: `2♥` (weak) and `2♠`
+//!   (constructive, 13–15) let opener pass its major or correct, `4♥` plays
+//!   game in either major opposite three-three support, and `2NT` is the
+//!   game-forcing ask.  Opener answers the ask by strength: `3♣`/`3♦` a
+//!   maximum (8–10) with hearts/spades, `3♥`/`3♠` a minimum.
+//! * **Muiderberg `2♥`/`2♠`** ([`muiderberg_responses`]) — the five-card
+//!   major is known, so responder raises: game on 16+ with three, `3M`
+//!   preemptively with four and less than an invitation, which opener
+//!   passes.  Every other invitational-or-better hand asks for the minor
+//!   with `2NT`; over the answer a three-card fit invites with `3M`.
+//! * **Unusual `2NT`** ([`unusual_responses`]) — responder picks a minor at
+//!   the three level, bids its game with four-card support on 13+, or plays
+//!   `3NT` with both majors stopped.  A six-card major is natural.  Opener
+//!   passes, or corrects a minor to the longer one.
+
+use crate::bidding::constraint::{at_least_as_long, hcp, len, longer_suit, points, stopper_in};
+use crate::bidding::rows::{Entry, Package, Pattern, rows_of};
+use crate::bidding::{Alert, Rules};
+use contract_bridge::auction::Call;
+use contract_bridge::{Bid, Strain, Suit};
+
+/// Responder's 2NT ask over the Multi
+const MULTI_ASK: Alert = Alert("dutch-2:multi-ask");
+/// A pass-or-correct response to the Multi
+const PASS_OR_CORRECT: Alert = Alert("dutch-2:pass-or-correct");
+/// Opener's maximum answer to the Multi ask, naming the major a step below
+const MULTI_MAXIMUM: Alert = Alert("dutch-2:multi-maximum");
+/// Responder's 2NT ask for Muiderberg's minor
+const MINOR_ASK: Alert = Alert("dutch-2:minor-ask");
+
+/// Points for game opposite a weak two-level opening
+const GAME: u8 = 16;
+/// Points for an invitation opposite a weak two-level opening
+const INVITE: u8 = 13;
+
+/// Responder's first call over the Multi `2♦`
+fn multi_responses() -> Rules {
+    Rules::new()
+        .rule(Bid::new(2, Strain::Notrump), 120, points(GAME..))
+        .alert(MULTI_ASK)
+        .rule(
+            Bid::new(4, Strain::Hearts),
+            110,
+            len(Suit::Hearts, 3..) & len(Suit::Spades, 3..) & points(INVITE..GAME),
+        )
+        .alert(PASS_OR_CORRECT)
+        .rule(Bid::new(2, Strain::Spades), 100, points(INVITE..GAME))
+        .alert(PASS_OR_CORRECT)
+        .rule(Bid::new(2, Strain::Hearts), 90, points(..INVITE))
+        .alert(PASS_OR_CORRECT)
+}
+
+/// Opener over `2♦ - 2♥`: pass with hearts, correct to `2♠`
+fn multi_over_two_hearts() -> Rules {
+    Rules::new()
+        .rule(Bid::new(2, Strain::Spades), 100, len(Suit::Spades, 6..))
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// Opener over `2♦ - 2♠`: pass with spades, bid `3♥` with hearts
+fn multi_over_two_spades() -> Rules {
+    Rules::new()
+        .rule(Bid::new(3, Strain::Hearts), 100, len(Suit::Hearts, 6..))
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// Opener over `2♦ - 4♥`: pass with hearts, correct to `4♠`
+fn multi_over_four_hearts() -> Rules {
+    Rules::new()
+        .rule(Bid::new(4, Strain::Spades), 100, len(Suit::Spades, 6..))
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// Opener answering the Multi ask `2♦ - 2NT`: the major and the strength
+fn multi_ask_answers() -> Rules {
+    let maximum = || points(8..);
+    Rules::new()
+        .rule(
+            Bid::new(3, Strain::Clubs),
+            110,
+            len(Suit::Hearts, 6..) & maximum(),
+        )
+        .alert(MULTI_MAXIMUM)
+        .rule(
+            Bid::new(3, Strain::Diamonds),
+            110,
+            len(Suit::Spades, 6..) & maximum(),
+        )
+        .alert(MULTI_MAXIMUM)
+        .rule(Bid::new(3, Strain::Hearts), 100, len(Suit::Hearts, 6..))
+        .rule(Bid::new(3, Strain::Spades), 100, len(Suit::Spades, 6..))
+}
+
+/// Responder placing the contract once opener has named `major`: game in it
+/// with two, else `3NT`
+fn multi_placement(major: Suit) -> Rules {
+    Rules::new()
+        .rule(Bid::new(4, Strain::from(major)), 100, len(major, 2..))
+        .rule(Bid::new(3, Strain::Notrump), 10, hcp(0..))
+}
+
+/// Responder's first call over Muiderberg `2M`
+fn muiderberg_responses(major: Suit) -> Rules {
+    let strain = Strain::from(major);
+    Rules::new()
+        .rule(Bid::new(4, strain), 120, len(major, 3..) & points(GAME..))
+        .rule(Bid::new(3, strain), 110, len(major, 4..) & points(..INVITE))
+        .rule(Bid::new(2, Strain::Notrump), 100, points(INVITE..))
+        .alert(MINOR_ASK)
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// A pass: opener over the preemptive `2M - 3M`, and either hand once the
+/// unusual `2NT` has found its strain
+fn settle() -> Rules {
+    Rules::new().rule(Call::Pass, 0, hcp(0..))
+}
+
+/// Opener over the invitation `2M - 2NT - 3m - 3M`: game on a maximum
+fn muiderberg_over_invite(major: Suit) -> Rules {
+    Rules::new()
+        .rule(Bid::new(4, Strain::from(major)), 100, points(8..))
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// Opener naming the minor over `2M - 2NT`: `3♦` with diamonds (longer or
+/// equal), else `3♣`
+fn muiderberg_minor() -> Rules {
+    Rules::new()
+        .rule(
+            Bid::new(3, Strain::Diamonds),
+            100,
+            at_least_as_long(Suit::Diamonds, Suit::Clubs),
+        )
+        .rule(Bid::new(3, Strain::Clubs), 90, hcp(0..))
+}
+
+/// Responder after the minor is named: `3NT` on game values, `3M` to invite
+/// with three-card support, else pass
+fn muiderberg_placement(major: Suit) -> Rules {
+    Rules::new()
+        .rule(Bid::new(3, Strain::Notrump), 100, points(GAME..))
+        .rule(Bid::new(3, Strain::from(major)), 90, len(major, 3..))
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// Responder's first call over the unusual `2NT`
+fn unusual_responses() -> Rules {
+    let mut rules = Rules::new()
+        .rule(
+            Bid::new(3, Strain::Notrump),
+            115,
+            stopper_in(Suit::Hearts) & stopper_in(Suit::Spades) & points(GAME..),
+        )
+        .rule(
+            Bid::new(5, Strain::Diamonds),
+            110,
+            len(Suit::Diamonds, 4..) & points(INVITE..),
+        )
+        .rule(
+            Bid::new(5, Strain::Clubs),
+            105,
+            len(Suit::Clubs, 4..) & points(INVITE..),
+        )
+        .rule(
+            Bid::new(3, Strain::Diamonds),
+            60,
+            at_least_as_long(Suit::Diamonds, Suit::Clubs),
+        )
+        .rule(Bid::new(3, Strain::Clubs), 50, hcp(0..));
+    for major in [Suit::Hearts, Suit::Spades] {
+        let strain = Strain::from(major);
+        rules = rules
+            .rule(Bid::new(4, strain), 100, len(major, 6..) & points(INVITE..))
+            .rule(Bid::new(3, strain), 80, len(major, 6..));
+    }
+    rules
+}
+
+/// Opener over `2NT - 3♣`: correct to `3♦` with longer diamonds
+fn unusual_over_clubs() -> Rules {
+    Rules::new()
+        .rule(
+            Bid::new(3, Strain::Diamonds),
+            100,
+            longer_suit(Suit::Diamonds, Suit::Clubs),
+        )
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// Opener over `2NT - 3♦`: correct to `4♣` with longer clubs
+fn unusual_over_diamonds() -> Rules {
+    Rules::new()
+        .rule(
+            Bid::new(4, Strain::Clubs),
+            100,
+            longer_suit(Suit::Clubs, Suit::Diamonds),
+        )
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// The Multi, Muiderberg and unusual-`2NT` response trees
+pub(super) fn package() -> Package {
+    Package {
+        name: "dutch-two-level",
+        gate: |_| true,
+        entries: |_| {
+            let mut entries: Vec<Entry> = rows_of(Pattern::node("P* 2♦ -"), multi_responses());
+            for (call, rebid) in [
+                ("2♥", multi_over_two_hearts()),
+                ("2♠", multi_over_two_spades()),
+                ("4♥", multi_over_four_hearts()),
+                ("2NT", multi_ask_answers()),
+            ] {
+                entries.extend(rows_of(Pattern::node(&format!("P* 2♦ - {call} -")), rebid));
+            }
+            for (answer, major) in [
+                ("3♣", Suit::Hearts),
+                ("3♦", Suit::Spades),
+                ("3♥", Suit::Hearts),
+                ("3♠", Suit::Spades),
+            ] {
+                entries.extend(rows_of(
+                    Pattern::node(&format!("P* 2♦ - 2NT - {answer} -")),
+                    multi_placement(major),
+                ));
+            }
+            for (major, opening) in [(Suit::Hearts, "2♥"), (Suit::Spades, "2♠")] {
+                let base = format!("P* {opening} -");
+                let raise = format!("3{}", &opening[1..]);
+                entries.extend(rows_of(Pattern::node(&base), muiderberg_responses(major)));
+                entries.extend(rows_of(
+                    Pattern::node(&format!("{base} {raise} -")),
+                    settle(),
+                ));
+                entries.extend(rows_of(
+                    Pattern::node(&format!("{base} 2NT -")),
+                    muiderberg_minor(),
+                ));
+                for minor in ["3♣", "3♦"] {
+                    let named = format!("{base} 2NT - {minor} -");
+                    entries.extend(rows_of(Pattern::node(&named), muiderberg_placement(major)));
+                    entries.extend(rows_of(
+                        Pattern::node(&format!("{named} {raise} -")),
+                        muiderberg_over_invite(major),
+                    ));
+                }
+            }
+            entries.extend(rows_of(Pattern::node("P* 2NT -"), unusual_responses()));
+            // Every node American's strong-2NT structure authored under
+            // `P* 2NT -` is overridden: its Stayman answers, transfer
+            // completions, quantitative 4NT and Smolen mean nothing here.
+            for (tail, rebid) in [
+                ("3♣", unusual_over_clubs()),
+                ("3♦", unusual_over_diamonds()),
+                ("3♥", settle()),
+                ("3♠", settle()),
+                ("3NT", settle()),
+                ("4♥", settle()),
+                ("4♠", settle()),
+                ("4NT", settle()),
+                ("5♣", settle()),
+                ("5♦", settle()),
+                ("3♣ - 3♦", settle()),
+                ("3♦ - 4♣", settle()),
+                ("3♣ - 3♦ - 3♥", settle()),
+                ("3♣ - 3♦ - 3♠", settle()),
+            ] {
+                entries.extend(rows_of(Pattern::node(&format!("P* 2NT - {tail} -")), rebid));
+            }
+            entries
+        },
+    }
+}
@@ -8,8 +8,10 @@
 //!    consistent with everything the auction has shown (the actor's own hand is
 //!    pinned, so every layout is a full deal this auction could have come from).
 //! 2. **Finish the auction.**  Seed the candidate call onto the prior auction
-//!    and let a *continuation policy* bid it out — all four seats bid the same
-//!    policy (a self-play assumption: "what happens if everyone plays like us").
+//!    and let the *continuation policies* bid it out — one for our side and
+//!    one for theirs.  Passing the same policy twice is the self-play
+//!    assumption ("what happens if everyone plays like us"); against a field
+//!    that bids otherwise, model the opponents apart.
 //! 3. **Score double-dummy.**  Solve each sampled layout once and price the
 //!    contract each candidate reached, signed to the **actor's** favour, under
 //!    **perfect-defense doubling** ([`ns_score_bid`][crate::scoring::ns_score_bid]): a contract
@@ -19,24 +21,43 @@
 //!    chases phantom saves into runaway competitive auctions.
 //! 4. **Average** over layouts.  That average is the call's EV.
 //!
-//! The continuation policy is a [`Bidder`] *parameter*, not hardwired.  M2.2
+//! The continuation policies are [`Bidder`] *parameters*, not hardwired.  M2.2
 //! defaults callers to the deterministic [`american`][crate::american()]
 //! (debuggable, and ≈ the distilled net at bootstrap); the M3 search-improvement
-//! loop swaps in successive nets without touching this code.
+//! loop swaps in successive nets without touching this code.  The opponents'
+//! slot takes anything that bids: a [`Partnership`] reading us through
+//! [`with_opponents`][Partnership::with_opponents], a distilled reference net,
+//! or an external engine behind a [`Bidder`].  Self-play mis-prices exactly
+//! the competitive calls a different field answers differently;
+//! [`rank_agreement`] measures how far the two models' rankings part.
 //!
 //! The double-dummy solve is the cost, so it is **shared across candidates**:
 //! [`ev_all`] solves each layout once with [`NonEmptyStrainFlags::ALL`][ddss::NonEmptyStrainFlags::ALL] and
 //! prices every candidate contract from that one [`TrickCountTable`][ddss::TrickCountTable].  Cost is
 //! `n` solves, not `k · n`.  This batch form is also what the M2.3 live search
-//! bidder wants — score the net-shortlisted top-`k` at once.
+//! bidder, [`SearchBidder`][crate::bidding::SearchBidder], wants — score the shortlisted
+//! top-`k` at once.
+//!
+//! [`ev_fast`] is the solver-free path.  It samples and bids out the same
+//! way, but prices each reached contract from the actor's own hand with the
+//! [trick evaluator][super::evaluator], reading the finished auction as the
+//! actor would: the Gaussian trick estimate gives each trick count's chance
+//! in closed form, scored with the same perfect-defense doubling.  It costs
+//! milliseconds instead of solves and builds without the `dd` feature, so the
+//! wasm build has a call EV too; it is as good as the evaluator, no better.
 
 use super::Bidder;
-use super::context::Context;
+use super::book::Partnership;
+use super::context::{Context, relative};
+use super::inference::Relative;
 use super::sampler::{sample_layouts, sample_layouts_replay};
 use super::table::Table;
-use crate::scoring::{final_contract, ns_score_bid};
+use crate::scoring::final_contract;
+#[cfg(feature = "dd")]
+use crate::scoring::ns_score_bid;
 use contract_bridge::auction::{Auction, Call};
-use contract_bridge::{AbsoluteVulnerability, Hand, Seat};
+use contract_bridge::{AbsoluteVulnerability, Contract, FullDeal, Hand, Penalty, Seat};
+#[cfg(feature = "dd")]
 use ddss::{NonEmptyStrainFlags, Solver};
 use rand::Rng;
 
@@ -53,7 +74,9 @@ use rand::Rng;
 /// - `context` carries the prior auction; its
 ///   [`Inferences`][super::inference::Inferences] are read here to
 ///   sample the layouts the rollout continues.
-/// - `policy` bids every seat during the rollout (the self-play assumption).
+/// - `ours` bids the actor's side during the rollout and `theirs` the other;
+///   pass one policy twice for self-play.  The prior auction is replay-read
+///   the same way, each call by the side that made it.
 ///
 /// An entry is [`f32::NAN`] when its call is illegal in the prior auction, and
 /// every entry is `NAN` when no layout could be sampled (a tight or infeasible
@@ -63,6 +86,7 @@ use rand::Rng;
 ///
 /// Panics if `context`'s prior auction is not a legal sequence of calls (it
 /// always is when the context comes from a real table).
+#[cfg(feature = "dd")]
 #[must_use]
 #[allow(clippy::cast_precision_loss)] // averaging i64 points into an f32 EV
 #[allow(clippy::too_many_arguments)] // each argument is a distinct decision fact
@@ -72,7 +96,8 @@ pub fn ev_all(
     vul: AbsoluteVulnerability,
     context: &Context<'_>,
     calls: &[Call],
-    policy: &impl Bidder,
+    ours: &impl Bidder,
+    theirs: &impl Bidder,
     rng: &mut impl Rng,
     n: usize,
 ) -> Vec<f32> {
@@ -80,32 +105,14 @@ pub fn ev_all(
         return Vec::new();
     }
 
-    let inferences = context.inferences();
-    let deals = if context.reading_profile().rule_accept {
-        // Read each authored prior bid by replaying the rule that authored it
-        // (frozen at its node); unauthored nodes fall back to the range reading.
-        let mut deals = sample_layouts_replay(
-            hand,
-            seat,
-            policy,
-            context.vul(),
-            context.auction(),
-            &inferences,
-            rng,
-            n,
-        );
-        if deals.len() < n {
-            // Replay can still starve on a tight authored auction.  Top up with
-            // the range reader alone so the rollout keeps a usable layout count.
-            // ponytail: pays the full replay budget first; add a probe-budget
-            // early-abort if the wasted draws on starved auctions bite.
-            let more = sample_layouts(hand, seat, &inferences, rng, n - deals.len());
-            deals.extend(more);
-        }
-        deals
+    // The actor's side acts at the prior auction's parity, so it is the
+    // dealer's side exactly when the prior auction has even length.
+    let replay: &dyn Bidder = if context.auction().len().is_multiple_of(2) {
+        &ours.vs(theirs)
     } else {
-        sample_layouts(hand, seat, &inferences, rng, n)
+        &theirs.vs(ours)
     };
+    let deals = layouts(hand, seat, context, replay, rng, n);
     if deals.is_empty() {
         return vec![f32::NAN; calls.len()];
     }
@@ -113,8 +120,13 @@ pub fn ev_all(
     // One solve per layout, shared across every candidate call (the cost note).
     let tables = Solver::lock(None).solve_deals(&deals, NonEmptyStrainFlags::ALL);
     let dealer = dealer_of(seat, context.auction().len());
-    let table = Table::new(policy, policy, dealer, vul);
     let actor_is_ns = matches!(seat, Seat::North | Seat::South);
+    let (north_south, east_west): (&dyn Bidder, &dyn Bidder) = if actor_is_ns {
+        (ours, theirs)
+    } else {
+        (theirs, ours)
+    };
+    let table = Table::new(north_south, east_west, dealer, vul);
 
     calls
         .iter()
@@ -144,6 +156,207 @@ pub fn ev_all(
         .collect()
 }
 
+/// [`ev_all`] without the solver: each rollout priced by the learned trick
+/// evaluator
+///
+/// The layouts and the continuation are [`ev_all`]'s — sampled the same way,
+/// bid out by `policy` at all four seats — but a finished auction is not
+/// solved.  Instead the actor re-reads it, as `policy` would at the table,
+/// and the [trick evaluator][super::evaluator] prices the reached contract
+/// from the actor's hand and that reading: its Gaussian trick estimate gives
+/// the chance of every trick count in closed form, each scored with
+/// perfect-defense doubling (a failing contract doubled, a making one not,
+/// as [`ev_all`] prices it).  The cost is one bid-out and one forward pass
+/// per layout, so an estimate takes milliseconds, and nothing here needs the
+/// `dd` feature.
+///
+/// The layouts only drive the continuation; the price reads no hidden card.
+/// Returns one EV per call in the actor's favour, with [`ev_all`]'s `NAN`
+/// conventions.
+///
+/// # Panics
+///
+/// Panics if `context`'s prior auction is not a legal sequence of calls.
+#[must_use]
+#[allow(clippy::cast_precision_loss)] // averaging points into an f32 EV
+#[allow(clippy::too_many_arguments)] // the same decision facts as `ev_all`
+pub fn ev_fast(
+    hand: Hand,
+    seat: Seat,
+    vul: AbsoluteVulnerability,
+    context: &Context<'_>,
+    calls: &[Call],
+    policy: &Partnership,
+    rng: &mut impl Rng,
+    n: usize,
+) -> Vec<f32> {
+    if calls.is_empty() {
+        return Vec::new();
+    }
+    let deals = layouts(hand, seat, context, policy, rng, n);
+    if deals.is_empty() {
+        return vec![f32::NAN; calls.len()];
+    }
+
+    let dealer = dealer_of(seat, context.auction().len());
+    let table = Table::new(policy, policy, dealer, vul);
+    calls
+        .iter()
+        .map(|&call| {
+            let mut seed = Auction::new();
+            seed.try_extend(context.auction().iter().copied())
+                .expect("a prior table auction is legal");
+            if seed.can_push(call).is_err() {
+                return f32::NAN;
+            }
+            seed.push(call);
+
+            let total: f32 = deals
+                .iter()
+                .map(|deal| {
+                    let auction = table.bid_out_from(deal, seed.clone());
+                    expected_score(hand, seat, vul, dealer, &auction, policy)
+                })
+                .sum();
+            total / deals.len() as f32
+        })
+        .collect()
+}
+
+/// The actor's expected perfect-defense score of a finished `auction`, by
+/// the trick evaluator at the actor's reading of it
+fn expected_score(
+    hand: Hand,
+    seat: Seat,
+    vul: AbsoluteVulnerability,
+    dealer: Seat,
+    auction: &Auction,
+    reader: &Partnership,
+) -> f32 {
+    let Some((contract, declarer)) = final_contract(auction, dealer) else {
+        return 0.0;
+    };
+    // Read with the actor to act: the last bid is within the final four
+    // calls, so one of these prefixes keeps it and puts the actor on move.
+    let cut = (auction.len().saturating_sub(3)..=auction.len())
+        .find(|&len| dealer_of(seat, len) == dealer)
+        .expect("four consecutive lengths reach every seat");
+    let context = reader.prefixed_context(relative(vul, seat), &auction[..cut]);
+    let offset = (declarer as usize + 4 - seat as usize) % 4;
+    let relative_declarer = [
+        Relative::Me,
+        Relative::Lho,
+        Relative::Partner,
+        Relative::Rho,
+    ][offset];
+    let estimate = context
+        .trick_estimates(hand)
+        .get(contract.bid.strain, relative_declarer);
+
+    let ours = offset.is_multiple_of(2);
+    let declarer_vul = vul.contains(match declarer {
+        Seat::North | Seat::South => AbsoluteVulnerability::NS,
+        Seat::East | Seat::West => AbsoluteVulnerability::EW,
+    });
+    let needed = 6 + contract.bid.level.get();
+    (0..=13u8)
+        .map(|tricks| {
+            // The Gaussian's tails past 0 and 13 fold into the end counts.
+            let at_least = |t: u8| match t {
+                0 => 1.0,
+                14.. => 0.0,
+                _ => estimate.p_at_least(t),
+            };
+            let p = at_least(tricks) - at_least(tricks + 1);
+            let penalty = if tricks < needed {
+                Penalty::Doubled
+            } else {
+                Penalty::Undoubled
+            };
+            let score = Contract {
+                bid: contract.bid,
+                penalty,
+            }
+            .score(tricks, declarer_vul) as f32;
+            p * if ours { score } else { -score }
+        })
+        .sum()
+}
+
+/// How far two EV rankings of the same calls agree: Kendall's τ
+///
+/// Compares `a` and `b` — say a self-play and an asymmetric [`ev_all`] over
+/// one candidate list — pair by pair over the calls both priced: `1` when
+/// every pair is ordered alike, `-1` when every pair is flipped, and a tie
+/// in either ranking counts for neither.  [`None`] when fewer than two calls
+/// are finite in both.
+///
+/// # Panics
+///
+/// Panics if `a` and `b` differ in length.
+#[must_use]
+#[allow(clippy::cast_precision_loss)] // pair counts are tiny
+pub fn rank_agreement(a: &[f32], b: &[f32]) -> Option<f32> {
+    assert_eq!(a.len(), b.len(), "rankings of different call lists");
+    let priced: Vec<(f32, f32)> = a
+        .iter()
+        .zip(b)
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .map(|(&x, &y)| (x, y))
+        .collect();
+    let mut pairs = 0usize;
+    let mut score = 0i64;
+    for (i, &(xi, yi)) in priced.iter().enumerate() {
+        for &(xj, yj) in &priced[i + 1..] {
+            pairs += 1;
+            score += match (xi - xj) * (yi - yj) {
+                product if product > 0.0 => 1,
+                product if product < 0.0 => -1,
+                _ => 0,
+            };
+        }
+    }
+    (pairs > 0).then(|| score as f32 / pairs as f32)
+}
+
+/// Deal `n` layouts consistent with the prior auction in `context`
+///
+/// Each authored prior bid is read by replaying the rule that authored it
+/// when the reading profile says so, topped up by the range reader alone when
+/// replay starves; otherwise the range reader samples them all.
+fn layouts(
+    hand: Hand,
+    seat: Seat,
+    context: &Context<'_>,
+    policy: &dyn Bidder,
+    rng: &mut impl Rng,
+    n: usize,
+) -> Vec<FullDeal> {
+    let inferences = context.inferences();
+    if !context.reading_profile().rule_accept {
+        return sample_layouts(hand, seat, &inferences, rng, n);
+    }
+    let mut deals = sample_layouts_replay(
+        hand,
+        seat,
+        policy,
+        context.vul(),
+        context.auction(),
+        &inferences,
+        rng,
+        n,
+    );
+    if deals.len() < n {
+        // Replay can still starve on a tight authored auction.  Top up with
+        // the range reader alone so the rollout keeps a usable layout count.
+        // ponytail: pays the full replay budget first; add a probe-budget
+        // early-abort if the wasted draws on starved auctions bite.
+        let more = sample_layouts(hand, seat, &inferences, rng, n - deals.len());
+        deals.extend(more);
+    }
+    deals
+}
+
 /// The dealer such that the seat acting after the prior auction is `seat`
 ///
 /// [`Table`] positions a seeded auction from the dealer, so for the rollout's
@@ -20,13 +20,14 @@ fn balanced_twenty() -> Hand {
 }
 
 /// The deterministic continuation policy used throughout these tests.
-fn deterministic() -> impl Bidder {
+fn deterministic() -> Partnership {
     american(&crate::bidding::agreements::Agreements::default()).bind()
 }
 
 /// Sanity: the evaluator prefers the obviously-right call.  As dealer with a
 /// flat 20-count, a sound game (3NT) must out-value a hopeless grand (7NT),
 /// and the grand must price out clearly negative (it goes down off the top).
+#[cfg(feature = "dd")]
 #[test]
 fn prefers_game_over_hopeless_grand() {
     let policy = deterministic();
@@ -39,6 +40,7 @@ fn prefers_game_over_hopeless_grand() {
         &context,
         &[bid(3, Strain::Notrump), bid(7, Strain::Notrump)],
         &policy,
+        &policy,
         &mut rng,
         48,
     );
@@ -58,6 +60,7 @@ fn prefers_game_over_hopeless_grand() {
 
 /// Determinism: the model never samples its own RNG, so the same seed and
 /// inputs reproduce the same EVs exactly (invariant §0.5).
+#[cfg(feature = "dd")]
 #[test]
 fn deterministic_given_a_seed() {
     let policy = deterministic();
@@ -72,6 +75,7 @@ fn deterministic_given_a_seed() {
         &context,
         &calls,
         &policy,
+        &policy,
         &mut rng_a,
         24,
     );
@@ -83,6 +87,7 @@ fn deterministic_given_a_seed() {
         &context,
         &calls,
         &policy,
+        &policy,
         &mut rng_b,
         24,
     );
@@ -92,6 +97,7 @@ fn deterministic_given_a_seed() {
 /// An infeasible auction samples no layout, so every EV is `NaN` — the
 /// "no signal" contract, not a panic.  North hoards nine hearts while RHO's
 /// 1H opening demands five, leaving only four in the deck.
+#[cfg(feature = "dd")]
 #[test]
 fn infeasible_auction_is_no_signal() {
     let policy = deterministic();
@@ -109,6 +115,7 @@ fn infeasible_auction_is_no_signal() {
         &context,
         &[Call::Pass, bid(2, Strain::Hearts)],
         &policy,
+        &policy,
         &mut rng,
         8,
     );
@@ -119,6 +126,7 @@ fn infeasible_auction_is_no_signal() {
 }
 
 /// An illegal candidate carries no signal even when other candidates do.
+#[cfg(feature = "dd")]
 #[test]
 fn illegal_candidate_is_nan() {
     let policy = deterministic();
@@ -134,6 +142,7 @@ fn illegal_candidate_is_nan() {
         &context,
         &[bid(1, Strain::Clubs), Call::Pass],
         &policy,
+        &policy,
         &mut rng,
         8,
     );
@@ -142,6 +151,7 @@ fn illegal_candidate_is_nan() {
 }
 
 /// Requesting no candidates returns nothing.
+#[cfg(feature = "dd")]
 #[test]
 fn empty_candidates_is_empty() {
     let policy = deterministic();
@@ -155,6 +165,7 @@ fn empty_candidates_is_empty() {
             &context,
             &[],
             &policy,
+            &policy,
             &mut rng,
             8,
         )
@@ -172,3 +183,105 @@ fn rule_replay_is_the_default() {
         "the search EV samples its rollout worlds by rule-replay by default"
     );
 }
+
+/// The fast path agrees on the obvious call: with the flat 20-count as
+/// dealer, 3NT out-values 7NT once the evaluator prices each contract.
+#[test]
+fn fast_prefers_game_over_hopeless_grand() {
+    let policy = deterministic();
+    let context = Context::new(RelativeVulnerability::NONE, &[]);
+    let mut rng = StdRng::seed_from_u64(20);
+    let evs = ev_fast(
+        balanced_twenty(),
+        Seat::North,
+        AbsoluteVulnerability::NONE,
+        &context,
+        &[bid(3, Strain::Notrump), bid(7, Strain::Notrump)],
+        &policy,
+        &mut rng,
+        16,
+    );
+
+    assert!(
+        evs[0] > evs[1],
+        "3NT ({}) should beat 7NT ({})",
+        evs[0],
+        evs[1]
+    );
+    assert!(evs[1] < 0.0, "7NT should price negative, got {}", evs[1]);
+}
+
+/// The fast path keeps the `NaN` conventions and reproduces itself from a
+/// seed.
+#[test]
+fn fast_is_deterministic_and_flags_illegal_calls() {
+    let policy = deterministic();
+    let auction = [bid(1, Strain::Hearts)];
+    let context = Context::new(RelativeVulnerability::NONE, &auction);
+    let calls = [bid(1, Strain::Clubs), Call::Pass, Call::Double];
+    let run = |seed| {
+        ev_fast(
+            balanced_twenty(),
+            Seat::North,
+            AbsoluteVulnerability::NONE,
+            &context,
+            &calls,
+            &policy,
+            &mut StdRng::seed_from_u64(seed),
+            8,
+        )
+    };
+
+    let evs = run(3);
+    assert!(evs[0].is_nan(), "1C over 1H is illegal");
+    assert!(evs[1..].iter().all(|ev| ev.is_finite()));
+    assert_eq!(evs[1..], run(3)[1..]);
+}
+
+/// Opponents modelled apart still price every legal call, and the obvious
+/// ranking survives: 3NT over 7NT whoever sits East/West.
+#[cfg(feature = "dd")]
+#[test]
+fn asymmetric_opponents_keep_the_obvious_ranking() {
+    let ours = deterministic();
+    let theirs = crate::dutch(&crate::bidding::agreements::Agreements::default())
+        .bind()
+        .with_opponents(&ours);
+    let context = Context::new(RelativeVulnerability::NONE, &[]);
+    let mut rng = StdRng::seed_from_u64(20);
+    let evs = ev_all(
+        balanced_twenty(),
+        Seat::North,
+        AbsoluteVulnerability::NONE,
+        &context,
+        &[bid(3, Strain::Notrump), bid(7, Strain::Notrump)],
+        &ours,
+        &theirs,
+        &mut rng,
+        24,
+    );
+    assert!(evs.iter().all(|ev| ev.is_finite()));
+    assert!(
+        evs[0] > evs[1],
+        "3NT ({}) should beat 7NT ({})",
+        evs[0],
+        evs[1]
+    );
+}
+
+/// Kendall's τ over the calls both rankings priced.
+#[test]
+fn rank_agreement_counts_pairs() {
+    assert_eq!(
+        rank_agreement(&[3.0, 2.0, 1.0], &[30.0, 20.0, 10.0]),
+        Some(1.0)
+    );
+    assert_eq!(
+        rank_agreement(&[3.0, 2.0, 1.0], &[1.0, 2.0, 3.0]),
+        Some(-1.0)
+    );
+    // One flipped pair of three; the NaN call sits out.
+    let tau = rank_agreement(&[3.0, 2.0, 1.0, f32::NAN], &[2.0, 3.0, 1.0, 0.0]);
+    assert!((tau.expect("three priced calls") - 1.0 / 3.0).abs() < 1e-6);
+    assert_eq!(rank_agreement(&[1.0, f32::NAN], &[1.0, 2.0]), None);
+}
@@ -17,7 +17,7 @@
 //! | **Total**            |       | **88** |
 
 use super::agreements::Agreements;
-use super::american::{EUROPEAN, LebensohlStyle, NotrumpDefense, NotrumpShape};
+use super::american::{EUROPEAN, LebensohlStyle, NotrumpDefense, NotrumpRange, NotrumpShape};
 use super::card::Card;
 use super::context::{Context, DecisionProfile};
 use super::inference::{Envelope, EnvelopeUnion, Inferences, Range, Relative};
@@ -1065,6 +1065,12 @@ pub struct ConventionCard {
     pub one_notrump_offshape: bool,
     /// Which shapes open 1NT (`opening.notrump_shape`)
     pub shape: NotrumpShape,
+    /// How strong the 1NT opening is (`ReadingProfile::one_notrump_range`)
+    ///
+    /// Not encoded: the compact [`LEN_COMPACT`] layout is pinned to the v6
+    /// artifacts, none of which saw a weak notrump.  The range reaches the
+    /// floor through the reading instead, which narrows a 1NT opener to it.
+    pub one_notrump_range: NotrumpRange,
     /// The direct-seat defense to their 1NT (`ReadingProfile::notrump_defense`)
     pub defense: NotrumpDefense,
     /// Responder's machinery over their overcall of our 1NT
@@ -1105,6 +1111,7 @@ impl ConventionCard {
             nt_splinter: a.decision.reading.nt_splinter,
             one_notrump_offshape: a.opening.one_notrump_offshape,
             shape: a.opening.notrump_shape,
+            one_notrump_range: a.decision.reading.one_notrump_range,
             defense: a.decision.reading.notrump_defense,
             lebensohl: a.competition.lebensohl_style,
             minors_european: a.decision.reading.notrump_minors == EUROPEAN,
@@ -1154,6 +1161,13 @@ impl ConventionCard {
             } else {
                 NotrumpShape::Balanced
             },
+            one_notrump_range: if row("1NT opening range 12-14") {
+                NotrumpRange::Weak
+            } else if row("1NT opening range 13-15") {
+                NotrumpRange::Medium
+            } else {
+                NotrumpRange::Strong
+            },
             defense: if row("Multi-Landy") {
                 NotrumpDefense::Woolsey
             } else if row("Landy") {
@@ -1180,8 +1194,9 @@ impl ConventionCard {
 
     /// One side as `0.0`/`1.0` slots — **the slot contract** of [`LEN_COMPACT`]
     ///
-    /// Booleans occupy one slot each; the three enums are one-hot blocks in
-    /// declaration order.  `compact_layout_is_pinned` holds the expected vector
+    /// Booleans occupy one slot each; the shape, defense and lebensohl enums
+    /// are one-hot blocks in declaration order, and `one_notrump_range` has no
+    /// slot.  `compact_layout_is_pinned` holds the expected vector
     /// at the shipped defaults, so a reordering here fails a test rather than
     /// silently retargeting every artifact.
     fn encode(&self) -> [f32; LEN_COMPACT] {
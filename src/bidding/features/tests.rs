@@ -906,7 +906,8 @@ fn the_base_system_is_encoded() {
     assert_eq!(dutch.ours[..LEN_SYSTEM], [0.0, 0.0, 1.0, 0.0, 0.0]);
     assert_ne!(american, dutch);
 
-    // The header, plus the one row the two systems disagree on.
+    // The header, plus the rows the two systems disagree on: the diamond
+    // opening and the two-level openings.
     let differing = american
         .ours
         .iter()
@@ -914,8 +915,8 @@ fn the_base_system_is_encoded() {
         .filter(|(a, b)| a != b)
         .count();
     assert_eq!(
-        differing, 3,
-        "two one-hot slots plus `1D opening with 5 cards`"
+        differing, 6,
+        "two one-hot slots plus `1D opening with 5 cards`, `Multi` and the weak twos"
     );
 }
 
@@ -14,6 +14,8 @@
 /// stances.  One enum makes the honest domain the type, following the
 /// [`NotrumpDefense`][crate::bidding::american::NotrumpDefense] precedent.
 #[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum ReadingScope {
     /// Decode nothing off the authoring rules; every call falls to the natural
     /// walk's guess from auction shape.  The pre-alert behaviour, in which a
@@ -69,6 +71,8 @@ pub enum ReadingScope {
 /// is captured once when a decision scope is entered and compared only by
 /// debug assertions on the cached path.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct ReadingProfile {
     /// Quantify a natural notrump raise of our own `1NT` opening
     ///
@@ -744,6 +748,10 @@ pub struct ReadingProfile {
     /// Club / WJ and common continental response set.  Both variants are
     /// authored; only the selected one's `2♠`/`2NT`/`3♣` rules are gated into
     /// the trie, and the inference engine reads the same field to decode them.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::bidding::american::notrump::minor_scheme")
+    )]
     pub notrump_minors: crate::bidding::rules::Alert,
 
     /// Author opener's `3M` jump rebid of a six-card major with extras
@@ -1030,6 +1038,56 @@ pub struct ReadingProfile {
     /// rather than keeping another copy of its own — one value, one home.
     pub two_notrump_wide: bool,
 
+    /// How strong the `1NT` opening is
+    ///
+    /// **Default [`Strong`][crate::bidding::american::NotrumpRange::Strong]**
+    /// (15–17), byte-identical to the shipped card.
+    /// [`Weak`][crate::bidding::american::NotrumpRange::Weak] is the 12–14
+    /// Acol notrump: the opening reads 12–14, responder's natural `2NT` and
+    /// `3NT` read the invitation and game three points higher, and opener's
+    /// `1NT` rebid after a one-level response reads the 15–17 that no longer
+    /// opens `1NT`.  The walk answers from the *opening side's* profile, so a
+    /// declared Acol opponent's notrump reads weak on our side of the table
+    /// too.
+    ///
+    /// Read at build time too (`american/openings/one_notrump.rs` gauges the
+    /// opening off it), so the opening book takes it from here — one value,
+    /// one home.
+    pub one_notrump_range: crate::bidding::american::NotrumpRange,
+
+    /// Read a one-of-a-major opening as four-plus, not five-plus
+    ///
+    /// **Default off** — every american-family book opens five-card majors.
+    /// On is the Acol reading: `1♥`/`1♠` promises four, so the walk floors
+    /// opener's major at four and a three-card raise is no longer a known
+    /// fit.  Like [`one_notrump_range`][field@Self::one_notrump_range] it is
+    /// read off the opening side's profile.
+    pub four_card_majors: bool,
+
+    /// Read the openings as Precision's: a strong, artificial `1♣`
+    ///
+    /// **Default off**.  On, `1♣` reads 16+ with nothing about shape, the
+    /// other one-level suits, `2♣` and `2♦` read as the limited 11–15
+    /// openings — a nebulous two-plus `1♦`, five-card majors, long clubs and
+    /// the short-diamond three-suiter — and the walk treats `1♣` and `2♦`,
+    /// not `2♣`, as the artificial openings, so their continuations are left
+    /// to the projection rather than read as natural suits.  Opener's extras
+    /// floors (the reverse, the jump rebids) stay off behind a limited
+    /// opening.  Read off the opening side's profile, like
+    /// [`one_notrump_range`][field@Self::one_notrump_range].
+    pub strong_club: bool,
+
+    /// Read the two-level openings as Dutch's: Multi `2♦`, Muiderberg and the unusual `2NT`
+    ///
+    /// **Default off**.  On, the weak `2♦` reads as the Multi — a six-card
+    /// major, nothing about which, and at most four of each minor — `2♥`/`2♠`
+    /// as Muiderberg's exactly five in the major with a four-plus minor, and
+    /// `2NT` as a weak five-five in the minors rather than the strong
+    /// balanced hand.  The Multi is the artificial one: the walk leaves its
+    /// continuations to the projection.  Read off the opening side's profile,
+    /// like [`one_notrump_range`][field@Self::one_notrump_range].
+    pub dutch_twos: bool,
+
     /// The floor asks and answers RKCB 1430 (M6.4)
     ///
     /// **Default on**: with a known eight-card fit and combined small-slam
@@ -1132,6 +1190,10 @@ impl ReadingProfile {
             notrump_defense: crate::bidding::american::NotrumpDefense::Woolsey,
             natural_overcall_points: (9, 13),
             two_notrump_wide: true,
+            one_notrump_range: crate::bidding::american::NotrumpRange::Weak,
+            four_card_majors: true,
+            strong_club: true,
+            dutch_twos: true,
             floor_rkcb: false,
             rkcb_variant: crate::bidding::instinct::RkcbVariant::Kickback,
         }
@@ -1197,6 +1259,10 @@ impl Default for ReadingProfile {
             notrump_defense: crate::bidding::american::NotrumpDefense::Natural,
             natural_overcall_points: (8, 14),
             two_notrump_wide: false,
+            one_notrump_range: crate::bidding::american::NotrumpRange::Strong,
+            four_card_majors: false,
+            strong_club: false,
+            dutch_twos: false,
             floor_rkcb: true,
             rkcb_variant: crate::bidding::instinct::RkcbVariant::Plain,
         }
@@ -10,6 +10,7 @@ use super::knobs::*;
 use super::projection::*;
 use super::readers::*;
 use super::{LENGTH_CAP, POINTS_CAP};
+use crate::bidding::american::NotrumpRange;
 use crate::bidding::context::{Context, DecisionProfile};
 use contract_bridge::auction::Call;
 use contract_bridge::{Bid, Hand, Strain, Suit};
@@ -469,8 +470,19 @@ impl Inferences {
         // SAFETY: at most three passes precede the opening, so the cast is safe.
         #[allow(clippy::cast_possible_truncation)]
         let opener_seat = opening_index as u8 + 1;
-        let opening_artificial =
-            opening_bid.strain == Strain::Notrump || opening_bid == Bid::new(2, Strain::Clubs);
+        // Whether the opening names a suit it does not hold.  The strong `2♣`
+        // does — unless the opening side plays Precision, whose `2♣` is long
+        // clubs and whose strong `1♣` and three-suited `2♦` are the artificial
+        // openings instead (`ReadingProfile::strong_club`).  A Dutch Multi `2♦`
+        // does too (`ReadingProfile::dutch_twos`).
+        let opening_artificial = opening_bid.strain == Strain::Notrump
+            || if side_profile.strong_club {
+                opening_bid == Bid::new(1, Strain::Clubs)
+                    || opening_bid == Bid::new(2, Strain::Diamonds)
+            } else {
+                opening_bid == Bid::new(2, Strain::Clubs)
+                    || (side_profile.dutch_twos && opening_bid == Bid::new(2, Strain::Diamonds))
+            };
         let defending_parity = (opener_lane + 1) % 2;
         let read_nt_invite = profile.nt_invite;
         // A 1NT - 2♣ Stayman auction (opponents silent): opener's major answer and
@@ -647,9 +659,17 @@ impl Inferences {
                         && opening_bid.strain.is_suit()
                         && matches!(relative_of(len, index), Relative::Lho | Relative::Rho);
                     if index == opening_index {
-                        apply_opening(&mut players[who], bid, opener_seat, profile);
+                        // The opener's own agreement sets the 1NT range and the
+                        // major length — a weak notrump across the table reads
+                        // 12–14 even when ours is strong.
+                        apply_opening(&mut players[who], bid, opener_seat, side_profile);
                     } else if their_direct_nt_overcall {
-                        apply_opening(&mut players[who], bid, 1, their_profile);
+                        // A 1NT overcall is strong whatever their opening range.
+                        let overcall = ReadingProfile {
+                            one_notrump_range: NotrumpRange::Strong,
+                            ..their_profile
+                        };
+                        apply_opening(&mut players[who], bid, 1, overcall);
                     } else if let Some(suit) = bid.strain.suit() {
                         // A three-level suit bid over our 1NT is off-book and
                         // forcing — the instinct reading takes it as natural,
@@ -1019,8 +1039,9 @@ impl Inferences {
                             is_opening_side && lane == opener_lane && lane_bids[lane] == 1;
                         let responder_first =
                             is_opening_side && lane == responder_lane && lane_bids[lane] == 0;
-                        let opening_one_suit =
-                            opening_bid.level.get() == 1 && opening_bid.strain.is_suit();
+                        let opening_one_suit = opening_bid.level.get() == 1
+                            && opening_bid.strain.is_suit()
+                            && !opening_artificial;
 
                         if read_nt_invite
                             && bid.strain == Strain::Notrump
@@ -1036,13 +1057,16 @@ impl Inferences {
                             // (Puppet 2♠/3♣, European 2♠ clubs / 3♣ diamonds) stay
                             // silent here — `project_authored` narrows the single
                             // suits.  This is what lets opener (or the sampler behind
-                            // the search floor) judge responder.
+                            // the search floor) judge responder.  Opposite a weaker
+                            // notrump every band sits as many points higher as the
+                            // opening sits lower (`NotrumpRange::lift`).
+                            let lift = side_profile.one_notrump_range.lift();
                             match bid.level.get() {
                                 2 => {
                                     if side_profile.notrump_minors
                                         == crate::bidding::american::EUROPEAN
                                     {
-                                        players[who].narrow_points(Range::new(8, 9));
+                                        players[who].narrow_points(Range::new(8 + lift, 9 + lift));
                                     } else {
                                         players[who].narrow_length(
                                             Suit::Diamonds,
@@ -1050,23 +1074,41 @@ impl Inferences {
                                         );
                                     }
                                 }
-                                3 => players[who].narrow_points(Range::at_least(9, POINTS_CAP)),
+                                3 => players[who]
+                                    .narrow_points(Range::at_least(9 + lift, POINTS_CAP)),
                                 _ => {}
                             }
                         } else if bid.strain == Strain::Notrump && opening_one_suit {
                             if opener_rebid {
                                 // A balanced rebid.  1NT is a minimum (12–16: a
-                                // 17 would open the strong notrump); a *jump* to
-                                // 2NT is the strong 18–19 rebid.  A non-jump 2NT
-                                // (over a two-level response) is a minimum and is
-                                // left to the opening's bound.
+                                // 17 would open the strong notrump), or 15–17
+                                // behind a weak notrump, shown slack-wide as the
+                                // opening is, or the 11–12 below Precision's
+                                // 13–15; a *jump* to 2NT is the strong 18–19
+                                // rebid, which a limited Precision opening never
+                                // holds.  A non-jump 2NT (over a two-level
+                                // response) is a minimum and is left to the
+                                // opening's bound.
                                 let nt_jump = bid
                                     .level
                                     .get()
                                     .saturating_sub(cheapest_level(highest, Strain::Notrump));
                                 if bid.level.get() == 1 {
-                                    players[who].narrow_points(Range::new(12, 16));
-                                } else if bid.level.get() == 2 && nt_jump >= 1 {
+                                    let rebid = match side_profile.one_notrump_range {
+                                        NotrumpRange::Strong => Range::new(12, 16),
+                                        NotrumpRange::Weak => {
+                                            let slack = crate::bidding::constraint::flat_hcp_slack(
+                                                side_profile.point_scale,
+                                            );
+                                            Range::new(15 - slack, 18)
+                                        }
+                                        NotrumpRange::Medium => Range::new(10, 13),
+                                    };
+                                    players[who].narrow_points(rebid);
+                                } else if bid.level.get() == 2
+                                    && nt_jump >= 1
+                                    && !side_profile.strong_club
+                                {
                                     players[who].narrow_points(Range::new(18, 21));
                                 }
                             } else if responder_first && bid.level.get() == 1 {
@@ -1111,6 +1153,7 @@ impl Inferences {
                     // Opener's extras-ladder rebid shows extras and — for a
                     // new-suit rung — a five-card opened suit.  Sound floors: the
                     // jump-rebid is 16+, the reverse 17+, the jump-shift 18+.
+                    // A Precision opening is limited and has no extras to show.
                     if profile.opener_extras_ladder
                         && !side_acted[defending_parity]
                         && is_opening_side
@@ -1118,6 +1161,7 @@ impl Inferences {
                         && lane_bids[lane] == 1
                         && opening_bid.level.get() == 1
                         && matches!(opening_bid.strain, Strain::Clubs | Strain::Diamonds)
+                        && !side_profile.strong_club
                         && let (Some(bid_suit), Some(opened)) =
                             (bid.strain.suit(), opening_bid.strain.suit())
                     {
@@ -1151,7 +1195,9 @@ impl Inferences {
                     // a 3M jump in opener's own opened major over `1♥ - 1♠` / `1M - 1NT`
                     // shows 16+.  Natural, so the six-card length is read above
                     // (the `i_bid_it` branch); add the strength floor here.
+                    // Not behind Precision's limited 11–15 major.
                     if profile.opener_major_jump_rebid
+                        && !side_profile.strong_club
                         && !side_acted[defending_parity]
                         && is_opening_side
                         && lane == opener_lane
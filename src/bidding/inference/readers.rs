@@ -678,13 +678,21 @@ pub(super) fn apply_opening(inf: &mut Envelope, bid: Bid, seat: u8, profile: Rea
     let major_floor = if seat >= 3 { 9 } else { 10 };
     let minor_floor = 10;
     let majors_light = Range::new(major_floor, 21);
+    // Five-card majors, or four under the Acol reading.
+    let major_length = Range::at_least(if profile.four_card_majors { 4 } else { 5 }, LENGTH_CAP);
+    if profile.strong_club && apply_precision_opening(inf, bid) {
+        return;
+    }
+    if profile.dutch_twos && apply_dutch_two(inf, bid) {
+        return;
+    }
     match (bid.level.get(), bid.strain) {
         (1, Strain::Hearts) => {
-            inf.narrow_length(Suit::Hearts, Range::at_least(5, LENGTH_CAP));
+            inf.narrow_length(Suit::Hearts, major_length);
             inf.narrow_points(majors_light);
         }
         (1, Strain::Spades) => {
-            inf.narrow_length(Suit::Spades, Range::at_least(5, LENGTH_CAP));
+            inf.narrow_length(Suit::Spades, major_length);
             inf.narrow_points(majors_light);
         }
         (1, Strain::Diamonds) => {
@@ -709,20 +717,24 @@ pub(super) fn apply_opening(inf: &mut Envelope, bid: Bid, seat: u8, profile: Rea
             inf.narrow_length(Suit::Hearts, Range::new(2, 5));
             inf.narrow_length(Suit::Clubs, Range::new(2, 6));
             inf.narrow_length(Suit::Diamonds, Range::new(2, 6));
-            // Plain HCP 15–17 gates the opening (fifths archived).  The plain
+            // Plain HCP 15–17 (12–14 for the weak notrump,
+            // `ReadingProfile::one_notrump_range`) gates the opening (fifths
+            // archived).  The plain
             // rule-of-N+8 opt-in scale reads a flat 4-3-3-3 one under its HCP
             // (the shipped floored scale doesn't) and a 5422/6322 one over
             // (9-card long suits − 8); the legacy upgrade scale adds at most
-            // +1 the same way.  Sound band 15−slack..18 — the slack term
+            // +1 the same way.  Sound band lo−slack..hi+1 — the slack term
             // keeps every opt-in arm exact.  ponytail:
             // exact for the shipped plain-HCP gauge; the archived
             // `one_notrump_fifths` knob, if ever revived, would re-widen
-            // this to 14–19.
+            // this by a point each way.
+            let (lo, hi) = profile.one_notrump_range.hcp();
             let slack = crate::bidding::constraint::flat_hcp_slack(profile.point_scale);
-            inf.narrow_points(Range::new(15 - slack, 18));
-            // The `hcp` gauge is crisp raw HCP — 15–17 gates the opening, with
-            // no upgrade slack (notrump valuation, read behind Edit 2's knob).
-            inf.narrow_hcp(Range::new(15, 17), profile.point_scale);
+            inf.narrow_points(Range::new(lo - slack, hi + 1));
+            // The `hcp` gauge is crisp raw HCP — the range gates the opening,
+            // with no upgrade slack (notrump valuation, read behind Edit 2's
+            // knob).
+            inf.narrow_hcp(Range::new(lo, hi), profile.point_scale);
         }
         (2, Strain::Clubs) => {
             // Strong and artificial: 22+ points, but nothing about shape.
@@ -760,6 +772,83 @@ pub(super) fn apply_opening(inf: &mut Envelope, bid: Bid, seat: u8, profile: Rea
     }
 }
 
+/// Apply a Precision opening's meaning, or return `false` for the openings it
+/// shares with the natural reading
+///
+/// `1♣` is 16+ and says nothing about clubs.  The limited openings are
+/// authored 11–15 and read a point looser each way, as the natural one-level
+/// floors are: `1♦` two-plus diamonds without a five-card major, `1♥`/`1♠`
+/// five-plus, `2♣` five-plus clubs, and `2♦` the short-diamond three-suiter.
+/// `1NT`, the weak twos and the preempts read as anyone's.
+fn apply_precision_opening(inf: &mut Envelope, bid: Bid) -> bool {
+    let limited = Range::new(10, 16);
+    match (bid.level.get(), bid.strain) {
+        (1, Strain::Clubs) => inf.narrow_points(Range::at_least(15, POINTS_CAP)),
+        (1, Strain::Diamonds) => {
+            inf.narrow_length(Suit::Diamonds, Range::at_least(2, LENGTH_CAP));
+            inf.narrow_length(Suit::Hearts, Range::new(0, 4));
+            inf.narrow_length(Suit::Spades, Range::new(0, 4));
+            inf.narrow_points(limited);
+        }
+        (1, Strain::Hearts) => {
+            inf.narrow_length(Suit::Hearts, Range::at_least(5, LENGTH_CAP));
+            inf.narrow_points(limited);
+        }
+        (1, Strain::Spades) => {
+            inf.narrow_length(Suit::Spades, Range::at_least(5, LENGTH_CAP));
+            inf.narrow_points(limited);
+        }
+        (2, Strain::Clubs) => {
+            inf.narrow_length(Suit::Clubs, Range::at_least(5, LENGTH_CAP));
+            inf.narrow_points(limited);
+        }
+        (2, Strain::Diamonds) => {
+            inf.narrow_length(Suit::Diamonds, Range::new(0, 1));
+            inf.narrow_length(Suit::Hearts, Range::new(3, 4));
+            inf.narrow_length(Suit::Spades, Range::new(3, 4));
+            inf.narrow_length(Suit::Clubs, Range::new(4, 5));
+            inf.narrow_points(limited);
+        }
+        _ => return false,
+    }
+    true
+}
+
+/// Apply a Dutch two-level opening's meaning, or return `false` for the
+/// openings it shares with the natural reading
+///
+/// All three are weak, 5–10.  The Multi `2♦` caps both minors at four and
+/// cannot say which major is the six-card one; Muiderberg `2♥`/`2♠` is exactly
+/// five in the major and at most three in the other, and the minor it promises
+/// is a disjunction the envelope cannot hold.  `2NT` is five-five in the
+/// minors.
+fn apply_dutch_two(inf: &mut Envelope, bid: Bid) -> bool {
+    if bid.level.get() != 2 {
+        return false;
+    }
+    match bid.strain {
+        Strain::Diamonds => {
+            inf.narrow_length(Suit::Clubs, Range::new(0, 4));
+            inf.narrow_length(Suit::Diamonds, Range::new(0, 4));
+        }
+        Strain::Hearts => {
+            inf.narrow_length(Suit::Hearts, Range::new(5, 5));
+            inf.narrow_length(Suit::Spades, Range::new(0, 3));
+        }
+        Strain::Spades => {
+            inf.narrow_length(Suit::Spades, Range::new(5, 5));
+            inf.narrow_length(Suit::Hearts, Range::new(0, 3));
+        }
+        Strain::Notrump => {
+            inf.narrow_length(Suit::Clubs, Range::at_least(5, LENGTH_CAP));
+            inf.narrow_length(Suit::Diamonds, Range::at_least(5, LENGTH_CAP));
+        }
+        Strain::Clubs => return false,
+    }
+    inf.narrow_points(Range::new(5, 10));
+    true
+}
+
 /// Narrow a balanced opener: two to five cards in every suit
 fn balanced(inf: &mut Envelope) {
     for suit in Suit::ASC {
@@ -1528,7 +1528,12 @@ fn authored_calls_read_what_they_gate() {
         // rival major, so a 5-4 answers in its five-carder instead of losing
         // the cross-call weight tie to the call encoding.  Same staircase, same
         // blind spot, knob-on still 0.
-        ("length", 85, 0),
+        // 85 → 88 when Dutch's two-level openings shipped (2026-10-17): the
+        // Muiderberg `2♥`/`2♠` gate the `4+ ♣ | 4+ ♦` minor disjunction, and
+        // the minor answer `3♦` gates `at_least_as_long`.  Knob-on still 0.
+        // 88 → 90 when opener's pass-or-correct over the unusual `2NT` was
+        // authored: the `3♦` and `4♣` corrections gate `longer_suit`.
+        ("length", 90, 0),
         ("points", 11, 0),
         // 0/0 measured at birth (2026-07-25): every `suit_hcp` gate the
         // walk reaches (Ogust, the Lebensohl trap pass) is `&`-chained, and
@@ -1550,7 +1555,10 @@ fn authored_calls_read_what_they_gate() {
         // competition, high-overcall, free-bid answer) followed the same
         // guard-to-exact path: eight raise rules of the answer tables
         // surface identically.  Ledger rows in docs/dnf-migration.md.
-        ("support", 115, 0),
+        // 115 → 112 when Dutch's two-level openings replaced the weak twos
+        // (2026-10-17): the three raises of the weak-two response table no
+        // longer sit on a Dutch node.
+        ("support", 112, 0),
         ("support points", 18, 0),
     ];
     let count = |leaks: &std::collections::BTreeMap<&str, Vec<String>>, column| {
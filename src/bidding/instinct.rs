@@ -79,6 +79,8 @@ const GAMBLING_3NT: Alert = Alert("1ntx:gambling-3nt");
 /// a hand with a five-card suit always escapes naturally, in every mode.
 #[doc(hidden)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Unusual2nt {
     /// `2NT` = both minors, four-four (the scramble); opener picks the better
     /// minor.  The historic behavior, now an opt-in.
@@ -103,6 +105,8 @@ pub enum Unusual2nt {
 /// side: the same penalty-vs-optional question the we-open `1NT (2X) X` faced.
 #[doc(hidden)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum LatchStyle {
     /// Pure penalty: the latched double needs a trump stack (4+ with two top
     /// honors) and partner *sits*.  The default — the human "once penalty, always
@@ -130,6 +134,8 @@ pub enum LatchStyle {
 /// configures: `rein_advance_raise` shapes the table at one site and is read
 /// again inside a `pred` closure at another, which puts it here.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct InstinctProfile {
     /// Consult the auction interpretation for known fits
     ///
@@ -739,6 +745,8 @@ fn partner_slam_strength(context: &Context<'_>) -> u8 {
 /// (`minor_asks_now`), because a ladder
 /// whose payoff is the minor lanes needs a minor to ask in.
 #[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum RkcbVariant {
     /// The ask is plain 4NT for every trump — the shipped default.
     #[default]
@@ -0,0 +1,218 @@
+//! Post-mortem auction analysis: what each of our calls cost
+//!
+//! [`explain_call`][Partnership::explain_call] names the rule behind a call;
+//! this module prices it.  [`post_mortem`] walks a finished auction and, at
+//! each of our side's decisions, prices the call made against the
+//! partnership's shortlist there.  Each decision is priced from the decider's
+//! chair: their own hand, the auction so far as their partnership reads it,
+//! and layouts sampled to match — never the hidden cards the deal actually
+//! held, which no player at the table could see.
+//!
+//! The pricer is a parameter.  [`rollout`] prices with [`ev_all`], the
+//! double-dummy rollout, and needs the `dd` feature; [`fast`] prices with
+//! [`ev_fast`], the trick evaluator, and runs anywhere, the wasm build
+//! included.  Every decision reports the chosen call's EV, the best-priced
+//! alternative and the regret between them; [`PostMortem::costliest`] flags
+//! the decision that cost the most.
+//!
+//! [`ev_all`]: super::ev::ev_all
+
+use super::Bidder;
+use super::book::Partnership;
+use super::context::{Context, relative};
+use super::ev::ev_fast;
+use contract_bridge::auction::{Auction, Call};
+use contract_bridge::{AbsoluteVulnerability, FullDeal, Hand, Seat};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// One of our decisions as the decider faced it
+#[derive(Clone, Debug)]
+pub struct Position<'a> {
+    /// Index of the decision's call in the auction
+    pub index: usize,
+    /// The decider's seat
+    pub seat: Seat,
+    /// The decider's hand
+    pub hand: Hand,
+    /// The absolute vulnerability
+    pub vul: AbsoluteVulnerability,
+    /// The auction so far, as the decider's partnership reads it
+    pub context: Context<'a>,
+}
+
+/// One priced decision
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Decision {
+    /// Index of the call in the auction
+    pub index: usize,
+    /// Who made it
+    pub seat: Seat,
+    /// The call made
+    pub chosen: Call,
+    /// Its EV in the decider's favour, `NAN` if the pricer found no signal
+    pub chosen_ev: f32,
+    /// The best-priced shortlisted alternative and its EV
+    pub best: Option<(Call, f32)>,
+    /// How much the best alternative out-priced the call made, 0 when nothing
+    /// did
+    pub regret: f32,
+}
+
+/// Every priced decision of one side in an auction
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PostMortem {
+    /// Our decisions in auction order
+    pub decisions: Vec<Decision>,
+}
+
+impl PostMortem {
+    /// The decision with the largest regret, if any call had one
+    #[must_use]
+    pub fn costliest(&self) -> Option<&Decision> {
+        self.decisions
+            .iter()
+            .filter(|decision| decision.regret > 0.0)
+            .max_by(|a, b| a.regret.total_cmp(&b.regret))
+    }
+
+    /// The regret summed over every decision
+    #[must_use]
+    pub fn total_regret(&self) -> f32 {
+        self.decisions.iter().map(|decision| decision.regret).sum()
+    }
+}
+
+/// Price every decision `pair`'s side made in `auction`
+///
+/// `pair` is either seat of the side; `ours` is its partnership, whose
+/// reading builds each decision's context and whose top `k` legal calls are
+/// the alternatives priced beside the call made.  `price` returns one EV per
+/// call for a position, aligned by index, in the decider's favour, with `NAN`
+/// for no signal — [`rollout`] and [`fast`] are the stock pricers.
+///
+/// # Panics
+///
+/// Panics if `auction` is not a legal sequence of calls.
+#[must_use]
+#[allow(clippy::too_many_arguments)] // the board, the side and the pricer
+pub fn post_mortem(
+    deal: &FullDeal,
+    dealer: Seat,
+    vul: AbsoluteVulnerability,
+    auction: &[Call],
+    pair: Seat,
+    ours: &Partnership,
+    k: usize,
+    mut price: impl FnMut(&Position<'_>, &[Call]) -> Vec<f32>,
+) -> PostMortem {
+    let mut prior = Auction::new();
+    let mut decisions = Vec::new();
+    for (index, &chosen) in auction.iter().enumerate() {
+        let seat = Seat::ALL[(dealer as usize + index) % 4];
+        if seat == pair || seat == pair.partner() {
+            let hand = deal[seat];
+            let relative_vul = relative(vul, seat);
+            let mut calls = vec![chosen];
+            if let Some(logits) = ours.classify(hand, relative_vul, &prior) {
+                let mut ranked: Vec<(Call, f32)> = logits
+                    .iter()
+                    .filter(|&(call, &logit)| {
+                        call != chosen && logit.is_finite() && prior.can_push(call).is_ok()
+                    })
+                    .map(|(call, &logit)| (call, logit))
+                    .collect();
+                ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+                calls.extend(ranked.into_iter().take(k).map(|(call, _)| call));
+            }
+
+            let position = Position {
+                index,
+                seat,
+                hand,
+                vul,
+                context: ours.prefixed_context(relative_vul, &auction[..index]),
+            };
+            let evs = price(&position, &calls);
+            decisions.push(judge(index, seat, &calls, &evs));
+        }
+        prior
+            .try_push(chosen)
+            .expect("a post-mortem auction is legal");
+    }
+    PostMortem { decisions }
+}
+
+/// Score the call made, `calls[0]`, against the alternatives after it
+fn judge(index: usize, seat: Seat, calls: &[Call], evs: &[f32]) -> Decision {
+    let chosen_ev = evs[0];
+    let best = calls[1..]
+        .iter()
+        .zip(&evs[1..])
+        .filter(|(_, ev)| ev.is_finite())
+        .map(|(&call, &ev)| (call, ev))
+        .max_by(|a, b| a.1.total_cmp(&b.1));
+    let regret = match best {
+        Some((_, ev)) if chosen_ev.is_finite() => (ev - chosen_ev).max(0.0),
+        _ => 0.0,
+    };
+    Decision {
+        index,
+        seat,
+        chosen: calls[0],
+        chosen_ev,
+        best,
+        regret,
+    }
+}
+
+/// A pricer by [`ev_fast`]: `ours` continues every seat over `layouts`
+/// layouts, each decision seeded from `seed` and its index
+pub fn fast(
+    ours: &Partnership,
+    layouts: usize,
+    seed: u64,
+) -> impl FnMut(&Position<'_>, &[Call]) -> Vec<f32> {
+    move |position, calls| {
+        let mut rng = StdRng::seed_from_u64(seed.wrapping_add(position.index as u64));
+        ev_fast(
+            position.hand,
+            position.seat,
+            position.vul,
+            &position.context,
+            calls,
+            ours,
+            &mut rng,
+            layouts,
+        )
+    }
+}
+
+/// A pricer by [`ev_all`][super::ev::ev_all]: `ours` and `theirs` continue
+/// their sides over `layouts` solved layouts, each decision seeded from
+/// `seed` and its index
+#[cfg(feature = "dd")]
+pub fn rollout(
+    ours: &impl Bidder,
+    theirs: &impl Bidder,
+    layouts: usize,
+    seed: u64,
+) -> impl FnMut(&Position<'_>, &[Call]) -> Vec<f32> {
+    move |position, calls| {
+        let mut rng = StdRng::seed_from_u64(seed.wrapping_add(position.index as u64));
+        super::ev::ev_all(
+            position.hand,
+            position.seat,
+            position.vul,
+            &position.context,
+            calls,
+            ours,
+            theirs,
+            &mut rng,
+            layouts,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,107 @@
+use super::*;
+use crate::american;
+use crate::bidding::agreements::Agreements;
+use contract_bridge::deck::fill_deals;
+use contract_bridge::{Bid, Builder, Level, Strain};
+
+const fn bid(level: u8, strain: Strain) -> Call {
+    Call::Bid(Bid {
+        level: Level::new(level),
+        strain,
+    })
+}
+
+/// A deal with the balanced 20-count AKQ2/KQ2/KJ2/Q32 in North
+fn deal_with_twenty_north() -> FullDeal {
+    let mut builder = Builder::new();
+    builder[Seat::North] = "AKQ2.KQ2.KJ2.Q32".parse().expect("valid test hand");
+    let partial = builder
+        .build_partial()
+        .expect("one hand is a valid partial deal");
+    fill_deals(&mut StdRng::seed_from_u64(1), partial)
+        .next()
+        .expect("the other 39 cards deal")
+}
+
+/// Regret is the best alternative's margin over the call made, floored at
+/// 0, and a call with no signal has none.
+#[test]
+fn judge_prices_regret() {
+    let calls = [Call::Pass, bid(1, Strain::Clubs), bid(1, Strain::Notrump)];
+    let decision = judge(3, Seat::East, &calls, &[-20.0, f32::NAN, 100.0]);
+    assert_eq!(decision.best, Some((bid(1, Strain::Notrump), 100.0)));
+    assert!((decision.regret - 120.0).abs() < 1e-4);
+
+    let best_already = judge(3, Seat::East, &calls, &[200.0, 50.0, 100.0]);
+    assert_eq!(best_already.regret, 0.0);
+
+    let unpriced = judge(3, Seat::East, &calls, &[f32::NAN, 50.0, 100.0]);
+    assert_eq!(unpriced.regret, 0.0);
+}
+
+/// Only our side's calls are priced, the call made is priced first, and the
+/// costliest decision is the one whose alternative out-priced it most.
+#[test]
+fn walks_only_our_decisions() {
+    let ours = american(&Agreements::default()).bind();
+    let deal = deal_with_twenty_north();
+    let auction = [
+        bid(2, Strain::Notrump),
+        Call::Pass,
+        bid(3, Strain::Notrump),
+        Call::Pass,
+        Call::Pass,
+        Call::Pass,
+    ];
+    let mut asked = Vec::new();
+    let report = post_mortem(
+        &deal,
+        Seat::North,
+        AbsoluteVulnerability::NONE,
+        &auction,
+        Seat::South,
+        &ours,
+        2,
+        |position, calls| {
+            assert_eq!(calls[0], auction[position.index]);
+            assert_eq!(position.context.auction(), &auction[..position.index]);
+            asked.push(position.index);
+            // Only South's raise had a better alternative.
+            let mut evs = vec![0.0; calls.len()];
+            if position.index == 2 {
+                evs[1..].fill(50.0);
+            }
+            evs
+        },
+    );
+
+    assert_eq!(asked, [0, 2, 4]);
+    let costliest = report.costliest().expect("alternatives out-priced a call");
+    assert_eq!(costliest.index, 2);
+    assert_eq!(costliest.seat, Seat::South);
+    assert_eq!(report.total_regret(), 50.0);
+}
+
+/// The evaluator-priced post-mortem blames a hopeless grand on the call
+/// that bid it.
+#[test]
+fn fast_pricer_blames_the_grand() {
+    let ours = american(&Agreements::default()).bind();
+    let deal = deal_with_twenty_north();
+    let auction = [bid(7, Strain::Notrump), Call::Pass, Call::Pass, Call::Pass];
+    let report = post_mortem(
+        &deal,
+        Seat::North,
+        AbsoluteVulnerability::NONE,
+        &auction,
+        Seat::North,
+        &ours,
+        3,
+        fast(&ours, 8, 0),
+    );
+
+    assert_eq!(report.decisions.len(), 2);
+    let costliest = report.costliest().expect("7NT has a better alternative");
+    assert_eq!(costliest.index, 0);
+    assert!(costliest.chosen_ev < 0.0, "7NT prices negative");
+}
@@ -0,0 +1,179 @@
+//! Precision — a strong-club system with limited 11–15 openings
+//!
+//! Precision shares `american()`'s competitive and defensive books and its
+//! responses to the limited one-level openings.  Its own structure is
+//! recompiled on top:
+//!
+//! * **`1♣` is artificial and strong**, 16+ of any shape, and every other
+//!   opening is **limited** to 11–15 (`openings`);
+//! * `1♦` is **nebulous** — two-plus diamonds, the catch-all for hands that
+//!   open nothing else — majors are five-card, `1NT` is a balanced 13–15,
+//!   `2♣` shows long clubs and `2♦` the short-diamond three-suiter;
+//! * over `1♣` a **`1♦` negative** shows 0–7 and every other response is a
+//!   game-forcing positive (`strong_club`);
+//! * the `2♣` and `2♦` openings have their own responses (`responses`), and
+//!   the 13–15 `1NT` takes [`acol`][super::acol]'s Stayman-and-transfers
+//!   structure, banded two points higher than american's.
+//!
+//! The reading learns the openings from
+//! [`strong_club`][field@crate::bidding::ReadingProfile::strong_club]: `1♣`
+//! and `2♦` are artificial, so the calls after them are decoded from their
+//! alerted rules' projections rather than read as natural suits.
+//!
+//! As for [`acol`][super::acol], the compact v6 floor has no Precision axis
+//! and is told the 2/1 regime; the range reaches it through the reading.
+
+mod openings;
+mod responses;
+mod strong_club;
+
+use super::System;
+use super::agreements::Agreements;
+use super::american::{EUROPEAN, NotrumpRange, NotrumpShape};
+use super::common::{with_floor, with_floor_v6, with_instinct_floor};
+use super::features::{CompactConfig, Config, ConventionCard};
+use super::rows::compile_into;
+
+/// Build the Precision system as one side's [`System`]
+///
+/// Bind it with [`System::bind`] and seat it with
+/// [`Table::of_systems`][super::Table::of_systems], exactly like `american()`.
+///
+/// `agreements` is read through [`precision_agreements`], which pins the knobs
+/// Precision fixes: the balanced 13–15 1NT and the strong-club reading.
+///
+/// ```
+/// use pons::precision_default;
+/// use pons::bidding::Bidder;
+/// use contract_bridge::auction::{Call, RelativeVulnerability};
+/// use contract_bridge::{Bid, Strain};
+///
+/// let partnership = precision_default().bind();
+/// let hand = "AQ32.K53.QJ4.A92".parse().unwrap(); // 16 HCP, balanced
+/// let logits = partnership
+///     .classify(hand, RelativeVulnerability::NONE, &[])
+///     .expect("an opening decision");
+/// let best = (&logits.0)
+///     .into_iter()
+///     .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+///     .map(|(call, _)| call)
+///     .unwrap();
+/// assert_eq!(best, Call::Bid(Bid::new(1, Strain::Clubs)));
+/// ```
+#[must_use]
+pub fn precision(agreements: &Agreements) -> System {
+    let agreements = precision_agreements(agreements);
+    with_floor_v6(
+        book(&agreements),
+        CompactConfig::symmetric(&ConventionCard::capture(&agreements, false)),
+        &agreements,
+    )
+}
+
+/// [`precision`] on the shipped agreements — see
+/// [`american_default`][super::american::american_default]
+#[must_use]
+pub fn precision_default() -> System {
+    precision(&Agreements::default())
+}
+
+/// [`precision`] against a **declared** opponent — see
+/// [`dutch_with_config`][super::dutch::dutch_with_config], whose caveat about
+/// a card the book does not play applies here too
+#[must_use]
+pub fn precision_with_config(agreements: &Agreements, config: Config) -> System {
+    let agreements = precision_agreements(agreements);
+    with_floor(book(&agreements), config, &agreements)
+}
+
+/// [`precision`] against a declared opponent, on the shipped v6 floor
+#[must_use]
+pub fn precision_with_card(agreements: &Agreements, theirs: &ConventionCard) -> System {
+    let agreements = precision_agreements(agreements);
+    with_floor_v6(
+        book(&agreements),
+        CompactConfig::new(&ConventionCard::capture(&agreements, false), theirs),
+        &agreements,
+    )
+}
+
+/// Precision with the deterministic **instinct** floor
+///
+/// Exactly [`precision`] but for the floor — the fully-disclosable reference,
+/// as [`american_instinct`][crate::american_instinct] is for `american()`.
+#[must_use]
+pub fn precision_instinct(agreements: &Agreements) -> System {
+    let agreements = precision_agreements(agreements);
+    with_instinct_floor(book(&agreements), &agreements)
+}
+
+/// [`precision_instinct`] on the shipped agreements — see
+/// [`american_default`][super::american::american_default]
+#[must_use]
+pub fn precision_instinct_default() -> System {
+    precision_instinct(&Agreements::default())
+}
+
+/// Build the Precision system as the authored books alone, with no floor
+///
+/// Takes a full [`american_book`][super::american::american_book] system under
+/// [`precision_agreements`] and compiles four ungated packages onto its
+/// constructive trie: `precision-openings`, `precision-strong-club`,
+/// `precision-two-level` and Acol's `acol-one-notrump`, which bands itself off
+/// the 13–15 range.
+#[must_use]
+pub fn precision_book(agreements: &Agreements) -> System {
+    book(&precision_agreements(agreements))
+}
+
+/// [`precision_book`] on the shipped agreements — see
+/// [`american_default`][super::american::american_default]
+#[must_use]
+pub fn precision_book_default() -> System {
+    precision_book(&Agreements::default())
+}
+
+/// `agreements` with the knobs Precision fixes pinned
+///
+/// The 1NT opening is a [`NotrumpShape::Balanced`] [`NotrumpRange::Medium`],
+/// and the reading takes the openings as Precision's
+/// ([`strong_club`][field@crate::bidding::ReadingProfile::strong_club]).  The
+/// 1NT responses are Acol's, so the reading is told what they are not, as
+/// [`acol_agreements`][super::acol::acol_agreements] tells it.  Reverse Drury
+/// is off: a limited `1M` needs no passed-hand brake.  Every other knob passes
+/// through.
+#[must_use]
+pub fn precision_agreements(agreements: &Agreements) -> Agreements {
+    let mut agreements = *agreements;
+    agreements.opening.notrump_shape = NotrumpShape::Balanced;
+    agreements.opening.one_notrump_offshape = false;
+    agreements.response.drury = false;
+    let reading = &mut agreements.decision.reading;
+    reading.one_notrump_range = NotrumpRange::Medium;
+    reading.strong_club = true;
+    reading.notrump_minors = EUROPEAN;
+    reading.nt_splinter = false;
+    reading.garbage_stayman = false;
+    agreements
+}
+
+/// [`precision_book`] on already-pinned agreements
+fn book(agreements: &Agreements) -> System {
+    let mut system = super::american::book(agreements);
+    // Compile after American: each package replaces the exact nodes it
+    // inherits.
+    compile_into(
+        &mut system.constructive.0,
+        agreements,
+        &[
+            openings::package(),
+            strong_club::package(),
+            responses::package(),
+            super::acol::notrump::package(),
+        ],
+    );
+    system
+}
+
+#[cfg(test)]
+mod tests;
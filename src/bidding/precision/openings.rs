@@ -0,0 +1,113 @@
+//! The Precision opening table — a strong `1♣` and limited everything else
+//!
+//! | Opening | Shows |
+//! | --- | --- |
+//! | `1♣` | 16+, any shape — artificial |
+//! | `1♦` | 11–15, two-plus diamonds, no five-card major — the catch-all |
+//! | `1♥`/`1♠` | 11–15, five-plus |
+//! | `1NT` | balanced 13–15 |
+//! | `2♣` | 11–15, six-plus clubs or five with a four-card major |
+//! | `2♦` | 11–15, short diamonds with 4-4 or 4-3 in the majors and four or five clubs |
+//! | `2♥`/`2♠` | weak, six cards |
+//!
+//! Three-level preempts are american's seven-card ones.  The limited
+//! openings leave no shape out: a hand short in diamonds without a five-card
+//! major has at least four clubs and a four-card major, so `2♣` or `2♦`
+//! takes it, and `1♦` never holds fewer than two.
+
+use crate::bidding::Alert;
+use crate::bidding::Rules;
+use crate::bidding::common::call;
+use crate::bidding::constraint::{Cons, Constraint, balanced, hcp, len, nth_seat, points};
+use crate::bidding::rows::{Package, Pattern, rows_of};
+use contract_bridge::auction::Call;
+use contract_bridge::{Strain, Suit};
+
+/// The strong `1♣` — 16+, any shape
+const STRONG_CLUB: Alert = Alert("precision:strong-club");
+/// The nebulous `1♦` — 11–15, possibly only two diamonds
+const NEBULOUS: Alert = Alert("precision:nebulous-diamond");
+/// The `2♦` three-suiter — short diamonds, 11–15
+const THREE_SUITER: Alert = Alert("precision:two-diamonds");
+
+/// The limited openings' strength
+fn limited() -> Cons<impl Constraint + Clone> {
+    points(11..=15)
+}
+
+/// The `2♦` shape: a singleton or void diamond, three or four of each major
+/// and four or five clubs
+fn three_suiter() -> Cons<impl Constraint + Clone> {
+    len(Suit::Diamonds, ..=1)
+        & len(Suit::Hearts, 3..=4)
+        & len(Suit::Spades, 3..=4)
+        & len(Suit::Clubs, 4..=5)
+}
+
+/// The opening table, shared by every seat
+///
+/// `1♣` (250) outranks everything, and the balanced `1NT` (200) the suit
+/// openings.  Among the limited suits the three-suiter (160) comes first,
+/// then the majors — spades first, unless hearts are the longer (150–155) —
+/// then long clubs (140); `1♦` (100) takes what is left.
+fn openings() -> Rules {
+    let mut rules = Rules::new()
+        .rule(call(1, Strain::Clubs), 250, points(16..))
+        .alert(STRONG_CLUB)
+        .rule(call(1, Strain::Notrump), 200, balanced() & hcp(13..=15))
+        .rule(call(2, Strain::Diamonds), 160, three_suiter() & limited())
+        .alert(THREE_SUITER)
+        .rule(
+            call(1, Strain::Spades),
+            150,
+            len(Suit::Spades, 5..) & limited(),
+        )
+        .rule(
+            call(1, Strain::Hearts),
+            155,
+            len(Suit::Hearts, 6..) & len(Suit::Spades, ..=5) & limited(),
+        )
+        .rule(
+            call(1, Strain::Hearts),
+            145,
+            len(Suit::Hearts, 5..) & limited(),
+        )
+        .rule(
+            call(2, Strain::Clubs),
+            140,
+            (len(Suit::Clubs, 6..)
+                | (len(Suit::Clubs, 5..) & (len(Suit::Hearts, 4..) | len(Suit::Spades, 4..))))
+                & limited(),
+        )
+        .rule(
+            call(1, Strain::Diamonds),
+            100,
+            len(Suit::Diamonds, 2..) & limited(),
+        )
+        .alert(NEBULOUS);
+    for major in [Suit::Hearts, Suit::Spades] {
+        rules = rules.rule(
+            call(2, Strain::from(major)),
+            120,
+            len(major, 6..=6) & points(5..=10) & !nth_seat(4),
+        );
+    }
+    for suit in Suit::ASC {
+        // Three-level preempts: a seven-card suit, not in fourth seat.
+        rules = rules.rule(
+            call(3, Strain::from(suit)),
+            90,
+            len(suit, 7..) & points(..11) & !nth_seat(4),
+        );
+    }
+    rules.rule(Call::Pass, 0, points(..11))
+}
+
+/// The Precision opening table at the empty auction
+pub(super) fn package() -> Package {
+    Package {
+        name: "precision-openings",
+        gate: |_| true,
+        entries: |_| rows_of(Pattern::node("P*"), openings()),
+    }
+}
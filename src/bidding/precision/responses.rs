@@ -0,0 +1,119 @@
+//! Responses to the limited two-level openings
+//!
+//! * **Over `2♣`** ([`over_two_clubs`]) — `3NT` on 13+, a five-card major at
+//!   the two level on 8+, forcing one round, and a raise to `3♣` on 6–10.
+//!   Opener raises the major with three and otherwise rebids `3♣`.
+//! * **Over `2♦`** ([`over_two_diamonds`]) — opener has three or four of each
+//!   major, so game is a five-card major or `3NT` on 13+, a jump in a major an
+//!   invitation on 11–12, and the weak hands sign off in two of a major — the
+//!   longer, hearts with a choice — or `3♣` with five.  Only a long diamond
+//!   suit passes.
+
+use crate::bidding::Rules;
+use crate::bidding::common::call;
+use crate::bidding::constraint::{hcp, len, points};
+use crate::bidding::rows::{Entry, Package, Pattern, rows_of};
+use contract_bridge::auction::Call;
+use contract_bridge::{Bid, Strain, Suit};
+
+/// Responder's first call over the `2♣` opening
+fn over_two_clubs() -> Rules {
+    Rules::new()
+        .rule(call(3, Strain::Notrump), 120, points(13..))
+        .rule(
+            call(2, Strain::Spades),
+            110,
+            len(Suit::Spades, 5..) & points(8..),
+        )
+        .rule(
+            call(2, Strain::Hearts),
+            105,
+            len(Suit::Hearts, 5..) & points(8..),
+        )
+        .rule(
+            call(3, Strain::Clubs),
+            90,
+            len(Suit::Clubs, 3..) & points(6..=10),
+        )
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// Opener's rebid after `2♣ - 2M`: a raise on three, else the clubs again
+fn after_two_clubs_major(major: Suit) -> Rules {
+    Rules::new()
+        .rule(call(3, Strain::from(major)), 100, len(major, 3..))
+        .rule(call(3, Strain::Clubs), 10, hcp(0..))
+}
+
+/// Responder's first call over the `2♦` three-suiter
+fn over_two_diamonds() -> Rules {
+    let mut rules = Rules::new()
+        .rule(call(3, Strain::Notrump), 100, points(13..))
+        .rule(
+            call(3, Strain::Clubs),
+            70,
+            len(Suit::Clubs, 5..) & points(..11),
+        )
+        .rule(Call::Pass, 30, len(Suit::Diamonds, 6..) & points(..11))
+        .rule(call(2, Strain::Hearts), 10, points(..11))
+        .rule(call(3, Strain::Notrump), 5, points(11..));
+    for (major, weight) in [(Suit::Hearts, 0), (Suit::Spades, 5)] {
+        let strain = Strain::from(major);
+        rules = rules
+            .rule(
+                call(4, strain),
+                115 + weight,
+                len(major, 5..) & points(13..),
+            )
+            .rule(
+                call(3, strain),
+                105 + weight,
+                len(major, 4..) & points(11..=12),
+            );
+    }
+    // The weak sign-off: spades only when longer than hearts.
+    rules
+        .rule(
+            call(2, Strain::Hearts),
+            80,
+            len(Suit::Hearts, 4..) & points(..11),
+        )
+        .rule(
+            call(2, Strain::Spades),
+            85,
+            ((len(Suit::Spades, 5..) & len(Suit::Hearts, ..=4))
+                | (len(Suit::Spades, 4..) & len(Suit::Hearts, ..=3)))
+                & points(..11),
+        )
+}
+
+/// Opener's answer to the invitation `2♦ - 3M`: game on 14+
+fn accept_two_diamonds_invite(major: Suit) -> Rules {
+    Rules::new()
+        .rule(call(4, Strain::from(major)), 100, points(14..))
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// The `2♣` and `2♦` response tables
+pub(super) fn package() -> Package {
+    Package {
+        name: "precision-two-level",
+        gate: |_| true,
+        entries: |_| {
+            let mut entries: Vec<Entry> = rows_of(Pattern::node("P* 2♣ -"), over_two_clubs());
+            entries.extend(rows_of(Pattern::node("P* 2♦ -"), over_two_diamonds()));
+            for major in [Suit::Hearts, Suit::Spades] {
+                let strain = Strain::from(major);
+                entries.extend(rows_of(
+                    Pattern::node(&format!("P* 2♣ - {} -", Bid::new(2, strain))),
+                    after_two_clubs_major(major),
+                ));
+                entries.extend(rows_of(
+                    Pattern::node(&format!("P* 2♦ - {} -", Bid::new(3, strain))),
+                    accept_two_diamonds_invite(major),
+                ));
+            }
+            entries
+        },
+    }
+}
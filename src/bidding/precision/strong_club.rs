@@ -0,0 +1,256 @@
+//! Responses to the strong `1♣`, and the auctions they open
+//!
+//! * **Responder's first call** ([`responses`]) — `1♦` is the negative, 0–7
+//!   of any shape.  Every other call is a game-forcing positive on 8+: a
+//!   five-card suit (`1♥`, `1♠`, `2♣`, `2♦`), `1NT` on 8–13 without one, and
+//!   `2NT` on 14+ without one.
+//! * **After the negative** ([`rebid_after_negative`]) — opener names a
+//!   five-card suit or shows a balanced range in notrump: `1NT` 16–18, `2NT`
+//!   19–21, `3NT` 22+.  Responder invites opposite the `1NT` on 7 and
+//!   otherwise raises a major or bids the cheapest notrump.
+//! * **After a positive** ([`rebid_after_positive`]) — the partnership is in
+//!   a game force, so neither hand passes below game: opener raises with
+//!   three, names a five-card suit or bids the cheapest notrump, and
+//!   responder ([`forcing_continuation`]) raises, rebids a six-card suit or
+//!   bids game.
+
+use crate::bidding::Alert;
+use crate::bidding::Rules;
+use crate::bidding::common::call;
+use crate::bidding::constraint::{balanced, hcp, len, points};
+use crate::bidding::rows::{Entry, Package, Pattern, rows_of};
+use contract_bridge::auction::Call;
+use contract_bridge::{Bid, Strain, Suit};
+
+/// The `1♦` negative — 0–7, any shape
+const NEGATIVE: Alert = Alert("precision:negative");
+
+/// The positive suit responses: the bid and the suit it shows, cheapest first
+const POSITIVES: [(u8, Suit); 4] = [
+    (1, Suit::Hearts),
+    (1, Suit::Spades),
+    (2, Suit::Clubs),
+    (2, Suit::Diamonds),
+];
+
+/// The cheapest level at which `strain` can be bid over `last`
+fn cheapest(last: Bid, strain: Strain) -> u8 {
+    if strain > last.strain {
+        last.level.get()
+    } else {
+        last.level.get() + 1
+    }
+}
+
+/// Responder's first call over the strong `1♣`
+///
+/// The positives prefer a major, then spades, as the opening table does.
+fn responses() -> Rules {
+    let mut rules = Rules::new()
+        .rule(call(1, Strain::Diamonds), 100, points(..8))
+        .alert(NEGATIVE)
+        .rule(call(2, Strain::Notrump), 135, points(14..))
+        .rule(call(1, Strain::Notrump), 130, points(8..=13));
+    for ((level, suit), weight) in POSITIVES.into_iter().zip([150, 155, 140, 145]) {
+        rules = rules.rule(
+            call(level, Strain::from(suit)),
+            weight,
+            len(suit, 5..) & points(8..),
+        );
+    }
+    rules
+}
+
+/// Opener's rebid after the `1♦` negative
+///
+/// Forcing, so no [`Pass`][Call::Pass] rule.  A balanced hand bids notrump by
+/// range; otherwise opener names a five-card suit, the majors first.  A
+/// 4-4-4-1 names a four-card major.
+fn rebid_after_negative() -> Rules {
+    Rules::new()
+        .rule(call(3, Strain::Notrump), 135, balanced() & hcp(22..))
+        .rule(call(2, Strain::Notrump), 130, balanced() & hcp(19..=21))
+        .rule(call(1, Strain::Notrump), 120, balanced() & hcp(..=18))
+        .rule(call(1, Strain::Spades), 110, len(Suit::Spades, 5..))
+        .rule(call(1, Strain::Hearts), 105, len(Suit::Hearts, 5..))
+        .rule(call(2, Strain::Clubs), 100, len(Suit::Clubs, 5..))
+        .rule(call(2, Strain::Diamonds), 95, len(Suit::Diamonds, 5..))
+        .rule(call(1, Strain::Hearts), 20, len(Suit::Hearts, 4..))
+        .rule(call(1, Strain::Spades), 15, len(Suit::Spades, 4..))
+        .rule(call(2, Strain::Clubs), 10, hcp(0..))
+}
+
+/// Responder's second call after `1♣ - 1♦ - 1NT`: invite on 7
+fn after_negative_notrump() -> Rules {
+    Rules::new()
+        .rule(call(2, Strain::Notrump), 100, hcp(7..))
+        .rule(
+            call(2, Strain::Hearts),
+            90,
+            len(Suit::Hearts, 5..) & hcp(..7),
+        )
+        .rule(
+            call(2, Strain::Spades),
+            95,
+            len(Suit::Spades, 5..) & hcp(..7),
+        )
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// Opener's answer to the invitation: game on 17+
+fn accept_negative_invite() -> Rules {
+    Rules::new()
+        .rule(call(3, Strain::Notrump), 100, hcp(17..))
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// Responder's second call after `1♣ - 1♦ - 1M`, which is forcing
+///
+/// A raise on three and 5–7, a five-card `1♠` over `1♥`, and `1NT` with
+/// nothing to say.
+fn after_negative_major(major: Suit) -> Rules {
+    let mut rules = Rules::new()
+        .rule(
+            call(2, Strain::from(major)),
+            110,
+            len(major, 3..) & points(5..),
+        )
+        .rule(call(1, Strain::Notrump), 10, hcp(0..));
+    if major == Suit::Hearts {
+        rules = rules.rule(
+            call(1, Strain::Spades),
+            100,
+            len(Suit::Spades, 5..) & points(5..),
+        );
+    }
+    rules
+}
+
+/// Opener's rebid after the positive `positive`, showing `shown`
+///
+/// Game forcing, so no [`Pass`][Call::Pass] rule: a raise on three, a
+/// five-card suit at its cheapest level, or the cheapest notrump — balanced,
+/// and the catch-all.  After a notrump positive there is nothing to raise.
+fn rebid_after_positive(positive: Bid, shown: Option<Suit>) -> Rules {
+    let notrump = cheapest(positive, Strain::Notrump);
+    let mut rules = Rules::new()
+        .rule(call(notrump, Strain::Notrump), 120, balanced())
+        .rule(call(notrump, Strain::Notrump), 10, hcp(0..));
+    if let Some(suit) = shown {
+        let raise = Strain::from(suit);
+        rules = rules.rule(call(cheapest(positive, raise), raise), 150, len(suit, 3..));
+    }
+    let mut weight = 130;
+    for suit in [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs] {
+        if Some(suit) == shown {
+            continue;
+        }
+        let strain = Strain::from(suit);
+        rules = rules.rule(
+            call(cheapest(positive, strain), strain),
+            weight,
+            len(suit, 5..),
+        );
+        weight -= 5;
+    }
+    rules
+}
+
+/// Responder's second call in the game force, after opener's `rebid`
+///
+/// `shown` is responder's positive suit, if any.  Never a pass below game:
+/// raise opener's suit on three — to game in a major — rebid a six-card
+/// suit, or bid notrump, game at most.  Once opener has bid game, responder
+/// passes.
+fn forcing_continuation(rebid: Bid, shown: Option<Suit>) -> Rules {
+    let game = match rebid.strain {
+        Strain::Notrump => 3,
+        Strain::Hearts | Strain::Spades => 4,
+        Strain::Clubs | Strain::Diamonds => 5,
+    };
+    if rebid.level.get() >= game {
+        return Rules::new().rule(Call::Pass, 0, hcp(0..));
+    }
+    let notrump = cheapest(rebid, Strain::Notrump).max(3);
+    let mut rules = Rules::new().rule(call(notrump, Strain::Notrump), 10, hcp(0..));
+    if rebid.strain != Strain::Notrump {
+        rules = rules.rule(call(notrump, Strain::Notrump), 100, balanced());
+    }
+    let major = matches!(rebid.strain, Strain::Hearts | Strain::Spades);
+    match rebid.strain.suit() {
+        // Opener raised: game in a major, else notrump.
+        Some(suit) if Some(suit) == shown && major => {
+            rules = rules.rule(call(game, rebid.strain), 120, hcp(0..));
+        }
+        Some(suit) if Some(suit) == shown => {}
+        Some(suit) => {
+            let level = cheapest(rebid, rebid.strain);
+            let level = if major { level.max(game) } else { level };
+            rules = rules.rule(call(level, rebid.strain), 120, len(suit, 3..));
+        }
+        None => {}
+    }
+    if let Some(suit) = shown
+        && Some(suit) != rebid.strain.suit()
+    {
+        let strain = Strain::from(suit);
+        rules = rules.rule(call(cheapest(rebid, strain), strain), 90, len(suit, 6..));
+    }
+    rules
+}
+
+/// The strong-club response table and the auctions it opens
+pub(super) fn package() -> Package {
+    Package {
+        name: "precision-strong-club",
+        gate: |_| true,
+        entries: |_| {
+            let mut entries: Vec<Entry> = rows_of(Pattern::node("P* 1♣ -"), responses());
+            entries.extend(rows_of(
+                Pattern::node("P* 1♣ - 1♦ -"),
+                rebid_after_negative(),
+            ));
+            entries.extend(rows_of(
+                Pattern::node("P* 1♣ - 1♦ - 1NT -"),
+                after_negative_notrump(),
+            ));
+            entries.extend(rows_of(
+                Pattern::node("P* 1♣ - 1♦ - 1NT - 2NT -"),
+                accept_negative_invite(),
+            ));
+            for major in [Suit::Hearts, Suit::Spades] {
+                entries.extend(rows_of(
+                    Pattern::node(&format!(
+                        "P* 1♣ - 1♦ - {} -",
+                        Bid::new(1, Strain::from(major))
+                    )),
+                    after_negative_major(major),
+                ));
+            }
+            let positives = POSITIVES
+                .into_iter()
+                .map(|(level, suit)| (Bid::new(level, Strain::from(suit)), Some(suit)))
+                .chain([1, 2].map(|level| (Bid::new(level, Strain::Notrump), None)));
+            for (positive, shown) in positives {
+                let prefix = format!("P* 1♣ - {positive} -");
+                let rebid = rebid_after_positive(positive, shown);
+                let mut made: Vec<Bid> = Vec::new();
+                for rule in rebid.rules() {
+                    if let Call::Bid(bid) = rule.call()
+                        && !made.contains(&bid)
+                    {
+                        made.push(bid);
+                    }
+                }
+                for bid in made {
+                    entries.extend(rows_of(
+                        Pattern::node(&format!("{prefix} {bid} -")),
+                        forcing_continuation(bid, shown),
+                    ));
+                }
+                entries.extend(rows_of(Pattern::node(&prefix), rebid));
+            }
+            entries
+        },
+    }
+}
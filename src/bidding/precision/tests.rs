@@ -0,0 +1,115 @@
+use super::{precision, precision_agreements};
+use crate::bidding::Bidder;
+use crate::bidding::agreements::Agreements;
+use crate::bidding::context::Context;
+use crate::bidding::inference::Inferences;
+use contract_bridge::auction::{Call, RelativeVulnerability};
+use contract_bridge::{Bid, Strain, Suit};
+
+const P: Call = Call::Pass;
+
+fn bid(level: u8, strain: Strain) -> Call {
+    Call::Bid(Bid::new(level, strain))
+}
+
+/// The Precision call after `auction` on the shipped agreements
+fn calls(auction: &[Call], hand: &str) -> Call {
+    let partnership = precision(&Agreements::default()).bind();
+    let hand = hand.parse().unwrap();
+    let logits = partnership
+        .classify(hand, RelativeVulnerability::NONE, auction)
+        .expect("a decision");
+    (&logits.0)
+        .into_iter()
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+        .map(|(call, _)| call)
+        .unwrap()
+}
+
+/// Every Precision override package preserves the declarative row invariants.
+#[test]
+fn row_package_invariants() {
+    crate::bidding::rows::assert_package_invariants(
+        &precision_agreements(&Agreements::default()),
+        &[
+            super::openings::package(),
+            super::strong_club::package(),
+            super::responses::package(),
+            crate::bidding::acol::notrump::package(),
+        ],
+    );
+}
+
+/// 16+ opens the strong club; a balanced 13–15 opens 1NT.
+#[test]
+fn the_club_is_strong() {
+    assert_eq!(calls(&[], "AQ32.K53.QJ4.A92"), bid(1, Strain::Clubs));
+    assert_eq!(calls(&[], "AQ32.K53.QJ4.J92"), bid(1, Strain::Notrump));
+}
+
+/// The limited openings: five-card majors, long clubs, the three-suiter and
+/// the nebulous diamond, which may hold two.
+#[test]
+fn limited_openings() {
+    assert_eq!(calls(&[], "AQJ32.K532.Q4.92"), bid(1, Strain::Spades));
+    assert_eq!(calls(&[], "K2.A32.32.AQJ432"), bid(2, Strain::Clubs));
+    assert_eq!(calls(&[], "AQ32.K532.2.KJ32"), bid(2, Strain::Diamonds));
+    assert_eq!(calls(&[], "AQ32.KJ32.42.Q32"), bid(1, Strain::Diamonds));
+}
+
+/// `1♦` is the negative; a five-card suit on 8+ is a positive.
+#[test]
+fn negative_and_positive_responses() {
+    let one_club = [bid(1, Strain::Clubs), P];
+    assert_eq!(
+        calls(&one_club, "J32.Q532.9432.32"),
+        bid(1, Strain::Diamonds)
+    );
+    assert_eq!(calls(&one_club, "K2.KQ832.932.J32"), bid(1, Strain::Hearts));
+    assert_eq!(
+        calls(&one_club, "K32.Q32.K932.Q32"),
+        bid(1, Strain::Notrump)
+    );
+
+    let negative = [bid(1, Strain::Clubs), P, bid(1, Strain::Diamonds), P];
+    assert_eq!(
+        calls(&negative, "AQ32.K53.QJ4.A92"),
+        bid(1, Strain::Notrump)
+    );
+    assert_eq!(calls(&negative, "AKQ32.KJ53.A4.92"), bid(1, Strain::Spades));
+}
+
+/// After a positive neither hand passes below game.
+#[test]
+fn positives_force_to_game() {
+    let positive = [bid(1, Strain::Clubs), P, bid(1, Strain::Hearts), P];
+    assert_eq!(calls(&positive, "AQ32.K53.QJ4.A92"), bid(2, Strain::Hearts));
+    let raised = [
+        bid(1, Strain::Clubs),
+        P,
+        bid(1, Strain::Hearts),
+        P,
+        bid(2, Strain::Hearts),
+        P,
+    ];
+    assert_eq!(calls(&raised, "K2.KQ832.932.J32"), bid(4, Strain::Hearts));
+}
+
+/// The reading decodes the artificial openings rather than reading suits.
+#[test]
+fn the_reading_decodes_the_artificial_openings() {
+    let agreements = precision_agreements(&Agreements::default());
+    let read = |auction: &[Call]| {
+        let context =
+            Context::new(RelativeVulnerability::NONE, auction).with_profile(agreements.decision);
+        *Inferences::read(&context).rho()
+    };
+    let strong = read(&[bid(1, Strain::Clubs)]);
+    assert!(strong.strength.points.min >= 15);
+    assert_eq!(strong.length(Suit::Clubs).min, 0);
+
+    let three_suiter = read(&[bid(2, Strain::Diamonds)]);
+    assert!(three_suiter.length(Suit::Diamonds).max <= 1);
+    assert!(three_suiter.length(Suit::Hearts).min >= 3);
+    assert!(three_suiter.strength.points.max <= 16);
+}
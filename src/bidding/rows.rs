@@ -1412,7 +1412,11 @@ pub(crate) fn compile_entries(book: &mut Trie, name: &str, entries: Vec<Entry>)
 // the entry deliberately: a *fifth* `2♣` rule at 150 is a new claim on the
 // partition and must come back for its own disjointness argument.
 #[cfg(test)]
-const KNOWN_WEIGHT_TIES: [&str; 1] = ["one-nt-base: \"P* 1NT -\" — 2♣ at weight 150, 4 rules"];
+const KNOWN_WEIGHT_TIES: [&str; 2] = [
+    "one-nt-base: \"P* 1NT -\" — 2♣ at weight 150, 4 rules",
+    // SAYC carries american's 1NT table, Stayman rules and all.
+    "sayc-one-notrump: \"P* 1NT -\" — 2♣ at weight 150, 4 rules",
+];
 
 /// Every pair of rules in one table justifying the same call at the same weight
 ///
@@ -0,0 +1,168 @@
+//! Standard American Yellow Card — the ACBL's club-standard natural system
+//!
+//! SAYC shares most of its skeleton with `american()`: five-card majors, a
+//! better minor, a 15–17 1NT with Stayman and Jacoby transfers, a strong
+//! artificial 2♣, weak twos in diamonds and both majors, Jacoby 2NT and limit
+//! raises.  It differs where a club partnership does:
+//!
+//! * a two-over-one response is **forcing one round**, not to game, and opener
+//!   grades the rebid (`responses`);
+//! * `1M - 1NT` is **non-forcing**;
+//! * the 1NT opening is **balanced** only — no 5422 or 6322 with a long minor;
+//! * over 1NT, `2♠` is **Minor Suit Stayman**, `2NT` a natural invitation, and
+//!   `3♣`/`3♦` invitational six-card minors (`notrump`).
+//!
+//! It is built like [`dutch`][super::dutch]: a full
+//! [`american_book`][super::american::american_book] with the divergent nodes
+//! recompiled on top.  Every other continuation — minor-opening responses,
+//! competition, defense — is american's; a club pair that plays something else
+//! there is not modelled.
+//!
+//! The floor's regime input has no SAYC axis, so the compact v6 floor is told
+//! the 2/1 regime with SAYC's 1NT shape.  Its game force is switched off
+//! ([`DecisionProfile::two_over_one_force`][crate::bidding::context::DecisionProfile::two_over_one_force]),
+//! so an unauthored node after a 2/1 may stop in a partscore.
+
+mod notrump;
+pub(in crate::bidding) mod responses;
+
+use super::System;
+use super::agreements::Agreements;
+use super::american::{EUROPEAN, NotrumpShape};
+use super::common::{with_floor, with_floor_v6, with_instinct_floor};
+use super::features::{CompactConfig, Config, ConventionCard};
+use super::rows::compile_into;
+
+/// Build the SAYC system as one side's [`System`]
+///
+/// Bind it with [`System::bind`] and seat it with
+/// [`Table::of_systems`][super::Table::of_systems], exactly like `american()`.
+///
+/// `agreements` is read through [`sayc_agreements`], which pins the knobs SAYC
+/// fixes: the balanced 1NT, the floor's 2/1 game force, and the reading of
+/// `1NT - 2NT`.
+///
+/// ```
+/// use pons::sayc_default;
+/// use pons::bidding::Bidder;
+/// use contract_bridge::auction::{Call, RelativeVulnerability};
+/// use contract_bridge::{Bid, Strain};
+///
+/// let partnership = sayc_default().bind();
+/// let hand = "Q2.K3.AQ4.KQ8765".parse().unwrap(); // 16 HCP, 6322
+/// let logits = partnership
+///     .classify(hand, RelativeVulnerability::NONE, &[])
+///     .expect("an opening decision");
+/// let best = (&logits.0)
+///     .into_iter()
+///     .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+///     .map(|(call, _)| call)
+///     .unwrap();
+/// assert_eq!(best, Call::Bid(Bid::new(1, Strain::Clubs)));
+/// ```
+#[must_use]
+pub fn sayc(agreements: &Agreements) -> System {
+    let agreements = sayc_agreements(agreements);
+    with_floor_v6(
+        book(&agreements),
+        CompactConfig::symmetric(&ConventionCard::capture(&agreements, false)),
+        &agreements,
+    )
+}
+
+/// [`sayc`] on the shipped agreements — see
+/// [`american_default`][super::american::american_default]
+#[must_use]
+pub fn sayc_default() -> System {
+    sayc(&Agreements::default())
+}
+
+/// [`sayc`] against a **declared** opponent — see
+/// [`dutch_with_config`][super::dutch::dutch_with_config], whose caveat about
+/// a card the book does not play applies here too
+#[must_use]
+pub fn sayc_with_config(agreements: &Agreements, config: Config) -> System {
+    let agreements = sayc_agreements(agreements);
+    with_floor(book(&agreements), config, &agreements)
+}
+
+/// [`sayc`] against a declared opponent, on the shipped v6 floor
+#[must_use]
+pub fn sayc_with_card(agreements: &Agreements, theirs: &ConventionCard) -> System {
+    let agreements = sayc_agreements(agreements);
+    with_floor_v6(
+        book(&agreements),
+        CompactConfig::new(&ConventionCard::capture(&agreements, false), theirs),
+        &agreements,
+    )
+}
+
+/// SAYC with the deterministic **instinct** floor
+///
+/// Exactly [`sayc`] but for the floor — the fully-disclosable reference, as
+/// [`american_instinct`][crate::american_instinct] is for `american()`.
+#[must_use]
+pub fn sayc_instinct(agreements: &Agreements) -> System {
+    let agreements = sayc_agreements(agreements);
+    with_instinct_floor(book(&agreements), &agreements)
+}
+
+/// [`sayc_instinct`] on the shipped agreements — see
+/// [`american_default`][super::american::american_default]
+#[must_use]
+pub fn sayc_instinct_default() -> System {
+    sayc_instinct(&Agreements::default())
+}
+
+/// Build the SAYC system as the authored books alone, with no floor
+///
+/// Takes a full [`american_book`][super::american::american_book] system under
+/// [`sayc_agreements`] and compiles two ungated row packages onto its
+/// constructive trie: `sayc-one-notrump` replaces the 1NT response table and
+/// the minor-scheme continuations, and `sayc-two-over-one` the major response
+/// tables, `1M - 1NT`, and the 2/1 auction through opener's third call.
+#[must_use]
+pub fn sayc_book(agreements: &Agreements) -> System {
+    book(&sayc_agreements(agreements))
+}
+
+/// [`sayc_book`] on the shipped agreements — see
+/// [`american_default`][super::american::american_default]
+#[must_use]
+pub fn sayc_book_default() -> System {
+    sayc_book(&Agreements::default())
+}
+
+/// `agreements` with the knobs SAYC fixes pinned
+///
+/// The 1NT opening is [`NotrumpShape::Balanced`], and the floor does not
+/// treat a 2/1 as game forcing.  The 1NT minor scheme is pinned to
+/// [`EUROPEAN`] although SAYC plays neither scheme: it is the one under which
+/// the reading walk takes `1NT - 2NT` as a balanced invitation rather than a
+/// diamond transfer.  The SAYC major responses replace american's
+/// wholesale, so Reverse Drury is off.  Every other knob passes through, so
+/// the american adjuncts SAYC inherits still answer to them.
+#[must_use]
+pub fn sayc_agreements(agreements: &Agreements) -> Agreements {
+    let mut agreements = *agreements;
+    agreements.opening.notrump_shape = NotrumpShape::Balanced;
+    agreements.decision.two_over_one_force = false;
+    agreements.response.drury = false;
+    agreements.decision.reading.notrump_minors = EUROPEAN;
+    agreements
+}
+
+/// [`sayc_book`] on already-pinned agreements
+fn book(agreements: &Agreements) -> System {
+    let mut system = super::american::book(agreements);
+    // Compile after American: these packages replace inherited exact nodes.
+    compile_into(
+        &mut system.constructive.0,
+        agreements,
+        &[notrump::package(), responses::package()],
+    );
+    system
+}
+
+#[cfg(test)]
+mod tests;
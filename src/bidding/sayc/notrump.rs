@@ -0,0 +1,145 @@
+//! SAYC responses to the 15–17 1NT — the minor-suit and invitational tier
+//!
+//! Stayman, Jacoby and Texas transfers, the quantitative 4NT and the notrump
+//! game are american's, carried verbatim.  What SAYC plays differently is
+//! the tier american spends on its minor scheme and the both-majors `3♦`:
+//!
+//! * `2♠!` is **Minor Suit Stayman** — both minors, no four-card major, game
+//!   values with shortness or slam interest;
+//! * `2NT` is a **natural invitation**, 8–9 balanced;
+//! * `3♣`/`3♦` are **invitational** — a good six-card minor (two of the top
+//!   three honors) and about 6–8, asking opener to bid 3NT with a fitting
+//!   honor.
+
+use crate::bidding::Alert;
+use crate::bidding::Rules;
+use crate::bidding::agreements::Agreements;
+use crate::bidding::american::notrump::BOTH_MAJORS;
+use crate::bidding::american::notrump_responses;
+use crate::bidding::constraint::{hcp, len, top_honors};
+use crate::bidding::rows::{Entry, Package, Pattern, rows_of};
+use contract_bridge::auction::Call;
+use contract_bridge::{Bid, Strain, Suit};
+
+/// Minor Suit Stayman — `1NT - 2♠` asks for a four-card minor
+const MINOR_STAYMAN: Alert = Alert("sayc:minor-stayman");
+
+/// Responder's first call over our 1NT
+///
+/// American's table with the active minor scheme and the both-majors `3♦`
+/// gated out, plus the SAYC tier.  Stayman (150) still owns every hand with a
+/// four-card major, and the transfers (200) every five-card major, so the new
+/// rows only see hands with neither.
+fn one_notrump_responses(agreements: &Agreements) -> Rules {
+    let no_major = len(Suit::Hearts, ..4) & len(Suit::Spades, ..4);
+    let mut rules = notrump_responses(agreements)
+        .gated_out(&[agreements.decision.reading.notrump_minors, BOTH_MAJORS])
+        // Minor Suit Stayman: 4-4 or longer in the minors, game values, and
+        // either a short major (a 3NT that may not hold) or slam interest.
+        .rule(
+            Bid::new(2, Strain::Spades),
+            140,
+            len(Suit::Clubs, 4..)
+                & len(Suit::Diamonds, 4..)
+                & no_major.clone()
+                & hcp(10..)
+                & (len(Suit::Hearts, ..=1) | len(Suit::Spades, ..=1) | hcp(14..)),
+        )
+        .alert(MINOR_STAYMAN)
+        // Natural 2NT: invitational, no four-card major.  Outranks the 3NT
+        // game force (100), which SAYC starts at 10.
+        .rule(Bid::new(2, Strain::Notrump), 110, hcp(8..=9) & no_major);
+    for minor in [Suit::Clubs, Suit::Diamonds] {
+        rules = rules.rule(
+            Bid::new(3, Strain::from(minor)),
+            120,
+            len(minor, 6..) & top_honors(minor, 2..) & hcp(6..=8),
+        );
+    }
+    rules
+}
+
+/// Opener's answer to Minor Suit Stayman
+///
+/// A four-card minor, clubs first with both; `2NT` denies one.  Forcing: no
+/// [`Pass`][Call::Pass] rule.
+fn minor_stayman_answers() -> Rules {
+    Rules::new()
+        .rule(Bid::new(3, Strain::Clubs), 110, len(Suit::Clubs, 4..))
+        .rule(Bid::new(3, Strain::Diamonds), 100, len(Suit::Diamonds, 4..))
+        .rule(Bid::new(2, Strain::Notrump), 50, hcp(0..))
+}
+
+/// Responder's placement after opener shows (or denies) a four-card minor
+///
+/// `fit` is the minor opener showed, or `None` after the `2NT` denial.  With a
+/// fit responder bids the small slam on 17+, the minor game with a short
+/// major, and otherwise settles for 3NT; without one, 6NT or 3NT on strength.
+fn minor_stayman_placement(fit: Option<Suit>) -> Rules {
+    let rules = Rules::new().rule(Bid::new(3, Strain::Notrump), 50, hcp(0..));
+    match fit {
+        Some(minor) => rules
+            .rule(Bid::new(6, Strain::from(minor)), 130, hcp(17..))
+            .rule(
+                Bid::new(5, Strain::from(minor)),
+                110,
+                len(Suit::Hearts, ..=1) | len(Suit::Spades, ..=1),
+            ),
+        None => rules.rule(Bid::new(6, Strain::Notrump), 130, hcp(18..)),
+    }
+}
+
+/// Opener's answer to the natural 2NT invitation: accept on 16–17
+fn two_notrump_answer() -> Rules {
+    Rules::new()
+        .rule(Bid::new(3, Strain::Notrump), 100, hcp(16..))
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// Opener's answer to an invitational `3♣`/`3♦`: 3NT with a fitting honor
+///
+/// Responder holds two of the top three, so one more runs the suit.
+fn minor_invite_answer(minor: Suit) -> Rules {
+    Rules::new()
+        .rule(Bid::new(3, Strain::Notrump), 100, top_honors(minor, 1..))
+        .rule(Call::Pass, 0, hcp(0..))
+}
+
+/// The SAYC 1NT tier: responder's first call and the continuations it opens
+pub(super) fn package() -> Package {
+    Package {
+        name: "sayc-one-notrump",
+        gate: |_| true,
+        entries: |agreements| {
+            let mut entries: Vec<Entry> =
+                rows_of(Pattern::node("P* 1NT -"), one_notrump_responses(agreements));
+            entries.extend(rows_of(
+                Pattern::node("P* 1NT - 2♠ -"),
+                minor_stayman_answers(),
+            ));
+            for (answer, fit) in [
+                ("3♣", Some(Suit::Clubs)),
+                ("3♦", Some(Suit::Diamonds)),
+                ("2NT", None),
+            ] {
+                entries.extend(rows_of(
+                    Pattern::node(&format!("P* 1NT - 2♠ - {answer} -")),
+                    minor_stayman_placement(fit),
+                ));
+            }
+            entries.extend(rows_of(
+                Pattern::node("P* 1NT - 2NT -"),
+                two_notrump_answer(),
+            ));
+            entries.extend(rows_of(
+                Pattern::node("P* 1NT - 3♣ -"),
+                minor_invite_answer(Suit::Clubs),
+            ));
+            entries.extend(rows_of(
+                Pattern::node("P* 1NT - 3♦ -"),
+                minor_invite_answer(Suit::Diamonds),
+            ));
+            entries
+        },
+    }
+}
@@ -0,0 +1,255 @@
+//! SAYC responses to a major — the 2/1 that forces one round, not to game
+//!
+//! Jacoby 2NT, limit raises, splinters and the rest of american's response
+//! table are carried verbatim; only the two-over-one rows change, and with them
+//! every node of the 2/1 auction that american plays as a game force:
+//!
+//! * **Responder's first call** ([`major_responses`]) — a new suit at the two
+//!   level on 10+, forcing one round.
+//! * **Opener's rebid** ([`opener_rebid`]) — graded: `2M`, `2NT` and a new suit
+//!   at the two level are minimum-range, a jump or a three-level new suit
+//!   shows extras.
+//! * **Responder's second call** ([`responder_rebid`]) over opener's
+//!   minimum-range rebids — 10–12 invites (`2NT`, `3M`, three of responder's
+//!   suit) and 13+ bids game; over opener's `2NT` and minor raise the auction
+//!   may stop.
+//! * **Opener's third call** ([`opener_after_invite`]) — accepts on 14+.
+//!
+//! After 1M - 1NT the notrump is **non-forcing**: opener passes a balanced
+//! minimum ([`rebid_after_notrump`]).
+
+use crate::bidding::Alert;
+use crate::bidding::Rules;
+use crate::bidding::agreements::Agreements;
+use crate::bidding::american::major_responses as american_major_responses;
+use crate::bidding::american::rebids::rebid_after_forcing_notrump;
+use crate::bidding::american::responses::GAME_FORCE;
+use crate::bidding::common::call;
+use crate::bidding::constraint::{balanced, hcp, len, points, support};
+use crate::bidding::rows::{Entry, Package, Pattern, expand, rows_of};
+use contract_bridge::auction::Call;
+use contract_bridge::{Bid, Level, Strain, Suit};
+
+/// SAYC two-over-one — a new suit at the two level, 10+, forcing one round
+const TWO_OVER_ONE: Alert = Alert("sayc:two-over-one");
+
+/// The suits responder can bid at the two level over `major`, cheapest first
+fn two_over_one_suits(major: Suit) -> impl Iterator<Item = Suit> {
+    [Suit::Clubs, Suit::Diamonds, Suit::Hearts]
+        .into_iter()
+        .filter(move |&suit| Strain::from(suit) < Strain::from(major))
+}
+
+/// Responses to our `1♥`/`1♠` opening
+///
+/// American's table with its game-forcing 2/1 rows gated out and SAYC's put
+/// back on 10+ points.  The suit-length floors are american's natural ones:
+/// `2♥` over `1♠` promises five, `2♣` over `1♠` may be three, the rest four.
+/// Four-card support takes the limit raise or Jacoby 2NT instead, which
+/// outrank these on weight.
+pub(in crate::bidding) fn major_responses(major: Suit, agreements: &Agreements) -> Rules {
+    let mut rules = american_major_responses(major, agreements).gated_out(&[GAME_FORCE]);
+    let mut weight = 110;
+    for suit in two_over_one_suits(major) {
+        let min_len = match suit {
+            Suit::Hearts => 5,
+            Suit::Clubs if major == Suit::Spades => 3,
+            _ => 4,
+        };
+        rules = rules
+            .rule(
+                call(2, Strain::from(suit)),
+                weight,
+                len(suit, min_len..) & points(10..) & !support(4..),
+            )
+            .alert(TWO_OVER_ONE);
+        weight -= 5;
+    }
+    rules
+}
+
+/// Opener's rebid after `1M - 1NT`, which SAYC plays non-forcing
+///
+/// American's forcing-notrump table plus a pass on a balanced minimum with
+/// only five of the major — above the three-card-minor rebids it replaces,
+/// below the six-card-major rebid.
+fn rebid_after_notrump(major: Suit, agreements: &Agreements) -> Rules {
+    rebid_after_forcing_notrump(major, agreements).rule(
+        Call::Pass,
+        95,
+        balanced() & hcp(..=14) & len(major, ..=5),
+    )
+}
+
+/// Opener's rebid after `1M - 2x`
+///
+/// Forcing one round, so no [`Pass`][Call::Pass] rule.  `2M`, `2NT` (12–14
+/// balanced) and the raise do not promise extras; the jump to `3M`, the
+/// three-level new suit and `3NT` (18–19 balanced) do.
+fn opener_rebid(major: Suit, resp: Suit) -> Rules {
+    let trump = Strain::from(major);
+    let mut rules = Rules::new()
+        .rule(call(3, trump), 170, len(major, 6..) & points(15..))
+        .rule(call(3, Strain::from(resp)), 160, support(4..))
+        .rule(call(2, trump), 140, len(major, 6..))
+        .rule(call(3, Strain::Notrump), 125, balanced() & hcp(18..=19))
+        .rule(call(2, Strain::Notrump), 120, balanced() & hcp(12..=14));
+    let mut two_level = 100;
+    for other in [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades] {
+        if other == major || other == resp {
+            continue;
+        }
+        if Strain::from(other) > Strain::from(resp) {
+            rules = rules.rule(call(2, Strain::from(other)), two_level, len(other, 4..));
+            two_level -= 5;
+        } else {
+            rules = rules.rule(
+                call(3, Strain::from(other)),
+                90,
+                len(other, 4..) & points(15..),
+            );
+        }
+    }
+    // Opener always holds five of the major.
+    rules.rule(call(2, trump), 30, len(major, 5..))
+}
+
+/// The minimum-range rebids of [`opener_rebid`], after which responder may
+/// still stop below game
+fn minimum_rebids(major: Suit, resp: Suit) -> Vec<Bid> {
+    let mut bids = vec![
+        Bid::new(2, Strain::from(major)),
+        Bid::new(2, Strain::Notrump),
+        Bid::new(3, Strain::from(resp)),
+    ];
+    bids.extend(
+        [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades]
+            .into_iter()
+            .filter(|&other| other != major && Strain::from(other) > Strain::from(resp))
+            .map(|other| Bid::new(2, Strain::from(other))),
+    );
+    bids
+}
+
+/// Responder's second call after `1M - 2x - rebid`, for a minimum-range rebid
+///
+/// 10–12 invites and 13+ bids game.  The invitations are `3M` with support
+/// (two cards opposite the six-card `2M` rebid, otherwise three), three of
+/// responder's own six-card suit, a raise of opener's second suit, and `2NT`.
+/// After `2M` or a new suit the 2/1 promised this rebid, so `2NT` is the
+/// fallback; after `2NT` or a raise of responder's suit opener has limited the
+/// hand and responder may pass.
+fn responder_rebid(major: Suit, resp: Suit, rebid: Bid) -> Rules {
+    let trump = Strain::from(major);
+    let fit = if rebid == Bid::new(2, trump) { 2 } else { 3 };
+    let mut rules = Rules::new()
+        .rule(call(4, trump), 150, len(major, fit..) & points(13..))
+        .rule(call(3, Strain::Notrump), 80, points(13..));
+    if Bid::new(3, trump) > rebid {
+        rules = rules.rule(call(3, trump), 140, len(major, fit..) & points(10..=12));
+    }
+    if Bid::new(3, Strain::from(resp)) > rebid {
+        rules = rules.rule(
+            call(3, Strain::from(resp)),
+            120,
+            len(resp, 6..) & points(10..=12),
+        );
+    }
+    if Bid::new(2, Strain::Notrump) > rebid {
+        rules = rules.rule(call(2, Strain::Notrump), 100, points(10..=12));
+    }
+    if second_suit(major, rebid) {
+        rules = rules.rule(call(3, rebid.strain), 130, support(4..) & points(10..=12));
+    }
+    if rebid == Bid::new(2, Strain::Notrump) || rebid == Bid::new(3, Strain::from(resp)) {
+        rules.rule(Call::Pass, 0, hcp(0..))
+    } else {
+        rules.rule(call(2, Strain::Notrump), 10, hcp(0..))
+    }
+}
+
+/// Whether `rebid` names a second suit of opener's at the two level
+fn second_suit(major: Suit, rebid: Bid) -> bool {
+    rebid.level == Level::new(2)
+        && rebid.strain != Strain::from(major)
+        && rebid.strain != Strain::Notrump
+}
+
+/// Responder's invitations in [`responder_rebid`] after `rebid`
+fn invitations(major: Suit, resp: Suit, rebid: Bid) -> Vec<Bid> {
+    let mut bids: Vec<Bid> = [
+        Bid::new(2, Strain::Notrump),
+        Bid::new(3, Strain::from(resp)),
+        Bid::new(3, Strain::from(major)),
+    ]
+    .into_iter()
+    .filter(|&invite| invite > rebid)
+    .collect();
+    if second_suit(major, rebid) {
+        bids.push(Bid::new(3, rebid.strain));
+    }
+    bids
+}
+
+/// Opener's third call over responder's 10–12 invitation
+///
+/// Accepts on 14+: four of a major that responder raised, otherwise 3NT.
+/// Over `2NT` a minimum with six of the major retreats to three of it.
+fn opener_after_invite(major: Suit, invite: Bid) -> Rules {
+    let rules = match invite.strain {
+        Strain::Hearts | Strain::Spades => {
+            Rules::new().rule(call(4, invite.strain), 100, hcp(14..))
+        }
+        Strain::Notrump => Rules::new()
+            .rule(call(3, Strain::Notrump), 100, hcp(14..))
+            .rule(call(3, Strain::from(major)), 90, len(major, 6..)),
+        Strain::Clubs | Strain::Diamonds => {
+            Rules::new().rule(call(3, Strain::Notrump), 100, hcp(14..))
+        }
+    };
+    rules.rule(Call::Pass, 0, hcp(0..))
+}
+
+/// The SAYC 2/1 auctions and the non-forcing 1NT response
+pub(in crate::bidding) fn package() -> Package {
+    Package {
+        name: "sayc-two-over-one",
+        gate: |_| true,
+        entries: |agreements| {
+            let mut entries: Vec<Entry> = expand(
+                "P* 1M -",
+                |_| true,
+                |b| major_responses(b.suit('M'), agreements),
+            );
+            entries.extend(expand(
+                "P* 1M - 1NT -",
+                |_| true,
+                |b| rebid_after_notrump(b.suit('M'), agreements),
+            ));
+            for major in [Suit::Hearts, Suit::Spades] {
+                for resp in two_over_one_suits(major) {
+                    let prefix = format!(
+                        "P* {} - {} -",
+                        call(1, Strain::from(major)),
+                        call(2, Strain::from(resp)),
+                    );
+                    entries.extend(rows_of(Pattern::node(&prefix), opener_rebid(major, resp)));
+                    for rebid in minimum_rebids(major, resp) {
+                        let after_rebid = format!("{prefix} {} -", Call::Bid(rebid));
+                        entries.extend(rows_of(
+                            Pattern::node(&after_rebid),
+                            responder_rebid(major, resp, rebid),
+                        ));
+                        for invite in invitations(major, resp, rebid) {
+                            entries.extend(rows_of(
+                                Pattern::node(&format!("{after_rebid} {} -", Call::Bid(invite))),
+                                opener_after_invite(major, invite),
+                            ));
+                        }
+                    }
+                }
+            }
+            entries
+        },
+    }
+}
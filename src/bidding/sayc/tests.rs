@@ -0,0 +1,117 @@
+use super::sayc;
+use crate::bidding::Bidder;
+use crate::bidding::agreements::Agreements;
+use contract_bridge::auction::{Call, RelativeVulnerability};
+use contract_bridge::{Bid, Strain};
+
+const P: Call = Call::Pass;
+
+fn bid(level: u8, strain: Strain) -> Call {
+    Call::Bid(Bid::new(level, strain))
+}
+
+/// The SAYC call after an undisturbed `auction`.
+fn responds(auction: &[Call], hand: &str) -> Call {
+    let partnership = sayc(&Agreements::default()).bind();
+    let hand = hand.parse().unwrap();
+    let logits = partnership
+        .classify(hand, RelativeVulnerability::NONE, auction)
+        .expect("a decision");
+    (&logits.0)
+        .into_iter()
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+        .map(|(call, _)| call)
+        .unwrap()
+}
+
+/// Both SAYC override packages preserve the declarative row invariants.
+#[test]
+fn row_package_invariants() {
+    crate::bidding::rows::assert_package_invariants(
+        &super::sayc_agreements(&Agreements::default()),
+        &[super::notrump::package(), super::responses::package()],
+    );
+}
+
+/// The 1NT opening is balanced only; a long minor opens the minor.
+#[test]
+fn one_notrump_is_balanced() {
+    assert_eq!(responds(&[], "AQ32.K53.QJ4.A92"), bid(1, Strain::Notrump));
+    assert_eq!(responds(&[], "Q432.KQ.K2.AK432"), bid(1, Strain::Clubs));
+    assert_eq!(responds(&[], "Q2.K3.AQ4.KQ8765"), bid(1, Strain::Clubs));
+}
+
+/// Responder's SAYC tier over 1NT, and opener's answers.
+#[test]
+fn one_notrump_responses() {
+    let one_notrump = [bid(1, Strain::Notrump), P];
+    // Minor Suit Stayman: both minors, a short major, game values.  (A
+    // 1=3=4=5 takes american's 3♠ splinter instead.)
+    assert_eq!(
+        responds(&one_notrump, "2.K32.AQ876.KJ54"),
+        bid(2, Strain::Spades)
+    );
+    // A natural 2NT invitation on a flat nine.
+    assert_eq!(
+        responds(&one_notrump, "K32.Q32.QJ32.J32"),
+        bid(2, Strain::Notrump)
+    );
+    // A good six-card minor invites at the three level.
+    assert_eq!(
+        responds(&one_notrump, "32.432.AQ8765.32"),
+        bid(3, Strain::Diamonds)
+    );
+
+    let minor_stayman = [bid(1, Strain::Notrump), P, bid(2, Strain::Spades), P];
+    assert_eq!(
+        responds(&minor_stayman, "K32.A3.KQ32.AJ32"),
+        bid(3, Strain::Clubs)
+    );
+    assert_eq!(
+        responds(&minor_stayman, "AQ32.K53.QJ4.A92"),
+        bid(2, Strain::Notrump)
+    );
+
+    let invite = [bid(1, Strain::Notrump), P, bid(2, Strain::Notrump), P];
+    assert_eq!(responds(&invite, "AQ32.K53.KJ4.Q92"), P);
+    assert_eq!(
+        responds(&invite, "AQ32.K53.QJ4.A92"),
+        bid(3, Strain::Notrump)
+    );
+}
+
+/// A 2/1 on 10–12 forces one round, and the partnership can stop below game.
+#[test]
+fn two_over_one_is_not_game_forcing() {
+    let one_heart = [bid(1, Strain::Hearts), P];
+    assert_eq!(
+        responds(&one_heart, "K32.32.AQ876.Q43"),
+        bid(2, Strain::Diamonds)
+    );
+    let two_diamonds = [bid(1, Strain::Hearts), P, bid(2, Strain::Diamonds), P];
+    assert_eq!(
+        responds(&two_diamonds, "Q3.AQ932.Q32.K32"),
+        bid(2, Strain::Notrump)
+    );
+    let minimum = [
+        bid(1, Strain::Hearts),
+        P,
+        bid(2, Strain::Diamonds),
+        P,
+        bid(2, Strain::Notrump),
+        P,
+    ];
+    assert_eq!(responds(&minimum, "K32.32.AQ876.Q43"), P);
+}
+
+/// Jacoby 2NT and the non-forcing 1NT response.
+#[test]
+fn major_raises_and_the_notrump_response() {
+    let one_spade = [bid(1, Strain::Spades), P];
+    assert_eq!(
+        responds(&one_spade, "KJ32.A32.K432.A2"),
+        bid(2, Strain::Notrump)
+    );
+    let one_notrump = [bid(1, Strain::Spades), P, bid(1, Strain::Notrump), P];
+    assert_eq!(responds(&one_notrump, "KQ432.A32.K32.32"), P);
+}
@@ -0,0 +1,249 @@
+//! Live search bidder — AI-bidder M2.3.
+//!
+//! The book says which call a hand makes; [`ev_all`] says what a call is
+//! worth.  A [`SearchBidder`] puts the two together at the table: it asks the
+//! wrapped [`Partnership`] for its logits, shortlists the top `k` legal calls,
+//! prices the shortlist with one [`ev_all`] rollout over layouts sampled from
+//! the auction (replay-read where the partnership's reading profile says so),
+//! and answers with the shortlist's logits shifted by EV.
+//!
+//! The search is costly — `layouts` double-dummy solves per searched call —
+//! so it is gated three ways:
+//!
+//! - **the trigger**: search only when the top two calls are within `δ` nats,
+//!   so a clear-cut book call is answered at book speed;
+//! - **the layout count**, the rollout's sample size;
+//! - **the time budget**: layouts are rolled out in chunks and the search
+//!   stops after the chunk that exhausts it, pricing on what it has.
+//!
+//! The rollout's continuation policy is the wrapped partnership, not the
+//! search bidder, so a search never recurses into another.  Each search seeds
+//! its own sampler from the bidder's seed, the hand and the auction, so the
+//! bidder stays a deterministic function of its inputs, as a [`Bidder`] must
+//! be for replays and A/B pairs to mean anything — unless a time budget is
+//! set.  How many chunks fit in the budget depends on the clock and the
+//! machine, so a budgeted search can price the same decision differently
+//! from one run to the next.  Leave it unset wherever results must replay.
+//!
+//! Every solve takes the global solver lock, so concurrent searches queue on
+//! it; never classify from a thread that already holds it.
+
+use super::Bidder;
+use super::array::Logits;
+use super::book::Partnership;
+use super::ev::ev_all;
+use contract_bridge::auction::{Auction, Call, RelativeVulnerability};
+use contract_bridge::{AbsoluteVulnerability, Hand, Seat};
+use core::hash::{Hash, Hasher};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::time::{Duration, Instant};
+
+/// Layouts rolled out between looks at the time budget
+const CHUNK: usize = 8;
+
+/// A [`Partnership`] that reranks its shortlist by rollout EV
+#[derive(Clone, Debug)]
+pub struct SearchBidder {
+    partnership: Partnership,
+    k: usize,
+    layouts: usize,
+    budget: Option<Duration>,
+    trigger: f32,
+    scale: f32,
+    seed: u64,
+}
+
+impl SearchBidder {
+    /// Search over `partnership` with the defaults: the top 3 calls, 32
+    /// layouts, no time budget, every decision searched, and 50 points of EV
+    /// to the nat
+    #[must_use]
+    pub const fn new(partnership: Partnership) -> Self {
+        Self {
+            partnership,
+            k: 3,
+            layouts: 32,
+            budget: None,
+            trigger: f32::INFINITY,
+            scale: 50.0,
+            seed: 0,
+        }
+    }
+
+    /// Shortlist the top `k` calls by logit
+    #[must_use]
+    pub const fn k(mut self, k: usize) -> Self {
+        self.k = k;
+        self
+    }
+
+    /// Roll each search out over `layouts` sampled layouts
+    #[must_use]
+    pub const fn layouts(mut self, layouts: usize) -> Self {
+        self.layouts = layouts;
+        self
+    }
+
+    /// Stop a search after the chunk of layouts that exhausts `budget`
+    ///
+    /// This gives up determinism: the layouts priced depend on wall-clock
+    /// time, so identical inputs can return different logits.
+    #[must_use]
+    pub const fn budget(mut self, budget: Duration) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Search only when the top two calls are within `delta` nats
+    #[must_use]
+    pub const fn trigger(mut self, delta: f32) -> Self {
+        self.trigger = delta;
+        self
+    }
+
+    /// Weigh EV at `points` points per nat of logit
+    #[must_use]
+    pub const fn scale(mut self, points: f32) -> Self {
+        self.scale = points;
+        self
+    }
+
+    /// Seed the layout sampler
+    #[must_use]
+    pub const fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// The wrapped partnership
+    #[must_use]
+    pub const fn partnership(&self) -> &Partnership {
+        &self.partnership
+    }
+
+    /// The calls a search would price: the top `k` legal calls with a finite
+    /// logit, best first
+    fn shortlist(&self, logits: &Logits, auction: &[Call]) -> Vec<(Call, f32)> {
+        let mut prior = Auction::new();
+        if prior.try_extend(auction.iter().copied()).is_err() {
+            return Vec::new();
+        }
+        let mut calls: Vec<(Call, f32)> = logits
+            .iter()
+            .filter(|&(call, &logit)| logit.is_finite() && prior.can_push(call).is_ok())
+            .map(|(call, &logit)| (call, logit))
+            .collect();
+        calls.sort_by(|a, b| b.1.total_cmp(&a.1));
+        calls.truncate(self.k);
+        calls
+    }
+
+    /// The EV of each of `calls`, `NAN` where no layout priced it
+    fn price(
+        &self,
+        hand: Hand,
+        vul: RelativeVulnerability,
+        auction: &[Call],
+        calls: &[Call],
+    ) -> Vec<f32> {
+        // The rollout is rotation-free, so seat the actor North: WE is
+        // North/South and THEY East/West.
+        let mut absolute = AbsoluteVulnerability::NONE;
+        absolute.set(
+            AbsoluteVulnerability::NS,
+            vul.contains(RelativeVulnerability::WE),
+        );
+        absolute.set(
+            AbsoluteVulnerability::EW,
+            vul.contains(RelativeVulnerability::THEY),
+        );
+        let context = self.partnership.prefixed_context(vul, auction);
+
+        let mut hasher = std::hash::DefaultHasher::new();
+        (self.seed, hand, auction).hash(&mut hasher);
+        let mut rng = StdRng::seed_from_u64(hasher.finish());
+
+        let start = Instant::now();
+        let mut sums = vec![0.0; calls.len()];
+        let mut counts = vec![0usize; calls.len()];
+        let mut rolled = 0;
+        while rolled < self.layouts {
+            let n = CHUNK.min(self.layouts - rolled);
+            let evs = ev_all(
+                hand,
+                Seat::North,
+                absolute,
+                &context,
+                calls,
+                &self.partnership,
+                &self.partnership,
+                &mut rng,
+                n,
+            );
+            for (i, ev) in evs.into_iter().enumerate() {
+                if ev.is_finite() {
+                    sums[i] += ev * n as f32;
+                    counts[i] += n;
+                }
+            }
+            rolled += n;
+            if self.budget.is_some_and(|budget| start.elapsed() >= budget) {
+                break;
+            }
+        }
+        sums.into_iter()
+            .zip(counts)
+            .map(|(sum, count)| {
+                if count > 0 {
+                    sum / count as f32
+                } else {
+                    f32::NAN
+                }
+            })
+            .collect()
+    }
+}
+
+impl Bidder for SearchBidder {
+    /// The partnership's logits, or — when the trigger fires — the
+    /// shortlist's logits plus EV / `scale`, every other call at `-∞`
+    ///
+    /// A shortlisted call the rollout could not price keeps its logit with
+    /// no EV, and a search that priced nothing returns the book's logits.
+    fn classify(&self, hand: Hand, vul: RelativeVulnerability, auction: &[Call]) -> Option<Logits> {
+        let logits = self.partnership.classify(hand, vul, auction)?;
+        let shortlist = self.shortlist(&logits, auction);
+        if shortlist.len() < 2 || shortlist[0].1 - shortlist[1].1 > self.trigger {
+            return Some(logits);
+        }
+
+        let calls: Vec<Call> = shortlist.iter().map(|&(call, _)| call).collect();
+        let evs = self.price(hand, vul, auction, &calls);
+        let Some(best) = evs
+            .iter()
+            .copied()
+            .filter(|ev| ev.is_finite())
+            .reduce(f32::max)
+        else {
+            return Some(logits);
+        };
+        let mut searched = Logits::new();
+        for ((call, logit), ev) in shortlist.into_iter().zip(evs) {
+            // Shift by EV relative to the best, so a clear loser sinks.
+            searched[call] = if ev.is_finite() {
+                logit + (ev - best) / self.scale
+            } else {
+                logit
+            };
+        }
+        Some(searched)
+    }
+
+    fn authored_at(&self, vul: RelativeVulnerability, auction: &[Call]) -> bool {
+        self.partnership.authored_at(vul, auction)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,52 @@
+use super::*;
+use crate::american_default;
+
+fn partnership() -> Partnership {
+    american_default().bind()
+}
+
+/// A 1♥/1♠ choice: five-five in the majors with a minimum opening
+fn majors() -> Hand {
+    "AQ982.KJ874.3.Q2".parse().expect("valid test hand")
+}
+
+#[test]
+fn an_untriggered_search_is_the_book() {
+    let book = partnership();
+    let hand = majors();
+    let expected = book.classify(hand, RelativeVulnerability::NONE, &[]);
+    for bidder in [
+        SearchBidder::new(book.clone()).trigger(-1.0),
+        SearchBidder::new(book.clone()).k(1),
+    ] {
+        assert_eq!(
+            bidder.classify(hand, RelativeVulnerability::NONE, &[]),
+            expected
+        );
+    }
+}
+
+#[test]
+fn a_search_answers_from_its_shortlist() {
+    let bidder = SearchBidder::new(partnership()).k(2).layouts(8).seed(3);
+    let auction = [Call::Pass];
+    let searched = bidder
+        .classify(majors(), RelativeVulnerability::WE, &auction)
+        .expect("the book covers an opening");
+    let book = bidder
+        .partnership()
+        .classify(majors(), RelativeVulnerability::WE, &auction)
+        .expect("the book covers an opening");
+
+    let shortlist = bidder.shortlist(&book, &auction);
+    assert_eq!(shortlist.len(), 2);
+    for (call, logit) in searched.iter() {
+        let listed = shortlist.iter().any(|&(c, _)| c == call);
+        assert_eq!(logit.is_finite(), listed, "{call:?}");
+    }
+    // Deterministic: the sampler is seeded from the inputs.
+    assert_eq!(
+        bidder.classify(majors(), RelativeVulnerability::WE, &auction),
+        Some(searched)
+    );
+}
@@ -13,8 +13,9 @@
 
 use super::Bidder;
 use super::array::{CALL_VARIANTS, Logits, encode_call};
-use super::book::{Partnership, System};
+use super::book::{ExplainedRule, Partnership, System};
 use super::context::relative;
+use super::trie::Provenance;
 use contract_bridge::auction::{Auction, Call};
 use contract_bridge::{AbsoluteVulnerability, FullDeal, Hand, Seat};
 
@@ -152,6 +153,30 @@ impl<N: Bidder, E: Bidder> Table<N, E> {
         }
     }
 
+    /// The North/South system
+    #[must_use]
+    pub const fn north_south(&self) -> &N {
+        &self.north_south
+    }
+
+    /// The East/West system
+    #[must_use]
+    pub const fn east_west(&self) -> &E {
+        &self.east_west
+    }
+
+    /// The dealer, who makes call 0
+    #[must_use]
+    pub const fn dealer(&self) -> Seat {
+        self.dealer
+    }
+
+    /// The table's absolute vulnerability
+    #[must_use]
+    pub const fn vul(&self) -> AbsoluteVulnerability {
+        self.vul
+    }
+
     /// The seat acting after `len` calls
     #[must_use]
     pub const fn seat_to_act(&self, len: usize) -> Seat {
@@ -257,6 +282,27 @@ impl Table<Partnership, Partnership> {
             Seat::East | Seat::West => self.east_west.infer(vul, auction),
         }
     }
+
+    /// Explain `call` made after `auction` by the seat to act
+    ///
+    /// The routing twin of [`classify`][Self::classify] for
+    /// [`Partnership::explain_call`]: the side of the seat to act explains its
+    /// own call from that seat's relative vulnerability.
+    #[must_use]
+    pub fn explain_call(
+        &self,
+        hand: Hand,
+        auction: &[Call],
+        call: Call,
+    ) -> Option<(Provenance, Option<ExplainedRule>)> {
+        let seat = self.seat_to_act(auction.len());
+        let vul = relative(self.vul, seat);
+
+        match seat {
+            Seat::North | Seat::South => self.north_south.explain_call(hand, vul, auction, call),
+            Seat::East | Seat::West => self.east_west.explain_call(hand, vul, auction, call),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,291 @@
+//! Duplicate matches between two systems
+//!
+//! The A/B measurement every system change goes through: deal a seeded run of
+//! boards, bid each at two tables — ours North/South at table A, East/West at
+//! table B — and score the difference.  A [`Match`] does the whole loop and
+//! hands back a [`Report`]: the boards as a [`Dump`] and the IMPs per board
+//! under each scorer, with a confidence interval on the mean.
+//!
+//! Only the boards whose two tables reached different contracts (or, for the
+//! single-dummy scorer, bid different auctions) are solved: a flat board
+//! swings nothing under any scorer, and the solver is the expensive half of a
+//! match.  Bidding fans across rayon's pool under the `rayon` feature;
+//! solving always runs on the calling thread, which the solver's lock wants.
+//!
+//! Three brackets price each divergent board.  Plain double-dummy
+//! ([`ns_score_contract`]) under-punishes overbids; perfect defense
+//! ([`ns_score_pd`]) doubles every failing contract and over-punishes them.
+//! The optional single-dummy bracket ([`Match::play_single_dummy`]) picks the
+//! opening lead blind from the auction and plays double-dummy after it — the
+//! realistic middle.
+
+use crate::bidding::context::relative;
+use crate::bidding::{Bidder, Partnership, System, Table};
+use crate::record::{Board, Dump, Header};
+use crate::scoring::{
+    final_contract, imps, ns_score_contract, ns_score_pd, ns_score_pd_tricks, ns_score_tricks,
+};
+use crate::single_dummy::{LeadQuestion, single_dummy_leads};
+use contract_bridge::deck::full_deal;
+use contract_bridge::{AbsoluteVulnerability, FullDeal, Seat};
+use ddss::{NonEmptyStrainFlags, Solver};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// IMPs per board under one scorer, our side's view
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bracket {
+    /// Our IMPs on each board, 0 on a flat one
+    pub imps: Vec<i64>,
+}
+
+impl Bracket {
+    /// Our IMPs over the match
+    #[must_use]
+    pub fn total(&self) -> i64 {
+        self.imps.iter().sum()
+    }
+
+    /// Mean IMPs per board and the half-width of its 95% confidence interval
+    ///
+    /// The half-width is 1.96 standard errors of the mean, and 0 for fewer
+    /// than two boards.
+    #[must_use]
+    pub fn mean_with_ci(&self) -> (f64, f64) {
+        let n = self.imps.len();
+        if n < 2 {
+            return (self.imps.first().map_or(0.0, |&x| x as f64), 0.0);
+        }
+        let mean = self.total() as f64 / n as f64;
+        let variance = self
+            .imps
+            .iter()
+            .map(|&x| (x as f64 - mean).powi(2))
+            .sum::<f64>()
+            / (n - 1) as f64;
+        (mean, 1.96 * (variance / n as f64).sqrt())
+    }
+}
+
+/// A played match
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    /// Every board with both auctions, [`Board::dd`] set on the divergent ones
+    /// and [`Board::sd`] wherever the single-dummy bracket priced them
+    pub dump: Dump,
+    /// Indices of the boards whose tables reached different contracts
+    pub divergent: Vec<usize>,
+    /// Plain double-dummy IMPs
+    pub dd: Bracket,
+    /// Perfect-defense IMPs
+    pub pd: Bracket,
+    /// Single-dummy-lead IMPs, plain and perfect-defense, when asked for
+    pub sd: Option<[Bracket; 2]>,
+}
+
+/// A seeded duplicate match: our bidder against theirs
+///
+/// Board `i` is dealt from seed `seed + i` and dealt by
+/// `Seat::ALL[i % 4]`, so two matches with one seed play the same boards
+/// whatever the systems.
+#[derive(Clone, Debug)]
+pub struct Match<A, B> {
+    ours: A,
+    theirs: B,
+    seed: u64,
+    boards: usize,
+    vulnerability: AbsoluteVulnerability,
+    labels: [String; 2],
+}
+
+impl<A, B> Match<A, B> {
+    /// A match of `boards` boards from `seed`, at no vulnerability
+    #[must_use]
+    pub fn new(ours: A, theirs: B, seed: u64, boards: usize) -> Self {
+        Self {
+            ours,
+            theirs,
+            seed,
+            boards,
+            vulnerability: AbsoluteVulnerability::NONE,
+            labels: ["ours".to_owned(), "theirs".to_owned()],
+        }
+    }
+
+    /// Play every board at `vulnerability`
+    #[must_use]
+    pub const fn vulnerability(mut self, vulnerability: AbsoluteVulnerability) -> Self {
+        self.vulnerability = vulnerability;
+        self
+    }
+
+    /// Name the two arms in the report's [`Header`]
+    #[must_use]
+    pub fn labels(mut self, ours: impl Into<String>, theirs: impl Into<String>) -> Self {
+        self.labels = [ours.into(), theirs.into()];
+        self
+    }
+
+    /// The deals, in board order
+    #[must_use]
+    pub fn deals(&self) -> Vec<FullDeal> {
+        (0..self.boards)
+            .map(|i| full_deal(&mut StdRng::seed_from_u64(self.seed.wrapping_add(i as u64))))
+            .collect()
+    }
+
+    fn header(&self) -> Header {
+        let [ours, theirs] = self.labels.clone();
+        let mut header = Header::new(ours, theirs, self.vulnerability);
+        header.seed = Some(self.seed);
+        header
+    }
+}
+
+impl<A: Bidder + Sync, B: Bidder + Sync> Match<A, B> {
+    /// Bid both tables of every board and score the double-dummy brackets
+    #[must_use]
+    pub fn play(&self) -> Report {
+        let vul = self.vulnerability;
+        let deals = self.deals();
+        let bid = |index: usize| {
+            let deal = &deals[index];
+            let dealer = Seat::ALL[index % 4];
+            let table_a = Table::new(&self.ours, &self.theirs, dealer, vul).bid_out(deal);
+            let table_b = Table::new(&self.theirs, &self.ours, dealer, vul).bid_out(deal);
+            Board::new(*deal, dealer, table_a, table_b)
+        };
+
+        #[cfg(feature = "rayon")]
+        let mut boards: Vec<Board> = {
+            use rayon::iter::{IntoParallelIterator as _, ParallelIterator as _};
+            (0..self.boards).into_par_iter().map(bid).collect()
+        };
+        #[cfg(not(feature = "rayon"))]
+        let mut boards: Vec<Board> = (0..self.boards).map(bid).collect();
+
+        let divergent: Vec<usize> = (0..boards.len())
+            .filter(|&i| boards[i].is_divergent())
+            .collect();
+        let solve: Vec<FullDeal> = divergent.iter().map(|&i| deals[i]).collect();
+        let tables = Solver::lock(None).solve_deals(&solve, NonEmptyStrainFlags::ALL);
+
+        let mut dd = vec![0; boards.len()];
+        let mut pd = vec![0; boards.len()];
+        for (&i, table) in divergent.iter().zip(&tables) {
+            let board = &mut boards[i];
+            let [a, b] = board.contracts();
+            let plain = [
+                ns_score_contract(a, table, vul),
+                ns_score_contract(b, table, vul),
+            ];
+            dd[i] = imps(plain[0] - plain[1]);
+            pd[i] = imps(ns_score_pd(a, table, vul) - ns_score_pd(b, table, vul));
+            board.dd = Some(plain);
+        }
+
+        Report {
+            dump: Dump {
+                header: self.header(),
+                boards,
+            },
+            divergent,
+            dd: Bracket { imps: dd },
+            pd: Bracket { imps: pd },
+            sd: None,
+        }
+    }
+}
+
+impl Match<Partnership, Partnership> {
+    /// A match between two systems, each bound once
+    #[must_use]
+    pub fn of_systems(ours: &System, theirs: &System, seed: u64, boards: usize) -> Self {
+        Self::new(ours.bind(), theirs.bind(), seed, boards)
+    }
+
+    /// [`play`][Self::play], then price the boards with a single-dummy lead
+    ///
+    /// The leader picks the opening lead as best over `worlds` layouts
+    /// consistent with the auction, as their own partnership reads it, and
+    /// play is double-dummy after it.  Every board whose tables bid different
+    /// auctions is priced, since the same contract reached another way can
+    /// draw another lead; the world sampler is seeded with the match's seed.
+    ///
+    /// # Panics
+    ///
+    /// If `worlds` is 0.
+    #[must_use]
+    pub fn play_single_dummy(&self, worlds: usize) -> Report {
+        let mut report = self.play();
+        let vul = self.vulnerability;
+
+        // One lead per contracted table of a board whose auctions differ.
+        let mut asked = Vec::new();
+        let mut questions = Vec::new();
+        for (i, board) in report.dump.boards.iter().enumerate() {
+            if board.table_a == board.table_b {
+                continue;
+            }
+            let auctions = [&board.table_a, &board.table_b];
+            for (t, auction) in auctions.into_iter().enumerate() {
+                let Some((contract, declarer)) = final_contract(auction, board.dealer) else {
+                    continue;
+                };
+                let leader = declarer.lho();
+                // Ours sit North/South at table A and East/West at table B.
+                let ns = t == 0;
+                let partnership = if matches!(leader, Seat::North | Seat::South) == ns {
+                    &self.ours
+                } else {
+                    &self.theirs
+                };
+                // Read up to the leader's turn: the last bid is within the
+                // final four calls, so one of these prefixes keeps it and
+                // puts the leader to act.
+                let cut = (auction.len().saturating_sub(3)..=auction.len())
+                    .find(|&len| Seat::ALL[(board.dealer as usize + len) % 4] == leader)
+                    .expect("four consecutive lengths reach every seat");
+                asked.push((i, t, contract, declarer));
+                questions.push(LeadQuestion {
+                    deal: board.deal,
+                    strain: contract.bid.strain,
+                    declarer,
+                    inferences: partnership.infer(relative(vul, leader), &auction[..cut]),
+                });
+            }
+        }
+
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let answers = if questions.is_empty() {
+            Vec::new()
+        } else {
+            single_dummy_leads(&questions, &mut rng, worlds)
+        };
+        let mut scores = vec![[[0i64; 2]; 2]; report.dump.boards.len()];
+        for (&(i, t, contract, declarer), &(_, tricks)) in asked.iter().zip(&answers) {
+            let tricks = u8::from(tricks);
+            scores[i][t] = [
+                ns_score_tricks(contract, declarer, tricks, vul),
+                ns_score_pd_tricks(contract, declarer, tricks, vul),
+            ];
+        }
+
+        let mut plain = vec![0; scores.len()];
+        let mut pd = vec![0; scores.len()];
+        for (i, board) in report.dump.boards.iter_mut().enumerate() {
+            if board.table_a == board.table_b {
+                continue;
+            }
+            let [a, b] = scores[i];
+            plain[i] = imps(a[0] - b[0]);
+            pd[i] = imps(a[1] - b[1]);
+            board.sd = Some([a[0] as f64, b[0] as f64]);
+        }
+        report.sd = Some([Bracket { imps: plain }, Bracket { imps: pd }]);
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,41 @@
+use super::*;
+use crate::{american_default, dutch_default};
+
+#[test]
+fn self_play_is_flat() {
+    let system = american_default();
+    let report = Match::of_systems(&system, &system, 11, 8).play();
+    assert_eq!(report.dump.boards.len(), 8);
+    assert_eq!(report.dump.header.seed, Some(11));
+    assert!(report.divergent.is_empty());
+    assert_eq!(report.dd.total(), 0);
+    assert_eq!(report.pd.mean_with_ci(), (0.0, 0.0));
+    assert!(report.dump.boards.iter().all(|b| b.dd.is_none()));
+}
+
+#[test]
+fn divergent_boards_are_the_solved_ones() {
+    let play = Match::of_systems(&american_default(), &dutch_default(), 3, 12)
+        .vulnerability(AbsoluteVulnerability::ALL)
+        .labels("american", "dutch");
+    let report = play.play_single_dummy(4);
+    let boards = &report.dump.boards;
+    assert_eq!(report.dump.header.our_label, "american");
+    assert_eq!(report.dump.header.vulnerability, AbsoluteVulnerability::ALL);
+
+    for (i, board) in boards.iter().enumerate() {
+        assert_eq!(board.deal, play.deals()[i]);
+        assert_eq!(board.dealer, Seat::ALL[i % 4]);
+        assert_eq!(report.divergent.contains(&i), board.dd.is_some());
+        assert_eq!(board.sd.is_some(), board.table_a != board.table_b);
+        if let Some([a, b]) = board.dd {
+            assert_eq!(report.dd.imps[i], imps(a - b));
+        } else {
+            assert_eq!(report.dd.imps[i], 0);
+            assert_eq!(report.pd.imps[i], 0);
+        }
+    }
+    let [sd, sd_pd] = report.sd.expect("single-dummy was asked for");
+    assert_eq!(sd.imps.len(), boards.len());
+    assert_eq!(sd_pd.imps.len(), boards.len());
+}
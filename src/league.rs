@@ -0,0 +1,418 @@
+//! Round-robin leagues of system variants
+//!
+//! One A/B gives a best response, not a ranking: a convention's value depends
+//! on what the opponents play.  A [`League`] plays every pair of named
+//! [`Variant`]s a [`Match`] over the same seeded deals and collects the results
+//! into a [`Payoff`] matrix, once per double-dummy bracket.  The matrix then
+//! answers three questions:
+//!
+//! - [`Payoff::ratings`] — Bradley–Terry strengths from the boards each
+//!   variant won, lost and tied, on the Elo scale with standard errors;
+//! - [`equilibrium`] — the mixed strategy neither side can exploit, solved by
+//!   fictitious play on the IMP matrix as a zero-sum game;
+//! - [`Payoff::dominated`] — the variants another variant beats or ties against
+//!   every opponent, which no mixture needs.
+//!
+//! Variants are [`Agreements`] built into a [`System`] by one constructor —
+//! [`american`] unless [`League::system`] says otherwise — so a league over
+//! one knob (each [`NotrumpDefense`], say) differs only in that knob.
+//!
+//! [`NotrumpDefense`]: crate::bidding::american::NotrumpDefense
+
+use crate::bidding::agreements::Agreements;
+use crate::bidding::{Partnership, System, american};
+use crate::duplicate::{Bracket, Match};
+use contract_bridge::AbsoluteVulnerability;
+use core::f64::consts::LN_10;
+
+/// A named set of agreements
+#[derive(Clone, Debug)]
+pub struct Variant {
+    /// How the variant is reported
+    pub label: String,
+    /// What it plays
+    pub agreements: Agreements,
+}
+
+impl Variant {
+    /// A variant playing `agreements`
+    #[must_use]
+    pub fn new(label: impl Into<String>, agreements: Agreements) -> Self {
+        Self {
+            label: label.into(),
+            agreements,
+        }
+    }
+}
+
+/// One variant's result against another, from its own side
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Pairing {
+    /// Mean IMPs per board
+    pub imps: f64,
+    /// Half-width of the 95% confidence interval on [`imps`][Self::imps]
+    pub ci: f64,
+    /// Boards won
+    pub won: usize,
+    /// Boards tied, flat ones included
+    pub tied: usize,
+    /// Boards lost
+    pub lost: usize,
+}
+
+impl Pairing {
+    fn of(bracket: &Bracket) -> Self {
+        let (imps, ci) = bracket.mean_with_ci();
+        let count = |f: fn(&i64) -> bool| bracket.imps.iter().filter(|x| f(x)).count();
+        Self {
+            imps,
+            ci,
+            won: count(|&x| x > 0),
+            tied: count(|&x| x == 0),
+            lost: count(|&x| x < 0),
+        }
+    }
+
+    /// The same result from the other side
+    #[must_use]
+    pub fn reversed(self) -> Self {
+        Self {
+            imps: -self.imps,
+            ci: self.ci,
+            won: self.lost,
+            tied: self.tied,
+            lost: self.won,
+        }
+    }
+}
+
+/// A variant's Bradley–Terry strength on the Elo scale
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rating {
+    /// Elo points, the league averaging 0
+    pub elo: f64,
+    /// The standard error of [`elo`][Self::elo]
+    pub se: f64,
+}
+
+/// Every pairing of a league under one scorer
+#[derive(Clone, Debug, PartialEq)]
+pub struct Payoff {
+    /// The variants, in the order they were entered
+    pub labels: Vec<String>,
+    /// `cells[i][j]` is variant `i` against variant `j`; the diagonal is empty
+    pub cells: Vec<Vec<Pairing>>,
+}
+
+impl Payoff {
+    /// Mean IMPs per board, row against column
+    #[must_use]
+    pub fn matrix(&self) -> Vec<Vec<f64>> {
+        self.cells
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.imps).collect())
+            .collect()
+    }
+
+    /// Bradley–Terry ratings from the boards won, lost and tied
+    ///
+    /// A tie counts half a win to each side.  Strengths are fitted by
+    /// Hunter's minorize–maximize iteration and reported as
+    /// `400 · log10(strength)`, centred on 0.  Standard errors come from the
+    /// inverse Fisher information under the same centring.  A variant that
+    /// won or lost every board has no finite rating; its strength is held
+    /// at the iteration's floor rather than diverging.
+    #[must_use]
+    pub fn ratings(&self) -> Vec<Rating> {
+        let k = self.labels.len();
+        let games = |i: usize, j: usize| {
+            let cell = &self.cells[i][j];
+            (cell.won + cell.tied + cell.lost) as f64
+        };
+        let wins: Vec<f64> = (0..k)
+            .map(|i| {
+                (0..k)
+                    .filter(|&j| j != i)
+                    .map(|j| self.cells[i][j].won as f64 + 0.5 * self.cells[i][j].tied as f64)
+                    .sum()
+            })
+            .collect();
+
+        let mut strength = vec![1.0; k];
+        for _ in 0..10_000 {
+            let mut next: Vec<f64> = (0..k)
+                .map(|i| {
+                    let denominator: f64 = (0..k)
+                        .filter(|&j| j != i)
+                        .map(|j| games(i, j) / (strength[i] + strength[j]))
+                        .sum();
+                    if denominator > 0.0 {
+                        (wins[i] / denominator).max(1e-9)
+                    } else {
+                        1.0
+                    }
+                })
+                .collect();
+            let centre = next.iter().map(|s| s.ln()).sum::<f64>() / k as f64;
+            next.iter_mut().for_each(|s| *s /= centre.exp());
+            let moved = next
+                .iter()
+                .zip(&strength)
+                .map(|(a, b)| (a.ln() - b.ln()).abs())
+                .fold(0.0, f64::max);
+            strength = next;
+            if moved < 1e-10 {
+                break;
+            }
+        }
+
+        // The Fisher information of the log-strengths is a weighted graph
+        // Laplacian; its pseudo-inverse is the covariance under centring.
+        let mut information = vec![vec![0.0; k]; k];
+        for i in 0..k {
+            for j in (0..k).filter(|&j| j != i) {
+                let p = strength[i] / (strength[i] + strength[j]);
+                let weight = games(i, j) * p * (1.0 - p);
+                information[i][i] += weight;
+                information[i][j] -= weight;
+            }
+        }
+        let covariance = pseudo_inverse(information);
+        let scale = 400.0 / LN_10;
+        (0..k)
+            .map(|i| Rating {
+                elo: scale * strength[i].ln(),
+                se: scale * covariance[i][i].max(0.0).sqrt(),
+            })
+            .collect()
+    }
+
+    /// `(dominated, by)`: each variant some other variant scores at least as
+    /// well as against every opponent, and strictly better against one
+    ///
+    /// Only domination by a single variant is found, not by a mixture.
+    #[must_use]
+    pub fn dominated(&self) -> Vec<(usize, usize)> {
+        let m = self.matrix();
+        let k = m.len();
+        (0..k)
+            .filter_map(|i| {
+                (0..k)
+                    .find(|&j| {
+                        j != i
+                            && (0..k).all(|c| m[j][c] >= m[i][c])
+                            && (0..k).any(|c| m[j][c] > m[i][c])
+                    })
+                    .map(|j| (i, j))
+            })
+            .collect()
+    }
+}
+
+/// The Moore–Penrose pseudo-inverse of a connected graph Laplacian:
+/// `(L + J/k)⁻¹ − J/k` with `J` all ones
+fn pseudo_inverse(laplacian: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    let k = laplacian.len();
+    let ones = 1.0 / k as f64;
+    let mut a: Vec<Vec<f64>> = laplacian
+        .into_iter()
+        .map(|row| row.into_iter().map(|x| x + ones).collect())
+        .collect();
+    let mut inverse: Vec<Vec<f64>> = (0..k)
+        .map(|i| (0..k).map(|j| f64::from(u8::from(i == j))).collect())
+        .collect();
+
+    // Gauss–Jordan with partial pivoting.
+    for column in 0..k {
+        let pivot = (column..k)
+            .max_by(|&x, &y| a[x][column].abs().total_cmp(&a[y][column].abs()))
+            .expect("a non-empty column");
+        a.swap(column, pivot);
+        inverse.swap(column, pivot);
+        let p = a[column][column];
+        if p.abs() < 1e-12 {
+            // Disconnected: no finite covariance.
+            return vec![vec![f64::INFINITY; k]; k];
+        }
+        for x in 0..k {
+            a[column][x] /= p;
+            inverse[column][x] /= p;
+        }
+        for row in (0..k).filter(|&row| row != column) {
+            let factor = a[row][column];
+            for x in 0..k {
+                a[row][x] -= factor * a[column][x];
+                inverse[row][x] -= factor * inverse[column][x];
+            }
+        }
+    }
+    inverse
+        .into_iter()
+        .map(|row| row.into_iter().map(|x| x - ones).collect())
+        .collect()
+}
+
+/// An approximate equilibrium of a zero-sum matrix game
+#[derive(Clone, Debug, PartialEq)]
+pub struct Equilibrium {
+    /// The row player's mixture
+    pub row: Vec<f64>,
+    /// The column player's mixture
+    pub column: Vec<f64>,
+    /// The game value, to within [`gap`][Self::gap]
+    pub value: f64,
+    /// How much a best response gains against the mixtures: 0 at an exact
+    /// equilibrium
+    pub gap: f64,
+}
+
+/// Solve the zero-sum game `matrix` (the row player maximizes) by
+/// `iterations` rounds of fictitious play
+///
+/// Each round both players best-respond to the other's history so far; the
+/// average mixtures converge to an equilibrium.  For a league's
+/// antisymmetric [`Payoff::matrix`] the value is 0 and both mixtures
+/// approach the same one.
+///
+/// # Panics
+///
+/// If `matrix` is empty or ragged, or holds a NaN.
+#[must_use]
+pub fn equilibrium(matrix: &[Vec<f64>], iterations: usize) -> Equilibrium {
+    let (rows, columns) = (matrix.len(), matrix[0].len());
+    assert!(
+        matrix.iter().all(|row| row.len() == columns),
+        "a ragged payoff matrix"
+    );
+    // Cumulative payoff of each pure strategy against the opponent's history.
+    let mut row_payoff = vec![0.0; rows];
+    let mut column_payoff = vec![0.0; columns];
+    let mut row_count = vec![0.0; rows];
+    let mut column_count = vec![0.0; columns];
+    let argmax = |v: &[f64]| -> usize {
+        v.iter()
+            .enumerate()
+            .max_by(|a, b| a.1.partial_cmp(b.1).expect("payoffs are finite"))
+            .expect("non-empty")
+            .0
+    };
+    for _ in 0..iterations.max(1) {
+        let i = argmax(&row_payoff);
+        row_count[i] += 1.0;
+        for (j, p) in column_payoff.iter_mut().enumerate() {
+            // The column player minimizes.
+            *p -= matrix[i][j];
+        }
+        let j = argmax(&column_payoff);
+        column_count[j] += 1.0;
+        for (i, p) in row_payoff.iter_mut().enumerate() {
+            *p += matrix[i][j];
+        }
+    }
+    let normalize = |counts: Vec<f64>| -> Vec<f64> {
+        let total: f64 = counts.iter().sum();
+        counts.into_iter().map(|c| c / total).collect()
+    };
+    let row = normalize(row_count);
+    let column = normalize(column_count);
+    let row_best = (0..rows)
+        .map(|i| (0..columns).map(|j| matrix[i][j] * column[j]).sum::<f64>())
+        .fold(f64::NEG_INFINITY, f64::max);
+    let column_best = (0..columns)
+        .map(|j| (0..rows).map(|i| row[i] * matrix[i][j]).sum::<f64>())
+        .fold(f64::INFINITY, f64::min);
+    Equilibrium {
+        row,
+        column,
+        value: 0.5 * (row_best + column_best),
+        gap: row_best - column_best,
+    }
+}
+
+/// A round robin: every variant against every other over the same boards
+#[derive(Clone, Debug)]
+pub struct League {
+    variants: Vec<Variant>,
+    system: fn(&Agreements) -> System,
+    seed: u64,
+    boards: usize,
+    vulnerability: AbsoluteVulnerability,
+}
+
+/// A played league
+#[derive(Clone, Debug, PartialEq)]
+pub struct Standings {
+    /// Plain double-dummy results
+    pub dd: Payoff,
+    /// Perfect-defense results
+    pub pd: Payoff,
+}
+
+impl League {
+    /// A league of `variants`, each pairing a [`Match`] of `boards` boards
+    /// from `seed`, at no vulnerability
+    #[must_use]
+    pub fn new(variants: Vec<Variant>, seed: u64, boards: usize) -> Self {
+        Self {
+            variants,
+            system: american,
+            seed,
+            boards,
+            vulnerability: AbsoluteVulnerability::NONE,
+        }
+    }
+
+    /// Build each variant's system with `system` instead of [`american`]
+    #[must_use]
+    pub fn system(mut self, system: fn(&Agreements) -> System) -> Self {
+        self.system = system;
+        self
+    }
+
+    /// Play every board at `vulnerability`
+    #[must_use]
+    pub const fn vulnerability(mut self, vulnerability: AbsoluteVulnerability) -> Self {
+        self.vulnerability = vulnerability;
+        self
+    }
+
+    /// Play every pairing once
+    ///
+    /// Pairing `(i, j)` seats variant `i` as ours; its result from `j`'s
+    /// side is the same match reversed, so `n` variants play
+    /// `n · (n - 1) / 2` matches.
+    #[must_use]
+    pub fn play(&self) -> Standings {
+        let bound: Vec<Partnership> = self
+            .variants
+            .iter()
+            .map(|v| (self.system)(&v.agreements).bind())
+            .collect();
+        let k = bound.len();
+        let mut dd = vec![vec![Pairing::default(); k]; k];
+        let mut pd = vec![vec![Pairing::default(); k]; k];
+        for i in 0..k {
+            for j in i + 1..k {
+                let report = Match::new(&bound[i], &bound[j], self.seed, self.boards)
+                    .vulnerability(self.vulnerability)
+                    .play();
+                for (cells, bracket) in [(&mut dd, &report.dd), (&mut pd, &report.pd)] {
+                    let pairing = Pairing::of(bracket);
+                    cells[i][j] = pairing;
+                    cells[j][i] = pairing.reversed();
+                }
+            }
+        }
+        let labels: Vec<String> = self.variants.iter().map(|v| v.label.clone()).collect();
+        Standings {
+            dd: Payoff {
+                labels: labels.clone(),
+                cells: dd,
+            },
+            pd: Payoff { labels, cells: pd },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,88 @@
+use super::*;
+use crate::bidding::american::NotrumpDefense;
+
+fn pairing(won: usize, tied: usize, lost: usize, imps: f64) -> Pairing {
+    Pairing {
+        imps,
+        ci: 0.0,
+        won,
+        tied,
+        lost,
+    }
+}
+
+/// Three variants: `a` beats `b` and `c`, and `b` beats `c`
+fn payoff() -> Payoff {
+    let mut cells = vec![vec![Pairing::default(); 3]; 3];
+    for (i, j, cell) in [
+        (0, 1, pairing(30, 50, 20, 0.5)),
+        (0, 2, pairing(40, 50, 10, 1.5)),
+        (1, 2, pairing(30, 50, 20, 0.25)),
+    ] {
+        cells[i][j] = cell;
+        cells[j][i] = cell.reversed();
+    }
+    Payoff {
+        labels: vec!["a".into(), "b".into(), "c".into()],
+        cells,
+    }
+}
+
+#[test]
+fn ratings_order_and_centre() {
+    let ratings = payoff().ratings();
+    assert!(ratings[0].elo > ratings[1].elo && ratings[1].elo > ratings[2].elo);
+    assert!(ratings.iter().map(|r| r.elo).sum::<f64>().abs() < 1e-6);
+    assert!(ratings.iter().all(|r| r.se > 0.0 && r.se.is_finite()));
+
+    // Two variants with a 3:1 record are ln 3 apart in strength.
+    let mut cells = vec![vec![Pairing::default(); 2]; 2];
+    cells[0][1] = pairing(75, 0, 25, 1.0);
+    cells[1][0] = cells[0][1].reversed();
+    let ratings = Payoff {
+        labels: vec!["x".into(), "y".into()],
+        cells,
+    }
+    .ratings();
+    let gap = ratings[0].elo - ratings[1].elo;
+    assert!((gap - 400.0 * 3f64.log10()).abs() < 1e-6, "{gap}");
+}
+
+#[test]
+fn dominance_and_equilibrium() {
+    let payoff = payoff();
+    // `a` scores at least as well as `b` and `c` against everyone.
+    assert_eq!(payoff.dominated(), [(1, 0), (2, 0)]);
+    let nash = equilibrium(&payoff.matrix(), 10_000);
+    assert!(nash.row[0] > 0.99 && nash.value.abs() < 0.01, "{nash:?}");
+
+    // Rock–paper–scissors: nothing dominated, uniform equilibrium.
+    let rps = vec![
+        vec![0.0, -1.0, 1.0],
+        vec![1.0, 0.0, -1.0],
+        vec![-1.0, 1.0, 0.0],
+    ];
+    let nash = equilibrium(&rps, 100_000);
+    assert!(nash.value.abs() < 0.01 && nash.gap < 0.02, "{nash:?}");
+    assert!(nash.row.iter().all(|p| (p - 1.0 / 3.0).abs() < 0.05));
+}
+
+#[test]
+fn a_small_league_is_antisymmetric() {
+    let variants: Vec<Variant> = [NotrumpDefense::Natural, NotrumpDefense::AlwaysPass]
+        .into_iter()
+        .map(|defense| {
+            let mut agreements = Agreements::default();
+            agreements.decision.reading.notrump_defense = defense;
+            Variant::new(format!("{defense:?}"), agreements)
+        })
+        .collect();
+    let standings = League::new(variants, 5, 8).play();
+    for payoff in [&standings.dd, &standings.pd] {
+        assert_eq!(payoff.labels, ["Natural", "AlwaysPass"]);
+        let (ab, ba) = (payoff.cells[0][1], payoff.cells[1][0]);
+        assert_eq!(ab.reversed(), ba);
+        assert_eq!(ab.won + ab.tied + ab.lost, 8);
+        assert_eq!(payoff.cells[0][0], Pairing::default());
+    }
+}
@@ -15,27 +15,52 @@
 
 /// Bidding in contract bridge
 pub mod bidding;
+/// Seeded duplicate matches between two systems, scored in IMPs
+#[cfg(feature = "dd")]
+pub mod duplicate;
 /// GIB hand-record format (deal + cached double-dummy table)
 #[cfg(feature = "dd")]
 pub mod gib;
+/// Round-robin leagues of system variants: payoffs, ratings, equilibria
+#[cfg(feature = "dd")]
+pub mod league;
+/// BBO LIN hand records with alerts and play
+pub mod lin;
+/// PBN (Portable Bridge Notation) hand records
+#[cfg(feature = "dd")]
+pub mod pbn;
 /// Compact binary DD database format (`.pdd`)
 #[cfg(feature = "dd")]
 pub mod pdd;
+/// Board records: a match as data, with JSON and JSONL files
+pub mod record;
 pub mod scoring;
+/// A board in play: auction, lead and play, undo and claim, for any front end
+pub mod session;
 /// Single-dummy trick estimation by Monte-Carlo double-dummy
 #[cfg(feature = "dd")]
 pub mod single_dummy;
 /// Statistics
 #[cfg(feature = "dd")]
 pub mod stats;
+/// Simulated pairs tournaments: movements, a field of systems, standings
+#[cfg(feature = "dd")]
+pub mod tournament;
+/// Knob tuning by sequential A/B testing
+#[cfg(feature = "dd")]
+pub mod tune;
 
 pub use bidding::{
     Alert, Bidder, Competitive, Constructive, Context, Defensive, OrElse, Partnership, Phase,
-    Rules, System, Table, Trie, Versus, american, american_book, american_book_default,
-    american_default, american_floor, american_floor_default, american_instinct,
-    american_instinct_default, american_with_card, american_with_config, dutch, dutch_book,
-    dutch_book_default, dutch_default, dutch_instinct, dutch_instinct_default, dutch_with_card,
-    dutch_with_config, instinct,
+    Rules, System, Table, Trie, Versus, acol, acol_book, acol_book_default, acol_default,
+    acol_instinct, acol_instinct_default, acol_with_card, acol_with_config, american,
+    american_book, american_book_default, american_default, american_floor, american_floor_default,
+    american_instinct, american_instinct_default, american_with_card, american_with_config, dutch,
+    dutch_book, dutch_book_default, dutch_default, dutch_instinct, dutch_instinct_default,
+    dutch_with_card, dutch_with_config, instinct, precision, precision_book,
+    precision_book_default, precision_default, precision_instinct, precision_instinct_default,
+    precision_with_card, precision_with_config, sayc, sayc_book, sayc_book_default, sayc_default,
+    sayc_instinct, sayc_instinct_default, sayc_with_card, sayc_with_config,
 };
 #[cfg(feature = "dd")]
 pub use single_dummy::{
@@ -0,0 +1,555 @@
+//! BBO LIN records, read and written.
+//!
+//! LIN is Bridge Base Online's hand-record format: a flat run of
+//! `key|value|` pairs, one command each, that the BBO hand viewer replays in
+//! order.  A board is a handful of those commands; this module maps one board
+//! to a [`Record`]:
+//!
+//! - `md|` — the dealer digit (`1` = South, clockwise) and the hands from
+//!   South, the last of which may be left out and is then the remainder.
+//! - `sv|` — the vulnerability (`o`/`0`, `n`, `e`, `b`).
+//! - `mb|` — one call each; a trailing `!` marks it alerted, and an `an|`
+//!   straight after it is its explanation ([`Annotation`]).
+//! - `pc|` — one card each, in the order played.
+//! - `mc|` — a claim, as declarer's total tricks.
+//! - `pn|`, `ah|Board n|`, `qx|o1|` — the players and the board number.
+//!
+//! Everything else (`st|`, `rh|`, `pg|`, `nt|`, `sk|`, …) is viewer
+//! presentation and is skipped on read.  A file may hold several boards:
+//! `qx|` or a second `md|` starts the next one, and players carry over, as in
+//! vugraph archives.
+//!
+//! The other direction is the point of [`Record::bid_out`]: bid a deal out at
+//! a [`Table`] and annotate each call with the rule that chose it
+//! ([`Partnership::explain_call`]), so a bot auction opens in the BBO viewer
+//! ([`Record::handviewer_url`]) with its alerts and explanations next to the
+//! real table's.
+
+use crate::bidding::{Partnership, Table};
+use crate::scoring::{board_dealer, board_vulnerability};
+use contract_bridge::auction::{Auction, Call, IllegalCall};
+use contract_bridge::{AbsoluteVulnerability, Builder, Card, FullDeal, Hand, Rank, Seat, Suit};
+use core::fmt::{self, Write as _};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+use thiserror::Error;
+
+/// The seats in LIN's order: `md|` hands, `pn|` names, and dealer digits
+/// `1..=4` all start from South.
+const LIN_SEATS: [Seat; 4] = [Seat::South, Seat::West, Seat::North, Seat::East];
+
+/// An alert and/or explanation attached to one call
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Annotation {
+    /// Whether the call was alerted (`mb|…!|`)
+    pub alerted: bool,
+    /// The explanation (`an|…|`), empty if none was given
+    pub text: String,
+}
+
+/// One board of a LIN file: the deal, the auction with its alerts, and the
+/// play as far as it was recorded
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    /// Board number (`ah|Board n|` or the digits of `qx|`)
+    pub board: Option<u32>,
+    /// Player names indexed by [`Seat`]; empty where unknown
+    pub players: [String; 4],
+    /// The dealer, who makes the first call of [`auction`](Self::auction)
+    pub dealer: Seat,
+    /// Absolute vulnerability of the board
+    pub vul: AbsoluteVulnerability,
+    /// The four hands
+    pub deal: FullDeal,
+    /// The calls from the dealer; need not have ended
+    pub auction: Auction,
+    /// Alerts and explanations keyed by the index of the call they annotate
+    pub annotations: BTreeMap<usize, Annotation>,
+    /// The cards played, in order
+    pub play: Vec<Card>,
+    /// Declarer's total tricks by claim (`mc|`)
+    pub claim: Option<u8>,
+}
+
+impl Record {
+    /// A board with nothing bid or played yet
+    #[must_use]
+    pub const fn new(deal: FullDeal, dealer: Seat, vul: AbsoluteVulnerability) -> Self {
+        Self {
+            board: None,
+            players: [String::new(), String::new(), String::new(), String::new()],
+            dealer,
+            vul,
+            deal,
+            auction: Auction::new(),
+            annotations: BTreeMap::new(),
+            play: Vec::new(),
+            claim: None,
+        }
+    }
+
+    /// Duplicate board `board`, its dealer and vulnerability taken from the
+    /// standard rotation
+    #[must_use]
+    pub const fn numbered(board: u32, deal: FullDeal) -> Self {
+        let mut record = Self::new(deal, board_dealer(board), board_vulnerability(board));
+        record.board = Some(board);
+        record
+    }
+
+    /// Bid `deal` out at `table`, annotating every rule-backed call
+    ///
+    /// The auction is [`Table::bid_out`]'s.  Each call the books attribute to
+    /// a rule gets that rule's prose as its explanation, prefixed with the
+    /// rule's label when it has one, and is alerted when the rule carries an
+    /// [`Alert`](crate::bidding::Alert).  Calls from a learned floor, or that
+    /// only the legality filter chose, are left bare.
+    #[must_use]
+    pub fn bid_out(table: &Table<Partnership, Partnership>, deal: FullDeal) -> Self {
+        let mut record = Self::new(deal, table.dealer(), table.vul());
+        record.auction = table.bid_out(&deal);
+        for (index, &call) in record.auction.iter().enumerate() {
+            let hand = deal[table.seat_to_act(index)];
+            let Some((_, Some(rule))) = table.explain_call(hand, &record.auction[..index], call)
+            else {
+                continue;
+            };
+            let text = if rule.label.is_empty() {
+                rule.description
+            } else {
+                format!("{}: {}", rule.label, rule.description)
+            };
+            record.annotations.insert(
+                index,
+                Annotation {
+                    alerted: rule.alert.is_some(),
+                    text,
+                },
+            );
+        }
+        record
+    }
+
+    /// A link that opens this board in the BBO hand viewer
+    #[must_use]
+    pub fn handviewer_url(&self) -> String {
+        let mut url = String::from("https://www.bridgebase.com/tools/handviewer.html?lin=");
+        for byte in self.to_string().bytes() {
+            if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+                url.push(char::from(byte));
+            } else {
+                let _ = write!(url, "%{byte:02X}");
+            }
+        }
+        url
+    }
+}
+
+/// What went wrong in a LIN file
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The text ends inside a `key|value|` pair
+    #[error("unterminated command")]
+    Unterminated,
+    /// A board has calls, cards, or a vulnerability but no `md|`
+    #[error("board has no deal")]
+    MissingDeal,
+    /// An `md|` value is not a dealer digit and three or four hands
+    #[error("invalid deal {0:?}")]
+    InvalidDeal(String),
+    /// An `sv|` value is not a vulnerability
+    #[error("invalid vulnerability {0:?}")]
+    InvalidVulnerability(String),
+    /// An `mb|` value is not a call
+    #[error("invalid call {0:?}")]
+    InvalidCall(String),
+    /// A call breaks the laws of the auction
+    #[error(transparent)]
+    IllegalCall(#[from] IllegalCall),
+    /// An `an|` comes before any call
+    #[error("explanation without a call")]
+    DanglingAnnotation,
+    /// A `pc|` value is not a card
+    #[error("invalid card {0:?}")]
+    InvalidCard(String),
+    /// An `mc|` value is not a trick count
+    #[error("invalid claim {0:?}")]
+    InvalidClaim(String),
+}
+
+/// A LIN syntax or content error, located by command
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[error("command {command}: {kind}")]
+pub struct Error {
+    /// 1-based index of the `key|value|` pair the error was found in
+    pub command: usize,
+    /// What is wrong
+    pub kind: ErrorKind,
+}
+
+/// The LIN spelling of a call: `p`, `d`, `r`, or a bid like `1N`
+fn call_str(call: Call) -> String {
+    match call {
+        Call::Pass => "p".to_owned(),
+        Call::Double => "d".to_owned(),
+        Call::Redouble => "r".to_owned(),
+        Call::Bid(bid) => format!("{}{}", bid.level, bid.strain.letter()),
+    }
+}
+
+fn parse_call(value: &str) -> Option<Call> {
+    match value.to_ascii_lowercase().as_str() {
+        "p" | "pass" => Some(Call::Pass),
+        "d" | "x" | "dbl" => Some(Call::Double),
+        "r" | "xx" | "rdbl" => Some(Call::Redouble),
+        other => other.to_ascii_uppercase().parse().ok(),
+    }
+}
+
+/// A card as LIN writes it, suit letter then rank letter
+fn card_str(card: Card) -> String {
+    format!("{}{}", card.suit.letter(), card.rank.letter())
+}
+
+fn parse_card(value: &str) -> Option<Card> {
+    value.to_ascii_uppercase().parse().ok()
+}
+
+fn parse_vul(value: &str) -> Option<AbsoluteVulnerability> {
+    match value.to_ascii_lowercase().as_str() {
+        "o" | "0" | "-" => Some(AbsoluteVulnerability::NONE),
+        "n" => Some(AbsoluteVulnerability::NS),
+        "e" => Some(AbsoluteVulnerability::EW),
+        "b" => Some(AbsoluteVulnerability::ALL),
+        _ => None,
+    }
+}
+
+const fn vul_str(vul: AbsoluteVulnerability) -> &'static str {
+    match vul.bits() {
+        0 => "o",
+        1 => "n",
+        2 => "e",
+        _ => "b",
+    }
+}
+
+/// One LIN hand, `S…H…D…C…` with suits in any order
+fn parse_hand(text: &str) -> Option<Hand> {
+    let mut hand = Hand::EMPTY;
+    let mut suit = None;
+    for c in text.chars() {
+        let c = c.to_ascii_uppercase();
+        match c {
+            'S' => suit = Some(Suit::Spades),
+            'H' => suit = Some(Suit::Hearts),
+            'D' => suit = Some(Suit::Diamonds),
+            'C' => suit = Some(Suit::Clubs),
+            _ => {
+                let rank: Rank = c.to_string().parse().ok()?;
+                if !hand.insert(Card { suit: suit?, rank }) {
+                    return None;
+                }
+            }
+        }
+    }
+    Some(hand)
+}
+
+fn hand_str(hand: Hand) -> String {
+    let mut text = String::with_capacity(17);
+    for suit in Suit::DESC {
+        text.push(suit.letter());
+        for rank in hand[suit] {
+            text.push(rank.letter());
+        }
+    }
+    text
+}
+
+/// Parse an `md|` value into the dealer and the deal
+fn parse_deal(value: &str) -> Option<(Seat, FullDeal)> {
+    let value = value.trim();
+    let digit = value.chars().next()?.to_digit(10)?;
+    let dealer = *LIN_SEATS.get(usize::try_from(digit).ok()?.checked_sub(1)?)?;
+    let hands: Vec<&str> = value[1..].split(',').collect();
+    if !(3..=4).contains(&hands.len()) {
+        return None;
+    }
+    let mut builder = Builder::new();
+    let mut seen = Hand::EMPTY;
+    for (&seat, text) in LIN_SEATS.iter().zip(&hands) {
+        builder[seat] = parse_hand(text)?;
+        seen |= builder[seat];
+    }
+    // The last hand is often omitted, or present but empty.
+    let last = LIN_SEATS[3];
+    if builder[last].is_empty() {
+        builder[last] = Hand::ALL - seen;
+    }
+    Some((dealer, builder.build_full().ok()?))
+}
+
+/// A board under construction while reading
+struct Draft {
+    board: Option<u32>,
+    players: [String; 4],
+    deal: Option<(Seat, FullDeal)>,
+    vul: Option<AbsoluteVulnerability>,
+    auction: Auction,
+    annotations: BTreeMap<usize, Annotation>,
+    play: Vec<Card>,
+    claim: Option<u8>,
+    /// The first command of the board, for [`ErrorKind::MissingDeal`]
+    start: usize,
+}
+
+impl Draft {
+    const fn new(players: [String; 4], start: usize) -> Self {
+        Self {
+            board: None,
+            players,
+            deal: None,
+            vul: None,
+            auction: Auction::new(),
+            annotations: BTreeMap::new(),
+            play: Vec::new(),
+            claim: None,
+            start,
+        }
+    }
+
+    /// Whether anything but the inherited players has been read
+    fn is_empty(&self) -> bool {
+        self.deal.is_none()
+            && self.vul.is_none()
+            && self.auction.is_empty()
+            && self.play.is_empty()
+            && self.claim.is_none()
+    }
+
+    fn finish(self) -> Result<Record, Error> {
+        let (dealer, deal) = self.deal.ok_or(Error {
+            command: self.start,
+            kind: ErrorKind::MissingDeal,
+        })?;
+        Ok(Record {
+            board: self.board,
+            players: self.players,
+            dealer,
+            vul: self.vul.unwrap_or_else(|| {
+                self.board
+                    .map_or(AbsoluteVulnerability::NONE, board_vulnerability)
+            }),
+            deal,
+            auction: self.auction,
+            annotations: self.annotations,
+            play: self.play,
+            claim: self.claim,
+        })
+    }
+}
+
+/// Parse every board of a LIN file
+///
+/// # Errors
+///
+/// Returns the first syntax or content error, with the index of the
+/// offending command.
+pub fn parse(text: &str) -> Result<Vec<Record>, Error> {
+    let mut records = Vec::new();
+    let mut draft = Draft::new(Default::default(), 1);
+    let mut fields = text.split('|');
+    let mut command = 0;
+    while let Some(key) = fields.next() {
+        let key = key.trim();
+        if key.is_empty() && fields.clone().next().is_none() {
+            break;
+        }
+        command += 1;
+        let fail = |kind| Error { command, kind };
+        let value = fields.next().ok_or_else(|| fail(ErrorKind::Unterminated))?;
+
+        let starts_board = match key.to_ascii_lowercase().as_str() {
+            "qx" => true,
+            "md" => draft.deal.is_some(),
+            _ => false,
+        };
+        if starts_board {
+            let players = draft.players.clone();
+            if !draft.is_empty() {
+                let done = core::mem::replace(&mut draft, Draft::new(players, command));
+                records.push(done.finish()?);
+            } else {
+                draft.start = command;
+            }
+        }
+
+        match key.to_ascii_lowercase().as_str() {
+            "qx" => {
+                let digits: String = value.chars().filter(char::is_ascii_digit).collect();
+                draft.board = digits.parse().ok();
+            }
+            "ah" => {
+                if let Some(board) = value
+                    .trim()
+                    .strip_prefix("Board")
+                    .and_then(|n| n.trim().parse().ok())
+                {
+                    draft.board = Some(board);
+                }
+            }
+            "pn" => {
+                // Vugraph headers may list eight names, two tables' worth;
+                // the first four are this table's.
+                for (&seat, name) in LIN_SEATS.iter().zip(value.split(',')) {
+                    draft.players[seat as usize] = name.trim().to_owned();
+                }
+            }
+            "md" => {
+                let deal = parse_deal(value)
+                    .ok_or_else(|| fail(ErrorKind::InvalidDeal(value.to_owned())))?;
+                draft.deal = Some(deal);
+            }
+            "sv" => {
+                let vul = parse_vul(value.trim())
+                    .ok_or_else(|| fail(ErrorKind::InvalidVulnerability(value.to_owned())))?;
+                draft.vul = Some(vul);
+            }
+            "mb" => {
+                let token = value.trim();
+                let (token, alerted) = token
+                    .strip_suffix('!')
+                    .map_or((token, false), |token| (token, true));
+                let call = parse_call(token)
+                    .ok_or_else(|| fail(ErrorKind::InvalidCall(value.to_owned())))?;
+                draft
+                    .auction
+                    .try_push(call)
+                    .map_err(|e| fail(ErrorKind::from(e)))?;
+                if alerted {
+                    draft.annotations.insert(
+                        draft.auction.len() - 1,
+                        Annotation {
+                            alerted,
+                            text: String::new(),
+                        },
+                    );
+                }
+            }
+            "an" => {
+                let index = draft
+                    .auction
+                    .len()
+                    .checked_sub(1)
+                    .ok_or_else(|| fail(ErrorKind::DanglingAnnotation))?;
+                draft.annotations.entry(index).or_default().text = value.trim().to_owned();
+            }
+            "pc" => {
+                let card = parse_card(value.trim())
+                    .ok_or_else(|| fail(ErrorKind::InvalidCard(value.to_owned())))?;
+                draft.play.push(card);
+            }
+            "mc" => {
+                let tricks = value
+                    .trim()
+                    .parse()
+                    .ok()
+                    .filter(|&tricks| tricks <= 13)
+                    .ok_or_else(|| fail(ErrorKind::InvalidClaim(value.to_owned())))?;
+                draft.claim = Some(tricks);
+            }
+            _ => {}
+        }
+    }
+    if !draft.is_empty() {
+        records.push(draft.finish()?);
+    }
+    Ok(records)
+}
+
+/// Load a LIN file
+///
+/// # Errors
+///
+/// I/O errors, and malformed content as [`io::ErrorKind::InvalidData`].
+pub fn load(path: impl AsRef<Path>) -> io::Result<Vec<Record>> {
+    let text = std::fs::read_to_string(path)?;
+    parse(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Write records, one board per line
+///
+/// # Errors
+///
+/// Propagates I/O errors from `w`.
+pub fn write(mut w: impl io::Write, records: &[Record]) -> io::Result<()> {
+    for record in records {
+        writeln!(w, "{record}")?;
+    }
+    Ok(())
+}
+
+/// Strip the field separator from free text so it cannot end its command
+fn sanitize(text: &str) -> String {
+    text.replace('|', "/")
+}
+
+impl fmt::Display for Record {
+    /// The board as one line of LIN commands, in the order BBO writes them
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.players.iter().any(|name| !name.is_empty()) {
+            let names: Vec<String> = LIN_SEATS
+                .iter()
+                .map(|&seat| sanitize(&self.players[seat as usize]).replace(',', " "))
+                .collect();
+            write!(f, "pn|{}|", names.join(","))?;
+        }
+        f.write_str("st||")?;
+        let digit = LIN_SEATS
+            .iter()
+            .position(|&seat| seat == self.dealer)
+            .map_or(1, |i| i + 1);
+        let hands: Vec<String> = LIN_SEATS
+            .iter()
+            .map(|&seat| hand_str(self.deal[seat]))
+            .collect();
+        write!(f, "md|{digit}{}|rh||", hands.join(","))?;
+        if let Some(board) = self.board {
+            write!(f, "ah|Board {board}|")?;
+        }
+        write!(f, "sv|{}|", vul_str(self.vul))?;
+
+        for (index, &call) in self.auction.iter().enumerate() {
+            let annotation = self.annotations.get(&index);
+            let alert = if annotation.is_some_and(|a| a.alerted) {
+                "!"
+            } else {
+                ""
+            };
+            write!(f, "mb|{}{alert}|", call_str(call))?;
+            if let Some(annotation) = annotation.filter(|a| !a.text.is_empty()) {
+                write!(f, "an|{}|", sanitize(&annotation.text))?;
+            }
+        }
+        if !self.auction.is_empty() {
+            f.write_str("pg||")?;
+        }
+
+        for trick in self.play.chunks(4) {
+            for &card in trick {
+                write!(f, "pc|{}|", card_str(card))?;
+            }
+            f.write_str("pg||")?;
+        }
+        if let Some(tricks) = self.claim {
+            write!(f, "mc|{tricks}|")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
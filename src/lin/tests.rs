@@ -0,0 +1,124 @@
+use super::*;
+use crate::bidding::american_default;
+use contract_bridge::{Bid, Level, Strain};
+
+/// The `pbn` tests' board as BBO would export it, East's hand left implicit.
+const BOARD: &str = "pn|Alice,Bob,Carol,Dave|st||md|3SAQJ987H8532D84CK,ST5HK4D652CA98542,SK6HQJT976DQT7CQ6,|rh||ah|Board 1|sv|o|\
+mb|1H|mb|d|mb|1S!|an|forcing, 4+ spades|mb|p|mb|2H|mb|p|mb|4H|mb|p|mb|p|mb|p|pg||\
+pc|DA|pc|D4|pc|D2|pc|D7|pg||pc|dk|pc|d8|pc|d5|pc|dt|pg||mc|10|";
+
+fn fixture() -> FullDeal {
+    "W:T5.K4.652.A98542 K6.QJT976.QT7.Q6 432.A.AKJ93.JT73 AQJ987.8532.84.K"
+        .parse()
+        .expect("valid PBN deal")
+}
+
+#[test]
+fn reads_a_played_board() {
+    let records = parse(BOARD).expect("valid LIN");
+    assert_eq!(records.len(), 1);
+    let record = &records[0];
+
+    assert_eq!(record.board, Some(1));
+    assert_eq!(record.players[Seat::North as usize], "Carol");
+    assert_eq!(record.players[Seat::South as usize], "Alice");
+    assert_eq!(record.dealer, Seat::North);
+    assert_eq!(record.vul, AbsoluteVulnerability::NONE);
+    assert_eq!(record.deal, fixture());
+
+    assert_eq!(record.auction.len(), 10);
+    assert!(record.auction.has_ended());
+    assert_eq!(
+        record.auction[2],
+        Call::Bid(Bid {
+            level: Level::new(1),
+            strain: Strain::Spades,
+        })
+    );
+    assert_eq!(
+        record.annotations.get(&2),
+        Some(&Annotation {
+            alerted: true,
+            text: "forcing, 4+ spades".to_owned(),
+        })
+    );
+    assert_eq!(record.annotations.len(), 1);
+
+    assert_eq!(record.play.len(), 8);
+    assert_eq!(record.play[4], "DK".parse().expect("valid card"));
+    assert_eq!(record.claim, Some(10));
+}
+
+#[test]
+fn record_round_trips() {
+    let record = parse(BOARD).expect("valid LIN").swap_remove(0);
+    let text = record.to_string();
+    assert!(text.contains("mb|1S!|an|forcing, 4+ spades|"));
+    assert_eq!(parse(&text).expect("own output parses"), [record]);
+}
+
+#[test]
+fn boards_split_and_inherit_players() {
+    let text = "vg|Final,Segment 1|pn|Alice,Bob,Carol,Dave|\
+qx|o2|md|3SAQJ987H8532D84CK,ST5HK4D652CA98542,SK6HQJT976DQT7CQ6|mb|p|\
+qx|c2|md|4SAQJ987H8532D84CK,ST5HK4D652CA98542,SK6HQJT976DQT7CQ6|sv|b|";
+    let records = parse(text).expect("valid LIN");
+    assert_eq!(records.len(), 2);
+    // No `sv|` on the first board: board 2's rotation, North-South.
+    assert_eq!(records[0].vul, AbsoluteVulnerability::NS);
+    assert_eq!(records[0].board, Some(2));
+    assert_eq!(records[0].auction.len(), 1);
+    assert_eq!(records[1].players, records[0].players);
+    assert_eq!(records[1].dealer, Seat::East);
+    assert_eq!(records[1].vul, AbsoluteVulnerability::ALL);
+    assert!(records[1].auction.is_empty());
+}
+
+#[test]
+fn rejects_broken_records() {
+    let kind = |text: &str| parse(text).expect_err("invalid LIN").kind;
+    let md = "md|3SAQJ987H8532D84CK,ST5HK4D652CA98542,SK6HQJT976DQT7CQ6|";
+
+    assert_eq!(kind("sv|o|mb|1C|"), ErrorKind::MissingDeal);
+    assert_eq!(kind("md|3SAK|"), ErrorKind::InvalidDeal("3SAK".to_owned()));
+    assert_eq!(
+        kind(&format!("{md}sv|q|")),
+        ErrorKind::InvalidVulnerability("q".to_owned())
+    );
+    assert_eq!(kind(&format!("{md}an|hi|")), ErrorKind::DanglingAnnotation);
+    assert!(matches!(
+        kind(&format!("{md}mb|1H|mb|1C|")),
+        ErrorKind::IllegalCall(_)
+    ));
+    assert_eq!(
+        kind(&format!("{md}mc|14|")),
+        ErrorKind::InvalidClaim("14".to_owned())
+    );
+    assert_eq!(kind(&format!("{md}pc")), ErrorKind::Unterminated);
+    assert_eq!(
+        parse(&format!("{md}mb|9C|")).expect_err("bad call").command,
+        2
+    );
+}
+
+#[test]
+fn bot_auction_carries_rule_explanations() {
+    let system = american_default();
+    let table = Table::of_systems(&system, &system, Seat::North, AbsoluteVulnerability::NONE);
+    let record = Record::bid_out(&table, fixture());
+
+    assert_eq!(record.auction, table.bid_out(&fixture()));
+    assert!(!record.annotations.is_empty());
+    assert!(record.annotations.values().all(|a| !a.text.is_empty()));
+
+    let text = record.to_string();
+    assert_eq!(
+        parse(&text).expect("own output parses"),
+        core::slice::from_ref(&record)
+    );
+    let url = record.handviewer_url();
+    assert!(
+        url.starts_with("https://www.bridgebase.com/tools/handviewer.html?lin=st%7C%7Cmd%7C3S")
+    );
+    assert!(!url.contains('|'));
+}
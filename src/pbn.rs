@@ -0,0 +1,857 @@
+//! PBN (Portable Bridge Notation) hand records, read and written.
+//!
+//! PBN is what every other bridge program exchanges: tournament hand records,
+//! dealer programs, BBO/Bridgify exports, and double-dummy analysers all speak
+//! it.  A file is a sequence of *games* separated by blank lines; each game is
+//! a list of `[Tag "value"]` pairs, some of which (`Auction`, `Play`,
+//! `OptimumResultTable`) own the data lines that follow them.  This module maps
+//! one game to a [`Game`]:
+//!
+//! - `[Board]`, `[Dealer]`, `[Vulnerable]`, `[Deal]` — the board itself.  A
+//!   missing dealer or vulnerability falls back to the board number's
+//!   standard rotation ([`board_dealer`], [`board_vulnerability`]).
+//! - `[Auction]` with `=n=` note references and the `[Note "n:text"]` tags
+//!   they point at — the calls as an [`Auction`] plus per-call note text.
+//! - `[Contract]`, `[Declarer]`, `[Result]` — the reached contract and
+//!   declarer's tricks, in the `(Contract, Seat)` shape of
+//!   [`final_contract`](crate::scoring::final_contract).
+//! - `[Play]` — the card play, trick by trick ([`Play`]).
+//! - `[OptimumResultTable]` — the double-dummy table as a
+//!   [`TrickCountTable`], so a solved hand record prices a contract with the
+//!   `ns_score_*` scorers without a solve.
+//! - `[OptimumScore]`, `[ParContract]` — the par, written by
+//!   [`Game::set_par`] and read back as ordinary [`Game::tags`].
+//!
+//! Every other tag (`Event`, `Site`, player names, `Scoring`, …) is kept
+//! verbatim in [`Game::tags`] and written back, so a file round-trips through
+//! [`parse`] and [`write`] with only its layout normalised.  The import
+//! conventions of PBN 2.1 are honoured on read: `;` and `{…}` comments, `%`
+//! directives, the `#` "same as the previous game" value, `AP` (all pass),
+//! `*` section ends, and the `!`/`?` suffixes and `$n` NAGs that annotate a
+//! call.  Export writes ASCII suits, four calls or one trick per line, and
+//! explicit passes.
+
+use crate::scoring::{board_dealer, board_vulnerability, final_contract};
+use contract_bridge::auction::{Auction, Call, IllegalCall};
+use contract_bridge::{
+    AbsoluteVulnerability, Bid, Card, Contract, FullDeal, Penalty, Seat, Strain, Suit,
+};
+use core::fmt::{self, Write as _};
+use ddss::{Par, TrickCountRow, TrickCountTable};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+use thiserror::Error;
+
+/// The card play of one game, as PBN lays it out
+///
+/// PBN records a trick as a row of four cards in *seat* order — column `i` is
+/// the card of `leader` rotated clockwise `i` times — not in the order they
+/// were played.  [`sequence`](Self::sequence) recovers the chronological
+/// order, which needs the trump suit to tell who won each trick.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Play {
+    /// The opening leader, the seat of every row's first column
+    pub leader: Seat,
+    /// One row per trick; [`None`] marks a card not recorded (a claim)
+    pub tricks: Vec<[Option<Card>; 4]>,
+}
+
+impl Play {
+    /// The recorded cards in the order they were played
+    ///
+    /// Each trick is read from the seat on lead, which is the winner of the
+    /// previous trick under `strain`.  Stops at the first incomplete trick:
+    /// once a card is missing the next leader is unknown.
+    #[must_use]
+    pub fn sequence(&self, strain: Strain) -> Vec<Card> {
+        let trump = Suit::try_from(strain).ok();
+        let mut leader = self.leader;
+        let mut cards = Vec::with_capacity(52);
+        for trick in &self.tricks {
+            // Rotate the row so slot 0 is the seat on lead.
+            let offset = (leader as usize + 4 - self.leader as usize) % 4;
+            let ordered: Option<Vec<Card>> = (0..4).map(|i| trick[(offset + i) % 4]).collect();
+            let Some(ordered) = ordered else {
+                cards.extend((0..4).map_while(|i| trick[(offset + i) % 4]));
+                break;
+            };
+            let beats = |a: Card, b: Card| {
+                if a.suit == b.suit {
+                    a.rank > b.rank
+                } else {
+                    Some(a.suit) == trump
+                }
+            };
+            let winner = (1..4).fold(0, |best, i| {
+                if beats(ordered[i], ordered[best]) {
+                    i
+                } else {
+                    best
+                }
+            });
+            cards.extend_from_slice(&ordered);
+            leader = Seat::ALL[(leader as usize + winner) % 4];
+        }
+        cards
+    }
+}
+
+/// One PBN game: a board and whatever of its auction, play, result, and
+/// double-dummy analysis the record carries
+///
+/// Only the deal, dealer, and vulnerability are required; everything else is
+/// optional, as in PBN itself.  A hand record straight from a dealing program
+/// is just the first group of fields; a played board fills the rest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    /// Board number (`[Board]`)
+    pub board: Option<u32>,
+    /// The dealer, who makes the first call of [`auction`](Self::auction)
+    pub dealer: Seat,
+    /// Absolute vulnerability of the board
+    pub vul: AbsoluteVulnerability,
+    /// The four hands
+    pub deal: FullDeal,
+    /// The calls from the dealer; need not have ended
+    pub auction: Option<Auction>,
+    /// Note text keyed by the index of the call it annotates (alerts and
+    /// explanations, PBN's `=n=` references)
+    pub notes: BTreeMap<usize, String>,
+    /// The reached contract and its declarer; [`None`] for a pass-out or an
+    /// unrecorded contract
+    pub contract: Option<(Contract, Seat)>,
+    /// Tricks won by declarer (`[Result]`)
+    pub result: Option<u8>,
+    /// The card play
+    pub play: Option<Play>,
+    /// The double-dummy table (`[OptimumResultTable]`)
+    pub tricks: Option<TrickCountTable>,
+    /// Every other tag in file order, written back verbatim
+    pub tags: Vec<(String, String)>,
+}
+
+impl Game {
+    /// A bare hand record: just the board
+    #[must_use]
+    pub const fn new(deal: FullDeal, dealer: Seat, vul: AbsoluteVulnerability) -> Self {
+        Self {
+            board: None,
+            dealer,
+            vul,
+            deal,
+            auction: None,
+            notes: BTreeMap::new(),
+            contract: None,
+            result: None,
+            play: None,
+            tricks: None,
+            tags: Vec::new(),
+        }
+    }
+
+    /// A bare hand record for duplicate board `board`, its dealer and
+    /// vulnerability taken from the standard rotation
+    #[must_use]
+    pub const fn numbered(board: u32, deal: FullDeal) -> Self {
+        let mut game = Self::new(deal, board_dealer(board), board_vulnerability(board));
+        game.board = Some(board);
+        game
+    }
+
+    /// Record `par` as the `OptimumScore` and `ParContract` tags, replacing
+    /// any already there
+    ///
+    /// The score is North/South's, as `NS 420`.  Each par contract is prefixed
+    /// by the declarers who make it — `NS 4S` when either partner does, `N 3NT`
+    /// when only North does — and a sacrifice is doubled, `EW 5DX`.  A
+    /// passed-out par has the contract `Pass`.
+    pub fn set_par(&mut self, par: &Par) {
+        let mut distinct: Vec<Contract> = Vec::new();
+        for entry in &par.contracts {
+            if !distinct.contains(&entry.contract) {
+                distinct.push(entry.contract);
+            }
+        }
+        let contracts: Vec<String> = distinct
+            .into_iter()
+            .map(|contract| {
+                let seats: String = Seat::ALL
+                    .into_iter()
+                    .filter(|&seat| {
+                        par.contracts
+                            .iter()
+                            .any(|entry| entry.contract == contract && entry.declarer == seat)
+                    })
+                    .map(|seat| seat.letter())
+                    .collect();
+                format!("{seats} {}", contract_str(contract))
+            })
+            .collect();
+        let contract = if contracts.is_empty() {
+            "Pass".to_owned()
+        } else {
+            contracts.join(", ")
+        };
+        self.tags
+            .retain(|(name, _)| name != "OptimumScore" && name != "ParContract");
+        self.tags
+            .push(("OptimumScore".to_owned(), format!("NS {}", par.score)));
+        self.tags.push(("ParContract".to_owned(), contract));
+    }
+
+    /// The value of an unmodelled tag such as `Event` or `North`
+    #[must_use]
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// What went wrong in a PBN file
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A line starting with `[` is not a `[Name "value"]` tag
+    #[error("malformed tag")]
+    MalformedTag,
+    /// A game has no `[Deal]` tag
+    #[error("game has no deal")]
+    MissingDeal,
+    /// A tag value does not parse
+    #[error("invalid {0} tag: {1:?}")]
+    InvalidTag(&'static str, String),
+    /// An auction token is not a call
+    #[error("invalid call {0:?}")]
+    InvalidCall(String),
+    /// A call breaks the laws of the auction
+    #[error(transparent)]
+    IllegalCall(#[from] IllegalCall),
+    /// An auction refers to a note no `[Note]` tag defines
+    #[error("undefined note {0}")]
+    UndefinedNote(u32),
+    /// A play token is not a card
+    #[error("invalid card {0:?}")]
+    InvalidCard(String),
+    /// An `OptimumResultTable` row is not `<seat> <strain> <tricks>`
+    #[error("invalid optimum result row {0:?}")]
+    InvalidTableRow(String),
+    /// An `OptimumResultTable` does not cover all 20 seat-strain cells
+    #[error("optimum result table is incomplete")]
+    IncompleteTable,
+}
+
+/// A PBN syntax or content error, located by line
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[error("line {line}: {kind}")]
+pub struct Error {
+    /// 1-based line of the tag or section the error was found in
+    pub line: usize,
+    /// What is wrong
+    pub kind: ErrorKind,
+}
+
+/// One tag of a game as read: name, value, its line, and the section tokens
+/// (for `Auction`/`Play`) or rows (for `OptimumResultTable`) that follow it.
+#[derive(Default)]
+struct RawTag {
+    name: String,
+    value: String,
+    line: usize,
+    rows: Vec<String>,
+}
+
+/// Split `[Name "value"]` (the brackets already located) into its parts,
+/// unescaping `\"` and `\\` in the value.  Any other backslash is literal,
+/// as in the `\2R` column formats of `OptimumResultTable`.
+fn split_tag(inner: &str) -> Option<(String, String)> {
+    let (name, rest) = inner.trim().split_once(char::is_whitespace)?;
+    let rest = rest.trim().strip_prefix('"')?.strip_suffix('"')?;
+    let mut value = String::with_capacity(rest.len());
+    let mut chars = rest.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('\\', Some(&next @ ('"' | '\\'))) => {
+                value.push(next);
+                chars.next();
+            }
+            _ => value.push(c),
+        }
+    }
+    Some((name.to_owned(), value))
+}
+
+/// Find the `]` closing a tag that opens a line, skipping quoted text.
+fn tag_end(line: &str) -> Option<usize> {
+    let mut quoted = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            ']' if !quoted => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Where a `;` rest-of-line comment starts, ignoring semicolons inside a
+/// quoted tag value (`OptimumResultTable`'s column list has several).
+fn comment_start(line: &str) -> usize {
+    let mut quoted = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            ';' if !quoted => return i,
+            _ => {}
+        }
+    }
+    line.len()
+}
+
+/// Split a file into games of raw tags, dropping comments and directives.
+fn games(text: &str) -> Result<Vec<Vec<RawTag>>, Error> {
+    let mut games = Vec::new();
+    let mut game: Vec<RawTag> = Vec::new();
+    let mut in_brace = false;
+    for (index, line) in text.lines().enumerate() {
+        let number = index + 1;
+        // Brace comments may span lines; strip them before anything else.
+        let mut clean = String::with_capacity(line.len());
+        for c in line.chars() {
+            match c {
+                '}' if in_brace => in_brace = false,
+                _ if in_brace => {}
+                '{' => in_brace = true,
+                _ => clean.push(c),
+            }
+        }
+        if clean.starts_with('%') {
+            continue;
+        }
+        let clean = clean[..comment_start(&clean)].trim();
+        if clean.is_empty() {
+            // A blank line (not a comment-only one) ends the game.
+            if line.trim().is_empty() && !in_brace && !game.is_empty() {
+                games.push(core::mem::take(&mut game));
+            }
+            continue;
+        }
+        let mut rest = clean;
+        if let Some(body) = rest.strip_prefix('[') {
+            let error = Error {
+                line: number,
+                kind: ErrorKind::MalformedTag,
+            };
+            let end = tag_end(body).ok_or_else(|| error.clone())?;
+            let (name, value) = split_tag(&body[..end]).ok_or(error)?;
+            game.push(RawTag {
+                name,
+                value,
+                line: number,
+                rows: Vec::new(),
+            });
+            rest = body[end + 1..].trim();
+        }
+        // Section data belongs to the latest tag; data before any tag is
+        // not PBN and is dropped like a comment.
+        if !rest.is_empty()
+            && let Some(tag) = game.last_mut()
+        {
+            tag.rows.push(rest.to_owned());
+        }
+    }
+    if !game.is_empty() {
+        games.push(game);
+    }
+    Ok(games)
+}
+
+/// The PBN spelling of a strain (`C`, `D`, `H`, `S`, `NT`)
+fn strain_str(strain: Strain) -> &'static str {
+    match strain {
+        Strain::Clubs => "C",
+        Strain::Diamonds => "D",
+        Strain::Hearts => "H",
+        Strain::Spades => "S",
+        Strain::Notrump => "NT",
+    }
+}
+
+/// The PBN spelling of a bid, ASCII rather than [`Bid`]'s suit symbols
+fn bid_str(bid: Bid) -> String {
+    format!("{}{}", bid.level, strain_str(bid.strain))
+}
+
+/// The PBN spelling of a contract: the bid, then `X` or `XX`
+fn contract_str(contract: Contract) -> String {
+    let penalty = match contract.penalty {
+        Penalty::Undoubled => "",
+        Penalty::Doubled => "X",
+        Penalty::Redoubled => "XX",
+    };
+    format!("{}{penalty}", bid_str(contract.bid))
+}
+
+/// The PBN spelling of a call
+fn call_str(call: Call) -> String {
+    match call {
+        Call::Pass => "Pass".to_owned(),
+        Call::Double => "X".to_owned(),
+        Call::Redouble => "XX".to_owned(),
+        Call::Bid(bid) => bid_str(bid),
+    }
+}
+
+/// The PBN spelling of a card, suit letter then rank letter
+fn card_str(card: Card) -> String {
+    format!("{}{}", card.suit.letter(), card.rank.letter())
+}
+
+fn parse_vul(value: &str) -> Option<AbsoluteVulnerability> {
+    match value.to_ascii_lowercase().as_str() {
+        "none" | "love" | "-" => Some(AbsoluteVulnerability::NONE),
+        "ns" => Some(AbsoluteVulnerability::NS),
+        "ew" => Some(AbsoluteVulnerability::EW),
+        "all" | "both" => Some(AbsoluteVulnerability::ALL),
+        _ => None,
+    }
+}
+
+const fn vul_str(vul: AbsoluteVulnerability) -> &'static str {
+    match vul.bits() {
+        0 => "None",
+        1 => "NS",
+        2 => "EW",
+        _ => "All",
+    }
+}
+
+/// Parse an `[Auction]` section: calls from `first`, `=n=` note references,
+/// and the import conventions listed in the module docs.  Returns the calls
+/// and the note numbers keyed by the call they follow.
+fn parse_auction(tokens: &str) -> Result<(Auction, Vec<(usize, u32)>), ErrorKind> {
+    let mut auction = Auction::new();
+    let mut refs = Vec::new();
+    for token in tokens.split_whitespace() {
+        if token == "*" {
+            break;
+        }
+        if token.starts_with('$') {
+            continue;
+        }
+        if let Some(note) = token.strip_prefix('=').and_then(|t| t.strip_suffix('=')) {
+            let note = note
+                .parse()
+                .map_err(|_| ErrorKind::InvalidCall(token.to_owned()))?;
+            if let Some(index) = auction.len().checked_sub(1) {
+                refs.push((index, note));
+            }
+            continue;
+        }
+        if token.eq_ignore_ascii_case("AP") {
+            while !auction.has_ended() {
+                auction.try_push(Call::Pass)?;
+            }
+            continue;
+        }
+        let bare = token.trim_end_matches(['!', '?']);
+        let call: Call = bare
+            .parse()
+            .map_err(|_| ErrorKind::InvalidCall(token.to_owned()))?;
+        auction.try_push(call)?;
+    }
+    Ok((auction, refs))
+}
+
+fn parse_play(leader: Seat, tokens: &str) -> Result<Play, ErrorKind> {
+    let mut cards = Vec::new();
+    for token in tokens.split_whitespace() {
+        if token == "*" {
+            break;
+        }
+        if token.starts_with('$') || token.starts_with('=') {
+            continue;
+        }
+        let bare = token.trim_end_matches(['!', '?', '+']);
+        cards.push(if bare == "-" {
+            None
+        } else {
+            Some(
+                bare.parse()
+                    .map_err(|_| ErrorKind::InvalidCard(token.to_owned()))?,
+            )
+        });
+    }
+    let tricks = cards
+        .chunks(4)
+        .map(|chunk| {
+            let mut trick = [None; 4];
+            trick[..chunk.len()].copy_from_slice(chunk);
+            trick
+        })
+        .collect();
+    Ok(Play { leader, tricks })
+}
+
+fn parse_table(rows: &[String]) -> Result<TrickCountTable, ErrorKind> {
+    let mut cells = [[None::<u8>; 4]; 5];
+    for row in rows {
+        let invalid = || ErrorKind::InvalidTableRow(row.clone());
+        let mut fields = row.split_whitespace();
+        let (Some(seat), Some(strain), Some(tricks), None) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            return Err(invalid());
+        };
+        let seat: Seat = seat.parse().map_err(|_| invalid())?;
+        let strain: Strain = strain.parse().map_err(|_| invalid())?;
+        let tricks: u8 = tricks
+            .parse()
+            .ok()
+            .filter(|&t| t <= 13)
+            .ok_or_else(invalid)?;
+        cells[strain as usize][seat as usize] = Some(tricks);
+    }
+    let mut table = TrickCountTable([TrickCountRow::new(0, 0, 0, 0); 5]);
+    for (row, cell) in table.0.iter_mut().zip(cells) {
+        let [Some(n), Some(e), Some(s), Some(w)] = cell else {
+            return Err(ErrorKind::IncompleteTable);
+        };
+        *row = TrickCountRow::new(n, e, s, w);
+    }
+    Ok(table)
+}
+
+/// Build one game from its raw tags, resolving `#` against `previous`.
+fn build(tags: &[RawTag], previous: &BTreeMap<String, String>) -> Result<Game, Error> {
+    let first_line = tags.first().map_or(0, |tag| tag.line);
+    let mut by_name: BTreeMap<&str, &RawTag> = BTreeMap::new();
+    for tag in tags {
+        by_name.entry(tag.name.as_str()).or_insert(tag);
+    }
+    let value = |name: &str| -> Option<(&str, usize)> {
+        let tag = by_name.get(name)?;
+        let value = if tag.value == "#" {
+            previous.get(name).map_or("", String::as_str)
+        } else {
+            tag.value.as_str()
+        };
+        Some((value, tag.line)).filter(|(v, _)| !v.is_empty() && *v != "?")
+    };
+    let fail = |line, kind| Error { line, kind };
+    let invalid = |name: &'static str, (value, line): (&str, usize)| {
+        fail(line, ErrorKind::InvalidTag(name, value.to_owned()))
+    };
+
+    let board = match value("Board") {
+        Some(v) => Some(v.0.parse().map_err(|_| invalid("Board", v))?),
+        None => None,
+    };
+    let dealer = match value("Dealer") {
+        Some(v) => v.0.parse().map_err(|_| invalid("Dealer", v))?,
+        None => board_dealer(board.unwrap_or(1)),
+    };
+    let vul = match value("Vulnerable") {
+        Some(v) => parse_vul(v.0).ok_or_else(|| invalid("Vulnerable", v))?,
+        None => board_vulnerability(board.unwrap_or(1)),
+    };
+    let deal: FullDeal = match value("Deal") {
+        Some(v) => v.0.parse().map_err(|_| invalid("Deal", v))?,
+        None => return Err(fail(first_line, ErrorKind::MissingDeal)),
+    };
+
+    let mut notes = BTreeMap::new();
+    let auction = match by_name.get("Auction") {
+        Some(tag) => {
+            let first: Seat = tag
+                .value
+                .parse()
+                .map_err(|_| invalid("Auction", (&tag.value, tag.line)))?;
+            if first != dealer {
+                return Err(invalid("Auction", (&tag.value, tag.line)));
+            }
+            let (auction, refs) =
+                parse_auction(&tag.rows.join(" ")).map_err(|kind| fail(tag.line, kind))?;
+            let texts: BTreeMap<u32, &str> = tags
+                .iter()
+                .filter(|tag| tag.name == "Note")
+                .filter_map(|tag| {
+                    let (number, text) = tag.value.split_once(':')?;
+                    Some((number.trim().parse().ok()?, text))
+                })
+                .collect();
+            for (index, number) in refs {
+                let text = texts
+                    .get(&number)
+                    .ok_or_else(|| fail(tag.line, ErrorKind::UndefinedNote(number)))?;
+                notes.insert(index, (*text).to_owned());
+            }
+            Some(auction)
+        }
+        None => None,
+    };
+
+    let declarer = match value("Declarer") {
+        Some(v) => Some(
+            v.0.trim_start_matches('^')
+                .parse::<Seat>()
+                .map_err(|_| invalid("Declarer", v))?,
+        ),
+        None => None,
+    };
+    let contract = match value("Contract") {
+        Some((pass, _)) if pass.eq_ignore_ascii_case("pass") => None,
+        Some(v) => {
+            let contract: Contract = v.0.parse().map_err(|_| invalid("Contract", v))?;
+            let declarer = declarer.ok_or_else(|| invalid("Declarer", ("", v.1)))?;
+            Some((contract, declarer))
+        }
+        None => auction
+            .as_ref()
+            .filter(|auction| auction.has_ended())
+            .and_then(|auction| final_contract(auction, dealer)),
+    };
+    let result = match value("Result") {
+        Some(v) => Some(
+            v.0.parse()
+                .ok()
+                .filter(|&t: &u8| t <= 13)
+                .ok_or_else(|| invalid("Result", v))?,
+        ),
+        None => None,
+    };
+    let play = match by_name.get("Play") {
+        Some(tag) if !matches!(tag.value.as_str(), "" | "?" | "-") => {
+            let leader: Seat = tag
+                .value
+                .parse()
+                .map_err(|_| invalid("Play", (&tag.value, tag.line)))?;
+            Some(parse_play(leader, &tag.rows.join(" ")).map_err(|kind| fail(tag.line, kind))?)
+        }
+        _ => None,
+    };
+    let tricks = match by_name.get("OptimumResultTable") {
+        Some(tag) => Some(parse_table(&tag.rows).map_err(|kind| fail(tag.line, kind))?),
+        None => None,
+    };
+
+    let modelled = [
+        "Board",
+        "Dealer",
+        "Vulnerable",
+        "Deal",
+        "Auction",
+        "Note",
+        "Declarer",
+        "Contract",
+        "Result",
+        "Play",
+        "OptimumResultTable",
+    ];
+    let extra = tags
+        .iter()
+        .filter(|tag| !modelled.contains(&tag.name.as_str()))
+        .map(|tag| {
+            let value = match tag.value.as_str() {
+                "#" => previous.get(&tag.name).cloned().unwrap_or_default(),
+                value => value.to_owned(),
+            };
+            (tag.name.clone(), value)
+        })
+        .collect();
+
+    Ok(Game {
+        board,
+        dealer,
+        vul,
+        deal,
+        auction,
+        notes,
+        contract,
+        result,
+        play,
+        tricks,
+        tags: extra,
+    })
+}
+
+/// Parse every game of a PBN file
+///
+/// # Errors
+///
+/// The first malformed tag, unparsable value, illegal auction, or incomplete
+/// double-dummy table, with its line.  Unknown tags are never an error — they
+/// land in [`Game::tags`].
+pub fn parse(text: &str) -> Result<Vec<Game>, Error> {
+    let mut previous = BTreeMap::new();
+    games(text)?
+        .into_iter()
+        .map(|tags| {
+            let game = build(&tags, &previous)?;
+            for tag in &tags {
+                if tag.value != "#" {
+                    previous.insert(tag.name.clone(), tag.value.clone());
+                }
+            }
+            Ok(game)
+        })
+        .collect()
+}
+
+/// Read a PBN file ([`parse`] on its contents)
+///
+/// A parse error is an [`io::ErrorKind::InvalidData`] error, as in
+/// [`pdd::load`](crate::pdd::load).
+pub fn load(path: impl AsRef<Path>) -> io::Result<Vec<Game>> {
+    let text = std::fs::read_to_string(path)?;
+    parse(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Write one `[Name "value"]` line, escaping backslashes and quotes in the
+/// value.
+fn tag(f: &mut fmt::Formatter<'_>, name: &str, value: &str) -> fmt::Result {
+    let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+    writeln!(f, "[{name} \"{value}\"]")
+}
+
+/// Tags PBN exports in a fixed order ahead of the rest; the ones [`Game`]
+/// models are written from its fields, the others from [`Game::tags`].
+const ROSTER: [&str; 15] = [
+    "Event",
+    "Site",
+    "Date",
+    "Board",
+    "West",
+    "North",
+    "East",
+    "South",
+    "Dealer",
+    "Vulnerable",
+    "Deal",
+    "Scoring",
+    "Declarer",
+    "Contract",
+    "Result",
+];
+
+impl fmt::Display for Game {
+    /// The game in PBN export format, ending without a blank line
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let passed_out = self.contract.is_none()
+            && self
+                .auction
+                .as_ref()
+                .is_some_and(|auction| auction.has_ended());
+        for name in ROSTER {
+            match name {
+                "Board" => {
+                    if let Some(board) = self.board {
+                        tag(f, name, &board.to_string())?;
+                    }
+                }
+                "Dealer" => tag(f, name, &self.dealer.letter().to_string())?,
+                "Vulnerable" => tag(f, name, vul_str(self.vul))?,
+                "Deal" => tag(f, name, &self.deal.display(self.dealer).to_string())?,
+                "Declarer" => match self.contract {
+                    Some((_, declarer)) => tag(f, name, &declarer.letter().to_string())?,
+                    None if passed_out => tag(f, name, "")?,
+                    None => {}
+                },
+                "Contract" => match self.contract {
+                    Some((contract, _)) => tag(f, name, &contract_str(contract))?,
+                    None if passed_out => tag(f, name, "Pass")?,
+                    None => {}
+                },
+                "Result" => {
+                    if let Some(tricks) = self.result {
+                        tag(f, name, &tricks.to_string())?;
+                    }
+                }
+                _ => {
+                    for (_, value) in self.tags.iter().filter(|(key, _)| key == name) {
+                        tag(f, name, value)?;
+                    }
+                }
+            }
+        }
+        if let Some(auction) = &self.auction {
+            tag(f, "Auction", &self.dealer.letter().to_string())?;
+            let mut numbers = BTreeMap::new();
+            for (i, calls) in auction.chunks(4).enumerate() {
+                let mut line = String::new();
+                for (j, &call) in calls.iter().enumerate() {
+                    if j > 0 {
+                        line.push(' ');
+                    }
+                    line.push_str(&call_str(call));
+                    if self.notes.contains_key(&(4 * i + j)) {
+                        let number = numbers.len() + 1;
+                        numbers.insert(4 * i + j, number);
+                        write!(line, " ={number}=")?;
+                    }
+                }
+                writeln!(f, "{line}")?;
+            }
+            for (index, number) in &numbers {
+                tag(f, "Note", &format!("{number}:{}", self.notes[index]))?;
+            }
+        }
+        if let Some(play) = &self.play {
+            tag(f, "Play", &play.leader.letter().to_string())?;
+            for trick in &play.tricks {
+                let row: Vec<String> = trick
+                    .iter()
+                    .map(|card| card.map_or_else(|| "-".to_owned(), card_str))
+                    .collect();
+                writeln!(f, "{}", row.join(" "))?;
+            }
+            // A play cut short (a claim) ends with `*`.
+            if play.tricks.len() < 13 || play.tricks.iter().flatten().any(Option::is_none) {
+                writeln!(f, "*")?;
+            }
+        }
+        if let Some(table) = &self.tricks {
+            // Written raw: the `\2R` column formats are PBN's own backslashes.
+            writeln!(
+                f,
+                r#"[OptimumResultTable "Declarer;Denomination\2R;Result\2R"]"#
+            )?;
+            for seat in [Seat::North, Seat::South, Seat::East, Seat::West] {
+                for strain in Strain::DESC {
+                    let tricks = table[strain].get(seat).get();
+                    writeln!(f, "{} {} {tricks}", seat.letter(), strain_str(strain))?;
+                }
+            }
+        }
+        for (name, value) in &self.tags {
+            if !ROSTER.contains(&name.as_str()) {
+                tag(f, name, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Write games as a PBN file: an export-format header, then each game
+/// followed by a blank line
+///
+/// # Errors
+///
+/// Whatever `w` reports.
+pub fn write(mut w: impl io::Write, games: &[Game]) -> io::Result<()> {
+    writeln!(w, "% PBN 2.1")?;
+    writeln!(w, "% EXPORT")?;
+    writeln!(w)?;
+    for game in games {
+        writeln!(w, "{game}")?;
+    }
+    w.flush()
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,212 @@
+use super::*;
+use crate::gib;
+
+/// The first line of `sol100000.txt` (same fixture as the `gib` tests).
+const LINE: &str =
+    "T5.K4.652.A98542 K6.QJT976.QT7.Q6 432.A.AKJ93.JT73 AQJ987.8532.84.K:65658888888843433232";
+
+/// A played board in the shape tournament software exports, with the import
+/// conventions a reader must tolerate: comments, `AP`, a note, a claim.
+const RECORD: &str = r##"% PBN 2.1
+% EXPORT
+[Event "Club pairs"]
+[Site "?"]
+[Board "1"]
+[North "Alice"]
+[Dealer "N"]
+[Vulnerable "None"]
+[Deal "W:T5.K4.652.A98542 K6.QJT976.QT7.Q6 432.A.AKJ93.JT73 AQJ987.8532.84.K"]
+[Declarer "N"]
+[Contract "4H"]
+[Result "10"]
+{ a brace comment
+  spanning lines }
+[Auction "N"]
+1H X 1S =1= Pass ; a rest-of-line comment
+2H Pass 4H AP
+[Note "1:forcing, 4+ spades"]
+[Play "E"]
+DA D4 D2 D7
+DK D8 D5 DT
+C3 CK CA C6
+S2 SA ST SK
+*
+
+[Board "2"]
+[North "#"]
+[Deal "E:432.A.AKJ93.JT73 AQJ987.8532.84.K T5.K4.652.A98542 K6.QJT976.QT7.Q6"]
+"##;
+
+fn fixture() -> (FullDeal, TrickCountTable) {
+    gib::parse_line(LINE).expect("valid GIB line")
+}
+
+const fn card(suit: Suit, rank: u8) -> Card {
+    Card {
+        suit,
+        rank: contract_bridge::Rank::new(rank),
+    }
+}
+
+#[test]
+fn reads_a_played_board() {
+    let games = parse(RECORD).expect("valid PBN");
+    assert_eq!(games.len(), 2);
+    let (deal, _) = fixture();
+    let game = &games[0];
+
+    assert_eq!(game.board, Some(1));
+    assert_eq!(game.dealer, Seat::North);
+    assert_eq!(game.vul, AbsoluteVulnerability::NONE);
+    assert_eq!(game.deal, deal);
+    assert_eq!(game.tag("Event"), Some("Club pairs"));
+    assert_eq!(game.tag("North"), Some("Alice"));
+
+    let auction = game.auction.as_ref().expect("auction");
+    assert_eq!(auction.len(), 10);
+    assert!(auction.has_ended());
+    assert_eq!(
+        game.notes.get(&2).map(String::as_str),
+        Some("forcing, 4+ spades")
+    );
+    assert_eq!(
+        game.contract,
+        Some((
+            Contract::new(4, Strain::Hearts, Penalty::Undoubled),
+            Seat::North
+        ))
+    );
+    assert_eq!(game.contract, final_contract(auction, game.dealer));
+    assert_eq!(game.result, Some(10));
+
+    let play = game.play.as_ref().expect("play");
+    assert_eq!(play.leader, Seat::East);
+    assert_eq!(play.tricks.len(), 4);
+}
+
+#[test]
+fn later_games_inherit_and_rotate() {
+    let games = parse(RECORD).expect("valid PBN");
+    let game = &games[1];
+    // `#` repeats the previous game's value; absent dealer and vulnerability
+    // follow board 2's rotation.
+    assert_eq!(game.tag("North"), Some("Alice"));
+    assert_eq!(game.dealer, Seat::East);
+    assert_eq!(game.vul, AbsoluteVulnerability::NS);
+    assert_eq!(game.deal, fixture().0);
+    assert_eq!(game.auction, None);
+    assert_eq!(game.contract, None);
+}
+
+#[test]
+fn play_sequence_follows_trick_winners() {
+    let games = parse(RECORD).expect("valid PBN");
+    let play = games[0].play.as_ref().expect("play");
+    let sequence = play.sequence(Strain::Hearts);
+    // Trick 3 goes to West's ♣A, so West leads trick 4 and the row's
+    // East-first columns are read from West.
+    assert_eq!(
+        sequence[8..],
+        [
+            card(Suit::Clubs, 3),
+            card(Suit::Clubs, 13),
+            card(Suit::Clubs, 14),
+            card(Suit::Clubs, 6),
+            card(Suit::Spades, 10),
+            card(Suit::Spades, 13),
+            card(Suit::Spades, 2),
+            card(Suit::Spades, 14),
+        ]
+    );
+}
+
+#[test]
+fn game_round_trips() {
+    let (deal, table) = fixture();
+    let mut game = parse(RECORD).expect("valid PBN").swap_remove(0);
+    game.tricks = Some(table);
+
+    let mut text = Vec::new();
+    write(&mut text, core::slice::from_ref(&game)).expect("write to memory");
+    let text = String::from_utf8(text).expect("PBN is UTF-8");
+    assert!(text.contains("[Deal \"N:K6.QJT976.QT7.Q6 "));
+    assert!(text.contains("1S =1= Pass"));
+
+    let back = parse(&text).expect("own output parses");
+    assert_eq!(back, [game]);
+    assert_eq!(back[0].deal, deal);
+    assert_eq!(back[0].tricks, Some(table));
+}
+
+#[test]
+fn backslashes_in_tag_values_round_trip() {
+    let (deal, _) = fixture();
+    let mut game = Game::numbered(1, deal);
+    game.tags.push(("Event".into(), r#"a\b "c""#.into()));
+    game.tags.push(("Site".into(), r"C:\".into()));
+    let text = game.to_string();
+    assert!(text.contains(r#"[Site "C:\\"]"#));
+    assert_eq!(parse(&text).expect("own output parses"), [game]);
+}
+
+#[test]
+fn optimum_result_table_is_the_dd_table() {
+    let (deal, table) = fixture();
+    let mut game = Game::numbered(1, deal);
+    game.tricks = Some(table);
+    let text = game.to_string();
+    assert!(text.contains("[OptimumResultTable \"Declarer;Denomination\\2R;Result\\2R\"]"));
+    assert!(text.contains("\nN S 8\n"));
+    assert_eq!(parse(&text).expect("valid PBN")[0].tricks, Some(table));
+}
+
+#[test]
+fn par_tags_name_the_score_and_the_declarers() {
+    let (deal, table) = fixture();
+    let mut game = Game::numbered(1, deal);
+    let par = crate::stats::par(table);
+    let par = par.get(game.dealer, game.vul);
+    game.set_par(par);
+    // Setting it twice replaces rather than repeats the tags.
+    game.set_par(par);
+    // East and West both make 4♣: one contract, both declarers.
+    assert_eq!(game.tag("OptimumScore"), Some("NS -130"));
+    assert_eq!(game.tag("ParContract"), Some("EW 4C"));
+    let text = game.to_string();
+    assert_eq!(text.matches("[OptimumScore ").count(), 1);
+    assert_eq!(parse(&text).expect("own output parses"), [game]);
+}
+
+#[test]
+fn passed_out_board() {
+    let text = "[Dealer \"S\"]\n[Deal \"N:K6.QJT976.QT7.Q6 432.A.AKJ93.JT73 AQJ987.8532.84.K T5.K4.652.A98542\"]\n[Contract \"Pass\"]\n[Auction \"S\"]\nAP\n";
+    let game = parse(text).expect("valid PBN").swap_remove(0);
+    assert_eq!(game.auction.as_ref().map(|a| a.len()), Some(4));
+    assert_eq!(game.contract, None);
+    let written = game.to_string();
+    assert!(written.contains("[Contract \"Pass\"]"));
+    assert_eq!(parse(&written).expect("own output parses"), [game]);
+}
+
+#[test]
+fn rejects_broken_records() {
+    let deal = "[Deal \"N:K6.QJT976.QT7.Q6 432.A.AKJ93.JT73 AQJ987.8532.84.K T5.K4.652.A98542\"]";
+    let kind = |text: &str| parse(text).expect_err("invalid PBN").kind;
+
+    assert_eq!(kind("[Board \"1\"]\n"), ErrorKind::MissingDeal);
+    assert_eq!(kind("[Deal N:]\n"), ErrorKind::MalformedTag);
+    assert_eq!(
+        kind(&format!("{deal}\n[Auction \"N\"]\n1H =3= Pass\n")),
+        ErrorKind::UndefinedNote(3)
+    );
+    assert!(matches!(
+        kind(&format!("{deal}\n[Auction \"N\"]\n1H 1C\n")),
+        ErrorKind::IllegalCall(_)
+    ));
+    assert_eq!(
+        kind(&format!("{deal}\n[OptimumResultTable \"\"]\nN NT 7\n")),
+        ErrorKind::IncompleteTable
+    );
+    let error = parse(&format!("\n{deal}\n[Vulnerable \"Maybe\"]\n")).expect_err("bad tag");
+    assert_eq!(error.line, 3);
+}
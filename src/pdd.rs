@@ -17,19 +17,241 @@
 //! bits, [`Builder::build_full`](contract_bridge::Builder::build_full) rejects
 //! any non-partition, and [`TrickCountRow::try_new`](ddss::TrickCountRow::try_new)
 //! rejects nibbles above 13.
+//!
+//! # Version 2: board metadata
+//!
+//! A `ponsDD02` file ([`MAGIC_V2`]) keeps the version-1 row as its prefix and
+//! appends optional per-row columns, so an A/B dump and its solved bank can
+//! share one file.  After the magic comes a 4-byte header:
+//!
+//! - u16: the [`Columns`] present, as bits;
+//! - u8: the auction capacity in calls (0 without [`Columns::AUCTION`]);
+//! - u8: reserved, zero.
+//!
+//! Each row is then the 34-byte version-1 row followed by the present
+//! columns, in bit order:
+//!
+//! - [`DEALER`](Columns::DEALER), 1 byte: [`Seat`] discriminant;
+//! - [`VULNERABILITY`](Columns::VULNERABILITY), 1 byte:
+//!   [`AbsoluteVulnerability`] bits;
+//! - [`BOARD`](Columns::BOARD), 4 bytes: u32 board number;
+//! - [`PAR`](Columns::PAR), 5 bytes: i16 North/South par score, then the
+//!   first par contract as a bid code (`0xFF` when the deal is passed out),
+//!   a byte of declarer (bits 0–1) and penalty (bits 2–3), and i8 overtricks;
+//! - [`AUCTION`](Columns::AUCTION), `1 + capacity` bytes: the number of
+//!   calls, then one code per call (pass, double, redouble, then the bids
+//!   from 1♣ up), unused slots `0xFF`.
+//!
+//! Rows stay fixed-width, so seeking and [`rows_in`]-style tail handling work
+//! as in version 1.  [`Writer`] chooses the version: a schema without columns
+//! writes `ponsDD01`, which every older reader understands.
+//!
+//! [`load`] and [`from_bytes`] materialise a whole database.  For banks too
+//! large for that, [`Reader`] decodes rows lazily from any `Read + Seek` —
+//! a file, or a memory-mapped slice through [`Reader::from_slice`] — with
+//! random access by row and strided or sharded iteration for parallel
+//! workers.
 
+use crate::bidding::array::{CALL_VARIANTS, decode_call, encode_call};
 use crate::gib;
-use contract_bridge::{Builder, FullDeal, Hand, Seat};
-use ddss::{TrickCountRow, TrickCountTable};
-use std::io;
+use contract_bridge::auction::{Auction, Call};
+use contract_bridge::{AbsoluteVulnerability, Builder, Contract, FullDeal, Hand, Penalty, Seat};
+use ddss::{Par, ParContract, TrickCountRow, TrickCountTable};
+use std::io::{self, Read, Seek, SeekFrom, Write};
 use std::path::Path;
 
 /// File magic; the trailing digits version the format.
 pub const MAGIC: [u8; 8] = *b"ponsDD01";
 
+/// File magic of version 2, the format with per-row [`Columns`]
+pub const MAGIC_V2: [u8; 8] = *b"ponsDD02";
+
 /// Bytes per row: three hand words plus five trick-row words.
 pub const ROW_LEN: usize = 34;
 
+/// Bytes of the version-2 header following [`MAGIC_V2`]
+const HEADER_LEN: usize = 4;
+
+/// Code of an empty call slot and of a passed-out par contract
+const NO_CALL: u8 = 0xFF;
+
+bitflags::bitflags! {
+    /// Optional per-row columns of a version-2 file
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    pub struct Columns: u16 {
+        /// The dealer
+        const DEALER = 1;
+        /// The absolute vulnerability
+        const VULNERABILITY = 1 << 1;
+        /// The board number
+        const BOARD = 1 << 2;
+        /// The par score and a par contract
+        const PAR = 1 << 3;
+        /// An auction, up to the schema's capacity
+        const AUCTION = 1 << 4;
+    }
+}
+
+/// The row layout of a `.pdd` file: which [`Columns`] follow the deal and
+/// table, and how many calls the auction column holds
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Schema {
+    /// The columns present in every row
+    pub columns: Columns,
+    /// Calls the auction column holds; ignored without [`Columns::AUCTION`]
+    pub auction_calls: u8,
+}
+
+impl Schema {
+    /// The bare version-1 row
+    pub const V1: Self = Self {
+        columns: Columns::empty(),
+        auction_calls: 0,
+    };
+
+    /// Bytes per row under this schema
+    #[must_use]
+    pub const fn row_len(self) -> usize {
+        let mut len = ROW_LEN;
+        if self.columns.contains(Columns::DEALER) {
+            len += 1;
+        }
+        if self.columns.contains(Columns::VULNERABILITY) {
+            len += 1;
+        }
+        if self.columns.contains(Columns::BOARD) {
+            len += 4;
+        }
+        if self.columns.contains(Columns::PAR) {
+            len += 5;
+        }
+        if self.columns.contains(Columns::AUCTION) {
+            len += 1 + self.auction_calls as usize;
+        }
+        len
+    }
+
+    /// The smallest schema holding every field the entries carry
+    ///
+    /// A column is present if any entry fills it, with room for the longest
+    /// auction.  Writing entries that leave a present column empty fails, so
+    /// this suits banks whose rows are uniform — one A/B dump, one solve.
+    #[must_use]
+    pub fn of<'a>(entries: impl IntoIterator<Item = &'a Entry>) -> Self {
+        let mut schema = Self::V1;
+        for entry in entries {
+            schema.columns |= entry.columns();
+            if let Some(auction) = &entry.auction {
+                let len = u8::try_from(auction.len()).unwrap_or(u8::MAX);
+                schema.auction_calls = schema.auction_calls.max(len);
+            }
+        }
+        schema
+    }
+
+    /// The file prefix announcing this schema: the magic, plus the header for
+    /// version 2
+    #[must_use]
+    pub fn header(self) -> Vec<u8> {
+        if self.columns.is_empty() {
+            return MAGIC.to_vec();
+        }
+        let mut header = MAGIC_V2.to_vec();
+        header.extend_from_slice(&self.columns.bits().to_le_bytes());
+        let calls = if self.columns.contains(Columns::AUCTION) {
+            self.auction_calls
+        } else {
+            0
+        };
+        header.extend_from_slice(&[calls, 0]);
+        header
+    }
+
+    /// Read the schema from the start of a file, returning it with the
+    /// header's length; [`None`] if the bytes are not a `.pdd` header
+    fn sniff(bytes: &[u8]) -> Option<(Self, usize)> {
+        if bytes.starts_with(&MAGIC) {
+            return Some((Self::V1, MAGIC.len()));
+        }
+        let header = bytes.strip_prefix(&MAGIC_V2)?.get(..HEADER_LEN)?;
+        let columns = Columns::from_bits(u16::from_le_bytes([header[0], header[1]]))?;
+        let schema = Self {
+            columns,
+            auction_calls: header[2],
+        };
+        Some((schema, MAGIC_V2.len() + HEADER_LEN))
+    }
+}
+
+/// The par column: the score and one contract achieving it
+///
+/// A [`Par`] may list several contracts; the file keeps the first, the one a
+/// report would print.  `contract` is [`None`] for a passed-out deal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParResult {
+    /// North/South par score
+    pub score: i32,
+    /// The first par contract
+    pub contract: Option<ParContract>,
+}
+
+impl From<&Par> for ParResult {
+    fn from(par: &Par) -> Self {
+        Self {
+            score: par.score,
+            contract: par.contracts.first().copied(),
+        }
+    }
+}
+
+/// One row of a `.pdd` file: the deal and its table, plus whatever board
+/// metadata the file's [`Schema`] carries
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    /// The four hands
+    pub deal: FullDeal,
+    /// The double-dummy table
+    pub tricks: TrickCountTable,
+    /// The dealer
+    pub dealer: Option<Seat>,
+    /// The absolute vulnerability
+    pub vul: Option<AbsoluteVulnerability>,
+    /// The board number
+    pub board: Option<u32>,
+    /// The par score and contract
+    pub par: Option<ParResult>,
+    /// An auction from the dealer, e.g. the one an A/B arm bid
+    pub auction: Option<Auction>,
+}
+
+impl Entry {
+    /// A deal and its table with no metadata: a version-1 row
+    #[must_use]
+    pub const fn new(deal: FullDeal, tricks: TrickCountTable) -> Self {
+        Self {
+            deal,
+            tricks,
+            dealer: None,
+            vul: None,
+            board: None,
+            par: None,
+            auction: None,
+        }
+    }
+
+    /// The columns this entry fills
+    #[must_use]
+    pub fn columns(&self) -> Columns {
+        let mut columns = Columns::empty();
+        columns.set(Columns::DEALER, self.dealer.is_some());
+        columns.set(Columns::VULNERABILITY, self.vul.is_some());
+        columns.set(Columns::BOARD, self.board.is_some());
+        columns.set(Columns::PAR, self.par.is_some());
+        columns.set(Columns::AUCTION, self.auction.is_some());
+        columns
+    }
+}
+
 /// Whole rows in a `.pdd` file of `len` bytes, rounding a ragged tail down.
 ///
 /// A generator killed mid-write leaves a partial row (the output buffer is not
@@ -91,64 +313,495 @@ pub fn decode_row(row: &[u8; ROW_LEN]) -> Option<(FullDeal, TrickCountTable)> {
     Some((deal, table))
 }
 
-/// Decode a whole DD database, sniffing the format.
+/// Encode an entry as a row of `schema`, version-1 prefix first.
 ///
-/// Bytes starting with [`MAGIC`] are `.pdd` rows — a truncated tail or an
-/// invalid row is an [`io::ErrorKind::InvalidData`] error. Anything else is
-/// treated as GIB text, permissively skipping unparsable lines like every
-/// existing consumer.
-pub fn from_bytes(bytes: &[u8]) -> io::Result<Vec<(FullDeal, TrickCountTable)>> {
+/// Fails with [`io::ErrorKind::InvalidInput`] if the entry leaves a column of
+/// the schema empty or its auction exceeds the schema's capacity.  Fields the
+/// schema has no column for are dropped.
+fn encode_entry(schema: Schema, entry: &Entry) -> io::Result<Vec<u8>> {
+    let missing = |what: &str| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("entry has no {what} for its .pdd column"),
+        )
+    };
+    let columns = schema.columns;
+    let mut row = Vec::with_capacity(schema.row_len());
+    row.extend_from_slice(&encode_row(&entry.deal, &entry.tricks));
+    if columns.contains(Columns::DEALER) {
+        row.push(entry.dealer.ok_or_else(|| missing("dealer"))? as u8);
+    }
+    if columns.contains(Columns::VULNERABILITY) {
+        row.push(entry.vul.ok_or_else(|| missing("vulnerability"))?.bits());
+    }
+    if columns.contains(Columns::BOARD) {
+        let board = entry.board.ok_or_else(|| missing("board number"))?;
+        row.extend_from_slice(&board.to_le_bytes());
+    }
+    if columns.contains(Columns::PAR) {
+        let par = entry.par.as_ref().ok_or_else(|| missing("par"))?;
+        let score = i16::try_from(par.score).map_err(|_| missing("par score in range"))?;
+        row.extend_from_slice(&score.to_le_bytes());
+        match par.contract {
+            Some(par_contract) => {
+                let penalty = match par_contract.contract.penalty {
+                    Penalty::Undoubled => 0,
+                    Penalty::Doubled => 1,
+                    Penalty::Redoubled => 2,
+                };
+                row.push(encode_call(Call::Bid(par_contract.contract.bid)) as u8);
+                row.push(par_contract.declarer as u8 | penalty << 2);
+                row.push(par_contract.overtricks.to_le_bytes()[0]);
+            }
+            None => row.extend_from_slice(&[NO_CALL, 0, 0]),
+        }
+    }
+    if columns.contains(Columns::AUCTION) {
+        let auction = entry.auction.as_ref().ok_or_else(|| missing("auction"))?;
+        let capacity = usize::from(schema.auction_calls);
+        if auction.len() > capacity {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "{}-call auction exceeds the .pdd capacity of {capacity}",
+                    auction.len()
+                ),
+            ));
+        }
+        row.push(auction.len() as u8);
+        row.extend(auction.iter().map(|&call| encode_call(call) as u8));
+        row.resize(schema.row_len(), NO_CALL);
+    }
+    Ok(row)
+}
+
+/// Decode one row of `schema`; [`None`] for anything [`decode_row`] rejects,
+/// an out-of-range column value, or an illegal auction.
+fn decode_entry(schema: Schema, row: &[u8]) -> Option<Entry> {
+    let (head, mut rest) = row.split_first_chunk::<ROW_LEN>()?;
+    let (deal, tricks) = decode_row(head)?;
+    let mut entry = Entry::new(deal, tricks);
+    let mut take = |n: usize| -> Option<&[u8]> {
+        let (field, tail) = rest.split_at_checked(n)?;
+        rest = tail;
+        Some(field)
+    };
+    let columns = schema.columns;
+    if columns.contains(Columns::DEALER) {
+        entry.dealer = Some(*Seat::ALL.get(usize::from(take(1)?[0]))?);
+    }
+    if columns.contains(Columns::VULNERABILITY) {
+        entry.vul = Some(AbsoluteVulnerability::from_bits(take(1)?[0])?);
+    }
+    if columns.contains(Columns::BOARD) {
+        entry.board = Some(u32::from_le_bytes(take(4)?.try_into().ok()?));
+    }
+    if columns.contains(Columns::PAR) {
+        let field = take(5)?;
+        let score = i32::from(i16::from_le_bytes([field[0], field[1]]));
+        let contract = if field[2] == NO_CALL {
+            None
+        } else {
+            let Call::Bid(bid) = decode_code(field[2])? else {
+                return None;
+            };
+            let penalty = match field[3] >> 2 {
+                0 => Penalty::Undoubled,
+                1 => Penalty::Doubled,
+                2 => Penalty::Redoubled,
+                _ => return None,
+            };
+            Some(ParContract {
+                contract: Contract { bid, penalty },
+                declarer: Seat::ALL[usize::from(field[3] & 3)],
+                overtricks: i8::from_le_bytes([field[4]]),
+            })
+        };
+        entry.par = Some(ParResult { score, contract });
+    }
+    if columns.contains(Columns::AUCTION) {
+        let len = usize::from(take(1)?[0]);
+        let codes = take(usize::from(schema.auction_calls))?;
+        let mut auction = Auction::new();
+        for &code in codes.get(..len)? {
+            auction.try_push(decode_code(code)?).ok()?;
+        }
+        entry.auction = Some(auction);
+    }
+    Some(entry)
+}
+
+/// The call behind a stored code, if it is one
+fn decode_code(code: u8) -> Option<Call> {
+    let index = usize::from(code);
+    (index < CALL_VARIANTS).then(|| decode_call(index))
+}
+
+/// Streaming `.pdd` writer for a fixed [`Schema`]
+///
+/// Writes the header on creation and one fixed-width row per
+/// [`write`](Self::write).  Buffer the sink yourself for large banks.
+#[derive(Debug)]
+pub struct Writer<W> {
+    inner: W,
+    schema: Schema,
+}
+
+impl<W: Write> Writer<W> {
+    /// Start a file of `schema` rows: `ponsDD01` if it has no columns,
+    /// `ponsDD02` otherwise
+    ///
+    /// # Errors
+    ///
+    /// Propagates I/O errors from writing the header.
+    pub fn new(mut inner: W, schema: Schema) -> io::Result<Self> {
+        inner.write_all(&schema.header())?;
+        Ok(Self { inner, schema })
+    }
+
+    /// The schema rows are written in
+    #[must_use]
+    pub const fn schema(&self) -> Schema {
+        self.schema
+    }
+
+    /// Append one entry
+    ///
+    /// # Errors
+    ///
+    /// [`io::ErrorKind::InvalidInput`] if the entry leaves a column empty or
+    /// its auction does not fit; I/O errors from the sink.
+    pub fn write(&mut self, entry: &Entry) -> io::Result<()> {
+        self.inner.write_all(&encode_entry(self.schema, entry)?)
+    }
+
+    /// Flush and return the sink
+    ///
+    /// # Errors
+    ///
+    /// Propagates the flush error.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+/// Decode a whole DD database with its metadata, sniffing the format.
+///
+/// Bytes starting with [`MAGIC`] or [`MAGIC_V2`] are `.pdd` rows — a
+/// truncated tail or an invalid row is an [`io::ErrorKind::InvalidData`]
+/// error. Anything else is treated as GIB text, permissively skipping
+/// unparsable lines like every existing consumer; text and version-1 rows
+/// carry no metadata.
+pub fn entries_from_bytes(bytes: &[u8]) -> io::Result<Vec<Entry>> {
     let invalid = |what| io::Error::new(io::ErrorKind::InvalidData, what);
-    let Some(rows) = bytes.strip_prefix(&MAGIC) else {
+    let Some((schema, start)) = Schema::sniff(bytes) else {
+        if bytes.starts_with(&MAGIC_V2) {
+            return Err(invalid("corrupt .pdd header"));
+        }
         let text = str::from_utf8(bytes).map_err(|_| invalid("neither .pdd nor GIB text"))?;
-        return Ok(text.lines().filter_map(gib::parse_line).collect());
+        return Ok(text
+            .lines()
+            .filter_map(gib::parse_line)
+            .map(|(deal, tricks)| Entry::new(deal, tricks))
+            .collect());
     };
-    if !rows.len().is_multiple_of(ROW_LEN) {
+    let rows = &bytes[start..];
+    let row_len = schema.row_len();
+    if !rows.len().is_multiple_of(row_len) {
         return Err(invalid("truncated .pdd file"));
     }
-    rows.as_chunks::<ROW_LEN>()
-        .0
-        .iter()
-        .map(|row| decode_row(row).ok_or_else(|| invalid("corrupt .pdd row")))
+    rows.chunks_exact(row_len)
+        .map(|row| decode_entry(schema, row).ok_or_else(|| invalid("corrupt .pdd row")))
         .collect()
 }
 
+/// Decode a whole DD database, sniffing the format.
+///
+/// [`entries_from_bytes`] without the metadata: the deals and tables of any
+/// `.pdd` version or GIB text.
+pub fn from_bytes(bytes: &[u8]) -> io::Result<Vec<(FullDeal, TrickCountTable)>> {
+    Ok(entries_from_bytes(bytes)?
+        .into_iter()
+        .map(|entry| (entry.deal, entry.tricks))
+        .collect())
+}
+
 /// Read a DD database file in either format ([`from_bytes`] on its contents).
 pub fn load(path: impl AsRef<Path>) -> io::Result<Vec<(FullDeal, TrickCountTable)>> {
     from_bytes(&std::fs::read(path)?)
 }
 
+/// Read a DD database file with its metadata ([`entries_from_bytes`] on its
+/// contents).
+pub fn load_entries(path: impl AsRef<Path>) -> io::Result<Vec<Entry>> {
+    entries_from_bytes(&std::fs::read(path)?)
+}
+
 /// Read up to `count` rows starting at row `skip` — a seek-based slice of a
 /// binary `.pdd` database, so experiments can shard a multi-gigabyte deal
 /// bank without reading it whole.  Binary-only: GIB text has no fixed row
 /// width to seek by.  A slice past the tail returns the rows that exist; the
-/// caller decides whether short counts as exhausted.
+/// caller decides whether short counts as exhausted.  A slice reaching into a
+/// ragged tail is an error, as in [`from_bytes`].
 pub fn load_slice(
     path: impl AsRef<Path>,
     skip: u64,
     count: usize,
 ) -> io::Result<Vec<(FullDeal, TrickCountTable)>> {
-    use std::io::{Read, Seek, SeekFrom};
-    let invalid = |what| io::Error::new(io::ErrorKind::InvalidData, what);
-    let mut file = std::fs::File::open(path)?;
-    let mut magic = [0u8; MAGIC.len()];
-    file.read_exact(&mut magic)?;
-    if magic != MAGIC {
-        return Err(invalid("sliced reads need the binary .pdd format"));
-    }
-    file.seek(SeekFrom::Start(MAGIC.len() as u64 + skip * ROW_LEN as u64))?;
-    let mut bytes = Vec::new();
-    file.take(count as u64 * ROW_LEN as u64)
-        .read_to_end(&mut bytes)?;
-    if !bytes.len().is_multiple_of(ROW_LEN) {
-        return Err(invalid("truncated .pdd file"));
+    let reader = Reader::open(path)?;
+    if reader.ragged_tail() != 0 && skip.saturating_add(count as u64) > reader.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "truncated .pdd file",
+        ));
+    }
+    reader.strided(skip, 1).take(count).collect()
+}
+
+/// Lazy, seekable reader over a binary `.pdd` database of either version
+///
+/// Construction reads the [`Schema`] from the header and counts whole rows
+/// the way [`rows_in`] does, so a ragged tail left by an interrupted
+/// generator is never yielded; [`ragged_tail`](Self::ragged_tail) reports it
+/// for callers that would rather reject the file, as [`from_bytes`] does.  As
+/// an [`Iterator`] the reader yields every selected deal and table in order,
+/// decoding one row per step, and seeks only when the selection skips rows;
+/// [`entries`](Self::entries) yields the whole [`Entry`] instead.
+/// [`row`](Self::row) and [`entry`](Self::entry) read any row by index
+/// without disturbing the iteration.
+///
+/// Wrap a [`File`](std::fs::File) in a [`BufReader`](io::BufReader) for
+/// sequential scans ([`open`](Self::open) does); a strided scan seeks by
+/// [`seek_relative`](io::BufReader::seek_relative), which keeps the buffer
+/// when the next row is already in it.
+#[derive(Debug)]
+pub struct Reader<R> {
+    inner: R,
+    schema: Schema,
+    /// Byte offset of row 0, past the magic and any header
+    start: u64,
+    rows: u64,
+    tail: u64,
+    /// Byte offset of `inner`, tracked so contiguous reads need no seek;
+    /// [`None`] after a failed read
+    position: Option<u64>,
+    next: u64,
+    end: u64,
+    step: u64,
+    buf: Vec<u8>,
+}
+
+impl<R: Read + Seek> Reader<R> {
+    /// Validate the header and count the rows of a `.pdd` stream
+    ///
+    /// # Errors
+    ///
+    /// I/O errors, and [`io::ErrorKind::InvalidData`] if the stream does not
+    /// start with [`MAGIC`] or a valid [`MAGIC_V2`] header (GIB text
+    /// included: it has no fixed row width to seek by).
+    pub fn new(mut inner: R) -> io::Result<Self> {
+        let len = inner.seek(SeekFrom::End(0))?;
+        inner.seek(SeekFrom::Start(0))?;
+        let mut head = Vec::with_capacity(MAGIC_V2.len() + HEADER_LEN);
+        (&mut inner)
+            .take((MAGIC_V2.len() + HEADER_LEN) as u64)
+            .read_to_end(&mut head)?;
+        let (schema, start) = Schema::sniff(&head)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "not a binary .pdd file"))?;
+        let start = start as u64;
+        let row_len = schema.row_len() as u64;
+        let rows = (len - start) / row_len;
+        Ok(Self {
+            inner,
+            schema,
+            start,
+            rows,
+            tail: len - start - rows * row_len,
+            position: Some(head.len() as u64),
+            next: 0,
+            end: rows,
+            step: 1,
+            buf: vec![0; schema.row_len()],
+        })
+    }
+
+    /// The layout of the rows, [`Schema::V1`] for a version-1 file
+    #[must_use]
+    pub const fn schema(&self) -> Schema {
+        self.schema
+    }
+
+    /// Whole rows in the database, regardless of the iteration's selection
+    #[must_use]
+    pub const fn len(&self) -> u64 {
+        self.rows
+    }
+
+    /// Whether the database holds no whole row
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.rows == 0
+    }
+
+    /// Bytes after the last whole row: nonzero only for an interrupted write
+    #[must_use]
+    pub const fn ragged_tail(&self) -> u64 {
+        self.tail
+    }
+
+    /// Iterate rows `start`, `start + step`, `start + 2 × step`, … to the end
+    ///
+    /// Worker `i` of `n` reading `strided(i, n)` covers the database
+    /// interleaved, each row exactly once.  Replaces any earlier selection.
+    ///
+    /// # Panics
+    ///
+    /// If `step` is zero.
+    #[must_use]
+    pub fn strided(mut self, start: u64, step: u64) -> Self {
+        assert!(step > 0, "stride must be positive");
+        self.next = start.min(self.rows);
+        self.end = self.rows;
+        self.step = step;
+        self
+    }
+
+    /// Iterate the `index`-th of `count` contiguous, near-equal blocks
+    ///
+    /// Worker `i` of `n` reading `shard(i, n)` covers the database in order,
+    /// each row exactly once, with block sizes differing by at most one.
+    /// Replaces any earlier selection.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is not below `count`.
+    #[must_use]
+    pub fn shard(mut self, index: u64, count: u64) -> Self {
+        assert!(index < count, "shard {index} of {count} does not exist");
+        let bound = |i: u64| (u128::from(self.rows) * u128::from(i) / u128::from(count)) as u64;
+        self.next = bound(index);
+        self.end = bound(index + 1);
+        self.step = 1;
+        self
+    }
+
+    /// Iterate whole entries, metadata included, over the same selection
+    #[must_use]
+    pub const fn entries(self) -> Entries<R> {
+        Entries(self)
+    }
+
+    /// Read and decode row `index`, independently of the iteration
+    ///
+    /// # Errors
+    ///
+    /// I/O errors; [`io::ErrorKind::InvalidInput`] for an index past the last
+    /// whole row and [`io::ErrorKind::InvalidData`] for a corrupt row.
+    pub fn entry(&mut self, index: u64) -> io::Result<Entry> {
+        if index >= self.rows {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "row {index} is past the {} rows of the .pdd file",
+                    self.rows
+                ),
+            ));
+        }
+        let offset = self.start + index * self.buf.len() as u64;
+        match self.position {
+            Some(position) if position == offset => {}
+            // A relative seek keeps a `BufReader`'s buffer when it can.
+            Some(position) => self.inner.seek_relative(offset as i64 - position as i64)?,
+            None => {
+                self.inner.seek(SeekFrom::Start(offset))?;
+            }
+        }
+        // Forget the offset until the read is known to be whole.
+        self.position = None;
+        self.inner.read_exact(&mut self.buf)?;
+        self.position = Some(offset + self.buf.len() as u64);
+        decode_entry(self.schema, &self.buf)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "corrupt .pdd row"))
+    }
+
+    /// Read and decode the deal and table of row `index`
+    ///
+    /// # Errors
+    ///
+    /// As [`entry`](Self::entry).
+    pub fn row(&mut self, index: u64) -> io::Result<(FullDeal, TrickCountTable)> {
+        self.entry(index).map(|entry| (entry.deal, entry.tricks))
+    }
+
+    /// The next selected row index, advancing the selection
+    fn advance(&mut self) -> Option<u64> {
+        if self.next >= self.end {
+            return None;
+        }
+        let index = self.next;
+        self.next = self.next.saturating_add(self.step);
+        Some(index)
+    }
+}
+
+impl Reader<io::BufReader<std::fs::File>> {
+    /// Open a `.pdd` file for buffered lazy reading
+    ///
+    /// # Errors
+    ///
+    /// As [`new`](Self::new), plus the errors of opening the file.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(io::BufReader::new(std::fs::File::open(path)?))
+    }
+}
+
+impl<'a> Reader<io::Cursor<&'a [u8]>> {
+    /// Read a `.pdd` database already in memory
+    ///
+    /// Meant for memory-mapped banks: map the file with the mapping crate of
+    /// your choice and pass the bytes here.  Each row is still copied into
+    /// the reader's one-row buffer before it decodes, but out of the page
+    /// cache, with no file reads or [`io::BufReader`] in between.
+    ///
+    /// # Errors
+    ///
+    /// As [`new`](Self::new).
+    pub fn from_slice(bytes: &'a [u8]) -> io::Result<Self> {
+        Self::new(io::Cursor::new(bytes))
+    }
+}
+
+impl<R: Read + Seek> Iterator for Reader<R> {
+    type Item = io::Result<(FullDeal, TrickCountTable)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.advance()?;
+        Some(self.row(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.end.saturating_sub(self.next).div_ceil(self.step);
+        let left = usize::try_from(left).unwrap_or(usize::MAX);
+        (left, Some(left))
+    }
+}
+
+/// [`Reader`] yielding whole [`Entry`] rows, from [`Reader::entries`]
+#[derive(Debug)]
+pub struct Entries<R>(Reader<R>);
+
+impl<R: Read + Seek> Iterator for Entries<R> {
+    type Item = io::Result<Entry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.0.advance()?;
+        Some(self.0.entry(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
     }
-    bytes
-        .as_chunks::<ROW_LEN>()
-        .0
-        .iter()
-        .map(|row| decode_row(row).ok_or_else(|| invalid("corrupt .pdd row")))
-        .collect()
 }
 
 #[cfg(test)]
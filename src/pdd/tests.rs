@@ -1,4 +1,7 @@
 use super::*;
+use contract_bridge::AbsoluteVulnerability;
+use contract_bridge::auction::{Auction, Call};
+use ddss::ParContract;
 
 /// The first line of `sol100000.txt` (same fixture as the `gib` tests).
 const LINE: &str =
@@ -84,3 +87,193 @@ fn sniffs_both_formats() {
     );
     assert!(from_bytes(&bin[..bin.len() - 1]).is_err());
 }
+
+/// `n` distinct rows: the fixture deal with row `i`'s clubs column set to `i`.
+fn bank(n: u8) -> (Vec<u8>, Vec<(FullDeal, TrickCountTable)>) {
+    let (deal, table) = fixture();
+    let mut bin = MAGIC.to_vec();
+    let mut rows = Vec::new();
+    for i in 0..n {
+        let mut table = table;
+        table.0[0] = TrickCountRow::new(i, 13 - i, i, 13 - i);
+        bin.extend_from_slice(&encode_row(&deal, &table));
+        rows.push((deal, table));
+    }
+    (bin, rows)
+}
+
+#[test]
+fn reader_streams_and_seeks() {
+    let (bin, rows) = bank(7);
+    let reader = Reader::from_slice(&bin).unwrap();
+    assert_eq!(reader.len(), 7);
+    assert_eq!(reader.ragged_tail(), 0);
+    assert_eq!(reader.size_hint(), (7, Some(7)));
+    assert_eq!(reader.collect::<io::Result<Vec<_>>>().unwrap(), rows);
+
+    // Random access leaves the iteration where it was.
+    let mut reader = Reader::new(io::Cursor::new(bin)).unwrap();
+    assert_eq!(reader.next().unwrap().unwrap(), rows[0]);
+    assert_eq!(reader.row(5).unwrap(), rows[5]);
+    assert_eq!(reader.row(2).unwrap(), rows[2]);
+    assert_eq!(reader.next().unwrap().unwrap(), rows[1]);
+    assert_eq!(
+        reader.row(7).unwrap_err().kind(),
+        io::ErrorKind::InvalidInput
+    );
+}
+
+#[test]
+fn reader_strides_and_shards() {
+    let (bin, rows) = bank(7);
+    let read = |reader: Reader<_>| reader.map(Result::unwrap).collect::<Vec<_>>();
+
+    let strided = read(Reader::from_slice(&bin).unwrap().strided(1, 3));
+    assert_eq!(strided, [rows[1], rows[4]]);
+    assert_eq!(Reader::from_slice(&bin).unwrap().strided(9, 2).count(), 0);
+
+    // Shards tile the bank in order, sizes differing by at most one.
+    let shards: Vec<_> = (0..3)
+        .map(|i| read(Reader::from_slice(&bin).unwrap().shard(i, 3)))
+        .collect();
+    assert_eq!(shards.iter().map(Vec::len).collect::<Vec<_>>(), [2, 2, 3]);
+    assert_eq!(shards.concat(), rows);
+}
+
+#[test]
+fn reader_validates_like_rows_in() {
+    let (mut bin, rows) = bank(3);
+    // A ragged tail is counted, not yielded.
+    bin.extend_from_slice(&[0; ROW_LEN - 1]);
+    let reader = Reader::from_slice(&bin).unwrap();
+    assert_eq!(reader.len(), rows_in(bin.len() as u64));
+    assert_eq!(reader.ragged_tail(), ROW_LEN as u64 - 1);
+    assert_eq!(reader.map(Result::unwrap).collect::<Vec<_>>(), rows);
+
+    for head in [&b"ponsDD"[..], b"not a pdd", LINE.as_bytes()] {
+        let error = Reader::from_slice(head).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    // A corrupt row is an error in place; the rows after it still read.
+    let (mut bin, rows) = bank(3);
+    bin[MAGIC.len() + ROW_LEN] |= 1;
+    let read: Vec<_> = Reader::from_slice(&bin).unwrap().collect();
+    assert_eq!(read[0].as_ref().unwrap(), &rows[0]);
+    assert_eq!(
+        read[1].as_ref().unwrap_err().kind(),
+        io::ErrorKind::InvalidData
+    );
+    assert_eq!(read[2].as_ref().unwrap(), &rows[2]);
+}
+
+/// The fixture with every version-2 column filled.
+fn full_entry() -> Entry {
+    let (deal, tricks) = fixture();
+    let auction: Auction = ["1H", "X", "1S", "P", "2H", "P", "4H", "P", "P", "P"]
+        .iter()
+        .map(|call| call.parse::<Call>().unwrap())
+        .fold(Auction::new(), |mut auction, call| {
+            auction.try_push(call).unwrap();
+            auction
+        });
+    Entry {
+        deal,
+        tricks,
+        dealer: Some(Seat::North),
+        vul: Some(AbsoluteVulnerability::EW),
+        board: Some(70_000),
+        par: Some(ParResult {
+            score: -420,
+            contract: Some(ParContract {
+                contract: "4SX".parse().unwrap(),
+                declarer: Seat::West,
+                overtricks: -1,
+            }),
+        }),
+        auction: Some(auction),
+    }
+}
+
+#[test]
+fn v2_round_trips_every_column() {
+    let entry = full_entry();
+    let passed_out = Entry {
+        par: Some(ParResult {
+            score: 0,
+            contract: None,
+        }),
+        auction: Some(Auction::new()),
+        ..entry.clone()
+    };
+    let schema = Schema::of([&entry, &passed_out]);
+    assert_eq!(schema.columns, Columns::all());
+    assert_eq!(schema.auction_calls, 10);
+
+    let mut writer = Writer::new(Vec::new(), schema).unwrap();
+    writer.write(&entry).unwrap();
+    writer.write(&passed_out).unwrap();
+    let bin = writer.into_inner().unwrap();
+    assert!(bin.starts_with(&MAGIC_V2));
+    assert_eq!(bin.len(), MAGIC_V2.len() + 4 + 2 * schema.row_len());
+
+    let both = [entry.clone(), passed_out];
+    assert_eq!(entries_from_bytes(&bin).unwrap(), both);
+    assert_eq!(from_bytes(&bin).unwrap(), [fixture(), fixture()]);
+
+    let mut reader = Reader::from_slice(&bin).unwrap();
+    assert_eq!(reader.schema(), schema);
+    assert_eq!(reader.entry(1).unwrap(), both[1]);
+    let entries: Vec<_> = reader.entries().map(Result::unwrap).collect();
+    assert_eq!(entries, both);
+}
+
+#[test]
+fn bare_schema_writes_v1() {
+    let (deal, table) = fixture();
+    let mut writer = Writer::new(Vec::new(), Schema::V1).unwrap();
+    writer.write(&full_entry()).unwrap();
+    let bin = writer.into_inner().unwrap();
+
+    let mut v1 = MAGIC.to_vec();
+    v1.extend_from_slice(&encode_row(&deal, &table));
+    assert_eq!(bin, v1);
+    assert_eq!(entries_from_bytes(&bin).unwrap(), [Entry::new(deal, table)]);
+}
+
+#[test]
+fn v2_rejects_what_it_cannot_hold() {
+    let entry = full_entry();
+    let schema = Schema {
+        columns: Columns::BOARD | Columns::AUCTION,
+        auction_calls: 4,
+    };
+    let mut writer = Writer::new(Vec::new(), schema).unwrap();
+    // Ten calls do not fit four slots; a missing board has no column value.
+    let error = writer.write(&entry).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    let bare = Entry::new(entry.deal, entry.tricks);
+    assert_eq!(
+        writer.write(&bare).unwrap_err().kind(),
+        io::ErrorKind::InvalidInput
+    );
+
+    // Unknown column bits, a short header, and an illegal stored auction.
+    let mut bin = MAGIC_V2.to_vec();
+    bin.extend_from_slice(&[0, 0x80, 0, 0]);
+    assert!(entries_from_bytes(&bin).is_err());
+    assert!(entries_from_bytes(&MAGIC_V2).is_err());
+    assert!(Reader::from_slice(&MAGIC_V2).is_err());
+
+    let schema = Schema {
+        columns: Columns::AUCTION,
+        auction_calls: 2,
+    };
+    let mut bin = schema.header();
+    bin.extend_from_slice(&encode_row(&entry.deal, &entry.tricks));
+    bin.extend_from_slice(&[2, 8, 3]); // 2♣ then 1♣
+    assert_eq!(
+        entries_from_bytes(&bin).unwrap_err().kind(),
+        io::ErrorKind::InvalidData
+    );
+}
@@ -0,0 +1,370 @@
+//! Board records: a match as data.
+//!
+//! A pons match bids every board twice, with our pair North/South at table A
+//! and East/West at table B, so a board's swing is the difference between two
+//! auctions on one deal.  A [`Board`] is that unit — the deal, the dealer, both
+//! tables' auctions and, once something has priced them, each table's
+//! North/South score.  A [`Header`] says which two arms played and how to
+//! reproduce the boards.
+//!
+//! The types are plain data and always available; the `serde` feature adds
+//! the two file layouts every A/B harness in this repository reads and
+//! writes:
+//!
+//! - a **dump** — one JSON object, the header's fields beside a `boards` array
+//!   ([`Dump::read`], [`Dump::load`]);
+//! - **JSONL** — the header on the first line, then one board per line, so a
+//!   generator can append boards as it bids them and a consumer can fold a
+//!   match far larger than memory ([`Writer`], [`Reader`]).
+//!
+//! # Versions
+//!
+//! Every header carries the [`VERSION`] it was written at.  Version 1 is the
+//! layout the harnesses wrote before the field existed, so a file without it
+//! reads as version 1; a reader rejects versions newer than its own instead
+//! of guessing at fields it does not know.  Fields added within a version are
+//! optional and omitted when empty, as the scores are.
+
+use crate::scoring::final_contract;
+use contract_bridge::auction::Auction;
+use contract_bridge::{AbsoluteVulnerability, Contract, FullDeal, Seat};
+
+/// The record layout this crate writes, and the newest it reads
+pub const VERSION: u32 = 1;
+
+/// Who played a match, and how to bid its boards again
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Header {
+    /// The record layout, [`VERSION`] when written by this crate
+    #[cfg_attr(feature = "serde", serde(default = "first_version"))]
+    pub version: u32,
+    /// The arm whose pair sits North/South at table A
+    pub our_label: String,
+    /// The arm it plays against
+    pub their_label: String,
+    /// The vulnerability the boards were bid at, unless a board says otherwise
+    pub vulnerability: AbsoluteVulnerability,
+    /// The deal seed, so the exact board stream is reproducible
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub seed: Option<u64>,
+    /// The generating command line (`argv[1..]`), so a scorer can rebuild
+    /// the exact configuration of both arms
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub gen_args: Vec<String>,
+}
+
+#[cfg(feature = "serde")]
+const fn first_version() -> u32 {
+    1
+}
+
+impl Header {
+    /// A header at the current [`VERSION`] with no seed or command line
+    #[must_use]
+    pub fn new(
+        our_label: impl Into<String>,
+        their_label: impl Into<String>,
+        vulnerability: AbsoluteVulnerability,
+    ) -> Self {
+        Self {
+            version: VERSION,
+            our_label: our_label.into(),
+            their_label: their_label.into(),
+            vulnerability,
+            seed: None,
+            gen_args: Vec::new(),
+        }
+    }
+}
+
+/// One board of a match: a deal bid at both tables
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Board {
+    /// The four hands
+    pub deal: FullDeal,
+    /// The dealer, who makes the first call at both tables
+    pub dealer: Seat,
+    /// This board's vulnerability, when it is not the [`Header`]'s
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub vulnerability: Option<AbsoluteVulnerability>,
+    /// The auction with our pair North/South
+    pub table_a: Auction,
+    /// The auction with our pair East/West
+    pub table_b: Auction,
+    /// North/South's double-dummy score at `[table A, table B]`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub dd: Option<[i64; 2]>,
+    /// North/South's expected single-dummy score at `[table A, table B]`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub sd: Option<[f64; 2]>,
+}
+
+impl Board {
+    /// A board at the match's vulnerability, not yet scored
+    #[must_use]
+    pub const fn new(deal: FullDeal, dealer: Seat, table_a: Auction, table_b: Auction) -> Self {
+        Self {
+            deal,
+            dealer,
+            vulnerability: None,
+            table_a,
+            table_b,
+            dd: None,
+            sd: None,
+        }
+    }
+
+    /// The vulnerability this board was bid at under `header`
+    #[must_use]
+    pub fn vulnerability(&self, header: &Header) -> AbsoluteVulnerability {
+        self.vulnerability.unwrap_or(header.vulnerability)
+    }
+
+    /// The contract and declarer each table reached, `None` for a pass-out
+    #[must_use]
+    pub fn contracts(&self) -> [Option<(Contract, Seat)>; 2] {
+        [
+            final_contract(&self.table_a, self.dealer),
+            final_contract(&self.table_b, self.dealer),
+        ]
+    }
+
+    /// Whether the tables reached different contracts — the only boards
+    /// that can swing
+    #[must_use]
+    pub fn is_divergent(&self) -> bool {
+        let [a, b] = self.contracts();
+        a != b
+    }
+
+    /// Our pair's double-dummy gain on this board in points, if scored
+    ///
+    /// North/South's score at table A less North/South's at table B: our
+    /// pair holds North/South's cards at A and East/West's at B.
+    #[must_use]
+    pub fn dd_swing(&self) -> Option<i64> {
+        self.dd.map(|[a, b]| a - b)
+    }
+}
+
+/// A whole match: one [`Header`] and its boards
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dump {
+    /// Who played and how to reproduce the boards
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub header: Header,
+    /// The boards, in the order they were bid
+    pub boards: Vec<Board>,
+}
+
+#[cfg(feature = "serde")]
+pub use file::{Error, Reader, Writer};
+
+#[cfg(feature = "serde")]
+mod file {
+    use super::{Board, Dump, Header, VERSION};
+    use std::io::{self, BufRead, Write};
+    use std::path::Path;
+    use thiserror::Error;
+
+    /// A record file that could not be read
+    #[derive(Debug, Error)]
+    pub enum Error {
+        /// The underlying read failed
+        #[error(transparent)]
+        Io(#[from] io::Error),
+        /// A line (1-based; 1 for a whole dump) is not the JSON it should be
+        #[error("line {line}: {source}")]
+        Json {
+            /// Where the bad JSON is
+            line: usize,
+            /// What is wrong with it
+            source: serde_json::Error,
+        },
+        /// A JSONL stream with no header line
+        #[error("no header line")]
+        MissingHeader,
+        /// Written at a newer layout than [`VERSION`]
+        #[error("record version {0} is newer than this reader's {VERSION}")]
+        Version(u32),
+    }
+
+    const fn check(header: &Header) -> Result<(), Error> {
+        if header.version > VERSION {
+            return Err(Error::Version(header.version));
+        }
+        Ok(())
+    }
+
+    impl Dump {
+        /// Read a dump from one JSON document
+        ///
+        /// # Errors
+        ///
+        /// If the reader fails, the text is not a dump, or the dump is newer
+        /// than [`VERSION`].
+        pub fn read(reader: impl io::Read) -> Result<Self, Error> {
+            let dump: Self = serde_json::from_reader(reader)
+                .map_err(|source| Error::Json { line: 1, source })?;
+            check(&dump.header)?;
+            Ok(dump)
+        }
+
+        /// Read a dump file, either layout: a `.jsonl` extension selects
+        /// JSONL, anything else one JSON document
+        ///
+        /// # Errors
+        ///
+        /// As [`read`][Self::read] and [`Reader::new`], plus failing to open
+        /// the file.
+        pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+            let path = path.as_ref();
+            let file = io::BufReader::new(std::fs::File::open(path)?);
+            if path.extension().is_some_and(|ext| ext == "jsonl") {
+                let mut reader = Reader::new(file)?;
+                let boards = reader.by_ref().collect::<Result<_, _>>()?;
+                Ok(Self {
+                    header: reader.header,
+                    boards,
+                })
+            } else {
+                Self::read(file)
+            }
+        }
+
+        /// Write the dump as JSONL: the header line, then one line per board
+        ///
+        /// # Errors
+        ///
+        /// If the writer fails.
+        pub fn write_jsonl(&self, writer: impl Write) -> io::Result<()> {
+            let mut out = Writer::new(writer, &self.header)?;
+            for board in &self.boards {
+                out.write(board)?;
+            }
+            out.flush()
+        }
+    }
+
+    /// Streams a match out as JSONL, one board per line
+    #[derive(Debug)]
+    pub struct Writer<W: Write> {
+        inner: W,
+    }
+
+    impl<W: Write> Writer<W> {
+        /// Start a stream with its header line
+        ///
+        /// # Errors
+        ///
+        /// If the writer fails.
+        pub fn new(mut inner: W, header: &Header) -> io::Result<Self> {
+            serde_json::to_writer(&mut inner, header)?;
+            inner.write_all(b"\n")?;
+            Ok(Self { inner })
+        }
+
+        /// Append one board
+        ///
+        /// # Errors
+        ///
+        /// If the writer fails.
+        pub fn write(&mut self, board: &Board) -> io::Result<()> {
+            serde_json::to_writer(&mut self.inner, board)?;
+            self.inner.write_all(b"\n")
+        }
+
+        /// Flush the underlying writer
+        ///
+        /// # Errors
+        ///
+        /// If the writer fails.
+        pub fn flush(&mut self) -> io::Result<()> {
+            self.inner.flush()
+        }
+
+        /// The underlying writer, unflushed
+        pub fn into_inner(self) -> W {
+            self.inner
+        }
+    }
+
+    /// Streams a JSONL match in, one board at a time
+    ///
+    /// Blank lines are skipped, so concatenated shards only need their
+    /// repeated header lines removed.
+    #[derive(Debug)]
+    pub struct Reader<R: BufRead> {
+        header: Header,
+        lines: io::Lines<R>,
+        line: usize,
+    }
+
+    impl<R: BufRead> Reader<R> {
+        /// Read the header line
+        ///
+        /// # Errors
+        ///
+        /// If the reader fails, the stream is empty, the first line is not a
+        /// header, or the header is newer than [`VERSION`].
+        pub fn new(reader: R) -> Result<Self, Error> {
+            let mut lines = reader.lines();
+            let mut line = 0;
+            let header = loop {
+                line += 1;
+                let text = lines.next().ok_or(Error::MissingHeader)??;
+                if !text.trim().is_empty() {
+                    break serde_json::from_str::<Header>(&text)
+                        .map_err(|source| Error::Json { line, source })?;
+                }
+            };
+            check(&header)?;
+            Ok(Self {
+                header,
+                lines,
+                line,
+            })
+        }
+
+        /// The stream's header
+        #[must_use]
+        pub const fn header(&self) -> &Header {
+            &self.header
+        }
+    }
+
+    impl<R: BufRead> Iterator for Reader<R> {
+        type Item = Result<Board, Error>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            loop {
+                self.line += 1;
+                let text = match self.lines.next()? {
+                    Ok(text) => text,
+                    Err(e) => return Some(Err(e.into())),
+                };
+                if !text.trim().is_empty() {
+                    let line = self.line;
+                    return Some(
+                        serde_json::from_str(&text).map_err(|source| Error::Json { line, source }),
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,141 @@
+use super::*;
+use contract_bridge::auction::Call;
+use contract_bridge::{Bid, Level, Strain};
+
+fn deal() -> FullDeal {
+    "W:T5.K4.652.A98542 K6.QJT976.QT7.Q6 432.A.AKJ93.JT73 AQJ987.8532.84.K"
+        .parse()
+        .expect("valid PBN deal")
+}
+
+fn auction(calls: &[Call]) -> Auction {
+    let mut auction = Auction::new();
+    for &call in calls {
+        auction.try_push(call).expect("legal call");
+    }
+    auction
+}
+
+fn bid(level: u8, strain: Strain) -> Call {
+    Call::Bid(Bid {
+        level: Level::new(level),
+        strain,
+    })
+}
+
+/// North opens `1♥` and plays `4♥` at table A, `2♥` at table B.
+fn board() -> Board {
+    let table_a = auction(&[
+        bid(1, Strain::Hearts),
+        Call::Pass,
+        bid(4, Strain::Hearts),
+        Call::Pass,
+        Call::Pass,
+        Call::Pass,
+    ]);
+    let table_b = auction(&[
+        bid(1, Strain::Hearts),
+        Call::Pass,
+        bid(2, Strain::Hearts),
+        Call::Pass,
+        Call::Pass,
+        Call::Pass,
+    ]);
+    Board::new(deal(), Seat::North, table_a, table_b)
+}
+
+#[test]
+fn contracts_and_swing() {
+    let mut board = board();
+    let [a, b] = board.contracts();
+    assert_eq!(
+        a.map(|(c, seat)| (c.bid.level.get(), seat)),
+        Some((4, Seat::North))
+    );
+    assert_eq!(
+        b.map(|(c, seat)| (c.bid.level.get(), seat)),
+        Some((2, Seat::North))
+    );
+    assert!(board.is_divergent());
+    assert_eq!(board.dd_swing(), None);
+
+    board.dd = Some([420, 170]);
+    assert_eq!(board.dd_swing(), Some(250));
+
+    let header = Header::new("ours", "theirs", AbsoluteVulnerability::NONE);
+    assert_eq!(board.vulnerability(&header), AbsoluteVulnerability::NONE);
+    board.vulnerability = Some(AbsoluteVulnerability::ALL);
+    assert_eq!(board.vulnerability(&header), AbsoluteVulnerability::ALL);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn dumps_written_before_the_version_field_read_as_version_one() {
+    let board = serde_json::to_string(&board()).expect("a board serialises");
+    assert!(!board.contains("\"dd\"") && !board.contains("\"vulnerability\""));
+    let none = serde_json::to_string(&AbsoluteVulnerability::NONE).expect("serialises");
+    let text = format!(
+        r#"{{"our_label":"a","their_label":"b","vulnerability":{none},"boards":[{board}]}}"#
+    );
+    let dump = Dump::read(text.as_bytes()).expect("a version-1 dump");
+    assert_eq!(dump.header.version, 1);
+    assert_eq!(dump.header.seed, None);
+    assert_eq!(dump.boards, [self::board()]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn jsonl_round_trips() {
+    let mut header = Header::new("ours", "theirs", AbsoluteVulnerability::NS);
+    header.seed = Some(7);
+    header.gen_args = vec!["--count".to_owned(), "2".to_owned()];
+    let mut scored = board();
+    scored.dd = Some([420, 170]);
+    scored.sd = Some([390.5, 170.0]);
+    let dump = Dump {
+        header,
+        boards: vec![board(), scored],
+    };
+
+    let mut text = Vec::new();
+    dump.write_jsonl(&mut text).expect("writes to memory");
+    assert_eq!(text.iter().filter(|&&b| b == b'\n').count(), 3);
+
+    let mut reader = Reader::new(text.as_slice()).expect("a header line");
+    assert_eq!(reader.header(), &dump.header);
+    let boards: Vec<Board> = reader.by_ref().map(|b| b.expect("a board")).collect();
+    assert_eq!(boards, dump.boards);
+
+    // The whole-document layout carries the same match.
+    let json = serde_json::to_string(&dump).expect("a dump serialises");
+    assert_eq!(Dump::read(json.as_bytes()).expect("own output"), dump);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn rejects_what_it_cannot_read() {
+    assert!(matches!(
+        Reader::new(&b"\n\n"[..]),
+        Err(Error::MissingHeader)
+    ));
+
+    let mut header = Header::new("ours", "theirs", AbsoluteVulnerability::NONE);
+    header.version = VERSION + 1;
+    let line = serde_json::to_string(&header).expect("a header serialises");
+    assert!(matches!(
+        Reader::new(line.as_bytes()),
+        Err(Error::Version(v)) if v == VERSION + 1
+    ));
+
+    header.version = VERSION;
+    let text = format!(
+        "{}\n\n{{\"deal\":1}}\n",
+        serde_json::to_string(&header).unwrap()
+    );
+    let mut reader = Reader::new(text.as_bytes()).expect("a header line");
+    assert!(matches!(
+        reader.next(),
+        Some(Err(Error::Json { line: 3, .. }))
+    ));
+    assert!(reader.next().is_none());
+}
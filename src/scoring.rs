@@ -23,11 +23,27 @@
 //! contract under perfect defense but **carries the actual `X`/`XX`** (which
 //! cannot be taken back), so it is the right scorer for an A/B where a side may
 //! *defend* by passing — putting real doubled contracts on the table.
+//!
+//! A board played at more than two tables is compared across the field
+//! instead: [`matchpoints`], [`butler`] IMPs against a [`datum`],
+//! [`cross_imps`] and [`board_a_match`] all take the same signed NS scores.
+//!
+//! Rubber bridge and Chicago keep a running sheet instead, where a partscore
+//! carries over and a game's worth depends on the state of the rubber: a
+//! [`Scorecard`] scores each [`final_contract`] and its tricks, and the
+//! [`GameIn`] it is bid from prices a call under those economics.
+
+mod field;
+mod rubber;
+
+pub use field::{
+    AVERAGE, AVERAGE_MINUS, AVERAGE_PLUS, MatchpointScale, Outcome, board_a_match, butler,
+    cross_imps, datum, matchpoints,
+};
+pub use rubber::{GameIn, RubberFormat, Scorecard, SheetEntry, honors};
 
-#[cfg(feature = "dd")]
-use contract_bridge::AbsoluteVulnerability;
 use contract_bridge::auction::{Auction, Call};
-use contract_bridge::{Bid, Contract, Penalty, Seat};
+use contract_bridge::{AbsoluteVulnerability, Bid, Contract, Penalty, Seat};
 #[cfg(feature = "dd")]
 use ddss::TrickCountTable;
 
@@ -36,6 +52,32 @@ const fn seat_at(dealer: Seat, index: usize) -> Seat {
     Seat::ALL[(dealer as usize + index) % 4]
 }
 
+/// The dealer of duplicate board `board` (numbered from 1)
+///
+/// Deals rotate clockwise from North on board 1.  Board 0 is read as board 16
+/// of the previous set, so the rotation is total.
+#[must_use]
+pub const fn board_dealer(board: u32) -> Seat {
+    Seat::ALL[((board % 4 + 3) % 4) as usize]
+}
+
+/// The vulnerability of duplicate board `board` (numbered from 1)
+///
+/// The standard 16-board cycle: none, NS, EW, both on board 1, then each
+/// block of four boards starts one step further along, so every dealer sees
+/// every vulnerability once per set.
+#[must_use]
+pub const fn board_vulnerability(board: u32) -> AbsoluteVulnerability {
+    const CYCLE: [AbsoluteVulnerability; 4] = [
+        AbsoluteVulnerability::NONE,
+        AbsoluteVulnerability::NS,
+        AbsoluteVulnerability::EW,
+        AbsoluteVulnerability::ALL,
+    ];
+    let index = (board % 16 + 15) % 16;
+    CYCLE[(index + index / 4) as usize % 4]
+}
+
 /// The final contract and absolute declarer, or [`None`] for a pass-out
 ///
 /// The contract is the last bid with any doubles after it; the declarer is
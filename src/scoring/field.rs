@@ -0,0 +1,162 @@
+//! Scoring one board across a field of tables
+//!
+//! Every function here takes the North/South scores the `ns_score_*` family
+//! returns, one per table that played the board, and compares them the way a
+//! pairs event or a teams format other than a two-table match does.
+//! [`matchpoints`] ranks each result against the rest, [`butler`] measures it
+//! in IMPs against a trimmed-mean datum, [`cross_imps`] IMPs it against every
+//! other table, and [`board_a_match`] scores the two-table case as won, tied
+//! or lost.
+
+use super::imps;
+
+/// How matchpoints are counted
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MatchpointScale {
+    /// One point per result beaten and a half per tie (ACBL): a top on an
+    /// `n`-table board is `n - 1`
+    Acbl,
+    /// Two points per result beaten and one per tie (EBU), so every score is
+    /// whole: a top is `2(n - 1)`
+    Ebu,
+}
+
+impl MatchpointScale {
+    /// The points one comparison is worth
+    const fn per_result(self) -> f64 {
+        match self {
+            Self::Acbl => 1.0,
+            Self::Ebu => 2.0,
+        }
+    }
+
+    /// The top on a board played `tables` times
+    #[must_use]
+    pub fn top(self, tables: usize) -> f64 {
+        self.per_result() * tables.saturating_sub(1) as f64
+    }
+}
+
+/// One table's result on a board
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    /// The board was played: North/South's score
+    Played(i64),
+    /// The director awarded an artificial score, as a percentage of the top
+    /// to each side — [`AVERAGE_PLUS`], [`AVERAGE`] or [`AVERAGE_MINUS`], not
+    /// necessarily summing to 100
+    Adjusted {
+        /// North/South's percentage
+        ns: u8,
+        /// East/West's percentage
+        ew: u8,
+    },
+}
+
+/// The artificial score of a side not at fault
+pub const AVERAGE_PLUS: u8 = 60;
+/// The artificial score of a side partly at fault
+pub const AVERAGE: u8 = 50;
+/// The artificial score of a side at fault
+pub const AVERAGE_MINUS: u8 = 40;
+
+/// `[North/South, East/West]` matchpoints for each table's `outcome`
+///
+/// A played result scores against the other played results; adjusted
+/// tables score their percentage of the top.  When some tables are
+/// adjusted, the played results are factored up to the full field with
+/// Neuberg's formula, `(mp + h) · n / p − h` for `p` played of `n` tables
+/// and `h` half a comparison, which both the ACBL and the EBU use.  East/West
+/// hold the complement of North/South on a played table.
+#[must_use]
+pub fn matchpoints(outcomes: &[Outcome], scale: MatchpointScale) -> Vec<[f64; 2]> {
+    let played: Vec<i64> = outcomes
+        .iter()
+        .filter_map(|outcome| match *outcome {
+            Outcome::Played(score) => Some(score),
+            Outcome::Adjusted { .. } => None,
+        })
+        .collect();
+    let top = scale.top(outcomes.len());
+    let half = scale.per_result() / 2.0;
+    let factor = outcomes.len() as f64 / played.len().max(1) as f64;
+
+    outcomes
+        .iter()
+        .map(|outcome| match *outcome {
+            Outcome::Played(score) => {
+                let raw: f64 = played
+                    .iter()
+                    .map(|&other| match score.cmp(&other) {
+                        core::cmp::Ordering::Greater => 2.0 * half,
+                        core::cmp::Ordering::Equal => half,
+                        core::cmp::Ordering::Less => 0.0,
+                    })
+                    .sum::<f64>()
+                    // The result does not tie with itself.
+                    - half;
+                let ns = (raw + half) * factor - half;
+                [ns, top - ns]
+            }
+            Outcome::Adjusted { ns, ew } => {
+                [top * f64::from(ns) / 100.0, top * f64::from(ew) / 100.0]
+            }
+        })
+        .collect()
+}
+
+/// The Butler datum: the mean of `scores` after dropping the `trim` highest
+/// and `trim` lowest, rounded to the nearest 10
+///
+/// Trimming keeps one wild result from moving every other table's IMPs; a
+/// field of eight or more usually drops one at each end.  Trimming more than
+/// the field holds keeps the middle score (or the two middle scores).
+#[must_use]
+pub fn datum(scores: &[i64], trim: usize) -> i64 {
+    if scores.is_empty() {
+        return 0;
+    }
+    let mut sorted = scores.to_vec();
+    sorted.sort_unstable();
+    let trim = trim.min((sorted.len() - 1) / 2);
+    let kept = &sorted[trim..sorted.len() - trim];
+    let sum: i64 = kept.iter().sum();
+    let count = kept.len() as i64;
+    // Round half away from zero, then to the nearest 10 the same way.
+    let mean = (2 * sum + sum.signum() * count) / (2 * count);
+    (mean + mean.signum() * 5) / 10 * 10
+}
+
+/// North/South's Butler IMPs for each of `scores`: [`imps`] against the
+/// [`datum`] with `trim` results dropped from each end
+#[must_use]
+pub fn butler(scores: &[i64], trim: usize) -> Vec<i64> {
+    let datum = datum(scores, trim);
+    scores.iter().map(|&score| imps(score - datum)).collect()
+}
+
+/// North/South's cross-IMPs for each of `scores`: the sum of [`imps`] against
+/// every other table
+///
+/// Divide by `scores.len() - 1` for the per-comparison average some events
+/// publish instead.
+#[must_use]
+pub fn cross_imps(scores: &[i64]) -> Vec<i64> {
+    scores
+        .iter()
+        .map(|&score| scores.iter().map(|&other| imps(score - other)).sum())
+        .collect()
+}
+
+/// Board-a-match: 1 for a won board, ½ for a tie, 0 for a lost one
+///
+/// `diff` is the point difference between the two tables, as [`imps`] takes
+/// it; any margin wins the whole board.
+#[must_use]
+pub fn board_a_match(diff: i64) -> f64 {
+    match diff.signum() {
+        1 => 1.0,
+        0 => 0.5,
+        _ => 0.0,
+    }
+}
@@ -0,0 +1,387 @@
+//! Rubber and Chicago scoring
+//!
+//! Duplicate scores every board on its own; rubber bridge keeps a running
+//! sheet.  Trick points for contracts bid and made go *below the line* and
+//! accumulate toward a game of 100, carrying over from deal to deal until one
+//! side gets there and wipes both sides' partscores.  Everything else —
+//! overtricks, undertricks, the insult for making a doubled contract, slams
+//! and honors — goes *above the line*.  What a game is worth depends on the
+//! form of the game:
+//!
+//! - **Rubber**: a side that has won a game is vulnerable, and the first side
+//!   to two games scores 700 for the rubber (500 if the other side has a game
+//!   too).  A game on its own earns no bonus until the rubber ends.
+//! - **Chicago**: four deals at fixed vulnerability, each game paid as it is
+//!   made (300, or 500 vulnerable), plus 100 for a partscore made on the
+//!   fourth deal that does not complete a game.  Partscores lapse when the
+//!   four deals are up.
+//!
+//! [`GameIn`] is the state a deal is bid from — who is vulnerable and who has
+//! a partscore — and prices a hypothetical result from it with
+//! [`GameIn::ns_value`], so a rollout can evaluate calls under rubber
+//! economics instead of duplicate's.  [`Scorecard`] keeps the sheet.
+
+#[cfg(feature = "dd")]
+use contract_bridge::Bid;
+use contract_bridge::{
+    AbsoluteVulnerability, Contract, FullDeal, Penalty, Rank, Seat, Strain, Suit,
+};
+#[cfg(feature = "dd")]
+use ddss::TrickCountTable;
+
+/// The trick points that make a game
+const GAME: i64 = 100;
+
+/// The bits of the ten, jack, queen, king and ace in a suit holding
+const HONORS: u16 = 0b11111 << 10;
+
+/// Which form of the game a [`Scorecard`] keeps
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RubberFormat {
+    /// A side is vulnerable once it has won a game, and the first side to win
+    /// two games wins the rubber
+    Rubber,
+    /// Four deals at fixed vulnerability, each game paid as it is made
+    Chicago,
+}
+
+/// `0` for North/South, `1` for East/West
+const fn side(seat: Seat) -> usize {
+    match seat {
+        Seat::North | Seat::South => 0,
+        Seat::East | Seat::West => 1,
+    }
+}
+
+/// The hand holding honors in `strain`, and what they score
+///
+/// Four of the five trump honors in one hand score 100 and all five 150; at
+/// notrump, all four aces in one hand score 150.  Honors count for the side
+/// that holds them, declaring or defending, whether or not the contract
+/// makes.
+#[must_use]
+pub fn honors(deal: &FullDeal, strain: Strain) -> Option<(Seat, i64)> {
+    Seat::ALL.into_iter().find_map(|seat| {
+        let hand = deal[seat];
+        let points = match strain.suit() {
+            Some(suit) => match (hand[suit].to_bits() & HONORS).count_ones() {
+                5 => 150,
+                4 => 100,
+                _ => 0,
+            },
+            None if Suit::ASC.iter().all(|&suit| hand[suit].contains(Rank::A)) => 150,
+            None => 0,
+        };
+        (points > 0).then_some((seat, points))
+    })
+}
+
+/// What one deal writes on the score sheet, `[North/South, East/West]`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SheetEntry {
+    /// Trick points for contracts bid and made, toward game
+    pub below: [i64; 2],
+    /// Every other score: overtricks, undertricks, the insult, slam, game,
+    /// rubber and partscore bonuses, honors
+    pub above: [i64; 2],
+    /// Whether this deal ended the rubber, or the fourth deal of a Chicago
+    pub finished: bool,
+}
+
+impl SheetEntry {
+    /// Each side's points, below and above the line together
+    #[must_use]
+    pub const fn totals(&self) -> [i64; 2] {
+        [self.below[0] + self.above[0], self.below[1] + self.above[1]]
+    }
+
+    /// North/South's points less East/West's
+    #[must_use]
+    pub const fn ns(&self) -> i64 {
+        let [ns, ew] = self.totals();
+        ns - ew
+    }
+}
+
+/// The state a deal is bid from: the game "in" for each side
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GameIn {
+    /// The form of the game
+    pub format: RubberFormat,
+    /// Games each side has won in the current rubber, `[NS, EW]`; always
+    /// zero in Chicago, where games are paid as they are made
+    pub games: [u8; 2],
+    /// Trick points each side has toward the current game, `[NS, EW]`
+    pub partscore: [i64; 2],
+    /// Deals already scored in the current Chicago, `0..4`; always zero in
+    /// rubber
+    pub deal: u8,
+}
+
+impl GameIn {
+    /// The start of a rubber, or of a Chicago
+    #[must_use]
+    pub const fn new(format: RubberFormat) -> Self {
+        Self {
+            format,
+            games: [0; 2],
+            partscore: [0; 2],
+            deal: 0,
+        }
+    }
+
+    /// Who is vulnerable on the next deal
+    ///
+    /// In rubber, a side with a game.  In Chicago, nobody on the first deal,
+    /// the dealer's side on the second and third and everybody on the fourth,
+    /// with North dealing the first — so East/West then North/South, the
+    /// reverse of duplicate boards 2 and 3.
+    #[must_use]
+    pub fn vulnerability(&self) -> AbsoluteVulnerability {
+        match self.format {
+            RubberFormat::Rubber => {
+                let mut vul = AbsoluteVulnerability::NONE;
+                if self.games[0] > 0 {
+                    vul |= AbsoluteVulnerability::NS;
+                }
+                if self.games[1] > 0 {
+                    vul |= AbsoluteVulnerability::EW;
+                }
+                vul
+            }
+            RubberFormat::Chicago => match self.deal {
+                0 => AbsoluteVulnerability::NONE,
+                1 => AbsoluteVulnerability::EW,
+                2 => AbsoluteVulnerability::NS,
+                _ => AbsoluteVulnerability::ALL,
+            },
+        }
+    }
+
+    /// Whether `seat`'s side is vulnerable on the next deal
+    #[must_use]
+    pub fn is_vulnerable(&self, seat: Seat) -> bool {
+        self.vulnerability().contains(match side(seat) {
+            0 => AbsoluteVulnerability::NS,
+            _ => AbsoluteVulnerability::EW,
+        })
+    }
+
+    /// The trick points `seat`'s side still needs for game
+    #[must_use]
+    pub const fn needs(&self, seat: Seat) -> i64 {
+        GAME - self.partscore[side(seat)]
+    }
+
+    /// What the laws award each side if play stops here, `[NS, EW]`
+    ///
+    /// An unfinished rubber scores 300 for a side with a game and 100 for a
+    /// side with a partscore.  A Chicago stopped early scores nothing more.
+    #[must_use]
+    pub fn unfinished(&self) -> [i64; 2] {
+        match self.format {
+            RubberFormat::Rubber => [0, 1].map(|us| {
+                300 * i64::from(self.games[us]) + if self.partscore[us] > 0 { 100 } else { 0 }
+            }),
+            RubberFormat::Chicago => [0; 2],
+        }
+    }
+
+    /// Score one deal from this state: the sheet entry and the state after
+    ///
+    /// `result` is [`final_contract`][super::final_contract]'s, `tricks` the
+    /// tricks declarer took and `honors` what [`honors`] found in the hands.
+    /// A pass-out scores nothing; in Chicago it is redealt and does not count
+    /// as one of the four deals.
+    #[must_use]
+    pub fn score(
+        self,
+        result: Option<(Contract, Seat)>,
+        tricks: u8,
+        honors: Option<(Seat, i64)>,
+    ) -> (SheetEntry, Self) {
+        let mut entry = SheetEntry::default();
+        let mut next = self;
+        let Some((contract, declarer)) = result else {
+            return (entry, next);
+        };
+        if let Some((holder, points)) = honors {
+            entry.above[side(holder)] += points;
+        }
+
+        let us = side(declarer);
+        let vulnerable = self.is_vulnerable(declarer);
+        let level = contract.bid.level.get();
+        let doubled = i64::from(contract.penalty as u8);
+
+        if tricks >= level + 6 {
+            let trick_points = i64::from(contract.contract_points());
+            let overtricks = i64::from(tricks - level - 6);
+            let per_overtrick = match contract.penalty {
+                Penalty::Undoubled if contract.bid.strain.is_minor() => 20,
+                Penalty::Undoubled => 30,
+                _ => doubled * if vulnerable { 200 } else { 100 },
+            };
+            let slam = match level {
+                6 if vulnerable => 750,
+                6 => 500,
+                7 if vulnerable => 1500,
+                7 => 1000,
+                _ => 0,
+            };
+            entry.below[us] = trick_points;
+            entry.above[us] += overtricks * per_overtrick + doubled * 50 + slam;
+
+            next.partscore[us] += trick_points;
+            if next.partscore[us] >= GAME {
+                next.partscore = [0; 2];
+                match self.format {
+                    RubberFormat::Rubber => {
+                        next.games[us] += 1;
+                        if next.games[us] == 2 {
+                            entry.above[us] += if next.games[1 - us] == 0 { 700 } else { 500 };
+                            entry.finished = true;
+                            next.games = [0; 2];
+                        }
+                    }
+                    RubberFormat::Chicago => entry.above[us] += if vulnerable { 500 } else { 300 },
+                }
+            } else if self.format == RubberFormat::Chicago && self.deal == 3 {
+                entry.above[us] += 100;
+            }
+        } else {
+            // Undertricks score the same as at duplicate.
+            entry.above[1 - us] -= i64::from(contract.score(tricks, vulnerable));
+        }
+
+        if self.format == RubberFormat::Chicago {
+            next.deal += 1;
+            if next.deal == 4 {
+                entry.finished = true;
+                next.deal = 0;
+                next.partscore = [0; 2];
+            }
+        }
+        (entry, next)
+    }
+
+    /// North/South's value of a result from this state, for pricing calls
+    ///
+    /// The points the deal scores, net for North/South, plus the change in
+    /// what the games already won are worth — 300 each while a rubber is
+    /// unfinished — so a rubber's first game prices near a duplicate game
+    /// and the game that ends it at the rest of the rubber bonus.  A
+    /// partscore is worth its trick points alone: what it is really worth is
+    /// the cheaper game it leaves, which the next deal's state prices.
+    /// Honors are left out, as a caller bidding the hand cannot see them.
+    #[must_use]
+    pub fn ns_value(&self, result: Option<(Contract, Seat)>, tricks: u8) -> i64 {
+        let (entry, next) = self.score(result, tricks, None);
+        let games = |state: &Self| match state.format {
+            RubberFormat::Rubber => 300 * (i64::from(state.games[0]) - i64::from(state.games[1])),
+            RubberFormat::Chicago => 0,
+        };
+        entry.ns() + games(&next) - games(self)
+    }
+
+    /// Perfect-defense [`ns_value`][Self::ns_value] of a `bid` from a
+    /// double-dummy `table`: the rubber sibling of
+    /// [`ns_score_bid`][super::ns_score_bid], doubling a contract that fails
+    #[cfg(feature = "dd")]
+    #[must_use]
+    pub fn ns_score_bid(&self, result: Option<(Bid, Seat)>, table: &TrickCountTable) -> i64 {
+        let Some((bid, declarer)) = result else {
+            return 0;
+        };
+        let tricks = u8::from(table[bid.strain].get(declarer));
+        let penalty = if tricks < bid.level.get() + 6 {
+            Penalty::Doubled
+        } else {
+            Penalty::Undoubled
+        };
+        self.ns_value(Some((Contract { bid, penalty }, declarer)), tricks)
+    }
+}
+
+/// A running rubber or Chicago score sheet
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scorecard {
+    game_in: GameIn,
+    dealt: u32,
+    sheet: SheetEntry,
+    finished: Vec<[i64; 2]>,
+}
+
+impl Scorecard {
+    /// An empty sheet, North to deal
+    #[must_use]
+    pub const fn new(format: RubberFormat) -> Self {
+        Self {
+            game_in: GameIn::new(format),
+            dealt: 0,
+            sheet: SheetEntry {
+                below: [0; 2],
+                above: [0; 2],
+                finished: false,
+            },
+            finished: Vec::new(),
+        }
+    }
+
+    /// The state the next deal is bid from
+    #[must_use]
+    pub const fn game_in(&self) -> GameIn {
+        self.game_in
+    }
+
+    /// Who deals next: the deal passes clockwise, but a passed-out Chicago
+    /// deal is redealt by the same player
+    #[must_use]
+    pub const fn dealer(&self) -> Seat {
+        Seat::ALL[(self.dealt % 4) as usize]
+    }
+
+    /// Score one deal, as [`GameIn::score`], and return its entry
+    pub fn record(
+        &mut self,
+        result: Option<(Contract, Seat)>,
+        tricks: u8,
+        honors: Option<(Seat, i64)>,
+    ) -> SheetEntry {
+        let (entry, next) = self.game_in.score(result, tricks, honors);
+        if result.is_some() || self.game_in.format == RubberFormat::Rubber {
+            self.dealt += 1;
+        }
+        for us in 0..2 {
+            self.sheet.below[us] += entry.below[us];
+            self.sheet.above[us] += entry.above[us];
+        }
+        if entry.finished {
+            self.finished.push(self.sheet.totals());
+            self.sheet = SheetEntry::default();
+        }
+        self.game_in = next;
+        entry
+    }
+
+    /// The current rubber or Chicago so far
+    #[must_use]
+    pub const fn sheet(&self) -> SheetEntry {
+        self.sheet
+    }
+
+    /// Each finished rubber or Chicago's `[NS, EW]` totals, in order
+    #[must_use]
+    pub fn finished(&self) -> &[[i64; 2]] {
+        &self.finished
+    }
+
+    /// `[NS, EW]` over every finished rubber or Chicago and the current one,
+    /// without the awards for stopping it unfinished
+    #[must_use]
+    pub fn totals(&self) -> [i64; 2] {
+        self.finished
+            .iter()
+            .fold(self.sheet.totals(), |[ns, ew], [a, b]| [ns + a, ew + b])
+    }
+}
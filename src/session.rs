@@ -0,0 +1,560 @@
+//! One board at the table, from the deal to the result
+//!
+//! A [`Session`] owns a board's deal, dealer and vulnerability and walks it
+//! through its [`Phase`]s: the auction, the play from the opening lead
+//! trick by trick, and the result.  Seats are human or bot.  A bot seat bids
+//! from the session's [`Table`], which asks whichever [`Bidder`] sits on
+//! that side; a human seat acts through [`Session::bid`], which enforces turn
+//! order and the laws of the auction.  Cards come through [`Session::play`]
+//! for every seat — the crate has no card-play bot, so a front end supplies
+//! the bots' cards its own way — and follow suit is enforced for all of them.
+//!
+//! [`Session::undo`] takes back the last human action with every bot call
+//! made after it, and [`Session::claim`] ends the play early.  A
+//! [`Snapshot`] is the board as one seat may see it, serialisable under the
+//! `serde` feature, and [`Session::record`] is the board as a LIN
+//! [`Record`].
+
+use crate::bidding::{Bidder, Table};
+use crate::lin::Record;
+use crate::scoring::final_contract;
+use contract_bridge::auction::{Auction, Call, IllegalCall};
+use contract_bridge::{
+    AbsoluteVulnerability, Bid, Card, Contract, FullDeal, Hand, Seat, SeatFlags, Strain, Suit,
+};
+use thiserror::Error;
+
+/// Where a board stands
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Phase {
+    /// Calls are being made
+    Auction,
+    /// A contract was reached and cards are being played
+    Play,
+    /// Passed out, played out, or claimed
+    Done,
+}
+
+/// An action the board refused
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum Error {
+    /// The action belongs to another phase
+    #[error("the board is in its {found:?} phase")]
+    Phase {
+        /// The phase the board is in
+        found: Phase,
+    },
+    /// A call for a seat no human sits in
+    #[error("{seat} is not a human seat")]
+    NotHuman {
+        /// The seat to act
+        seat: Seat,
+    },
+    /// A call against the laws of the auction
+    #[error(transparent)]
+    IllegalCall(#[from] IllegalCall),
+    /// A card the seat to play does not hold
+    #[error("{seat} does not hold {card}")]
+    NotHeld {
+        /// The seat to play
+        seat: Seat,
+        /// The card tried
+        card: Card,
+    },
+    /// A discard while holding the suit led
+    #[error("{seat} must follow to {led}")]
+    Revoke {
+        /// The seat to play
+        seat: Seat,
+        /// The suit led
+        led: Suit,
+    },
+    /// A claim outside what declarer has won and what is left to win
+    #[error("declarer cannot claim {tricks} tricks with {won} won and {left} to play")]
+    Claim {
+        /// Declarer's total tricks claimed
+        tricks: u8,
+        /// Tricks declarer has won so far
+        won: u8,
+        /// Tricks not yet complete
+        left: u8,
+    },
+    /// Nothing a human did is left to take back
+    #[error("nothing to undo")]
+    NothingToUndo,
+}
+
+/// One trick, complete or in progress
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trick {
+    /// The seat that led
+    pub leader: Seat,
+    /// The cards in the order played, from the leader
+    pub cards: Vec<Card>,
+}
+
+impl Trick {
+    /// Who won the trick, once all four have played
+    #[must_use]
+    pub fn winner(&self, trump: Option<Suit>) -> Option<Seat> {
+        (self.cards.len() == 4).then(|| trick_winner(self.leader, &self.cards, trump))
+    }
+}
+
+/// Winner of a completed trick led by `leader`
+pub(crate) fn trick_winner(leader: Seat, trick: &[Card], trump: Option<Suit>) -> Seat {
+    let mut winner = leader;
+    let mut best = trick[0];
+    let mut seat = leader;
+    for &card in &trick[1..] {
+        seat = seat.lho();
+        let beats = if card.suit == best.suit {
+            card.rank > best.rank
+        } else {
+            Some(card.suit) == trump
+        };
+        if beats {
+            winner = seat;
+            best = card;
+        }
+    }
+    winner
+}
+
+/// The board as one seat may see it
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Snapshot {
+    /// The dealer
+    pub dealer: Seat,
+    /// The absolute vulnerability
+    pub vul: AbsoluteVulnerability,
+    /// Where the board stands
+    pub phase: Phase,
+    /// The seat to call or play, [`None`] once the board is done
+    pub to_act: Option<Seat>,
+    /// Whether a human acts next, at their own seat or for their dummy
+    pub human_to_act: bool,
+    /// Each seat's unplayed cards in [`Seat::ALL`] order, [`None`] where
+    /// the viewer may not see them
+    pub hands: [Option<Hand>; 4],
+    /// The calls from the dealer
+    pub auction: Vec<Call>,
+    /// Every legal call while the auction lasts
+    pub legal_calls: Vec<Call>,
+    /// The contract and declarer, once the auction has reached one
+    pub contract: Option<(Contract, Seat)>,
+    /// Every trick begun, the last possibly in progress
+    pub tricks: Vec<Trick>,
+    /// Tricks won by declarer's side, counting a claim
+    pub declarer_tricks: u8,
+    /// North/South's score once the board is done
+    pub ns_score: Option<i64>,
+}
+
+/// A board in progress
+///
+/// The type parameters are the North/South and East/West [`Bidder`]s, as in
+/// [`Table`].  Bot seats bid as soon as they are on turn, so a new session
+/// or a human call returns with a human to act or the auction over.
+#[derive(Clone, Debug)]
+pub struct Session<N, E> {
+    table: Table<N, E>,
+    deal: FullDeal,
+    humans: SeatFlags,
+    auction: Auction,
+    play: Vec<Card>,
+    claim: Option<u8>,
+}
+
+impl<N: Bidder, E: Bidder> Session<N, E> {
+    /// Seat `humans` at `table` on `deal` and bid the bots up to the first
+    /// human call
+    #[must_use]
+    pub fn new(table: Table<N, E>, deal: FullDeal, humans: SeatFlags) -> Self {
+        let mut session = Self {
+            table,
+            deal,
+            humans,
+            auction: Auction::new(),
+            play: Vec::new(),
+            claim: None,
+        };
+        session.advance();
+        session
+    }
+
+    /// The table the bots bid from
+    #[must_use]
+    pub const fn table(&self) -> &Table<N, E> {
+        &self.table
+    }
+
+    /// The four hands as dealt
+    #[must_use]
+    pub const fn deal(&self) -> &FullDeal {
+        &self.deal
+    }
+
+    /// The dealer
+    #[must_use]
+    pub const fn dealer(&self) -> Seat {
+        self.table.dealer()
+    }
+
+    /// The absolute vulnerability
+    #[must_use]
+    pub const fn vul(&self) -> AbsoluteVulnerability {
+        self.table.vul()
+    }
+
+    /// The seats humans sit in
+    #[must_use]
+    pub const fn humans(&self) -> SeatFlags {
+        self.humans
+    }
+
+    /// The calls so far
+    #[must_use]
+    pub const fn auction(&self) -> &Auction {
+        &self.auction
+    }
+
+    /// The cards played so far, in order
+    #[must_use]
+    pub fn cards(&self) -> &[Card] {
+        &self.play
+    }
+
+    /// The opening lead, once made
+    #[must_use]
+    pub fn opening_lead(&self) -> Option<Card> {
+        self.play.first().copied()
+    }
+
+    /// The contract and declarer, once the auction has ended in one
+    #[must_use]
+    pub fn contract(&self) -> Option<(Contract, Seat)> {
+        if self.auction.has_ended() {
+            final_contract(&self.auction, self.dealer())
+        } else {
+            None
+        }
+    }
+
+    /// Where the board stands
+    #[must_use]
+    pub fn phase(&self) -> Phase {
+        if !self.auction.has_ended() {
+            Phase::Auction
+        } else if self.contract().is_none() || self.claim.is_some() || self.play.len() == 52 {
+            Phase::Done
+        } else {
+            Phase::Play
+        }
+    }
+
+    /// The seat to call or play, [`None`] once the board is done
+    #[must_use]
+    pub fn to_act(&self) -> Option<Seat> {
+        match self.phase() {
+            Phase::Auction => Some(self.table.seat_to_act(self.auction.len())),
+            Phase::Play => {
+                let trick = self.tricks().pop()?;
+                Some(Seat::ALL[(trick.leader as usize + trick.cards.len()) % 4])
+            }
+            Phase::Done => None,
+        }
+    }
+
+    /// Whether a human acts next, at their own seat or for their dummy
+    #[must_use]
+    pub fn human_to_act(&self) -> bool {
+        self.to_act()
+            .is_some_and(|seat| self.humans.contains(self.controller(seat).into()))
+    }
+
+    /// `seat`'s unplayed cards
+    #[must_use]
+    pub fn hand(&self, seat: Seat) -> Hand {
+        let mut hand = self.deal[seat];
+        for &card in &self.play {
+            hand.remove(card);
+        }
+        hand
+    }
+
+    /// Every trick begun, the last possibly in progress
+    ///
+    /// Empty until the opening lead is due; then the last trick is the one
+    /// being played, empty when its lead is due.
+    #[must_use]
+    pub fn tricks(&self) -> Vec<Trick> {
+        let Some((contract, declarer)) = self.contract() else {
+            return Vec::new();
+        };
+        let trump = contract.bid.strain.suit();
+        let mut tricks = Vec::with_capacity(13);
+        let mut leader = declarer.lho();
+        for cards in self.play.chunks(4) {
+            let trick = Trick {
+                leader,
+                cards: cards.to_vec(),
+            };
+            if let Some(winner) = trick.winner(trump) {
+                leader = winner;
+            }
+            tricks.push(trick);
+        }
+        if self.play.len().is_multiple_of(4) && self.play.len() < 52 {
+            tricks.push(Trick {
+                leader,
+                cards: Vec::new(),
+            });
+        }
+        tricks
+    }
+
+    /// Tricks won by declarer's side: the claim if one was made, else the
+    /// complete tricks taken
+    #[must_use]
+    pub fn declarer_tricks(&self) -> u8 {
+        if let Some(tricks) = self.claim {
+            return tricks;
+        }
+        self.won()
+    }
+
+    /// North/South's score once the board is done; 0 for a pass-out
+    #[must_use]
+    pub fn ns_score(&self) -> Option<i64> {
+        if self.phase() != Phase::Done {
+            return None;
+        }
+        let Some((contract, declarer)) = self.contract() else {
+            return Some(0);
+        };
+        let side = match declarer {
+            Seat::North | Seat::South => AbsoluteVulnerability::NS,
+            Seat::East | Seat::West => AbsoluteVulnerability::EW,
+        };
+        let score = i64::from(contract.score(self.declarer_tricks(), self.vul().contains(side)));
+        Some(match declarer {
+            Seat::North | Seat::South => score,
+            Seat::East | Seat::West => -score,
+        })
+    }
+
+    /// Every legal call while the auction lasts, passes and doubles first,
+    /// then bids up the line
+    #[must_use]
+    pub fn legal_calls(&self) -> Vec<Call> {
+        if self.auction.has_ended() {
+            return Vec::new();
+        }
+        all_calls()
+            .filter(|&call| self.auction.can_push(call).is_ok())
+            .collect()
+    }
+
+    /// A human's call
+    ///
+    /// # Errors
+    ///
+    /// If the auction is over, the seat to call is a bot's, or the call is
+    /// illegal.
+    pub fn bid(&mut self, call: Call) -> Result<(), Error> {
+        self.expect_phase(Phase::Auction)?;
+        let seat = self.table.seat_to_act(self.auction.len());
+        if !self.humans.contains(seat.into()) {
+            return Err(Error::NotHuman { seat });
+        }
+        self.auction.try_push(call)?;
+        self.advance();
+        Ok(())
+    }
+
+    /// A card from the seat to play, the opening lead first
+    ///
+    /// # Errors
+    ///
+    /// If no card is due, the seat to play does not hold `card`, or `card`
+    /// fails to follow suit.
+    pub fn play(&mut self, card: Card) -> Result<(), Error> {
+        self.expect_phase(Phase::Play)?;
+        let seat = self.to_act().expect("a seat is to play in the play phase");
+        let hand = self.hand(seat);
+        if !hand.contains(card) {
+            return Err(Error::NotHeld { seat, card });
+        }
+        let trick = self
+            .tricks()
+            .pop()
+            .expect("a trick is open in the play phase");
+        if let Some(&led) = trick.cards.first()
+            && card.suit != led.suit
+            && !hand[led.suit].is_empty()
+        {
+            return Err(Error::Revoke {
+                seat,
+                led: led.suit,
+            });
+        }
+        self.play.push(card);
+        Ok(())
+    }
+
+    /// End the play with declarer's side taking `tricks` in all, the LIN
+    /// `mc|` convention
+    ///
+    /// # Errors
+    ///
+    /// If no card is due, or `tricks` is fewer than declarer has already won
+    /// or more than they could still reach.
+    pub fn claim(&mut self, tricks: u8) -> Result<(), Error> {
+        self.expect_phase(Phase::Play)?;
+        let won = self.won();
+        let left = 13 - (self.play.len() / 4) as u8;
+        if tricks < won || tricks > won + left {
+            return Err(Error::Claim { tricks, won, left });
+        }
+        self.claim = Some(tricks);
+        Ok(())
+    }
+
+    /// Take back the last human action — a call, a card or a claim — and
+    /// every bot call made after it
+    ///
+    /// With no human at the table the last action of any kind goes.
+    ///
+    /// # Errors
+    ///
+    /// If no human action is left to take back; the board is then unchanged.
+    pub fn undo(&mut self) -> Result<(), Error> {
+        let saved = (self.auction.clone(), self.play.clone(), self.claim);
+        while let Some(actor) = self.pop() {
+            if self.humans.is_empty() || self.humans.contains(actor.into()) {
+                return Ok(());
+            }
+        }
+        (self.auction, self.play, self.claim) = saved;
+        Err(Error::NothingToUndo)
+    }
+
+    /// The board as `viewer` may see it: their own hand, dummy once the
+    /// opening lead is down, and every hand once the board is done;
+    /// everything for [`None`]
+    #[must_use]
+    pub fn snapshot(&self, viewer: Option<Seat>) -> Snapshot {
+        let phase = self.phase();
+        let contract = self.contract();
+        let dummy = contract
+            .filter(|_| !self.play.is_empty())
+            .map(|(_, declarer)| declarer.partner());
+        let hands = Seat::ALL.map(|seat| {
+            let visible = viewer
+                .is_none_or(|viewer| viewer == seat || Some(seat) == dummy || phase == Phase::Done);
+            visible.then(|| self.hand(seat))
+        });
+        Snapshot {
+            dealer: self.dealer(),
+            vul: self.vul(),
+            phase,
+            to_act: self.to_act(),
+            human_to_act: self.human_to_act(),
+            hands,
+            auction: self.auction.to_vec(),
+            legal_calls: self.legal_calls(),
+            contract,
+            tricks: self.tricks(),
+            declarer_tricks: self.declarer_tricks(),
+            ns_score: self.ns_score(),
+        }
+    }
+
+    /// The board as a LIN [`Record`]
+    #[must_use]
+    pub fn record(&self) -> Record {
+        let mut record = Record::new(self.deal, self.dealer(), self.vul());
+        record.auction = self.auction.clone();
+        record.play = self.play.clone();
+        record.claim = self.claim;
+        record
+    }
+
+    /// Bid bot seats until a human is to call or the auction ends
+    fn advance(&mut self) {
+        while !self.auction.has_ended() {
+            let seat = self.table.seat_to_act(self.auction.len());
+            if self.humans.contains(seat.into()) {
+                break;
+            }
+            let call = self.table.next_call(self.deal[seat], &self.auction);
+            self.auction.push(call);
+        }
+    }
+
+    fn expect_phase(&self, phase: Phase) -> Result<(), Error> {
+        let found = self.phase();
+        if found == phase {
+            Ok(())
+        } else {
+            Err(Error::Phase { found })
+        }
+    }
+
+    /// Who acts for `seat`: declarer for dummy once the play starts
+    fn controller(&self, seat: Seat) -> Seat {
+        match self.contract() {
+            Some((_, declarer)) if self.phase() == Phase::Play && seat == declarer.partner() => {
+                declarer
+            }
+            _ => seat,
+        }
+    }
+
+    /// Complete tricks won by declarer's side
+    fn won(&self) -> u8 {
+        let Some((contract, declarer)) = self.contract() else {
+            return 0;
+        };
+        let trump = contract.bid.strain.suit();
+        self.tricks()
+            .iter()
+            .filter_map(|trick| trick.winner(trump))
+            .filter(|&winner| winner == declarer || winner == declarer.partner())
+            .count() as u8
+    }
+
+    /// Drop the newest action and return who took it: declarer for a claim
+    /// or a card from dummy
+    fn pop(&mut self) -> Option<Seat> {
+        if self.claim.take().is_some() {
+            return self.contract().map(|(_, declarer)| declarer);
+        }
+        if self.play.pop().is_some() {
+            let seat = self.to_act().expect("a card is due once one is taken back");
+            return Some(self.controller(seat));
+        }
+        self.auction.pop()?;
+        Some(self.table.seat_to_act(self.auction.len()))
+    }
+}
+
+/// Every call, passes and doubles first, then bids up the line
+fn all_calls() -> impl Iterator<Item = Call> {
+    let bids = (1..=7).flat_map(|level| {
+        Strain::ASC
+            .into_iter()
+            .map(move |strain| Call::Bid(Bid::new(level, strain)))
+    });
+    [Call::Pass, Call::Double, Call::Redouble]
+        .into_iter()
+        .chain(bids)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,198 @@
+use super::*;
+use crate::bidding::Trie;
+use contract_bridge::Level;
+
+const fn bid(level: u8, strain: Strain) -> Call {
+    Call::Bid(Bid {
+        level: Level::new(level),
+        strain,
+    })
+}
+
+fn card(s: &str) -> Card {
+    s.parse().expect("valid test card")
+}
+
+/// North holds every spade, East every heart, South every diamond and West
+/// every club
+fn deal() -> FullDeal {
+    "N:AKQJT98765432... .AKQJT98765432.. ..AKQJT98765432. ...AKQJT98765432"
+        .parse()
+        .expect("valid test deal")
+}
+
+/// A table whose bots always pass
+fn silent(dealer: Seat) -> Table<Trie, Trie> {
+    Table::new(
+        Trie::new(),
+        Trie::new(),
+        dealer,
+        AbsoluteVulnerability::NONE,
+    )
+}
+
+/// Everyone human, North declaring 1NT after `1NT P P P`
+fn one_notrump() -> Session<Trie, Trie> {
+    let mut session = Session::new(silent(Seat::North), deal(), SeatFlags::ALL);
+    for call in [bid(1, Strain::Notrump), Call::Pass, Call::Pass, Call::Pass] {
+        session.bid(call).expect("a legal call in turn");
+    }
+    session
+}
+
+/// Bots bid up to the first human seat, and a human call only goes in at
+/// that seat and within the laws.
+#[test]
+fn bots_bid_up_to_the_human() {
+    let mut session = Session::new(silent(Seat::North), deal(), Seat::South.into());
+    assert_eq!(session.auction().len(), 2);
+    assert_eq!(session.to_act(), Some(Seat::South));
+    assert!(session.human_to_act());
+
+    assert!(matches!(
+        session.bid(Call::Double),
+        Err(Error::IllegalCall(IllegalCall::InadmissibleDouble(_)))
+    ));
+    session
+        .bid(bid(1, Strain::Diamonds))
+        .expect("an opening bid");
+    // West, North and East pass it out at once.
+    assert_eq!(session.auction().len(), 6);
+    assert_eq!(session.phase(), Phase::Play);
+    assert_eq!(session.to_act(), Some(Seat::West));
+    assert_eq!(
+        session.bid(Call::Pass),
+        Err(Error::Phase { found: Phase::Play })
+    );
+}
+
+/// The opening lead comes from declarer's left, suit must be followed when
+/// it can be, and the trick's winner leads the next.
+#[test]
+fn play_follows_the_laws() {
+    let mut session = one_notrump();
+    assert_eq!(session.phase(), Phase::Play);
+    assert_eq!(session.to_act(), Some(Seat::East));
+    assert_eq!(
+        session.play(card("SA")),
+        Err(Error::NotHeld {
+            seat: Seat::East,
+            card: card("SA"),
+        })
+    );
+    session.play(card("H2")).expect("East leads");
+    assert_eq!(session.opening_lead(), Some(card("H2")));
+    for lead in ["D2", "C2", "S2"] {
+        session.play(card(lead)).expect("a void seat discards");
+    }
+    let tricks = session.tricks();
+    assert_eq!(tricks[0].winner(None), Some(Seat::East));
+    assert_eq!(tricks[1].leader, Seat::East);
+    assert_eq!(session.declarer_tricks(), 0);
+    assert_eq!(session.hand(Seat::East).len(), 12);
+}
+
+/// Holding the suit led, a seat may not discard.
+#[test]
+fn revoke_is_refused() {
+    let deal: FullDeal = "N:AKQJ.AKQ.AKQ.AKQ T987.JT9.JT9.JT9 6543.876.876.876 2.5432.5432.5432"
+        .parse()
+        .expect("valid test deal");
+    let mut session = Session::new(silent(Seat::North), deal, SeatFlags::ALL);
+    for call in [bid(1, Strain::Notrump), Call::Pass, Call::Pass, Call::Pass] {
+        session.bid(call).expect("a legal call in turn");
+    }
+    session.play(card("S7")).expect("East leads");
+    assert_eq!(
+        session.play(card("H8")),
+        Err(Error::Revoke {
+            seat: Seat::South,
+            led: Suit::Spades,
+        })
+    );
+    session.play(card("S3")).expect("South follows");
+}
+
+/// A claim must lie between the tricks won and the tricks reachable, and
+/// ends the board with its score.
+#[test]
+fn claim_ends_the_board() {
+    let mut session = one_notrump();
+    assert_eq!(
+        session.claim(14),
+        Err(Error::Claim {
+            tricks: 14,
+            won: 0,
+            left: 13,
+        })
+    );
+    session.claim(7).expect("a claim for the contract");
+    assert_eq!(session.phase(), Phase::Done);
+    assert_eq!(session.to_act(), None);
+    assert_eq!(session.ns_score(), Some(90));
+    assert_eq!(session.record().claim, Some(7));
+}
+
+/// Undo takes back the human's call along with the bot calls after it.
+#[test]
+fn undo_takes_back_the_bots_too() {
+    let mut session = Session::new(silent(Seat::North), deal(), Seat::South.into());
+    assert_eq!(session.undo(), Err(Error::NothingToUndo));
+    let before = session.auction().to_vec();
+    session
+        .bid(bid(1, Strain::Diamonds))
+        .expect("an opening bid");
+    session.undo().expect("South's call");
+    assert_eq!(session.auction().to_vec(), before);
+    assert_eq!(session.to_act(), Some(Seat::South));
+}
+
+/// Undo in the play takes back the card; declarer owns dummy's cards.
+#[test]
+fn undo_in_the_play() {
+    let mut session = one_notrump();
+    session.play(card("H2")).expect("East leads");
+    session.play(card("D2")).expect("South discards");
+    assert!(session.human_to_act());
+    session.claim(0).expect("declarer concedes");
+    session.undo().expect("the claim");
+    assert_eq!(session.phase(), Phase::Play);
+    session.undo().expect("dummy's card");
+    assert_eq!(session.to_act(), Some(Seat::South));
+    assert_eq!(session.cards(), [card("H2")]);
+}
+
+/// Each viewer sees their own hand and dummy after the lead; the passed-out
+/// board shows everything and scores nothing.
+#[test]
+fn snapshot_hides_what_the_viewer_cannot_see() {
+    let mut session = one_notrump();
+    let before = session.snapshot(Some(Seat::West));
+    assert_eq!(
+        before.hands.map(|hand| hand.is_some()),
+        [false, false, false, true]
+    );
+    assert_eq!(
+        before.contract.map(|(_, declarer)| declarer),
+        Some(Seat::North)
+    );
+    assert!(before.legal_calls.is_empty());
+
+    session.play(card("H2")).expect("East leads");
+    let after = session.snapshot(Some(Seat::West));
+    assert_eq!(
+        after.hands.map(|hand| hand.is_some()),
+        [false, false, true, true]
+    );
+    assert_eq!(session.snapshot(None).hands.iter().flatten().count(), 4);
+
+    let mut passed = Session::new(silent(Seat::North), deal(), SeatFlags::ALL);
+    assert_eq!(passed.snapshot(None).legal_calls.len(), 36);
+    for _ in 0..4 {
+        passed.bid(Call::Pass).expect("a pass");
+    }
+    let done = passed.snapshot(Some(Seat::East));
+    assert_eq!(done.phase, Phase::Done);
+    assert_eq!(done.ns_score, Some(0));
+    assert_eq!(done.hands.iter().flatten().count(), 4);
+}
@@ -40,6 +40,7 @@
 //! the answer.
 
 use crate::bidding::{Inferences, sample_defender_remnants, sample_layouts};
+use crate::session::trick_winner;
 use crate::stats::HistogramTable;
 use contract_bridge::deal::PartialDeal;
 use contract_bridge::deck::fill_deals;
@@ -286,26 +287,6 @@ fn distinct_plays(hand: Hand, led: Option<Suit>, seen: Hand) -> Vec<Card> {
     out
 }
 
-/// Winner of a completed trick led by `leader`
-fn trick_winner(leader: Seat, trick: &[Card], trump: Option<Suit>) -> Seat {
-    let mut winner = leader;
-    let mut best = trick[0];
-    let mut seat = leader;
-    for &card in &trick[1..] {
-        seat = seat.lho();
-        let beats = if card.suit == best.suit {
-            card.rank > best.rank
-        } else {
-            Some(card.suit) == trump
-        };
-        if beats {
-            winner = seat;
-            best = card;
-        }
-    }
-    winner
-}
-
 /// Card-by-card playout state for [`single_dummy_playout`]
 struct Playout<'a> {
     solver: Solver,
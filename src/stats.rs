@@ -4,7 +4,7 @@ use contract_bridge::{AbsoluteVulnerability, Bid, Contract, Penalty, Seat, Strai
 use core::fmt;
 use core::num::NonZero;
 use core::ops::{Index, IndexMut};
-use ddss::TrickCountTable;
+use ddss::{Par, ParContract, TrickCountTable, Vulnerability, calculate_par};
 
 /// Representation of statistics on a variable
 ///
@@ -376,3 +376,66 @@ pub fn average_ns_par(
         contract: par_contract,
     })
 }
+
+/// The exact par of one deal at every dealer and vulnerability
+///
+/// Built by [`par`].  Each entry is a [`Par`]: the North/South par score and
+/// every contract that achieves it, with its declarer.  A par contract with
+/// negative overtricks is a doubled sacrifice, bid at the cheapest level
+/// that outranks the other side's best making contract — so the contract
+/// list is the whole par structure, makes and saves alike.
+#[derive(Debug, Clone)]
+pub struct ParTable([[Par; 4]; 4]);
+
+impl ParTable {
+    /// The par with `dealer` dealing at `vul`
+    #[must_use]
+    pub const fn get(&self, dealer: Seat, vul: AbsoluteVulnerability) -> &Par {
+        &self.0[vul.bits() as usize][dealer as usize]
+    }
+
+    /// The par of duplicate board `board`, at its dealer and vulnerability
+    #[must_use]
+    pub const fn board(&self, board: u32) -> &Par {
+        self.get(
+            crate::scoring::board_dealer(board),
+            crate::scoring::board_vulnerability(board),
+        )
+    }
+
+    /// Whether the par at `vul` depends on who deals
+    ///
+    /// It does only in the rare deal where both sides can make a contract at
+    /// the par level, so whichever side bids first takes it.
+    #[must_use]
+    pub fn depends_on_dealer(&self, vul: AbsoluteVulnerability) -> bool {
+        let pars = &self.0[vul.bits() as usize];
+        pars[1..].iter().any(|par| !par.equivalent(&pars[0]))
+    }
+
+    /// The sacrifices among the par contracts with `dealer` dealing at `vul`
+    pub fn sacrifices(
+        &self,
+        dealer: Seat,
+        vul: AbsoluteVulnerability,
+    ) -> impl Iterator<Item = &ParContract> {
+        self.get(dealer, vul)
+            .contracts
+            .iter()
+            .filter(|contract| contract.overtricks < 0)
+    }
+}
+
+/// The exact par of a solved deal for each dealer and each of the four
+/// vulnerabilities — the sixteen combinations a set of boards deals
+///
+/// Unlike [`average_ns_par`], which prices one contract against a histogram,
+/// this is the single-deal par with its full contract list, computed by the
+/// DDS par solver.
+#[must_use]
+pub fn par(tricks: TrickCountTable) -> ParTable {
+    ParTable(core::array::from_fn(|vul| {
+        let vul = Vulnerability::from_bits_truncate(vul as u8);
+        Seat::ALL.map(|dealer| calculate_par(tricks, vul, dealer))
+    }))
+}
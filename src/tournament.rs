@@ -0,0 +1,382 @@
+//! Simulated pairs tournaments
+//!
+//! A two-table match measures one system against one other; a pairs game
+//! measures it against a field.  A [`Movement`] says who plays which boards
+//! against whom — the [`Sitting`]s of every round — and [`play`] seats a
+//! population of [`Entrant`]s by it, bids every board with
+//! [`Table::bid_out`], prices each result double-dummy with one of the
+//! `ns_score_*` scorers and returns the [`Played`] results.  [`standings`]
+//! matchpoints each board across the tables that played it and ranks the
+//! pairs by percentage.
+//!
+//! Boards are numbered from 1 and take their dealer and vulnerability from
+//! [`board_dealer`] and [`board_vulnerability`], as a real set of boards
+//! does; board set `s` holds boards `s · per_round + 1` onward.
+
+use crate::bidding::{Partnership, System, Table};
+use crate::scoring::{
+    MatchpointScale, Outcome, board_dealer, board_vulnerability, final_contract, matchpoints,
+};
+use contract_bridge::auction::Auction;
+use contract_bridge::{AbsoluteVulnerability, Contract, FullDeal, Seat};
+use ddss::{NonEmptyStrainFlags, Solver, TrickCountTable};
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+/// How a movement moves its pairs
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// North/South pairs stay put, East/West pairs move up a table and the
+    /// boards down a table each round
+    Mitchell,
+    /// Every pair meets every other, sitting either way
+    Howell,
+}
+
+/// Who sits where for one round at one table
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Sitting {
+    /// The round, from 0
+    pub round: usize,
+    /// The table, from 0
+    pub table: usize,
+    /// The pair sitting North/South
+    pub ns: usize,
+    /// The pair sitting East/West
+    pub ew: usize,
+    /// The board set in play, from 0
+    pub set: usize,
+}
+
+/// A movement: tables, rounds and the board sets that travel between them
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Movement {
+    kind: Kind,
+    tables: usize,
+    per_round: u32,
+}
+
+impl Movement {
+    /// A Mitchell for `tables` tables, `per_round` boards a round
+    ///
+    /// Pairs `0..tables` sit North/South at their own table and pairs
+    /// `tables..2 · tables` start East/West at table `pair - tables`.  An odd
+    /// Mitchell plays a round per table; an even one plays one round fewer,
+    /// East/West skipping a table halfway so they never meet a board twice.
+    ///
+    /// # Panics
+    ///
+    /// If there are fewer than two tables or no boards a round.
+    #[must_use]
+    pub fn mitchell(tables: usize, per_round: u32) -> Self {
+        assert!(
+            tables >= 2 && per_round > 0,
+            "a Mitchell needs two tables and a board"
+        );
+        Self {
+            kind: Kind::Mitchell,
+            tables,
+            per_round,
+        }
+    }
+
+    /// A complete Howell for `tables` tables, `per_round` boards a round
+    ///
+    /// `2 · tables` pairs play `2 · tables - 1` rounds and every pair meets
+    /// every other once.  Every table plays the round's board set from its
+    /// own copy, as a computer-dealt event does: a spread of sets that never
+    /// meets a pair twice does not exist for every table count.
+    ///
+    /// # Panics
+    ///
+    /// If there are fewer than two tables or no boards a round.
+    #[must_use]
+    pub fn howell(tables: usize, per_round: u32) -> Self {
+        assert!(
+            tables >= 2 && per_round > 0,
+            "a Howell needs two tables and a board"
+        );
+        Self {
+            kind: Kind::Howell,
+            tables,
+            per_round,
+        }
+    }
+
+    /// Mitchell or Howell
+    #[must_use]
+    pub const fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// The number of tables
+    #[must_use]
+    pub const fn tables(&self) -> usize {
+        self.tables
+    }
+
+    /// The number of pairs, two per table
+    #[must_use]
+    pub const fn pairs(&self) -> usize {
+        2 * self.tables
+    }
+
+    /// The number of rounds
+    #[must_use]
+    pub const fn rounds(&self) -> usize {
+        match self.kind {
+            Kind::Mitchell if self.tables.is_multiple_of(2) => self.tables - 1,
+            Kind::Mitchell => self.tables,
+            Kind::Howell => 2 * self.tables - 1,
+        }
+    }
+
+    /// Boards in each set, played in one round
+    #[must_use]
+    pub const fn per_round(&self) -> u32 {
+        self.per_round
+    }
+
+    /// The number of boards in play, numbered `1..=boards()`
+    #[must_use]
+    pub const fn boards(&self) -> u32 {
+        let sets = match self.kind {
+            Kind::Mitchell => self.tables,
+            Kind::Howell => 2 * self.tables - 1,
+        };
+        sets as u32 * self.per_round
+    }
+
+    /// The boards of set `set`
+    #[must_use]
+    pub const fn set_boards(&self, set: usize) -> core::ops::RangeInclusive<u32> {
+        let first = set as u32 * self.per_round + 1;
+        first..=first + self.per_round - 1
+    }
+
+    /// Every table of every round, round by round
+    #[must_use]
+    pub fn sittings(&self) -> Vec<Sitting> {
+        let n = self.tables;
+        let mut sittings = Vec::with_capacity(n * self.rounds());
+        match self.kind {
+            Kind::Mitchell => {
+                for round in 0..self.rounds() {
+                    let skip = usize::from(n.is_multiple_of(2) && round >= n / 2);
+                    for table in 0..n {
+                        let ew = (table + 2 * n - round - skip) % n;
+                        sittings.push(Sitting {
+                            round,
+                            table,
+                            ns: table,
+                            ew: n + ew,
+                            set: (table + round) % n,
+                        });
+                    }
+                }
+            }
+            Kind::Howell => {
+                // The circle method: pair `m` sits at table 0 throughout, the
+                // others rotate past it, and pairs `r + i` and `r - i` meet at
+                // table `i` in round `r`.
+                let m = 2 * n - 1;
+                for round in 0..m {
+                    for table in 0..n {
+                        let (mut ns, mut ew) = if table == 0 {
+                            (m, round)
+                        } else {
+                            ((round + table) % m, (round + m - table) % m)
+                        };
+                        if (round + table) % 2 == 1 {
+                            core::mem::swap(&mut ns, &mut ew);
+                        }
+                        sittings.push(Sitting {
+                            round,
+                            table,
+                            ns,
+                            ew,
+                            set: round,
+                        });
+                    }
+                }
+            }
+        }
+        sittings
+    }
+}
+
+/// A pair in the field: a label and the system it plays
+#[derive(Clone, Debug)]
+pub struct Entrant {
+    /// How the pair is reported
+    pub label: String,
+    /// The system both players bid
+    pub system: System,
+}
+
+impl Entrant {
+    /// A pair playing `system`
+    #[must_use]
+    pub fn new(label: impl Into<String>, system: System) -> Self {
+        Self {
+            label: label.into(),
+            system,
+        }
+    }
+}
+
+/// One board as played at one table
+#[derive(Clone, Debug, PartialEq)]
+pub struct Played {
+    /// The board number, from 1
+    pub board: u32,
+    /// Where and when it was played
+    pub sitting: Sitting,
+    /// The auction
+    pub auction: Auction,
+    /// North/South's score
+    pub ns_score: i64,
+}
+
+/// A tournament that could not be played as asked
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum Error {
+    /// The field does not fill the movement
+    #[error("the movement seats {expected} pairs, not {found}")]
+    Entrants {
+        /// Pairs the movement seats
+        expected: usize,
+        /// Entrants given
+        found: usize,
+    },
+    /// The deals do not fill the board sets
+    #[error("the movement plays {expected} boards, not {found}")]
+    Deals {
+        /// Boards the movement plays
+        expected: usize,
+        /// Deals given
+        found: usize,
+    },
+}
+
+/// A signed-for-NS double-dummy scorer: [`ns_score_contract`] or
+/// [`ns_score_pd`]
+///
+/// [`ns_score_contract`]: crate::scoring::ns_score_contract
+/// [`ns_score_pd`]: crate::scoring::ns_score_pd
+pub type Scorer = fn(Option<(Contract, Seat)>, &TrickCountTable, AbsoluteVulnerability) -> i64;
+
+/// Play a whole movement: `deals[k]` is board `k + 1`, `entrants[p]` pair `p`
+///
+/// Each deal is solved double-dummy once and every table's result on it is
+/// priced from that solve with `scorer`.  Results come back in sitting
+/// order, board by board within a round.
+///
+/// # Errors
+///
+/// If `entrants` does not hold [`Movement::pairs`] pairs or `deals` does not
+/// hold [`Movement::boards`] boards.
+pub fn play(
+    movement: &Movement,
+    entrants: &[Entrant],
+    deals: &[FullDeal],
+    scorer: Scorer,
+) -> Result<Vec<Played>, Error> {
+    if entrants.len() != movement.pairs() {
+        return Err(Error::Entrants {
+            expected: movement.pairs(),
+            found: entrants.len(),
+        });
+    }
+    if deals.len() != movement.boards() as usize {
+        return Err(Error::Deals {
+            expected: movement.boards() as usize,
+            found: deals.len(),
+        });
+    }
+
+    let tables = Solver::lock(None).solve_deals(deals, NonEmptyStrainFlags::ALL);
+    // Binding builds the books, so each pair is bound once, not per board.
+    let bound: Vec<Partnership> = entrants.iter().map(|e| e.system.bind()).collect();
+    let mut played = Vec::with_capacity(deals.len() * movement.tables());
+    for sitting in movement.sittings() {
+        let (ns, ew) = (&bound[sitting.ns], &bound[sitting.ew]);
+        for board in movement.set_boards(sitting.set) {
+            let index = board as usize - 1;
+            let (dealer, vul) = (board_dealer(board), board_vulnerability(board));
+            let auction = Table::new(ns, ew, dealer, vul).bid_out(&deals[index]);
+            let ns_score = scorer(final_contract(&auction, dealer), &tables[index], vul);
+            played.push(Played {
+                board,
+                sitting,
+                auction,
+                ns_score,
+            });
+        }
+    }
+    Ok(played)
+}
+
+/// A pair's matchpoint total over the boards it played
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Standing {
+    /// The pair
+    pub pair: usize,
+    /// Matchpoints scored
+    pub matchpoints: f64,
+    /// The most it could have scored: the sum of the tops
+    pub top: f64,
+}
+
+impl Standing {
+    /// Matchpoints as a percentage of the tops
+    #[must_use]
+    pub fn percentage(&self) -> f64 {
+        if self.top > 0.0 {
+            100.0 * self.matchpoints / self.top
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Every pair's standing, best percentage first
+///
+/// Each board is matchpointed across the tables that played it, North/South
+/// against North/South, so a Mitchell's two directions are scored on one
+/// list as well as ranked on it — split the list by pair number for the
+/// usual two winners.
+#[must_use]
+pub fn standings(played: &[Played], scale: MatchpointScale) -> Vec<Standing> {
+    let mut boards: BTreeMap<u32, Vec<&Played>> = BTreeMap::new();
+    for result in played {
+        boards.entry(result.board).or_default().push(result);
+    }
+
+    let mut totals: BTreeMap<usize, Standing> = BTreeMap::new();
+    for results in boards.values() {
+        let outcomes: Vec<Outcome> = results
+            .iter()
+            .map(|r| Outcome::Played(r.ns_score))
+            .collect();
+        let top = scale.top(results.len());
+        for (result, [ns, ew]) in results.iter().zip(matchpoints(&outcomes, scale)) {
+            for (pair, points) in [(result.sitting.ns, ns), (result.sitting.ew, ew)] {
+                let standing = totals.entry(pair).or_insert(Standing {
+                    pair,
+                    matchpoints: 0.0,
+                    top: 0.0,
+                });
+                standing.matchpoints += points;
+                standing.top += top;
+            }
+        }
+    }
+
+    let mut standings: Vec<Standing> = totals.into_values().collect();
+    standings.sort_by(|a, b| b.percentage().total_cmp(&a.percentage()));
+    standings
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,111 @@
+use super::*;
+use crate::american_default;
+use crate::scoring::ns_score_contract;
+use contract_bridge::deck::full_deal;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::collections::HashSet;
+
+/// Each pair plays once a round, never meets a pair twice and never plays a
+/// board set twice
+fn assert_sound(movement: &Movement) {
+    let sittings = movement.sittings();
+    assert_eq!(sittings.len(), movement.tables() * movement.rounds());
+
+    for round in 0..movement.rounds() {
+        let seated: Vec<usize> = sittings
+            .iter()
+            .filter(|s| s.round == round)
+            .flat_map(|s| [s.ns, s.ew])
+            .collect();
+        let unique: HashSet<usize> = seated.iter().copied().collect();
+        assert_eq!(seated.len(), movement.pairs(), "{movement:?} round {round}");
+        assert_eq!(unique.len(), movement.pairs(), "{movement:?} round {round}");
+    }
+
+    let mut meetings = HashSet::new();
+    let mut sets = HashSet::new();
+    for s in &sittings {
+        assert!(*movement.set_boards(s.set).end() <= movement.boards());
+        assert!(
+            meetings.insert((s.ns.min(s.ew), s.ns.max(s.ew))),
+            "{movement:?} {s:?}"
+        );
+        assert!(sets.insert((s.ns, s.set)), "{movement:?} {s:?}");
+        assert!(sets.insert((s.ew, s.set)), "{movement:?} {s:?}");
+    }
+}
+
+#[test]
+fn mitchells_are_sound() {
+    for tables in 2..=9 {
+        let movement = Movement::mitchell(tables, 2);
+        assert_sound(&movement);
+        assert_eq!(movement.boards(), 2 * tables as u32);
+        // North/South stay at their table.
+        assert!(movement.sittings().iter().all(|s| s.ns == s.table));
+    }
+    assert_eq!(Movement::mitchell(7, 3).rounds(), 7);
+    assert_eq!(Movement::mitchell(8, 3).rounds(), 7);
+}
+
+#[test]
+fn howells_meet_every_pair() {
+    for tables in 2..=8 {
+        let movement = Movement::howell(tables, 2);
+        assert_sound(&movement);
+        let pairs = movement.pairs();
+        assert_eq!(movement.sittings().len(), pairs * (pairs - 1) / 2);
+        assert_eq!(movement.boards(), 2 * (pairs as u32 - 1));
+    }
+    assert_eq!(Movement::howell(3, 2).set_boards(2), 5..=6);
+}
+
+#[test]
+fn a_small_howell_scores_to_an_average_of_fifty() {
+    let movement = Movement::howell(2, 1);
+    let system = american_default();
+    let entrants: Vec<Entrant> = (0..movement.pairs())
+        .map(|pair| Entrant::new(format!("pair {pair}"), system.clone()))
+        .collect();
+    let deals: Vec<FullDeal> = (0..u64::from(movement.boards()))
+        .map(|seed| full_deal(&mut StdRng::seed_from_u64(seed)))
+        .collect();
+
+    assert_eq!(
+        play(&movement, &entrants[1..], &deals, ns_score_contract),
+        Err(Error::Entrants {
+            expected: 4,
+            found: 3
+        })
+    );
+    assert_eq!(
+        play(&movement, &entrants, &deals[1..], ns_score_contract),
+        Err(Error::Deals {
+            expected: 3,
+            found: 2
+        })
+    );
+
+    let played = play(&movement, &entrants, &deals, ns_score_contract).expect("a full field");
+    assert_eq!(played.len(), 6);
+    // One system throughout: both tables bid each board alike.
+    for pair in played.chunks(2) {
+        let other = played
+            .iter()
+            .find(|p| p.board == pair[0].board && p.sitting != pair[0].sitting)
+            .expect("every board is played twice");
+        assert_eq!(pair[0].auction, other.auction);
+    }
+
+    let standings = standings(&played, MatchpointScale::Acbl);
+    assert_eq!(standings.len(), 4);
+    assert!(standings.iter().all(|s| s.top == 3.0));
+    let mean = standings.iter().map(Standing::percentage).sum::<f64>() / 4.0;
+    assert!((mean - 50.0).abs() < 1e-9);
+    assert!(
+        standings
+            .windows(2)
+            .all(|w| w[0].percentage() >= w[1].percentage())
+    );
+}
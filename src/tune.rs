@@ -0,0 +1,424 @@
+//! Knob tuning by sequential A/B testing
+//!
+//! A numeric knob used to be tuned by a shell loop: one fixed-size A/B per
+//! value, read off by eye.  A [`Tuner`] walks the values instead.  Each
+//! [`Axis`] is one knob key from the [registry][crate::bidding::agreements]
+//! and the values it may take, in order; the search is coordinate descent,
+//! stepping an axis to a neighbouring value while the neighbour beats the
+//! incumbent, one axis after another until a whole pass moves nothing.
+//!
+//! Each step is a paired duplicate [`Match`] of the challenger against the
+//! incumbent, played in batches until a sequential probability ratio test
+//! ([`Sprt`]) decides between "no better" and "better by `delta` IMPs a
+//! board" — a clear difference stops after a batch or two, a marginal one
+//! runs to the board cap and keeps the incumbent.  Every comparison is a
+//! [`Trial`] in the [`Tuning::ledger`], which prints as a Markdown table in
+//! the layout of `docs/bidding-options.md`.
+
+use crate::bidding::agreements::{Agreements, KnobError, knob};
+use crate::bidding::{System, american};
+use crate::duplicate::Match;
+use crate::stats::Accumulator;
+use contract_bridge::AbsoluteVulnerability;
+use core::fmt;
+use core::ops::RangeInclusive;
+use std::collections::HashSet;
+use thiserror::Error;
+
+/// A tuning that could not start
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum Error {
+    /// A key or value the registry rejects
+    #[error(transparent)]
+    Knob(#[from] KnobError),
+    /// The base agreements hold a value the axis does not list
+    #[error("`{key}` starts at `{value}`, which is not one of its values")]
+    Start {
+        /// The knob
+        key: String,
+        /// Its value in the base agreements
+        value: String,
+    },
+}
+
+/// One knob and the values the search may give it, in order
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Axis {
+    /// The knob's registry key
+    pub key: String,
+    /// Its values, written as [`Agreements::set`] reads them
+    pub values: Vec<String>,
+}
+
+impl Axis {
+    /// A knob with explicit values, such as the bands of a tuple knob
+    ///
+    /// # Errors
+    ///
+    /// If the key is unknown or a value is not of the knob's kind.
+    pub fn new(
+        key: impl Into<String>,
+        values: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<Self, Error> {
+        let key = key.into();
+        let knob = knob(&key).ok_or_else(|| KnobError::UnknownKey(key.clone()))?;
+        let values: Vec<String> = values.into_iter().map(Into::into).collect();
+        let mut scratch = Agreements::default();
+        for value in &values {
+            knob.set(&mut scratch, value)?;
+        }
+        Ok(Self { key, values })
+    }
+
+    /// An integer knob over `range`
+    ///
+    /// # Errors
+    ///
+    /// If the key is unknown or does not take these integers.
+    pub fn range(key: impl Into<String>, range: RangeInclusive<i64>) -> Result<Self, Error> {
+        Self::new(key, range.map(|value| value.to_string()))
+    }
+}
+
+/// Which double-dummy bracket prices the boards
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Scoring {
+    /// [`ns_score_contract`][crate::scoring::ns_score_contract]
+    #[default]
+    Plain,
+    /// [`ns_score_pd`][crate::scoring::ns_score_pd]
+    PerfectDefense,
+}
+
+/// A sequential probability ratio test on the mean IMPs per board
+///
+/// `H0`: the challenger gains nothing; `H1`: it gains `delta` IMPs a board.
+/// After each batch the log-likelihood ratio of the normal model, with the
+/// variance estimated from the boards so far, is compared with Wald's
+/// bounds `ln(β / (1 − α))` and `ln((1 − β) / α)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sprt {
+    /// The gain worth detecting, in IMPs per board
+    pub delta: f64,
+    /// The chance of accepting a challenger that gains nothing
+    pub alpha: f64,
+    /// The chance of rejecting one that gains `delta`
+    pub beta: f64,
+    /// Boards per batch, between looks
+    pub batch: usize,
+    /// Boards after which an undecided trial keeps the incumbent
+    pub max_boards: usize,
+}
+
+impl Default for Sprt {
+    fn default() -> Self {
+        Self {
+            delta: 0.05,
+            alpha: 0.05,
+            beta: 0.05,
+            batch: 1000,
+            max_boards: 100_000,
+        }
+    }
+}
+
+impl Sprt {
+    /// The log-likelihood ratio of `H1` over `H0` after `imps`
+    ///
+    /// 0 until the boards have some spread: flat boards carry no evidence.
+    #[must_use]
+    pub fn llr(&self, imps: &Accumulator) -> f64 {
+        if imps.count() < 2 || imps.sdm() <= 0.0 {
+            return 0.0;
+        }
+        let count = imps.count() as f64;
+        let variance = imps.sdm() / (count - 1.0);
+        self.delta / variance * (imps.mean() - self.delta / 2.0) * count
+    }
+
+    /// The verdict `llr` reaches, if any
+    #[must_use]
+    pub fn decide(&self, llr: f64) -> Option<Verdict> {
+        if llr >= ((1.0 - self.beta) / self.alpha).ln() {
+            Some(Verdict::Better)
+        } else if llr <= (self.beta / (1.0 - self.alpha)).ln() {
+            Some(Verdict::NotBetter)
+        } else {
+            None
+        }
+    }
+}
+
+/// How a trial ended
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Verdict {
+    /// The test accepted the challenger; the search moved to it
+    Better,
+    /// The test rejected the challenger
+    NotBetter,
+    /// The board cap came first; the incumbent stays
+    Inconclusive,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Better => "better",
+            Self::NotBetter => "not better",
+            Self::Inconclusive => "inconclusive",
+        })
+    }
+}
+
+/// One challenger against one incumbent
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trial {
+    /// The knob that differs
+    pub key: String,
+    /// Its incumbent value
+    pub incumbent: String,
+    /// Its challenging value
+    pub challenger: String,
+    /// The first deal seed of the trial
+    pub seed: u64,
+    /// Boards played
+    pub boards: usize,
+    /// The challenger's mean IMPs per board
+    pub imps: f64,
+    /// Half-width of the 95% confidence interval on [`imps`][Self::imps]
+    pub ci: f64,
+    /// The final log-likelihood ratio
+    pub llr: f64,
+    /// How it ended
+    pub verdict: Verdict,
+}
+
+/// A finished search
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tuning {
+    /// The agreements the search settled on
+    pub agreements: Agreements,
+    /// Every axis's final value, in axis order
+    pub settings: Vec<(String, String)>,
+    /// Every trial, in the order it was played
+    pub ledger: Vec<Trial>,
+}
+
+impl fmt::Display for Tuning {
+    /// The ledger as a Markdown table, then the settled values
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "| Knob | Incumbent | Challenger | Seed | Boards | IMPs/bd | LLR | Verdict |"
+        )?;
+        writeln!(f, "| --- | --- | --- | --- | --- | --- | --- | --- |")?;
+        for t in &self.ledger {
+            writeln!(
+                f,
+                "| {} | `{}` | `{}` | {} | {} | {:+.4} ±{:.4} | {:+.2} | {} |",
+                t.key, t.incumbent, t.challenger, t.seed, t.boards, t.imps, t.ci, t.llr, t.verdict
+            )?;
+        }
+        writeln!(f)?;
+        for (key, value) in &self.settings {
+            writeln!(f, "- `{key}` = `{value}`")?;
+        }
+        Ok(())
+    }
+}
+
+/// A coordinate-descent search over knob values
+#[derive(Clone, Debug)]
+pub struct Tuner {
+    base: Agreements,
+    system: fn(&Agreements) -> System,
+    axes: Vec<Axis>,
+    sprt: Sprt,
+    scoring: Scoring,
+    seed: u64,
+    vulnerability: AbsoluteVulnerability,
+}
+
+impl Tuner {
+    /// Tune `axes` from `base`, dealing from `seed`
+    ///
+    /// Every trial deals fresh boards, continuing the seed stream where the
+    /// last trial stopped, so no value is judged on the boards that picked
+    /// its incumbent.
+    #[must_use]
+    pub fn new(base: Agreements, axes: Vec<Axis>, seed: u64) -> Self {
+        Self {
+            base,
+            system: american,
+            axes,
+            sprt: Sprt::default(),
+            scoring: Scoring::Plain,
+            seed,
+            vulnerability: AbsoluteVulnerability::NONE,
+        }
+    }
+
+    /// Build systems with `system` instead of [`american`]
+    #[must_use]
+    pub fn system(mut self, system: fn(&Agreements) -> System) -> Self {
+        self.system = system;
+        self
+    }
+
+    /// Stop each trial by `sprt` instead of the default test
+    #[must_use]
+    pub const fn sprt(mut self, sprt: Sprt) -> Self {
+        self.sprt = sprt;
+        self
+    }
+
+    /// Price the boards with `scoring`
+    #[must_use]
+    pub const fn scoring(mut self, scoring: Scoring) -> Self {
+        self.scoring = scoring;
+        self
+    }
+
+    /// Play every board at `vulnerability`
+    #[must_use]
+    pub const fn vulnerability(mut self, vulnerability: AbsoluteVulnerability) -> Self {
+        self.vulnerability = vulnerability;
+        self
+    }
+
+    /// Run the search
+    ///
+    /// # Errors
+    ///
+    /// [`Error::Start`] if the base agreements hold a value an axis does
+    /// not list.
+    ///
+    /// # Panics
+    ///
+    /// If the test's batch is empty.
+    pub fn run(&self) -> Result<Tuning, Error> {
+        assert!(self.sprt.batch > 0, "a trial needs boards");
+        let mut at = self
+            .axes
+            .iter()
+            .map(|axis| {
+                let value = self.base.get(&axis.key)?;
+                axis.values
+                    .iter()
+                    .position(|v| *v == value)
+                    .ok_or(Error::Start {
+                        key: axis.key.clone(),
+                        value,
+                    })
+            })
+            .collect::<Result<Vec<usize>, Error>>()?;
+
+        let mut agreements = self.base;
+        let mut ledger = Vec::new();
+        let mut seed = self.seed;
+        let mut tried = HashSet::new();
+        let mut moved = true;
+        while moved {
+            moved = false;
+            for (a, axis) in self.axes.iter().enumerate() {
+                // Step up while up wins; failing that, down while down wins.
+                for step in [1, -1] {
+                    let mut stepped = false;
+                    while let Some(next) = at[a].checked_add_signed(step) {
+                        if next >= axis.values.len() || !tried.insert((a, at[a], next)) {
+                            break;
+                        }
+                        let (trial, challenger) =
+                            self.trial(axis, at[a], next, &agreements, seed)?;
+                        seed = seed.wrapping_add(trial.boards as u64);
+                        let verdict = trial.verdict;
+                        ledger.push(trial);
+                        if verdict != Verdict::Better {
+                            break;
+                        }
+                        agreements = challenger;
+                        at[a] = next;
+                        stepped = true;
+                    }
+                    if stepped {
+                        moved = true;
+                        break;
+                    }
+                }
+            }
+        }
+
+        let settings = self
+            .axes
+            .iter()
+            .zip(&at)
+            .map(|(axis, &i)| (axis.key.clone(), axis.values[i].clone()))
+            .collect();
+        Ok(Tuning {
+            agreements,
+            settings,
+            ledger,
+        })
+    }
+
+    /// Play `axis` at value `to` against value `from` from `incumbent`, batch
+    /// by batch until the test decides or the cap is reached
+    fn trial(
+        &self,
+        axis: &Axis,
+        from: usize,
+        to: usize,
+        incumbent: &Agreements,
+        seed: u64,
+    ) -> Result<(Trial, Agreements), Error> {
+        let mut challenger = *incumbent;
+        challenger.set(&axis.key, &axis.values[to])?;
+        let ours = (self.system)(&challenger).bind();
+        let theirs = (self.system)(incumbent).bind();
+        let mut imps = Accumulator::new();
+        let mut verdict = Verdict::Inconclusive;
+        let mut llr = 0.0;
+        while imps.count() < self.sprt.max_boards {
+            let boards = self.sprt.batch.min(self.sprt.max_boards - imps.count());
+            let report = Match::new(
+                &ours,
+                &theirs,
+                seed.wrapping_add(imps.count() as u64),
+                boards,
+            )
+            .vulnerability(self.vulnerability)
+            .play();
+            let bracket = match self.scoring {
+                Scoring::Plain => report.dd,
+                Scoring::PerfectDefense => report.pd,
+            };
+            bracket.imps.iter().for_each(|&x| imps.push(x as f64));
+            llr = self.sprt.llr(&imps);
+            if let Some(decided) = self.sprt.decide(llr) {
+                verdict = decided;
+                break;
+            }
+        }
+        let count = imps.count();
+        let ci = if count < 2 {
+            0.0
+        } else {
+            1.96 * (imps.sdm() / (count - 1) as f64 / count as f64).sqrt()
+        };
+        let trial = Trial {
+            key: axis.key.clone(),
+            incumbent: axis.values[from].clone(),
+            challenger: axis.values[to].clone(),
+            seed,
+            boards: count,
+            imps: imps.mean(),
+            ci,
+            llr,
+            verdict,
+        };
+        Ok((trial, challenger))
+    }
+}
+
+#[cfg(test)]
+mod tests;
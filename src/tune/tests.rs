@@ -0,0 +1,72 @@
+use super::*;
+
+#[test]
+fn axes_are_checked_against_the_registry() {
+    let axis = Axis::range("competition.free_bid_floor", 6..=9).expect("an integer knob");
+    assert_eq!(axis.values, ["6", "7", "8", "9"]);
+    assert!(matches!(
+        Axis::range("competition.no_such_knob", 0..=1),
+        Err(Error::Knob(KnobError::UnknownKey(_)))
+    ));
+    assert!(matches!(
+        Axis::new("competition.free_bid_floor", ["seven"]),
+        Err(Error::Knob(KnobError::InvalidValue { .. }))
+    ));
+    let axis = Axis::new("opening.weak_two_hcp", ["none", "5,10", "6,10"]).expect("bands");
+    assert_eq!(axis.values.len(), 3);
+}
+
+#[test]
+fn the_test_stops_on_clear_evidence_only() {
+    let sprt = Sprt::default();
+    let accumulate = |values: &[f64]| {
+        let mut imps = Accumulator::new();
+        values.iter().for_each(|&x| imps.push(x));
+        sprt.llr(&imps)
+    };
+    // Flat boards say nothing.
+    assert_eq!(accumulate(&[0.0; 100]), 0.0);
+
+    let gains: Vec<f64> = (0..2000).map(|i| [1.0, 0.0, 0.0, -0.5][i % 4]).collect();
+    assert_eq!(sprt.decide(accumulate(&gains)), Some(Verdict::Better));
+    let losses: Vec<f64> = gains.iter().map(|x| -x).collect();
+    assert_eq!(sprt.decide(accumulate(&losses)), Some(Verdict::NotBetter));
+    assert_eq!(sprt.decide(accumulate(&gains[..8])), None);
+}
+
+#[test]
+fn the_search_records_every_trial() {
+    let base = Agreements::default();
+    let start: i64 = base
+        .get("competition.free_bid_floor")
+        .expect("a knob")
+        .parse()
+        .expect("an integer");
+    let axis = Axis::range("competition.free_bid_floor", start - 1..=start + 1).expect("an axis");
+    let sprt = Sprt {
+        batch: 4,
+        max_boards: 8,
+        ..Sprt::default()
+    };
+    let tuning = Tuner::new(base, vec![axis], 9)
+        .sprt(sprt)
+        .run()
+        .expect("the base value is on the axis");
+
+    assert!(!tuning.ledger.is_empty());
+    assert_eq!(tuning.ledger[0].incumbent, start.to_string());
+    assert_eq!(tuning.ledger[0].seed, 9);
+    assert!(tuning.ledger.iter().all(|t| t.boards <= 8));
+    for pair in tuning.ledger.windows(2) {
+        assert_eq!(pair[1].seed, pair[0].seed + pair[0].boards as u64);
+    }
+    assert_eq!(tuning.settings.len(), 1);
+    assert!(tuning.to_string().starts_with("| Knob |"));
+
+    let outside =
+        Axis::range("competition.free_bid_floor", start + 1..=start + 2).expect("an axis");
+    assert!(matches!(
+        Tuner::new(base, vec![outside], 9).run(),
+        Err(Error::Start { .. })
+    ));
+}
@@ -4,8 +4,8 @@ use contract_bridge::auction::{Auction, Call};
 use contract_bridge::{AbsoluteVulnerability, Bid, Contract, Penalty, Seat, Strain};
 use ddss::{TrickCountRow, TrickCountTable};
 use pons::scoring::{
-    final_contract, imps, ns_score_bid, ns_score_contract, ns_score_pd, ns_score_pd_tricks,
-    ns_score_tricks,
+    board_dealer, board_vulnerability, final_contract, imps, ns_score_bid, ns_score_contract,
+    ns_score_pd, ns_score_pd_tricks, ns_score_tricks,
 };
 
 const fn bid(level: u8, strain: Strain) -> Call {
@@ -270,3 +270,226 @@ fn test_imps_scale() {
     assert_eq!(imps(4000), 24);
     assert_eq!(imps(-100_000), -24);
 }
+
+#[test]
+fn test_board_rotation() {
+    let (none, ns, ew, all) = (
+        AbsoluteVulnerability::NONE,
+        AbsoluteVulnerability::NS,
+        AbsoluteVulnerability::EW,
+        AbsoluteVulnerability::ALL,
+    );
+    let cycle = [
+        none, ns, ew, all, ns, ew, all, none, ew, all, none, ns, all, none, ns, ew,
+    ];
+    for (board, vul) in (1..=16).zip(cycle) {
+        assert_eq!(board_vulnerability(board), vul, "board {board}");
+        assert_eq!(board_vulnerability(board + 16), vul, "board {}", board + 16);
+    }
+    assert_eq!(board_dealer(1), Seat::North);
+    assert_eq!(board_dealer(4), Seat::West);
+    assert_eq!(board_dealer(17), Seat::North);
+    assert_eq!(board_dealer(0), board_dealer(16));
+}
+
+#[test]
+fn test_board_rotation_at_max() {
+    // u32::MAX is 15 mod 16: the rotation must not overflow on the way there.
+    assert_eq!(board_dealer(u32::MAX), board_dealer(15));
+    assert_eq!(board_vulnerability(u32::MAX), board_vulnerability(15));
+}
+
+#[test]
+fn matchpoints_count_results_beaten_and_ties() {
+    use pons::scoring::{MatchpointScale, Outcome, matchpoints};
+
+    // 4♥ making twice, 4♥ +1 once, 2♥ +2 once.
+    let field = [420, 450, 420, 170].map(Outcome::Played);
+    let acbl = matchpoints(&field, MatchpointScale::Acbl);
+    assert_eq!(acbl, [[1.5, 1.5], [3.0, 0.0], [1.5, 1.5], [0.0, 3.0]]);
+    let ebu = matchpoints(&field, MatchpointScale::Ebu);
+    assert_eq!(ebu, [[3.0, 3.0], [6.0, 0.0], [3.0, 3.0], [0.0, 6.0]]);
+    assert!(
+        ebu.iter()
+            .all(|[ns, ew]| ns.fract() == 0.0 && ew.fract() == 0.0),
+        "EBU scores are whole"
+    );
+}
+
+#[test]
+fn adjusted_tables_factor_the_played_results() {
+    use pons::scoring::{AVERAGE_MINUS, AVERAGE_PLUS, MatchpointScale, Outcome, matchpoints};
+
+    let field = [
+        Outcome::Played(420),
+        Outcome::Played(170),
+        Outcome::Played(-50),
+        Outcome::Adjusted {
+            ns: AVERAGE_PLUS,
+            ew: AVERAGE_MINUS,
+        },
+    ];
+    let [top, middle, bottom, adjusted] = matchpoints(&field, MatchpointScale::Acbl)[..] else {
+        panic!("one row per table");
+    };
+    // Neuberg: (mp + ½) · 4/3 − ½ on a top of 3.
+    assert!((top[0] - 2.833_333).abs() < 1e-5);
+    assert!((middle[0] - 1.5).abs() < 1e-9);
+    assert!((bottom[0] - 0.166_666).abs() < 1e-5);
+    assert!((top[0] + top[1] - 3.0).abs() < 1e-9);
+    assert!((adjusted[0] - 1.8).abs() < 1e-9 && (adjusted[1] - 1.2).abs() < 1e-9);
+}
+
+#[test]
+fn butler_trims_and_rounds_the_datum() {
+    use pons::scoring::{butler, datum};
+
+    let scores = [420, 420, 450, 170, 140, -100, 1430, 420];
+    // Trimming 1430 and −100 leaves a mean of 336.67.
+    assert_eq!(datum(&scores, 1), 340);
+    assert_eq!(datum(&scores, 0), 420);
+    assert_eq!(datum(&[-45], 0), -50);
+    assert_eq!(datum(&[], 1), 0);
+    // Trimming past the middle keeps the median pair.
+    assert_eq!(datum(&[100, 200, 300, 400], 5), 250);
+    assert_eq!(
+        butler(&scores, 1),
+        [
+            imps(80),
+            imps(80),
+            imps(110),
+            imps(-170),
+            imps(-200),
+            imps(-440),
+            imps(1090),
+            imps(80)
+        ]
+    );
+}
+
+#[test]
+fn cross_imps_and_board_a_match() {
+    use pons::scoring::{board_a_match, cross_imps};
+
+    // 420 vs 170 is 6 IMPs, 420 vs −50 is 10, 170 vs −50 is 6.
+    assert_eq!(cross_imps(&[420, 170, -50]), [16, 0, -16]);
+    assert_eq!(cross_imps(&[420]), [0]);
+    assert_eq!(board_a_match(10), 1.0);
+    assert_eq!(board_a_match(0), 0.5);
+    assert_eq!(board_a_match(-2000), 0.0);
+}
+
+#[test]
+fn rubber_carries_partscores_and_pays_the_rubber() {
+    use pons::scoring::{RubberFormat, Scorecard};
+
+    let made = |level, strain, seat| Some((Contract::new(level, strain, Penalty::Undoubled), seat));
+    let mut card = Scorecard::new(RubberFormat::Rubber);
+
+    // 2♠ +1 leaves a 60 partscore, which East/West's 3NT game then wipes.
+    let entry = card.record(made(2, Strain::Spades, Seat::North), 9, None);
+    assert_eq!((entry.below, entry.above), ([60, 0], [30, 0]));
+    assert_eq!(card.game_in().partscore, [60, 0]);
+    card.record(made(3, Strain::Notrump, Seat::East), 9, None);
+    assert_eq!(card.game_in().partscore, [0, 0]);
+    assert_eq!(card.game_in().vulnerability(), AbsoluteVulnerability::EW);
+
+    card.record(made(4, Strain::Hearts, Seat::South), 10, None);
+    assert_eq!(card.game_in().vulnerability(), AbsoluteVulnerability::ALL);
+    let doubled = Contract::new(1, Strain::Notrump, Penalty::Doubled);
+    let entry = card.record(Some((doubled, Seat::West)), 5, Some((Seat::North, 150)));
+    assert_eq!(entry.above, [650, 0]);
+
+    // 3NT +1 vulnerable ends the rubber 2–1.
+    let entry = card.record(made(3, Strain::Notrump, Seat::North), 10, None);
+    assert!(entry.finished);
+    assert_eq!(entry.above, [530, 0]);
+    assert_eq!(card.finished(), [[1490, 100]]);
+    assert_eq!(card.totals(), [1490, 100]);
+    assert_eq!(card.game_in().games, [0, 0]);
+    assert_eq!(card.dealer(), Seat::East);
+}
+
+#[test]
+fn rubber_prices_games_by_the_state_of_the_rubber() {
+    use pons::scoring::{GameIn, RubberFormat};
+
+    let four_spades = Some((
+        Contract::new(4, Strain::Spades, Penalty::Undoubled),
+        Seat::North,
+    ));
+    let two_spades = Some((
+        Contract::new(2, Strain::Spades, Penalty::Undoubled),
+        Seat::North,
+    ));
+    let fresh = GameIn::new(RubberFormat::Rubber);
+    // The first game is worth what stopping the rubber would pay for it.
+    assert_eq!(fresh.ns_value(four_spades, 10), 420);
+    // A partscore is only its trick points: no duplicate partscore bonus.
+    assert_eq!(fresh.ns_value(two_spades, 8), 60);
+    assert_eq!(fresh.ns_value(four_spades, 9), -50);
+
+    let game_up = GameIn {
+        games: [1, 0],
+        ..fresh
+    };
+    assert!(game_up.is_vulnerable(Seat::South));
+    // The game that ends the rubber collects the rest of the 700.
+    assert_eq!(game_up.ns_value(four_spades, 10), 520);
+
+    let partscore = GameIn {
+        partscore: [60, 0],
+        ..fresh
+    };
+    assert_eq!(partscore.needs(Seat::South), 40);
+    assert_eq!(partscore.ns_value(two_spades, 8), 360);
+    assert_eq!(partscore.unfinished(), [100, 0]);
+}
+
+#[test]
+fn chicago_cycles_vulnerability_over_four_deals() {
+    use pons::scoring::{RubberFormat, Scorecard};
+
+    let made = |level, strain, seat| Some((Contract::new(level, strain, Penalty::Undoubled), seat));
+    let mut card = Scorecard::new(RubberFormat::Chicago);
+    assert_eq!(card.game_in().vulnerability(), AbsoluteVulnerability::NONE);
+
+    card.record(made(2, Strain::Hearts, Seat::North), 8, None);
+    // The dealer's side is vulnerable on the second and third deals.
+    assert_eq!(card.dealer(), Seat::East);
+    assert_eq!(card.game_in().vulnerability(), AbsoluteVulnerability::EW);
+    // 2♦ on top of the 60 partscore is a non-vulnerable game.
+    let entry = card.record(made(2, Strain::Diamonds, Seat::South), 8, None);
+    assert_eq!((entry.below, entry.above), ([40, 0], [300, 0]));
+
+    // A pass-out is redealt by the same dealer at the same vulnerability.
+    card.record(None, 0, None);
+    assert_eq!(card.dealer(), Seat::South);
+    assert_eq!(card.game_in().vulnerability(), AbsoluteVulnerability::NS);
+
+    let doubled = Contract::new(4, Strain::Spades, Penalty::Doubled);
+    card.record(Some((doubled, Seat::East)), 9, None);
+    assert_eq!(card.game_in().vulnerability(), AbsoluteVulnerability::ALL);
+
+    // A partscore on the fourth deal earns 100, and the Chicago ends.
+    let entry = card.record(made(2, Strain::Clubs, Seat::West), 8, None);
+    assert_eq!((entry.below, entry.above), ([0, 40], [0, 100]));
+    assert!(entry.finished);
+    assert_eq!(card.finished(), [[500, 140]]);
+    assert_eq!(card.game_in().deal, 0);
+    assert_eq!(card.game_in().vulnerability(), AbsoluteVulnerability::NONE);
+}
+
+#[test]
+fn honors_by_hand() {
+    use contract_bridge::FullDeal;
+    use pons::scoring::honors;
+
+    let deal: FullDeal = "N:AKQJ.AKQ.AKQ.AKQ T98.JT98.JT9.JT9 765.765.876.8765 432.432.5432.432"
+        .parse()
+        .expect("valid PBN deal");
+    assert_eq!(honors(&deal, Strain::Spades), Some((Seat::North, 100)));
+    assert_eq!(honors(&deal, Strain::Notrump), Some((Seat::North, 150)));
+    // North's ♥AKQ and East's ♥JT are split.
+    assert_eq!(honors(&deal, Strain::Hearts), None);
+}
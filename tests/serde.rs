@@ -95,3 +95,80 @@ fn envelope_union_and_inferences_use_canonical_keys() -> Result<(), serde_json::
     assert!(serde_json::from_value::<Inferences>(legacy_announced).is_err());
     Ok(())
 }
+
+#[test]
+fn agreements_roundtrip_with_defaults_for_missing_fields() -> Result<(), serde_json::Error> {
+    use pons::bidding::agreements::Agreements;
+    use pons::bidding::american::{EUROPEAN, LebensohlStyle, NotrumpDefense};
+    use pons::bidding::instinct::RkcbVariant;
+
+    let shipped = Agreements::default();
+    assert_eq!(Agreements::from_json(&shipped.to_json())?, shipped);
+    assert_eq!(Agreements::from_json("{}")?, shipped);
+
+    let mut arm = shipped;
+    arm.decision.reading.notrump_minors = EUROPEAN;
+    arm.decision.reading.notrump_defense = NotrumpDefense::Woolsey;
+    arm.decision.reading.rkcb_variant = RkcbVariant::Kickback;
+    arm.competition.lebensohl_style = LebensohlStyle::Plain;
+    assert_eq!(Agreements::from_json(&arm.to_json())?, arm);
+
+    let sparse = Agreements::from_json(
+        r#"{
+            "decision": { "reading": { "notrump_minors": "european", "rkcb_variant": "kickback" } },
+            "competition": { "lebensohl_style": "plain" }
+        }"#,
+    )?;
+    assert_eq!(sparse.decision.reading.notrump_minors, EUROPEAN);
+    assert_eq!(sparse.decision.reading.rkcb_variant, RkcbVariant::Kickback);
+    assert_eq!(sparse.competition.lebensohl_style, LebensohlStyle::Plain);
+    assert_eq!(sparse.defense, shipped.defense);
+
+    assert!(Agreements::from_json(r#"{ "rebid": { "fourth_suit_forcin": true } }"#).is_err());
+    assert!(
+        Agreements::from_json(r#"{ "decision": { "reading": { "notrump_minors": "stayman" } } }"#)
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn every_knob_key_is_a_json_path() -> Result<(), serde_json::Error> {
+    use pons::bidding::agreements::{Agreements, KNOBS, Kind};
+
+    let shipped = Agreements::default();
+    let tree: serde_json::Value = serde_json::from_str(&shipped.to_json())?;
+    for knob in KNOBS {
+        let value = knob
+            .key
+            .split('.')
+            .try_fold(&tree, |node, step| node.get(step))
+            .unwrap_or_else(|| panic!("`{}` is not in the JSON tree", knob.key));
+        // Toggles and unit choices share their spelling with the file.
+        match (knob.kind, value) {
+            (Kind::Toggle, serde_json::Value::Bool(b)) => {
+                assert_eq!(b.to_string(), knob.get(&shipped))
+            }
+            (Kind::Choice, serde_json::Value::String(s)) => assert_eq!(*s, knob.get(&shipped)),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn agreements_roundtrip_through_toml() -> Result<(), toml::de::Error> {
+    use pons::bidding::agreements::Agreements;
+
+    let mut arm = Agreements::default();
+    arm.decision.reading.xyz = false;
+    arm.opening.one_notrump_offshape = true;
+    assert_eq!(Agreements::from_toml(&arm.to_toml())?, arm);
+
+    let sparse = Agreements::from_toml(
+        "[decision.reading]\nxyz = false\n\n[opening]\none_notrump_offshape = true\n",
+    )?;
+    assert_eq!(sparse, arm);
+    Ok(())
+}
@@ -335,3 +335,72 @@ fn test_par_count_averages_across_deals() -> anyhow::Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_par_lists_every_contract_and_sacrifice() {
+    use ddss::{TrickCountRow, TrickCountTable};
+    use pons::stats::par;
+
+    // East/West make 12 tricks in spades or clubs; North/South 8 in hearts.
+    let table = TrickCountTable([
+        TrickCountRow::new(1, 12, 1, 12),
+        TrickCountRow::new(2, 10, 2, 10),
+        TrickCountRow::new(8, 5, 8, 5),
+        TrickCountRow::new(1, 12, 1, 12),
+        TrickCountRow::new(1, 7, 1, 7),
+    ]);
+    let pars = par(table);
+
+    let none = pars.get(Seat::North, AbsoluteVulnerability::NONE);
+    assert_eq!(none.score, -980);
+    let declarers: Vec<_> = none
+        .contracts
+        .iter()
+        .map(|c| (c.contract, c.declarer))
+        .collect();
+    assert!(declarers.contains(&(
+        Contract::new(6, Strain::Spades, Penalty::Undoubled),
+        Seat::East
+    )));
+    assert!(declarers.contains(&(
+        Contract::new(6, Strain::Spades, Penalty::Undoubled),
+        Seat::West
+    )));
+    assert_eq!(
+        pars.sacrifices(Seat::North, AbsoluteVulnerability::NONE)
+            .count(),
+        0
+    );
+    assert_eq!(pars.board(1).score, -980);
+
+    // Vulnerable against not, the grand slam save in hearts pays.
+    let ew = pars.get(Seat::East, AbsoluteVulnerability::EW);
+    assert_eq!(ew.score, -1100);
+    assert_eq!(
+        pars.sacrifices(Seat::East, AbsoluteVulnerability::EW)
+            .count(),
+        2
+    );
+    assert!(
+        pars.sacrifices(Seat::East, AbsoluteVulnerability::EW)
+            .all(|c| {
+                c.contract == Contract::new(7, Strain::Hearts, Penalty::Doubled)
+                    && c.overtricks == -5
+            })
+    );
+    assert!(!pars.depends_on_dealer(AbsoluteVulnerability::EW));
+}
+
+#[test]
+fn test_par_goes_to_the_dealer_when_both_sides_make_it() {
+    use ddss::{TrickCountRow, TrickCountTable};
+    use pons::stats::par;
+
+    // Each side takes seven tricks at notrump and six in every suit.
+    let mut table = TrickCountTable([TrickCountRow::new(6, 6, 6, 6); 5]);
+    table.0[4] = TrickCountRow::new(7, 7, 7, 7);
+    let pars = par(table);
+    assert!(pars.depends_on_dealer(AbsoluteVulnerability::NONE));
+    assert_eq!(pars.get(Seat::North, AbsoluteVulnerability::NONE).score, 90);
+    assert_eq!(pars.get(Seat::East, AbsoluteVulnerability::NONE).score, -90);
+}
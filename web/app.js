@@ -135,7 +135,12 @@ function renderHint() {
   const box = id('p-hint');
   const on = id('p-hint-on').checked;
   const rows = on ? JSON.parse(game.hint()) : null;
-  box.classList.toggle('hidden', !rows);
+  const regrets = on && !rows ? JSON.parse(game.post_mortem(POST_MORTEM_SAMPLES)) : null;
+  box.classList.toggle('hidden', !rows && !regrets);
+  if (regrets) {
+    renderPostMortem(box, regrets);
+    return;
+  }
   if (!rows) return;
 
   box.innerHTML =
@@ -146,6 +151,24 @@ function renderHint() {
        <span class="hintsd">± ${r.sd.toFixed(1)}</span></div>`).join('')}</div>`;
 }
 
+// After the auction the same panel turns post-mortem: each of our calls against
+// the book's best-priced alternative, EVs from the evaluator net, the call that
+// cost the most marked.
+const POST_MORTEM_SAMPLES = 32;
+
+function renderPostMortem(box, rows) {
+  const ev = (v) => (v === null ? '?' : `${v >= 0 ? '+' : ''}${Math.round(v)}`);
+  box.innerHTML =
+    '<div class="seat-head">What each of our calls cost</div>' +
+    rows.map((r) => `
+      <div class="regretrow${r.costliest ? ' costliest' : ''}">
+        <span class="statlabel">${r.seat}</span>
+        <span>${colorizeCalls(r.call)} ${ev(r.ev)}</span>
+        <span>${r.best === null ? '—' : `${colorizeCalls(r.best)} ${ev(r.best_ev)}`}</span>
+        <span class="hintsd">${r.regret > 0 ? `−${Math.round(r.regret)}` : ''}</span>
+      </div>`).join('');
+}
+
 function renderDemo(s) {
   id('d-edit').disabled = false;
   id('d-info').textContent = `Dealer ${SEAT_NAMES[s.dealer]} · Vul ${s.vul}`;
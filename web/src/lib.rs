@@ -14,20 +14,22 @@
 
 use std::collections::{BTreeMap, HashSet};
 
-use contract_bridge::auction::{Auction, Call, display_calls};
+use contract_bridge::auction::{Call, display_calls};
 use contract_bridge::deal::PartialDeal;
 use contract_bridge::deck::{fill_deals, full_deal};
 use contract_bridge::eval::{self, HandEvaluator as _, SimpleEvaluator};
 use contract_bridge::{
-    AbsoluteVulnerability, Bid, Builder, Contract, FullDeal, Hand, Seat, Strain,
+    AbsoluteVulnerability, Builder, Contract, FullDeal, Hand, Seat, SeatFlags, Strain,
 };
 use pons::bidding::agreements::{Agreements, TheirDisclosures};
 use pons::bidding::american::american_book;
 use pons::bidding::evaluator::trick_estimates;
 use pons::bidding::fallback::Fallback;
 use pons::bidding::features::ConventionCard;
-use pons::bidding::{Partnership, Relative, Table, american, american_with_card, instinct};
-use pons::scoring::{final_contract, imps};
+use pons::bidding::postmortem::{self, post_mortem};
+use pons::bidding::{Partnership, Relative, Table, american, american_with_card};
+use pons::scoring::imps;
+use pons::session::{Phase, Session};
 use pons_dds::{Par, Solver, TrickCountTable, Vulnerability, calculate_par, solve_deal_on};
 use rand::SeedableRng as _;
 use rand::rngs::StdRng;
@@ -88,13 +90,9 @@ struct Snapshot<'a> {
 
 /// One dealt board and its auction state
 struct Board {
-    table: Table<Partnership, Partnership>,
-    deal: FullDeal,
-    dealer: Seat,
-    vul: AbsoluteVulnerability,
-    /// The human's seat, or [`None`] in demo mode
-    human: Option<Seat>,
-    auction: Auction,
+    /// The deal and its auction, with the human's seat, if any, as its only
+    /// human
+    session: Session<Partnership, Partnership>,
     feedback: Vec<Feedback>,
     /// Cached double-dummy table, solved on first request after the reveal
     dd: Option<TrickCountTable>,
@@ -112,14 +110,45 @@ struct HintRow {
     sd: f32,
 }
 
+/// One of our calls in the post-mortem, against its best alternative
+#[derive(Serialize)]
+struct RegretRow {
+    seat: char,
+    call: String,
+    /// The call's EV for us, in points; `null` where nothing priced it
+    ev: Option<f32>,
+    best: Option<String>,
+    best_ev: Option<f32>,
+    regret: f32,
+    /// Whether this call cost the most
+    costliest: bool,
+}
+
 impl Board {
+    /// The human's seat, or [`None`] in demo mode
+    fn human(&self) -> Option<Seat> {
+        Seat::ALL
+            .into_iter()
+            .find(|&seat| self.session.humans().contains(seat.into()))
+    }
+
+    /// The seat to call next
+    fn seat_to_act(&self) -> Seat {
+        let session = &self.session;
+        session.table().seat_to_act(session.auction().len())
+    }
+
     /// Price every strain for the side to act, off what the auction has shown
     ///
     /// Declarer is whichever of us the net rates higher — the system picks the
     /// better hand to play it, so the useful number is the max, not our own.
     fn hint(&self) -> Vec<HintRow> {
-        let seat = self.table.seat_to_act(self.auction.len());
-        let estimates = trick_estimates(self.deal[seat], &self.table.infer(&self.auction));
+        let session = &self.session;
+        let seat = self.seat_to_act();
+        let estimates = trick_estimates(
+            session.deal()[seat],
+            &session.table().infer(session.auction()),
+        );
 
         Strain::ASC
             .iter()
@@ -142,16 +171,40 @@ impl Board {
             .collect()
     }
 
-    /// Bid bot seats forward until the human is to act or the auction ends
-    fn advance(&mut self) {
-        while !self.auction.has_ended() {
-            let seat = self.table.seat_to_act(self.auction.len());
-            if Some(seat) == self.human {
-                break;
-            }
-            let call = self.table.next_call(self.deal[seat], &self.auction);
-            self.auction.push(call);
-        }
+    /// Our side's decisions in the finished auction, each priced by
+    /// [`ev_fast`][pons::bidding::ev_fast] against the book's shortlist
+    fn post_mortem(&self, samples: usize) -> Vec<RegretRow> {
+        let session = &self.session;
+        let pair = self.human().unwrap_or(Seat::North);
+        let ours = match pair {
+            Seat::North | Seat::South => session.table().north_south(),
+            Seat::East | Seat::West => session.table().east_west(),
+        };
+        let report = post_mortem(
+            session.deal(),
+            session.dealer(),
+            session.vul(),
+            session.auction(),
+            pair,
+            ours,
+            3,
+            postmortem::fast(ours, samples, 0),
+        );
+        let costliest = report.costliest().map(|decision| decision.index);
+        let ev = |ev: f32| ev.is_finite().then_some(ev);
+        report
+            .decisions
+            .iter()
+            .map(|decision| RegretRow {
+                seat: decision.seat.letter(),
+                call: decision.chosen.to_string(),
+                ev: ev(decision.chosen_ev),
+                best: decision.best.map(|(call, _)| call.to_string()),
+                best_ev: decision.best.and_then(|(_, best)| ev(best)),
+                regret: decision.regret,
+                costliest: Some(decision.index) == costliest,
+            })
+            .collect()
     }
 
     /// The bot's ranked top-3 legal calls with softmax percentages
@@ -159,15 +212,19 @@ impl Board {
     /// Port of the CLI feedback in `examples/practice-bidding`: finite logits
     /// only, legal calls only, percent from the full softmax.
     fn top3(&self) -> Vec<(String, f32)> {
-        let seat = self.table.seat_to_act(self.auction.len());
-        let Some(logits) = self.table.classify(self.deal[seat], &self.auction) else {
+        let session = &self.session;
+        let seat = self.seat_to_act();
+        let Some(logits) = session
+            .table()
+            .classify(session.deal()[seat], session.auction())
+        else {
             return Vec::new();
         };
         let softmax = logits.softmax();
         let mut scored: Vec<(Call, f32)> = logits
             .iter()
             .filter(|&(_, &logit)| logit.is_finite())
-            .filter(|(call, _)| self.auction.can_push(*call).is_ok())
+            .filter(|(call, _)| session.auction().can_push(*call).is_ok())
             .map(|(call, &logit)| (call, logit))
             .collect();
         scored.sort_by(|a, b| b.1.partial_cmp(&a.1).expect("logits are never NaN"));
@@ -181,55 +238,38 @@ impl Board {
             .collect()
     }
 
-    /// All calls the seat to act may legally make, as display codes
-    fn legal(&self) -> Vec<String> {
-        if self.auction.has_ended() {
-            return Vec::new();
-        }
-        let bids = (1..=7).flat_map(|level| {
-            Strain::ASC
-                .into_iter()
-                .map(move |strain| Call::Bid(Bid::new(level, strain)))
-        });
-        [Call::Pass, Call::Double, Call::Redouble]
-            .into_iter()
-            .chain(bids)
-            .filter(|&call| self.auction.can_push(call).is_ok())
-            .map(|call| call.to_string())
-            .collect()
-    }
-
     fn snapshot(&self) -> Snapshot<'_> {
-        let ended = self.auction.has_ended();
-        let seat_to_act = self.table.seat_to_act(self.auction.len());
+        let session = &self.session;
+        let human = self.human();
+        let ended = session.phase() != Phase::Auction;
 
         // Practice shows only the human's hand until the reveal
-        let visible = |seat: Seat| ended || self.human.is_none_or(|human| human == seat);
+        let visible = |seat: Seat| ended || human.is_none_or(|human| human == seat);
         let hands = Seat::ALL
             .into_iter()
             .filter(|&seat| visible(seat))
-            .map(|seat| (seat.letter(), HandJson::new(self.deal[seat])))
+            .map(|seat| (seat.letter(), HandJson::new(session.deal()[seat])))
             .collect();
 
-        let contract = ended.then(|| match final_contract(&self.auction, self.dealer) {
+        let contract = ended.then(|| match session.contract() {
             Some((contract, declarer)) => format!("{contract} by {}", declarer.letter()),
             None => "Passed out".to_string(),
         });
 
         Snapshot {
-            mode: if self.human.is_some() {
-                "practice"
-            } else {
-                "demo"
-            },
-            dealer: self.dealer.letter(),
-            vul: vul_name(self.vul),
-            seat: self.human.map(Seat::letter),
+            mode: if human.is_some() { "practice" } else { "demo" },
+            dealer: session.dealer().letter(),
+            vul: vul_name(session.vul()),
+            seat: human.map(Seat::letter),
             hands,
-            auction: self.auction.iter().map(ToString::to_string).collect(),
-            your_turn: !ended && self.human == Some(seat_to_act),
+            auction: session.auction().iter().map(ToString::to_string).collect(),
+            your_turn: !ended && session.human_to_act(),
             ended,
-            legal: self.legal(),
+            legal: session
+                .legal_calls()
+                .into_iter()
+                .map(|call| call.to_string())
+                .collect(),
             contract,
             feedback: &self.feedback,
         }
@@ -485,25 +525,25 @@ impl WebTable {
     /// snapshot unchanged; the UI prevents these by disabling buttons.
     pub fn bid(&mut self, call: &str) -> String {
         if let Some(board) = &mut self.board
-            && !board.auction.has_ended()
-            && board.human == Some(board.table.seat_to_act(board.auction.len()))
+            && board.session.phase() == Phase::Auction
+            && board.session.human_to_act()
             && let Ok(call) = call.parse::<Call>()
-            && board.auction.can_push(call).is_ok()
         {
             // The bot's opinion must be read before the auction grows
             let top = board.top3();
-            let agreed = match top.first() {
-                Some((best, _)) => *best == call.to_string(),
-                None => call == Call::Pass,
-            };
-            board.feedback.push(Feedback {
-                index: board.auction.len(),
-                human: call.to_string(),
-                agreed,
-                top,
-            });
-            board.auction.push(call);
-            board.advance();
+            let index = board.session.auction().len();
+            if board.session.bid(call).is_ok() {
+                let agreed = match top.first() {
+                    Some((best, _)) => *best == call.to_string(),
+                    None => call == Call::Pass,
+                };
+                board.feedback.push(Feedback {
+                    index,
+                    human: call.to_string(),
+                    agreed,
+                    top,
+                });
+            }
         }
         self.snapshot()
     }
@@ -518,15 +558,16 @@ impl WebTable {
         let Some(board) = &mut self.board else {
             return "null".to_string();
         };
-        if !board.auction.has_ended() {
+        if board.session.phase() == Phase::Auction {
             return "null".to_string();
         }
 
+        let session = &board.session;
         let solver = board
             .solver
             .get_or_insert_with(|| Solver::with_memory(Strain::Notrump, TT_MB.0, TT_MB.1));
         if board.dd.is_none() {
-            board.dd = Some(solve_deal_on(solver, board.deal));
+            board.dd = Some(solve_deal_on(solver, *session.deal()));
         }
         let table = board.dd.expect("just solved");
 
@@ -534,12 +575,12 @@ impl WebTable {
         // par is defined even for a pass-out.
         let par = calculate_par(
             table,
-            Vulnerability::from_bits_truncate(board.vul.bits()),
-            board.dealer,
+            Vulnerability::from_bits_truncate(session.vul().bits()),
+            session.dealer(),
         );
-        let reached = final_contract(&board.auction, board.dealer);
+        let reached = session.contract();
         let tricks = reached.map(|(c, d)| table[c.bid.strain].get(d).get());
-        let verdict = Some(verdict_lines(reached, tricks, &par, board.vul));
+        let verdict = Some(verdict_lines(reached, tricks, &par, session.vul()));
 
         const SEAT_COLS: [Seat; 4] = [Seat::West, Seat::North, Seat::East, Seat::South];
         let rows = Strain::ASC
@@ -572,21 +613,22 @@ impl WebTable {
         let Some(board) = &mut self.board else {
             return "null".to_string();
         };
-        let Some(human) = board.human else {
+        let Some(human) = board.human() else {
             return "null".to_string();
         };
-        if !board.auction.has_ended() {
+        if board.session.phase() == Phase::Auction {
             return "null".to_string();
         }
-        let Some((contract, declarer)) = final_contract(&board.auction, board.dealer) else {
+        let Some((contract, declarer)) = board.session.contract() else {
             return "null".to_string();
         };
 
+        let deal = board.session.deal();
         let partner = human.partner();
         let partial = set_seat(
-            set_seat(Builder::new(), human, board.deal[human]),
+            set_seat(Builder::new(), human, deal[human]),
             partner,
-            board.deal[partner],
+            deal[partner],
         )
         .build_partial()
         .expect("two disjoint 13-card hands form a valid partial deal");
@@ -598,7 +640,7 @@ impl WebTable {
         solver.set_strain(strain);
 
         let needed = 6 + contract.bid.level.get();
-        let declarer_vul = board.vul.contains(side(declarer));
+        let declarer_vul = board.session.vul().contains(side(declarer));
         let human_declaring = side(human) == side(declarer);
 
         for deal in fill_deals(&mut self.rng, partial).take(samples as usize) {
@@ -634,11 +676,30 @@ impl WebTable {
         let Some(board) = &self.board else {
             return "null".to_string();
         };
-        if board.auction.has_ended() {
+        if board.session.phase() != Phase::Auction {
             return "null".to_string();
         }
         serde_json::to_string(&board.hint()).expect("hint serialization")
     }
+
+    /// What each of our calls cost, once the auction has ended
+    ///
+    /// Walks the finished auction and prices every call our side made — the
+    /// human's side, or North/South in a demo — against the book's top three
+    /// alternatives, from the caller's chair over `samples` layouts consistent
+    /// with the auction so far.  Priced by the evaluator net, not double
+    /// dummy, so it answers at once.  `"null"` while the auction is live.
+    #[must_use]
+    pub fn post_mortem(&self, samples: u32) -> String {
+        let Some(board) = &self.board else {
+            return "null".to_string();
+        };
+        if board.session.phase() == Phase::Auction {
+            return "null".to_string();
+        }
+        serde_json::to_string(&board.post_mortem(samples as usize))
+            .expect("post-mortem serialization")
+    }
 }
 
 impl WebTable {
@@ -667,20 +728,14 @@ impl WebTable {
         let dealer = dealer.parse().unwrap_or(Seat::North);
         let vul = vul.parse().unwrap_or(AbsoluteVulnerability::NONE);
         let (ns, ew) = partnerships();
-        let mut board = Board {
-            table: Table::new(ns, ew, dealer, vul),
-            deal,
-            dealer,
-            vul,
-            human,
-            auction: Auction::new(),
+        let humans = human.map_or(SeatFlags::empty(), SeatFlags::from);
+        self.board = Some(Board {
+            session: Session::new(Table::new(ns, ew, dealer, vul), deal, humans),
             feedback: Vec::new(),
             dd: None,
             oracle: Oracle::default(),
             solver: None,
-        };
-        board.advance();
-        self.board = Some(board);
+        });
         self.snapshot()
     }
 }
@@ -908,128 +963,19 @@ fn partnerships() -> (Partnership, Partnership) {
     (ns.clone().with_opponents(&ew), ew.with_opponents(&ns))
 }
 
-/// Define a registry row's `set`/`get` system over one [`Agreements`] field
-///
-/// One line per knob whose engine cell has been deleted.  The system keeps the
-/// `fn(&mut Agreements, bool)` / `fn(&Agreements) -> bool` shape
-/// [`Setting::Toggle`] stores, so the same registry edits either partnership.
-macro_rules! knob {
-    ($set:ident, $get:ident, $($field:ident).+ : $ty:ty) => {
-        fn $set(agreements: &mut Agreements, value: $ty) {
-            agreements.$($field).+ = value;
-        }
-        fn $get(agreements: &Agreements) -> $ty {
-            agreements.$($field).+
-        }
-    };
-}
-
-knob!(set_open_one_notrump, open_one_notrump, opening.open_one_notrump: bool);
-knob!(set_notrump_shape, notrump_shape_setting, opening.notrump_shape: american::NotrumpShape);
-knob!(set_second_suit_agreement, second_suit_agreement, game_force.second_suit_agreement: bool);
-knob!(set_game_backstop, game_backstop_enabled, game_force.game_backstop: bool);
-knob!(set_fourth_suit_forcing, fourth_suit_forcing, rebid.fourth_suit_forcing: bool);
-knob!(set_meckstroth_adjunct, meckstroth_adjunct, rebid.meckstroth_adjunct: bool);
-knob!(set_limit_raise_acceptance, limit_raise_acceptance, response.limit_raise_acceptance: bool);
-knob!(set_transfer_super_accept, transfer_super_accept, notrump.transfer_super_accept: bool);
-knob!(set_transfer_slam_try, transfer_slam_try, notrump.transfer_slam_try: bool);
-knob!(set_texas_slam_drive, texas_slam_drive, notrump.texas_slam_drive: bool);
-knob!(set_stayman_both_majors, stayman_both_majors, notrump.stayman_both_majors: bool);
-knob!(set_stayman_5card_max, stayman_5card_max, notrump.stayman_5card_max: bool);
-knob!(set_invitational_5card_majors, invitational_5card_majors, notrump.invitational_5card_majors: bool);
-knob!(set_transfer_longer_major, transfer_longer_major, notrump.transfer_longer_major: bool);
-knob!(set_stayman_cue_continuation, stayman_cue_continuation, notrump.stayman_cue_continuation: bool);
-knob!(set_stayman_minor_slam_try, stayman_minor_slam_try, notrump.stayman_minor_slam_try: bool);
-knob!(set_splinter_doubled, splinter_doubled, competition.splinter_doubled: bool);
-knob!(set_uvu, uvu, competition.uvu: bool);
-knob!(set_uvu_over_majors, uvu_over_majors, competition.uvu_over_majors: bool);
-knob!(set_uvu_over_minors, uvu_over_minors, competition.uvu_over_minors: bool);
-knob!(set_direct_3nt_stopper, direct_3nt_stopper, competition.direct_3nt_stopper: bool);
-knob!(set_cue_raise_answer, cue_raise_answer, competition.cue_raise_answer: bool);
-knob!(set_cue_minor_raise_answer, cue_minor_raise_answer, competition.cue_minor_raise_answer: bool);
-knob!(set_major_support_double, major_support_double, competition.major_support_double: bool);
-knob!(set_high_overcall_responses, high_overcall_responses, competition.high_overcall_responses: bool);
-knob!(set_nt_high_overcall_responses, nt_high_overcall_responses, competition.nt_high_overcall_responses: bool);
-knob!(set_nt_high_overcall_3nt_stopper, nt_high_overcall_3nt_stopper, competition.nt_high_overcall_3nt_stopper: bool);
-knob!(set_nt_3c_transfers, nt_3c_transfers, competition.nt_3c_transfers: bool);
-knob!(set_nt_high_overcall_x_major_at_four, nt_high_overcall_x_major_at_four, competition.nt_high_overcall_x_major_at_four: bool);
-knob!(set_nt_high_overcall_x_leave_in, nt_high_overcall_x_leave_in, competition.nt_high_overcall_x_leave_in: bool);
-knob!(set_nt_high_overcall_x_leave_in_three, nt_high_overcall_x_leave_in_three, competition.nt_high_overcall_x_leave_in_three: bool);
-knob!(set_jordan_truscott, jordan_truscott, competition.jordan_truscott: bool);
-knob!(set_delayed_cue, delayed_cue, competition.delayed_cue: bool);
-knob!(set_competition_over_stayman, competition_over_stayman, competition.competition_over_stayman: bool);
-knob!(set_competition_over_minor_transfer, competition_over_minor_transfer, competition.competition_over_minor_transfer: bool);
-knob!(set_competition_over_diamond_transfer, competition_over_diamond_transfer, competition.competition_over_diamond_transfer: bool);
-knob!(set_multi_stopper_ask, multi_stopper_ask, competition.multi_stopper_ask: american::MultiStopperAsk);
-// The declaration's read-side wiring: their 2♣ reads as both majors instead
-// of the natural walk's clubs.  Default on (shipped 2026-08-14, plain wash |
-// PD win); a no-op unless the disclosure is declared.
-knob!(set_their_landy_reading, their_landy_reading, decision.reading.their_landy_reading: bool);
-knob!(set_their_multi_reading, their_multi_reading, decision.reading.their_multi_reading: bool);
-knob!(set_defense_to_2c_landy_cues, defense_to_2c_landy_cues, competition.defense_2c_landy_cues: bool);
-knob!(set_defense_to_2c_landy_transfer, defense_to_2c_landy_transfer, competition.defense_2c_landy_transfer: bool);
-knob!(set_defense_to_2c_landy_cue_floor, defense_to_2c_landy_cue_floor, competition.defense_2c_landy_cue_floor: bool);
-knob!(set_defense_to_2c_landy_fit_answers, defense_to_2c_landy_fit_answers, competition.defense_2c_landy_fit_answers: bool);
-knob!(set_defense_to_2c_landy_competition, defense_to_2c_landy_competition, competition.defense_2c_landy_competition: bool);
-knob!(set_defense_to_2c_landy_low_minors, defense_to_2c_landy_low_minors, competition.defense_2c_landy_low_minors: bool);
-knob!(set_defense_to_2c_landy_hcp_rungs, defense_to_2c_landy_hcp_rungs, competition.defense_2c_landy_hcp_rungs: bool);
-knob!(set_defense_to_2c_landy_bba, defense_to_2c_landy_bba, competition.defense_2c_landy_bba: bool);
-knob!(set_defense_to_2c_landy_weak_2d_cap, defense_to_2c_landy_weak_2d_cap, competition.defense_2c_landy_weak_2d_cap: bool);
-
-// The classify-time half, on this value rather than the crate's thread-locals:
-// the system carries its agreements now, so a setter that wrote a global would
-// move nothing the built partnership ever reads.
-knob!(set_garbage_stayman, garbage_stayman, decision.reading.garbage_stayman: bool);
-knob!(set_crawling_stayman, crawling_stayman, decision.reading.crawling_stayman: bool);
-knob!(set_nt_splinter, nt_splinter, decision.reading.nt_splinter: bool);
-knob!(set_nt_overcall_gladiator, nt_overcall_gladiator, decision.reading.nt_overcall_gladiator: bool);
-knob!(set_rubens_advances, rubens_advances, decision.reading.rubens_advances: bool);
-knob!(set_floor_rkcb, floor_rkcb, decision.reading.floor_rkcb: bool);
-knob!(set_penalty_latch, penalty_latch, decision.reading.penalty_latch: bool);
-knob!(set_nt_invite, nt_invite, decision.reading.nt_invite: bool);
-knob!(set_rubens_transfer, rubens_transfer, decision.reading.rubens_transfer: bool);
-knob!(set_fallback_projection, fallback_projection, decision.reading.fallback_projection: bool);
-knob!(set_strength_ceilings, strength_ceilings, decision.reading.strength_ceilings: bool);
-knob!(set_bid_exclusion, bid_exclusion, decision.reading.bid_exclusion: bool);
-knob!(set_control_bid, control_bid, decision.reading.control_bid: bool);
-knob!(set_rule_accept, rule_accept, decision.reading.rule_accept: bool);
-knob!(set_transfer_gf_majors, transfer_gf_majors, decision.transfer_gf_majors: bool);
-knob!(set_transfer_gf_hearts, transfer_gf_hearts, decision.transfer_gf_hearts: bool);
-knob!(set_two_over_one_force, two_over_one_force, decision.two_over_one_force: bool);
-knob!(set_one_nt_runout, one_nt_runout, decision.instinct.one_nt_runout: bool);
-knob!(set_one_nt_runout_universal, one_nt_runout_universal, decision.instinct.one_nt_runout_universal: bool);
-knob!(set_settle_floor, settle_floor, decision.instinct.settle_floor: bool);
-knob!(set_penalize_escape_stack, penalize_escape_stack, decision.instinct.penalize_escape_stack: bool);
-knob!(set_penalize_escape_values, penalize_escape_values, decision.instinct.penalize_escape_values: bool);
-knob!(set_uvu_encircle, uvu_encircle, decision.instinct.uvu_encircle: bool);
-knob!(set_penalty_no_pull, penalty_no_pull, decision.instinct.penalty_no_pull: bool);
-knob!(set_advancer_xx_runout, advancer_xx_runout, decision.instinct.advancer_xx_runout: bool);
-knob!(set_forcing_ceiling_read, forcing_ceiling_read, decision.instinct.forcing_ceiling_read: bool);
-knob!(set_doubler_xx_runout, doubler_xx_runout, instinct.doubler_xx_runout: bool);
-knob!(set_negative_double_shape, negative_double_shape, competition.negative_double_shape: american::NegativeDoubleShape);
-knob!(set_lebensohl_style, lebensohl_style, competition.lebensohl_style: american::LebensohlStyle);
-// The forced 3♣ completion's alert (reads as a puppet, not clubs).  Off
-// pending its A/B — it moves the default system.
-knob!(set_completion_alerts, completion_alerts, decision.reading.completion_alerts: bool);
-knob!(set_passed_hand_overcall, passed_hand_overcall, defense.passed_hand_overcall: bool);
-knob!(set_leaping_michaels, leaping_michaels, defense.leaping_michaels_enabled: bool);
-knob!(set_responsive_takeout, responsive_takeout, defense.responsive_takeout_enabled: bool);
-knob!(set_rich_advance_double, rich_advance_double, defense.rich_advance_double_enabled: bool);
-knob!(set_advance_rubens, advance_rubens, defense.advance_rubens_enabled: bool);
-knob!(set_direct_dont_four_four, direct_dont_four_four, defense.direct_dont_four_four: bool);
-knob!(set_stayman_defense, stayman_defense, defense.stayman_defense_enabled: bool);
-knob!(set_transfer_defense, transfer_defense, defense.transfer_defense_enabled: bool);
-knob!(set_minor_transfer_defense, minor_transfer_defense, defense.minor_transfer_defense_enabled: bool);
-knob!(set_advance_sohl_style, advance_sohl_style, defense.advance_sohl_style: american::LebensohlStyle);
-
 /// The Settings-tab registry: one row per user-facing bidding knob
 ///
 /// This table is the **single source of truth** for the Settings tab.
 /// [`set_option`] / [`set_choice`] dispatch a call through it and
 /// [`describe_options`] serialises it for the JS renderer, so adding a convention
-/// to the UI needs only one row here (plus the engine `set_*` it points at) — the
-/// old hand-synced JS `CURATED` / `MORE` arrays are gone.  Each `set_*` is a
-/// field on the selected partnership's [`Agreements`] value.
+/// to the UI needs only one row here — the old hand-synced JS `CURATED` / `MORE`
+/// arrays are gone.  Most rows name a crate knob by its [`KNOBS`] key and read
+/// and write it through [`Agreements::get`] / [`Agreements::set`], so the field,
+/// its default and its spelling come from the crate; the few that present a
+/// knob differently carry their own functions (see [`Cell`]).
+///
+/// [`KNOBS`]: pons::bidding::agreements::KNOBS
+///
 /// A row's `requires`: the master this control is dead without.
 ///
 /// The usual forms resolve against the current partnership: `"key"` (that
@@ -1052,15 +998,10 @@ enum Setting {
         section: &'static str,
         /// Display label, or `""` to humanise the key in JS.
         label: &'static str,
-        default: bool,
         /// See [`Requires`].
         requires: Requires,
         #[serde(skip)]
-        set: fn(&mut Agreements, bool),
-        /// Reads the field `set` writes, so a test can prove `default` still
-        /// mirrors it. See `registry_defaults_match_the_engine`.
-        #[serde(skip)]
-        get: fn(&Agreements) -> bool,
+        cell: Cell,
     },
     /// A mutually-exclusive family, rendered as radio buttons.  Exactly one variant
     /// is active; the engine backs it with a single enum (e.g. [`NotrumpDefense`]).
@@ -1071,19 +1012,50 @@ enum Setting {
         section: &'static str,
         label: &'static str,
         variants: &'static [Variant],
-        /// The `value` of the default variant.
-        default: &'static str,
         /// See [`Requires`].
         requires: Requires,
         #[serde(skip)]
-        set: fn(&mut Agreements, &str),
-        /// The `value` of the variant the profile currently holds. See
-        /// [`Setting::Toggle::get`].
-        #[serde(skip)]
-        get: fn(&Agreements) -> &'static str,
+        cell: Cell,
     },
 }
 
+/// Where a [`Setting`] keeps its value on the selected [`Agreements`]
+///
+/// Values cross as the registry's text: `"true"` / `"false"` for a toggle, a
+/// variant's `value` for a choice.
+#[derive(Clone, Copy)]
+enum Cell {
+    /// A crate knob by its registry key, in the registry's own spelling.
+    Knob(&'static str),
+    /// A toggle over a knob that is not itself a toggle — Lebensohl's style,
+    /// the 1NT minor scheme.
+    Toggle(fn(&mut Agreements, bool), fn(&Agreements) -> bool),
+    /// A radio family whose `value`s are not the knob's own spellings, or
+    /// whose choice moves more than one knob.
+    Choice(fn(&mut Agreements, &str), fn(&Agreements) -> &'static str),
+}
+
+impl Cell {
+    /// Write `value`; one the cell cannot hold is a no-op, like an unknown key.
+    fn set(self, agreements: &mut Agreements, value: &str) {
+        match self {
+            Cell::Knob(key) => {
+                let _ = agreements.set(key, value);
+            }
+            Cell::Toggle(set, _) => set(agreements, value == "true"),
+            Cell::Choice(set, _) => set(agreements, value),
+        }
+    }
+
+    fn get(self, agreements: &Agreements) -> String {
+        match self {
+            Cell::Knob(key) => agreements.get(key).expect("a registry row names a knob"),
+            Cell::Toggle(_, get) => get(agreements).to_string(),
+            Cell::Choice(_, get) => get(agreements).to_owned(),
+        }
+    }
+}
+
 /// One radio option of a [`Setting::Choice`].
 #[derive(Serialize)]
 struct Variant {
@@ -1098,6 +1070,22 @@ impl Setting {
         }
     }
 
+    const fn cell(&self) -> Cell {
+        match self {
+            Setting::Toggle { cell, .. } | Setting::Choice { cell, .. } => *cell,
+        }
+    }
+
+    /// The row's value on the shipped [`Agreements`], as the JS renderer
+    /// wants it: a boolean for a toggle, the variant's `value` for a choice.
+    fn default(&self) -> serde_json::Value {
+        let value = self.cell().get(&Agreements::default());
+        match self {
+            Setting::Toggle { .. } => serde_json::Value::Bool(value == "true"),
+            Setting::Choice { .. } => serde_json::Value::String(value),
+        }
+    }
+
     /// Test-only: the row's gate. The UI reads the serialised field, not this.
     #[cfg(test)]
     const fn requires(&self) -> Requires {
@@ -1107,23 +1095,20 @@ impl Setting {
     }
 }
 
-/// Terser constructor for the common ungated [`Setting::Toggle`] row.
+/// Terser constructor for the common ungated [`Setting::Toggle`] row over the
+/// crate toggle `knob`.
 const fn toggle(
     key: &'static str,
     section: &'static str,
     label: &'static str,
-    default: bool,
-    set: fn(&mut Agreements, bool),
-    get: fn(&Agreements) -> bool,
+    knob: &'static str,
 ) -> Setting {
     Setting::Toggle {
         key,
         section,
         label,
-        default,
         requires: None,
-        set,
-        get,
+        cell: Cell::Knob(knob),
     }
 }
 
@@ -1133,19 +1118,32 @@ const fn gated(
     key: &'static str,
     section: &'static str,
     label: &'static str,
-    default: bool,
-    set: fn(&mut Agreements, bool),
-    get: fn(&Agreements) -> bool,
+    knob: &'static str,
     requires: &'static str,
 ) -> Setting {
     Setting::Toggle {
         key,
         section,
         label,
-        default,
         requires: Some(requires),
-        set,
-        get,
+        cell: Cell::Knob(knob),
+    }
+}
+
+/// A [`Setting::Toggle`] over a knob that is not a toggle.
+const fn custom(
+    key: &'static str,
+    section: &'static str,
+    label: &'static str,
+    set: fn(&mut Agreements, bool),
+    get: fn(&Agreements) -> bool,
+) -> Setting {
+    Setting::Toggle {
+        key,
+        section,
+        label,
+        requires: None,
+        cell: Cell::Toggle(set, get),
     }
 }
 
@@ -1235,29 +1233,6 @@ static NOTRUMP_SHAPE_VARIANTS: &[Variant] = &[
     },
 ];
 
-/// Select the 1NT opening shape from its registry `value`.
-fn set_notrump_shape_choice(agreements: &mut Agreements, value: &str) {
-    use american::NotrumpShape;
-    set_notrump_shape(
-        agreements,
-        match value {
-            "balanced" => NotrumpShape::Balanced,
-            "wide" => NotrumpShape::Wide,
-            _ => NotrumpShape::Wide6322,
-        },
-    );
-}
-
-/// The 1NT opening shape the engine currently holds, as its registry `value`.
-fn get_notrump_shape_choice(agreements: &Agreements) -> &'static str {
-    use american::NotrumpShape;
-    match notrump_shape_setting(agreements) {
-        NotrumpShape::Balanced => "balanced",
-        NotrumpShape::Wide => "wide",
-        _ => "wide6322",
-    }
-}
-
 /// The negative-double school over their overcall — variants map onto
 /// `american::NegativeDoubleShape`. Only the three shipped-or-playable schools
 /// surface; the pre-Modern `BothMajors` rule is not offered.
@@ -1276,30 +1251,6 @@ static NEGATIVE_DOUBLE_VARIANTS: &[Variant] = &[
     },
 ];
 
-/// Select the negative-double school from its registry `value`.
-fn set_negative_double_choice(agreements: &mut Agreements, value: &str) {
-    use american::NegativeDoubleShape;
-    set_negative_double_shape(
-        agreements,
-        match value {
-            "sputnik" => NegativeDoubleShape::Sputnik,
-            "cachalot" => NegativeDoubleShape::Cachalot,
-            _ => NegativeDoubleShape::Modern,
-        },
-    );
-}
-
-/// The negative-double school the engine currently holds, as its registry
-/// `value`.  The unoffered pre-Modern `BothMajors` reads as `modern`.
-fn get_negative_double_choice(agreements: &Agreements) -> &'static str {
-    use american::NegativeDoubleShape;
-    match negative_double_shape(agreements) {
-        NegativeDoubleShape::Sputnik => "sputnik",
-        NegativeDoubleShape::Cachalot => "cachalot",
-        _ => "modern",
-    }
-}
-
 /// The two independently measured continuations of the Multi stopper ask.
 static MULTI_STOPPER_ASK_VARIANTS: &[Variant] = &[
     Variant {
@@ -1318,19 +1269,16 @@ static MULTI_STOPPER_ASK_VARIANTS: &[Variant] = &[
 
 fn set_multi_stopper_ask_choice(agreements: &mut Agreements, value: &str) {
     use american::MultiStopperAsk;
-    set_multi_stopper_ask(
-        agreements,
-        match value {
-            "search" => MultiStopperAsk::FitSearch,
-            "place" => MultiStopperAsk::OpenerPlaces,
-            _ => MultiStopperAsk::Off,
-        },
-    );
+    agreements.competition.multi_stopper_ask = match value {
+        "search" => MultiStopperAsk::FitSearch,
+        "place" => MultiStopperAsk::OpenerPlaces,
+        _ => MultiStopperAsk::Off,
+    };
 }
 
 fn get_multi_stopper_ask_choice(agreements: &Agreements) -> &'static str {
     use american::MultiStopperAsk;
-    match multi_stopper_ask(agreements) {
+    match agreements.competition.multi_stopper_ask {
         MultiStopperAsk::FitSearch => "search",
         MultiStopperAsk::OpenerPlaces => "place",
         MultiStopperAsk::Off => "off",
@@ -1355,65 +1303,38 @@ static RKCB_VARIANT_VARIANTS: &[Variant] = &[
     },
 ];
 
-/// Select the keycard relocation stance from its registry `value`.
-fn set_rkcb_variant_choice(agreements: &mut Agreements, value: &str) {
-    use instinct::RkcbVariant;
-    agreements.decision.reading.rkcb_variant = match value {
-        "redwood" => RkcbVariant::Redwood,
-        "kickback" => RkcbVariant::Kickback,
-        _ => RkcbVariant::Plain,
-    };
-}
-
-/// The keycard relocation stance the engine currently holds, as its registry
-/// `value`.
-fn get_rkcb_variant_choice(agreements: &Agreements) -> &'static str {
-    use instinct::RkcbVariant;
-    match agreements.decision.reading.rkcb_variant {
-        RkcbVariant::Redwood => "redwood",
-        RkcbVariant::Kickback => "kickback",
-        _ => "plain",
-    }
-}
-
 /// Lebensohl as an on/off toggle: on = Transfer Lebensohl (the shipped package),
 /// off = none.  `LebensohlStyle::Plain` is deliberately unreachable here — it is a
 /// measured-worse arm, kept for A/B only.
 fn set_lebensohl_toggle(agreements: &mut Agreements, on: bool) {
     use american::LebensohlStyle;
-    set_lebensohl_style(
-        agreements,
-        if on {
-            LebensohlStyle::Transfer
-        } else {
-            LebensohlStyle::Off
-        },
-    );
+    agreements.competition.lebensohl_style = if on {
+        LebensohlStyle::Transfer
+    } else {
+        LebensohlStyle::Off
+    };
 }
 
 /// Whether Lebensohl is live.  See [`advance_sohl_toggle`] on `Plain`.
 fn lebensohl_toggle(agreements: &Agreements) -> bool {
-    lebensohl_style(agreements) != american::LebensohlStyle::Off
+    agreements.competition.lebensohl_style != american::LebensohlStyle::Off
 }
 
 /// Advancer's Lebensohl (after partner's takeout double is overcalled) as an on/off
 /// toggle: on = Transfer Lebensohl (the shipped default), off = none.
 fn set_advance_sohl_toggle(agreements: &mut Agreements, on: bool) {
     use american::LebensohlStyle;
-    set_advance_sohl_style(
-        agreements,
-        if on {
-            LebensohlStyle::Transfer
-        } else {
-            LebensohlStyle::Off
-        },
-    );
+    agreements.defense.advance_sohl_style = if on {
+        LebensohlStyle::Transfer
+    } else {
+        LebensohlStyle::Off
+    };
 }
 
 /// Whether advancer's Lebensohl is live.  `Plain` is unreachable from the UI but
 /// counts as on, so an A/B that selected it is not reported as "off".
 fn advance_sohl_toggle(agreements: &Agreements) -> bool {
-    advance_sohl_style(agreements) != american::LebensohlStyle::Off
+    agreements.defense.advance_sohl_style != american::LebensohlStyle::Off
 }
 
 /// Puppet Stayman as an on/off toggle: on = Puppet (the shipped default, 3♣ Puppet
@@ -1431,82 +1352,80 @@ fn puppet_stayman(agreements: &Agreements) -> bool {
     agreements.decision.reading.notrump_minors == american::PUPPET
 }
 
-/// The registry. Each `default` mirrors [`Agreements::default`]; the test below
-/// keeps them in sync when a knob's default changes.
+/// The registry.  Defaults are read off [`Agreements::default`] when
+/// [`describe_options`] runs, so a row cannot contradict the engine.
 ///
 /// `rustfmt::skip` keeps every row on one line — rustfmt otherwise explodes each
-/// `toggle(...)` whose call exceeds the width into a seven-line block; the table
+/// `toggle(...)` whose call exceeds the width into a multi-line block; the table
 /// reads far better one-setting-per-line.  Keep new rows one line each.
 #[rustfmt::skip]
 static SETTINGS: &[Setting] = &[
     // Openings
-    toggle("open_one_notrump", OPENINGS, "Open 1NT (15–17)", true, set_open_one_notrump, open_one_notrump),
-    Setting::Choice { key: "notrump_shape", section: OPENINGS, label: "1NT opening shape", variants: NOTRUMP_SHAPE_VARIANTS, default: "wide6322", requires: None, set: set_notrump_shape_choice, get: get_notrump_shape_choice },
+    toggle("open_one_notrump", OPENINGS, "Open 1NT (15–17)", "opening.open_one_notrump"),
+    Setting::Choice { key: "notrump_shape", section: OPENINGS, label: "1NT opening shape", variants: NOTRUMP_SHAPE_VARIANTS, requires: None, cell: Cell::Knob("opening.notrump_shape") },
     // Notrump
-    toggle("puppet_stayman", NOTRUMP, "Puppet Stayman (3♣)", true, set_puppet_stayman, puppet_stayman),
-    toggle("garbage_stayman", NOTRUMP, "Garbage Stayman", true, set_garbage_stayman, garbage_stayman),
-    toggle("transfer_super_accept", NOTRUMP, "", false, set_transfer_super_accept, transfer_super_accept),
-    toggle("transfer_slam_try", NOTRUMP, "", true, set_transfer_slam_try, transfer_slam_try),
-    toggle("texas_slam_drive", NOTRUMP, "", true, set_texas_slam_drive, texas_slam_drive),
-    toggle("transfer_gf_majors", NOTRUMP, "", true, set_transfer_gf_majors, transfer_gf_majors),
-    gated("transfer_gf_hearts", NOTRUMP, "", true, set_transfer_gf_hearts, transfer_gf_hearts, "transfer_gf_majors"),
-    toggle("stayman_both_majors", NOTRUMP, "", true, set_stayman_both_majors, stayman_both_majors),
-    toggle("stayman_5card_max", NOTRUMP, "", true, set_stayman_5card_max, stayman_5card_max),
-    toggle("invitational_5card_majors", NOTRUMP, "", true, set_invitational_5card_majors, invitational_5card_majors),
-    toggle("transfer_longer_major", NOTRUMP, "", true, set_transfer_longer_major, transfer_longer_major),
-    toggle("crawling_stayman", NOTRUMP, "", true, set_crawling_stayman, crawling_stayman),
-    toggle("stayman_cue_continuation", NOTRUMP, "", true, set_stayman_cue_continuation, stayman_cue_continuation),
-    toggle("stayman_minor_slam_try", NOTRUMP, "", true, set_stayman_minor_slam_try, stayman_minor_slam_try),
-    toggle("nt_splinter", NOTRUMP, "1NT - 3M splinter (short major, ♦4, ♣5–6)", true, set_nt_splinter, nt_splinter),
+    custom("puppet_stayman", NOTRUMP, "Puppet Stayman (3♣)", set_puppet_stayman, puppet_stayman),
+    toggle("garbage_stayman", NOTRUMP, "Garbage Stayman", "decision.reading.garbage_stayman"),
+    toggle("transfer_super_accept", NOTRUMP, "", "notrump.transfer_super_accept"),
+    toggle("transfer_slam_try", NOTRUMP, "", "notrump.transfer_slam_try"),
+    toggle("texas_slam_drive", NOTRUMP, "", "notrump.texas_slam_drive"),
+    toggle("transfer_gf_majors", NOTRUMP, "", "decision.transfer_gf_majors"),
+    gated("transfer_gf_hearts", NOTRUMP, "", "decision.transfer_gf_hearts", "transfer_gf_majors"),
+    toggle("stayman_both_majors", NOTRUMP, "", "notrump.stayman_both_majors"),
+    toggle("stayman_5card_max", NOTRUMP, "", "notrump.stayman_5card_max"),
+    toggle("invitational_5card_majors", NOTRUMP, "", "notrump.invitational_5card_majors"),
+    toggle("transfer_longer_major", NOTRUMP, "", "notrump.transfer_longer_major"),
+    toggle("crawling_stayman", NOTRUMP, "", "decision.reading.crawling_stayman"),
+    toggle("stayman_cue_continuation", NOTRUMP, "", "notrump.stayman_cue_continuation"),
+    toggle("stayman_minor_slam_try", NOTRUMP, "", "notrump.stayman_minor_slam_try"),
+    toggle("nt_splinter", NOTRUMP, "1NT - 3M splinter (short major, ♦4, ♣5–6)", "decision.reading.nt_splinter"),
     // Competition
-    toggle("lebensohl", COMPETITION, "Lebensohl (over 1NT interference)", true, set_lebensohl_toggle, lebensohl_toggle),
-    toggle("advance_lebensohl", COMPETITION, "Lebensohl advancing a double", true, set_advance_sohl_toggle, advance_sohl_toggle),
-    toggle("splinter_doubled", COMPETITION, "", true, set_splinter_doubled, splinter_doubled),
-    toggle("passed_hand_overcall", COMPETITION, "", true, set_passed_hand_overcall, passed_hand_overcall),
-    toggle("uvu", COMPETITION, "Unusual vs Unusual", true, set_uvu, uvu),
-    toggle("uvu_over_majors", COMPETITION, "Unusual vs Unusual (over majors)", true, set_uvu_over_majors, uvu_over_majors),
-    toggle("uvu_over_minors", COMPETITION, "Unusual vs Unusual (over minors)", false, set_uvu_over_minors, uvu_over_minors),
-    toggle("direct_3nt_stopper", COMPETITION, "", true, set_direct_3nt_stopper, direct_3nt_stopper),
-    toggle("cue_raise_answer", COMPETITION, "", true, set_cue_raise_answer, cue_raise_answer),
-    toggle("cue_minor_raise_answer", COMPETITION, "", true, set_cue_minor_raise_answer, cue_minor_raise_answer),
-    toggle("major_support_double", COMPETITION, "", true, set_major_support_double, major_support_double),
-    toggle("high_overcall_responses", COMPETITION, "", false, set_high_overcall_responses, high_overcall_responses),
-    toggle("nt_high_overcall_responses", COMPETITION, "Over their 3-level overcall of our 1NT", true, set_nt_high_overcall_responses, nt_high_overcall_responses),
-    gated("nt_high_overcall_3nt_stopper", COMPETITION, "…direct 3NT needs its own stopper", false, set_nt_high_overcall_3nt_stopper, nt_high_overcall_3nt_stopper, "nt_high_overcall_responses"),
-    gated("nt_3c_transfers", COMPETITION, "…transfers over their (3♣)", false, set_nt_3c_transfers, nt_3c_transfers, "nt_high_overcall_responses"),
-    gated("nt_high_overcall_x_major_at_four", COMPETITION, "…answer their (3♠) double in hearts at the four level", true, set_nt_high_overcall_x_major_at_four, nt_high_overcall_x_major_at_four, "nt_high_overcall_responses"),
-    gated("nt_high_overcall_x_leave_in", COMPETITION, "…opener passes the takeout double for penalty with four of their suit", true, set_nt_high_overcall_x_leave_in, nt_high_overcall_x_leave_in, "nt_high_overcall_responses"),
-    gated("nt_high_overcall_x_leave_in_three", COMPETITION, "…and on three to two of the top three honors", false, set_nt_high_overcall_x_leave_in_three, nt_high_overcall_x_leave_in_three, "nt_high_overcall_x_leave_in"),
-    toggle("jordan_truscott", COMPETITION, "Jordan / Truscott 2NT", true, set_jordan_truscott, jordan_truscott),
-    toggle("delayed_cue", COMPETITION, "", false, set_delayed_cue, delayed_cue),
-    toggle("competition_over_stayman", COMPETITION, "", true, set_competition_over_stayman, competition_over_stayman),
-    gated("competition_over_minor_transfer", COMPETITION, "", true, set_competition_over_minor_transfer, competition_over_minor_transfer, "puppet_stayman"),
-    gated("competition_over_diamond_transfer", COMPETITION, "", true, set_competition_over_diamond_transfer, competition_over_diamond_transfer, "puppet_stayman"),
-    Setting::Choice { key: "multi_stopper_ask", section: COMPETITION, label: "Multi 3♠ stopper ask", variants: MULTI_STOPPER_ASK_VARIANTS, default: "off", requires: Some("opponent:notrump_defense=woolsey"), set: set_multi_stopper_ask_choice, get: get_multi_stopper_ask_choice },
-    toggle("defense_to_2c_landy_cues", COMPETITION, "Landy counter: GF minor cues", false, set_defense_to_2c_landy_cues, defense_to_2c_landy_cues),
+    custom("lebensohl", COMPETITION, "Lebensohl (over 1NT interference)", set_lebensohl_toggle, lebensohl_toggle),
+    custom("advance_lebensohl", COMPETITION, "Lebensohl advancing a double", set_advance_sohl_toggle, advance_sohl_toggle),
+    toggle("splinter_doubled", COMPETITION, "", "competition.splinter_doubled"),
+    toggle("passed_hand_overcall", COMPETITION, "", "defense.passed_hand_overcall"),
+    toggle("uvu", COMPETITION, "Unusual vs Unusual", "competition.uvu"),
+    toggle("uvu_over_majors", COMPETITION, "Unusual vs Unusual (over majors)", "competition.uvu_over_majors"),
+    toggle("uvu_over_minors", COMPETITION, "Unusual vs Unusual (over minors)", "competition.uvu_over_minors"),
+    toggle("direct_3nt_stopper", COMPETITION, "", "competition.direct_3nt_stopper"),
+    toggle("cue_raise_answer", COMPETITION, "", "competition.cue_raise_answer"),
+    toggle("cue_minor_raise_answer", COMPETITION, "", "competition.cue_minor_raise_answer"),
+    toggle("major_support_double", COMPETITION, "", "competition.major_support_double"),
+    toggle("high_overcall_responses", COMPETITION, "", "competition.high_overcall_responses"),
+    toggle("nt_high_overcall_responses", COMPETITION, "Over their 3-level overcall of our 1NT", "competition.nt_high_overcall_responses"),
+    gated("nt_high_overcall_3nt_stopper", COMPETITION, "…direct 3NT needs its own stopper", "competition.nt_high_overcall_3nt_stopper", "nt_high_overcall_responses"),
+    gated("nt_3c_transfers", COMPETITION, "…transfers over their (3♣)", "competition.nt_3c_transfers", "nt_high_overcall_responses"),
+    gated("nt_high_overcall_x_major_at_four", COMPETITION, "…answer their (3♠) double in hearts at the four level", "competition.nt_high_overcall_x_major_at_four", "nt_high_overcall_responses"),
+    gated("nt_high_overcall_x_leave_in", COMPETITION, "…opener passes the takeout double for penalty with four of their suit", "competition.nt_high_overcall_x_leave_in", "nt_high_overcall_responses"),
+    gated("nt_high_overcall_x_leave_in_three", COMPETITION, "…and on three to two of the top three honors", "competition.nt_high_overcall_x_leave_in_three", "nt_high_overcall_x_leave_in"),
+    toggle("jordan_truscott", COMPETITION, "Jordan / Truscott 2NT", "competition.jordan_truscott"),
+    toggle("delayed_cue", COMPETITION, "", "competition.delayed_cue"),
+    toggle("competition_over_stayman", COMPETITION, "", "competition.competition_over_stayman"),
+    gated("competition_over_minor_transfer", COMPETITION, "", "competition.competition_over_minor_transfer", "puppet_stayman"),
+    gated("competition_over_diamond_transfer", COMPETITION, "", "competition.competition_over_diamond_transfer", "puppet_stayman"),
+    Setting::Choice { key: "multi_stopper_ask", section: COMPETITION, label: "Multi 3♠ stopper ask", variants: MULTI_STOPPER_ASK_VARIANTS, requires: Some("opponent:notrump_defense=woolsey"), cell: Cell::Choice(set_multi_stopper_ask_choice, get_multi_stopper_ask_choice) },
+    toggle("defense_to_2c_landy_cues", COMPETITION, "Landy counter: GF minor cues", "competition.defense_2c_landy_cues"),
     // Implies the cues row above (N1c keeps them and re-rungs what is below).
     // The stack below it shipped default-on 2026-08-14 (pooled two-seed
     // win|win vs the base counter); all four engage only under the
     // `their_2c_landy` declaration.
-    toggle("defense_to_2c_landy_transfer", COMPETITION, "Landy counter: club transfer + INV minors", true, set_defense_to_2c_landy_transfer, defense_to_2c_landy_transfer),
-    toggle("defense_to_2c_landy_cue_floor", COMPETITION, "Landy counter: 10+ cue floor", true, set_defense_to_2c_landy_cue_floor, defense_to_2c_landy_cue_floor),
-    toggle("defense_to_2c_landy_fit_answers", COMPETITION, "Landy counter: notrump on doubleton support", true, set_defense_to_2c_landy_fit_answers, defense_to_2c_landy_fit_answers),
-    toggle("defense_to_2c_landy_competition", COMPETITION, "Landy counter: interfered tails", true, set_defense_to_2c_landy_competition, defense_to_2c_landy_competition),
-    toggle("leaping_michaels", COMPETITION, "Leaping Michaels", true, set_leaping_michaels, leaping_michaels),
-    toggle("responsive_takeout", COMPETITION, "Responsive doubles", true, set_responsive_takeout, responsive_takeout),
-    toggle("rich_advance_double", COMPETITION, "", true, set_rich_advance_double, rich_advance_double),
-    gated("advance_rubens", COMPETITION, "Rubens advances", false, set_advance_rubens, advance_rubens, "rich_advance_double"),
-    toggle("nt_overcall_gladiator", COMPETITION, "Gladiator (1NT-overcall advance)", false, set_nt_overcall_gladiator, nt_overcall_gladiator),
+    toggle("defense_to_2c_landy_transfer", COMPETITION, "Landy counter: club transfer + INV minors", "competition.defense_2c_landy_transfer"),
+    toggle("defense_to_2c_landy_cue_floor", COMPETITION, "Landy counter: 10+ cue floor", "competition.defense_2c_landy_cue_floor"),
+    toggle("defense_to_2c_landy_fit_answers", COMPETITION, "Landy counter: notrump on doubleton support", "competition.defense_2c_landy_fit_answers"),
+    toggle("defense_to_2c_landy_competition", COMPETITION, "Landy counter: interfered tails", "competition.defense_2c_landy_competition"),
+    toggle("leaping_michaels", COMPETITION, "Leaping Michaels", "defense.leaping_michaels_enabled"),
+    toggle("responsive_takeout", COMPETITION, "Responsive doubles", "defense.responsive_takeout_enabled"),
+    toggle("rich_advance_double", COMPETITION, "", "defense.rich_advance_double_enabled"),
+    gated("advance_rubens", COMPETITION, "Rubens advances", "defense.advance_rubens_enabled", "rich_advance_double"),
+    toggle("nt_overcall_gladiator", COMPETITION, "Gladiator (1NT-overcall advance)", "decision.reading.nt_overcall_gladiator"),
     // Negative-double school over their overcall — the enum-backed radio family
     Setting::Choice {
         key: "negative_double_shape",
         section: COMPETITION,
         label: "Negative double (over their overcall)",
         variants: NEGATIVE_DOUBLE_VARIANTS,
-        default: "modern",
         requires: None,
-        set: set_negative_double_choice,
-        get: get_negative_double_choice,
+        cell: Cell::Knob("competition.negative_double_shape"),
     },
     // Defense to their 1NT — the radio family is the enum-backed choice
     Setting::Choice {
@@ -1514,49 +1433,48 @@ static SETTINGS: &[Setting] = &[
         section: DEFENSE,
         label: "Defense system",
         variants: NOTRUMP_DEFENSE_VARIANTS,
-        default: "natural",
         requires: None,
-        set: set_notrump_defense_choice,
-        get: get_notrump_defense_choice,
+        cell: Cell::Choice(set_notrump_defense_choice, get_notrump_defense_choice),
     },
-    gated("direct_dont_four_four", DEFENSE, "", true, set_direct_dont_four_four, direct_dont_four_four, "notrump_defense=direct_dont"),
-    toggle("stayman_defense", DEFENSE, "", false, set_stayman_defense, stayman_defense),
-    toggle("transfer_defense", DEFENSE, "", false, set_transfer_defense, transfer_defense),
-    toggle("minor_transfer_defense", DEFENSE, "", false, set_minor_transfer_defense, minor_transfer_defense),
+    gated("direct_dont_four_four", DEFENSE, "", "defense.direct_dont_four_four", "notrump_defense=direct_dont"),
+    toggle("stayman_defense", DEFENSE, "", "defense.stayman_defense_enabled"),
+    toggle("transfer_defense", DEFENSE, "", "defense.transfer_defense_enabled"),
+    toggle("minor_transfer_defense", DEFENSE, "", "defense.minor_transfer_defense_enabled"),
     // Rebids & responses
-    toggle("second_suit_agreement", REBIDS, "", true, set_second_suit_agreement, second_suit_agreement),
-    toggle("game_backstop", REBIDS, "2/1 game backstop (retired)", false, set_game_backstop, game_backstop_enabled),
-    toggle("fourth_suit_forcing", REBIDS, "Fourth suit forcing", true, set_fourth_suit_forcing, fourth_suit_forcing),
-    toggle("meckstroth_adjunct", REBIDS, "Meckstroth adjunct", true, set_meckstroth_adjunct, meckstroth_adjunct),
-    toggle("limit_raise_acceptance", REBIDS, "", true, set_limit_raise_acceptance, limit_raise_acceptance),
+    toggle("second_suit_agreement", REBIDS, "", "game_force.second_suit_agreement"),
+    toggle("game_backstop", REBIDS, "2/1 game backstop (retired)", "game_force.game_backstop"),
+    toggle("fourth_suit_forcing", REBIDS, "Fourth suit forcing", "rebid.fourth_suit_forcing"),
+    toggle("meckstroth_adjunct", REBIDS, "Meckstroth adjunct", "rebid.meckstroth_adjunct"),
+    toggle("limit_raise_acceptance", REBIDS, "", "response.limit_raise_acceptance"),
+    toggle("drury", REBIDS, "Reverse Drury (passed hand)", "response.drury"),
     // Floor (instinct)
-    toggle("one_nt_runout", FLOOR, "", true, set_one_nt_runout, one_nt_runout),
-    gated("one_nt_runout_universal", FLOOR, "", true, set_one_nt_runout_universal, one_nt_runout_universal, "one_nt_runout"),
-    toggle("settle_floor", FLOOR, "", true, set_settle_floor, settle_floor),
-    toggle("rubens_advances", FLOOR, "", false, set_rubens_advances, rubens_advances),
-    toggle("floor_rkcb", FLOOR, "", true, set_floor_rkcb, floor_rkcb),
+    toggle("one_nt_runout", FLOOR, "", "decision.instinct.one_nt_runout"),
+    gated("one_nt_runout_universal", FLOOR, "", "decision.instinct.one_nt_runout_universal", "one_nt_runout"),
+    toggle("settle_floor", FLOOR, "", "decision.instinct.settle_floor"),
+    toggle("rubens_advances", FLOOR, "", "decision.reading.rubens_advances"),
+    toggle("floor_rkcb", FLOOR, "", "decision.reading.floor_rkcb"),
     // One radio family, not two checkboxes: the old redwood/kickback toggles let
     // the UI show both checked, a state the engine cannot play.  `rkcb_minors`
     // used to sit here too and was dropped for the same reason — either
     // relocation implies the minors' reach (`minor_asks_now`), so the checkbox
     // was inert on two of its six cells.
-    Setting::Choice { key: "rkcb_variant", section: FLOOR, label: "Keycard ask relocation", variants: RKCB_VARIANT_VARIANTS, default: "plain", requires: Some("floor_rkcb"), set: set_rkcb_variant_choice, get: get_rkcb_variant_choice },
-    toggle("two_over_one_force", FLOOR, "2/1 forces game", true, set_two_over_one_force, two_over_one_force),
-    gated("penalize_escape_stack", FLOOR, "", true, set_penalize_escape_stack, penalize_escape_stack, "one_nt_runout"),
-    gated("penalize_escape_values", FLOOR, "", true, set_penalize_escape_values, penalize_escape_values, "one_nt_runout"),
-    gated("uvu_encircle", FLOOR, "UVU penalty procedure", true, set_uvu_encircle, uvu_encircle, "uvu"),
-    gated("penalty_latch", FLOOR, "", true, set_penalty_latch, penalty_latch, "notrump_defense=natural"),
-    gated("penalty_no_pull", FLOOR, "", true, set_penalty_no_pull, penalty_no_pull, "penalty_latch"),
-    toggle("advancer_xx_runout", FLOOR, "", true, set_advancer_xx_runout, advancer_xx_runout),
-    toggle("forcing_ceiling_read", FLOOR, "", true, set_forcing_ceiling_read, forcing_ceiling_read),
-    toggle("doubler_xx_runout", FLOOR, "", true, set_doubler_xx_runout, doubler_xx_runout),
+    Setting::Choice { key: "rkcb_variant", section: FLOOR, label: "Keycard ask relocation", variants: RKCB_VARIANT_VARIANTS, requires: Some("floor_rkcb"), cell: Cell::Knob("decision.reading.rkcb_variant") },
+    toggle("two_over_one_force", FLOOR, "2/1 forces game", "decision.two_over_one_force"),
+    gated("penalize_escape_stack", FLOOR, "", "decision.instinct.penalize_escape_stack", "one_nt_runout"),
+    gated("penalize_escape_values", FLOOR, "", "decision.instinct.penalize_escape_values", "one_nt_runout"),
+    gated("uvu_encircle", FLOOR, "UVU penalty procedure", "decision.instinct.uvu_encircle", "uvu"),
+    gated("penalty_latch", FLOOR, "", "decision.reading.penalty_latch", "notrump_defense=natural"),
+    gated("penalty_no_pull", FLOOR, "", "decision.instinct.penalty_no_pull", "penalty_latch"),
+    toggle("advancer_xx_runout", FLOOR, "", "decision.instinct.advancer_xx_runout"),
+    toggle("forcing_ceiling_read", FLOOR, "", "decision.instinct.forcing_ceiling_read"),
+    toggle("doubler_xx_runout", FLOOR, "", "instinct.doubler_xx_runout"),
     // Inference (auction reading)
-    gated("their_multi_reading", INFERENCE, "Read their Multi as 6+♥ or 6+♠", true, set_their_multi_reading, their_multi_reading, "opponent:notrump_defense=woolsey"),
-    toggle("nt_invite_inference", INFERENCE, "", true, set_nt_invite, nt_invite),
-    gated("rubens_transfer_reading", INFERENCE, "", true, set_rubens_transfer, rubens_transfer, "rubens_advances"),
-    toggle("fallback_projection", INFERENCE, "", true, set_fallback_projection, fallback_projection),
-    toggle("control_bid_reading", INFERENCE, "", true, set_control_bid, control_bid),
-    toggle("rule_accept", INFERENCE, "", true, set_rule_accept, rule_accept),
+    gated("their_multi_reading", INFERENCE, "Read their Multi as 6+♥ or 6+♠", "decision.reading.their_multi_reading", "opponent:notrump_defense=woolsey"),
+    toggle("nt_invite_inference", INFERENCE, "", "decision.reading.nt_invite"),
+    gated("rubens_transfer_reading", INFERENCE, "", "decision.reading.rubens_transfer", "rubens_advances"),
+    toggle("fallback_projection", INFERENCE, "", "decision.reading.fallback_projection"),
+    toggle("control_bid_reading", INFERENCE, "", "decision.reading.control_bid"),
+    toggle("rule_accept", INFERENCE, "", "decision.reading.rule_accept"),
 ];
 
 /// The in-browser half of `examples/binky`'s benchmark: fix N-S, reshuffle E-W.
@@ -1671,8 +1589,8 @@ impl Binky {
 /// Unknown partnership names and keys are a no-op.
 #[wasm_bindgen]
 pub fn set_option(pair: &str, key: &str, on: bool) {
-    if let Some(Setting::Toggle { set, .. }) = SETTINGS.iter().find(|s| s.key() == key) {
-        amend(pair, |agreements| set(agreements, on));
+    if let Some(Setting::Toggle { cell, .. }) = SETTINGS.iter().find(|s| s.key() == key) {
+        amend(pair, |agreements| cell.set(agreements, &on.to_string()));
     }
 }
 
@@ -1680,8 +1598,8 @@ pub fn set_option(pair: &str, key: &str, on: bool) {
 /// Unknown partnership names and keys are a no-op.
 #[wasm_bindgen]
 pub fn set_choice(pair: &str, key: &str, value: &str) {
-    if let Some(Setting::Choice { set, .. }) = SETTINGS.iter().find(|s| s.key() == key) {
-        amend(pair, |agreements| set(agreements, value));
+    if let Some(Setting::Choice { cell, .. }) = SETTINGS.iter().find(|s| s.key() == key) {
+        amend(pair, |agreements| cell.set(agreements, value));
     }
 }
 
@@ -1694,7 +1612,15 @@ pub fn set_choice(pair: &str, key: &str, value: &str) {
 /// is immaterial.
 #[wasm_bindgen]
 pub fn describe_options() -> String {
-    serde_json::to_string(SETTINGS).expect("settings registry serialises")
+    let rows: Vec<serde_json::Value> = SETTINGS
+        .iter()
+        .map(|setting| {
+            let mut row = serde_json::to_value(setting).expect("settings registry serialises");
+            row["default"] = setting.default();
+            row
+        })
+        .collect();
+    serde_json::Value::Array(rows).to_string()
 }
 
 #[cfg(test)]
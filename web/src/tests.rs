@@ -1,4 +1,5 @@
 use super::*;
+use contract_bridge::Bid;
 
 fn parse(snapshot: &str) -> serde_json::Value {
     serde_json::from_str(snapshot).expect("snapshot is valid JSON")
@@ -98,6 +99,28 @@ fn hint_prices_every_strain_on_a_live_auction() {
     assert_eq!(table.hint(), "null", "no hint once the auction has ended");
 }
 
+/// The post-mortem waits for the end of the auction, then prices every call
+/// the human's side made and flags at most one as the costliest.
+#[test]
+fn post_mortem_prices_our_calls_once_the_auction_ends() {
+    let mut table = WebTable::new("12345");
+    let mut snap = parse(&table.deal_practice("S", "N", "none", 0));
+    assert_eq!(table.post_mortem(4), "null", "no post-mortem mid-auction");
+
+    while snap["your_turn"] == true {
+        let legal = snap["legal"].as_array().expect("legal is an array");
+        let call = legal[0].as_str().expect("a legal call").to_string();
+        snap = parse(&table.bid(&call));
+    }
+    let rows = parse(&table.post_mortem(4));
+    let rows = rows.as_array().expect("an ended auction has a post-mortem");
+    assert!(!rows.is_empty());
+    let ours = |row: &serde_json::Value| row["seat"] == "N" || row["seat"] == "S";
+    assert!(rows.iter().all(ours));
+    assert!(rows.iter().all(|row| row["regret"].as_f64() >= Some(0.0)));
+    assert!(rows.iter().filter(|row| row["costliest"] == true).count() <= 1);
+}
+
 #[test]
 fn practice_board_runs_to_completion() {
     let mut table = WebTable::new("12345");
@@ -286,86 +309,67 @@ fn registry_is_well_formed() {
     }
 }
 
-/// Every registry `default` must mirror [`Agreements::default`].
-///
-/// Not cosmetic: `app.js` stores only *deltas* against these values, and the
-/// Settings reset button pushes them into the engine — so a drifted row rebids
-/// the board for anyone who touches the panel. `rich_advance_double`,
-/// `rubens_advances` and `fuzzy_fifths` all contradicted the engine for weeks
-/// (see `docs/bidding-options.md`); nothing but CI's absence hid them.
+/// Every knob-backed row names a crate knob that can hold what the row sends.
 ///
-/// Reads each field back rather than bidding boards and diffing auctions: many
-/// continuations need a specific sequence that random deals rarely reach.
+/// Defaults cannot drift any more — `describe_options` reads them off
+/// [`Agreements::default`] — but a row can still name a key the crate renamed,
+/// or offer a radio `value` the knob does not spell.  `app.js` stores only
+/// *deltas* against the defaults, so such a row would silently do nothing.
 #[test]
-fn registry_defaults_match_the_engine() {
-    let agreements = Agreements::default();
+fn knob_rows_name_crate_knobs() {
+    use pons::bidding::agreements::{Kind, knob};
+
     for setting in SETTINGS {
+        let Cell::Knob(path) = setting.cell() else {
+            continue;
+        };
+        let knob = knob(path).unwrap_or_else(|| panic!("{} names no knob {path}", setting.key()));
         match setting {
-            Setting::Toggle {
-                key, default, get, ..
-            } => assert_eq!(
-                get(&agreements),
-                *default,
-                "toggle {key} contradicts the engine"
-            ),
-            Setting::Choice {
-                key, default, get, ..
-            } => assert_eq!(
-                get(&agreements),
-                *default,
-                "choice {key} contradicts the engine"
-            ),
+            Setting::Toggle { key, .. } => {
+                assert_eq!(knob.kind, Kind::Toggle, "toggle {key} is over {path}");
+            }
+            Setting::Choice { key, variants, .. } => {
+                for variant in *variants {
+                    assert!(
+                        knob.variants.contains(&variant.value),
+                        "choice {key} offers {}, which {path} cannot hold",
+                        variant.value
+                    );
+                }
+            }
         }
     }
 }
 
 /// Each row's `get` must observe its own `set` — not a neighbour's cell.
 ///
-/// `registry_defaults_match_the_engine` cannot see this: a getter wired to the
-/// wrong knob still agrees with `default` whenever the two knobs share one. The
-/// getters were generated mechanically against a cell name, so that is exactly
-/// the mistake to expect, and `penalize_escape_stack` / `penalize_escape_values`
-/// are the type case — adjacent, identically defaulted, one letter apart.
+/// The hand-written cells are the ones to watch: a getter wired to the wrong
+/// knob still agrees with the default whenever the two knobs share one.
+/// `penalize_escape_stack` / `penalize_escape_values` were the type case back
+/// when every row had its own pair — adjacent, identically defaulted, one
+/// letter apart.
 #[test]
 fn every_registry_getter_observes_its_own_setter() {
     for setting in SETTINGS {
         let mut agreements = Agreements::default();
-        match setting {
-            Setting::Toggle {
-                key,
-                default,
-                get,
-                set,
-                ..
-            } => {
-                set(&mut agreements, !*default);
-                assert_eq!(
-                    get(&agreements),
-                    !*default,
-                    "toggle {key} does not read its own field"
-                );
-            }
-            Setting::Choice {
-                key,
-                default,
-                variants,
-                get,
-                set,
-                ..
-            } => {
-                let other = variants
-                    .iter()
-                    .map(|v| v.value)
-                    .find(|v| v != default)
-                    .expect("a choice has a second variant");
-                set(&mut agreements, other);
-                assert_eq!(
-                    get(&agreements),
-                    other,
-                    "choice {key} does not read its own field"
-                );
-            }
-        }
+        let cell = setting.cell();
+        let default = cell.get(&agreements);
+        let other = match setting {
+            Setting::Toggle { .. } => (default == "false").to_string(),
+            Setting::Choice { variants, .. } => variants
+                .iter()
+                .map(|v| v.value)
+                .find(|v| *v != default)
+                .expect("a choice has a second variant")
+                .to_owned(),
+        };
+        cell.set(&mut agreements, &other);
+        assert_eq!(
+            cell.get(&agreements),
+            other,
+            "{} does not read its own field",
+            setting.key()
+        );
     }
 }
 